@@ -2,10 +2,86 @@ pub mod rule_suggest;
 pub mod rules;
 pub mod tag_suggest;
 
+use crate::cache::ScanCache;
 use crate::scanner::{AssetInfo, ScanResult};
+use parking_lot::RwLock;
+use rules::duplicate::HashProgress;
 use rules::{Rule, RuleConfig};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use thiserror::Error;
+
+/// An analysis pass stopped early because its cancellation flag was set.
+#[derive(Debug, Error)]
+#[error("Analysis cancelled")]
+pub struct Cancelled;
+
+/// Which pass of a full analysis is running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AnalysisPhase {
+    /// Per-asset rules; counts assets checked.
+    Rules,
+    /// Duplicate hashing; counts bytes read.
+    Duplicates,
+    /// Missing references, PBR set, DCC source.
+    CrossAsset,
+    Completed,
+}
+
+/// Payload of the `analysis-progress-{project_id}` event. `current` /
+/// `total` are assets checked during `rules` and bytes hashed during
+/// `duplicates`; later phases keep the rules counts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct AnalysisProgress {
+    pub phase: AnalysisPhase,
+    pub current: u64,
+    pub total: u64,
+}
+
+/// Progress of one analysis run, shared with whoever reports it.
+#[derive(Debug)]
+pub struct AnalysisTracker {
+    phase: RwLock<AnalysisPhase>,
+    checked: AtomicU64,
+    total: AtomicU64,
+    pub hashing: HashProgress,
+}
+
+impl Default for AnalysisTracker {
+    fn default() -> Self {
+        Self {
+            phase: RwLock::new(AnalysisPhase::Rules),
+            checked: AtomicU64::new(0),
+            total: AtomicU64::new(0),
+            hashing: HashProgress::default(),
+        }
+    }
+}
+
+impl AnalysisTracker {
+    pub fn set_phase(&self, phase: AnalysisPhase) {
+        *self.phase.write() = phase;
+    }
+
+    pub fn snapshot(&self) -> AnalysisProgress {
+        let phase = *self.phase.read();
+        if phase == AnalysisPhase::Duplicates {
+            let hashing = self.hashing.snapshot();
+            return AnalysisProgress {
+                phase,
+                current: hashing.bytes_hashed,
+                total: hashing.bytes_total,
+            };
+        }
+        AnalysisProgress {
+            phase,
+            current: self.checked.load(Ordering::Relaxed),
+            total: self.total.load(Ordering::Relaxed),
+        }
+    }
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
@@ -25,11 +101,11 @@ pub struct Issue {
     pub suggestion: Option<String>,
     pub auto_fixable: bool,
     /// Every member of the same finding, root-relative and sorted, with the
-    /// kept "original" first. Currently only the `duplicate` rule fills this
-    /// (all files sharing one content hash); the frontend uses it to collapse
-    /// per-file duplicate issues into a single group card. `None` for rules
-    /// without grouped findings — omitted from serialized output so exports
-    /// stay byte-identical for those.
+    /// kept "original" first. Filled by the `duplicate` rule (all files
+    /// sharing one content hash) and `case_collision`; the frontend uses
+    /// it to collapse per-file duplicate issues into a single group card.
+    /// `None` for rules without grouped findings — omitted from serialized
+    /// output so exports stay byte-identical for those.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub related_paths: Option<Vec<String>>,
 }
@@ -86,13 +162,36 @@ pub struct Analyzer {
     rules: Vec<Box<dyn Rule>>,
 }
 
+/// Assets of one extension that no enabled type-specific rule applies to,
+/// as returned by `get_unanalyzed_assets`.
+#[derive(Debug, Clone, Serialize)]
+pub struct UnanalyzedGroup {
+    /// Lowercase, without the dot; empty for extensionless files.
+    pub extension: String,
+    pub count: usize,
+    pub total_size: u64,
+    pub paths: Vec<String>,
+}
+
+/// How one rule fared over a project, as returned by `get_rule_coverage`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RuleCoverage {
+    /// Assets the rule `applies_to`.
+    pub applicable: usize,
+    /// Applicable assets it raised an issue for.
+    pub failed: usize,
+    /// `(applicable - failed) / applicable`; `None` when nothing applied.
+    pub pass_rate: Option<f64>,
+}
+
 impl Analyzer {
     pub fn new() -> Self {
         Self { rules: Vec::new() }
     }
 
-    /// Create analyzer with default rules based on config
-    pub fn with_config(config: &RuleConfig) -> Self {
+    /// Create analyzer with default rules based on config, for a project
+    /// rooted at `root` (rules that read folder names look only below it)
+    pub fn with_config(config: &RuleConfig, root: &str) -> Self {
         let mut analyzer = Self::new();
 
         // Add naming rules
@@ -114,6 +213,32 @@ impl Analyzer {
         if config.texture.color_space.enabled {
             analyzer.add_rule(Box::new(rules::texture_colorspace::TextureColorSpaceRule));
         }
+        if config.texture.import_size.enabled {
+            analyzer.add_rule(Box::new(
+                rules::texture_import_size::TextureImportSizeRule::new(
+                    config.texture.import_size.clone(),
+                ),
+            ));
+        }
+        if config.texture.format_rules.enabled {
+            analyzer.add_rule(Box::new(rules::texture_format::TextureFormatRule::new(
+                config.texture.format_rules.clone(),
+                root,
+            )));
+        }
+        if config.texture.mipmaps.enabled {
+            analyzer.add_rule(Box::new(rules::texture_mipmap::TextureMipmapRule::new(
+                config.texture.mipmaps.clone(),
+                root,
+            )));
+        }
+        if config.texture.bit_depth.enabled {
+            analyzer.add_rule(Box::new(
+                rules::texture_bit_depth::TextureBitDepthRule::new(
+                    config.texture.bit_depth.clone(),
+                ),
+            ));
+        }
 
         // Add model rules
         if config.model.enabled {
@@ -129,6 +254,10 @@ impl Analyzer {
             )));
         }
 
+        if config.empty_asset.enabled {
+            analyzer.add_rule(Box::new(rules::empty_asset::EmptyAssetRule));
+        }
+
         analyzer
     }
 
@@ -151,22 +280,143 @@ impl Analyzer {
         issues
     }
 
+    /// Assets no registered type-specific rule applies to, grouped by
+    /// extension, largest group first. Naming / empty-file checks and the
+    /// cross-asset passes (duplicates, missing references) look at every
+    /// file and don't count as coverage.
+    pub fn unanalyzed(&self, assets: &[AssetInfo]) -> Vec<UnanalyzedGroup> {
+        let mut groups: HashMap<String, UnanalyzedGroup> = HashMap::new();
+        for asset in assets {
+            let covered = self
+                .rules
+                .iter()
+                .any(|rule| rule.type_specific() && rule.applies_to(asset));
+            if covered {
+                continue;
+            }
+            let extension = asset.extension.to_lowercase();
+            let group = groups
+                .entry(extension.clone())
+                .or_insert_with(|| UnanalyzedGroup {
+                    extension,
+                    count: 0,
+                    total_size: 0,
+                    paths: Vec::new(),
+                });
+            group.count += 1;
+            group.total_size += asset.size;
+            group.paths.push(asset.path.clone());
+        }
+
+        let mut groups: Vec<UnanalyzedGroup> = groups.into_values().collect();
+        for group in &mut groups {
+            group.paths.sort();
+        }
+        groups.sort_by(|a, b| {
+            b.count
+                .cmp(&a.count)
+                .then_with(|| a.extension.cmp(&b.extension))
+        });
+        groups
+    }
+
+    /// Per emitted `Issue::rule_id` (`texture.pot`, not the `texture`
+    /// family), how many of `assets` its rule applies to and how many of
+    /// those fail it — one `check` per applicable pair, as `analyze` runs.
+    /// A rule reports at most one issue per asset, so an asset failing
+    /// several sub-rules counts against the first only. Every id a
+    /// registered rule can emit is listed, even when nothing applied.
+    /// Cross-asset ids (`duplicate`, `missing_reference`, ...) aren't:
+    /// those checks have no per-asset notion of applying.
+    pub fn coverage(
+        &self,
+        assets: &[AssetInfo],
+        is_excluded: impl Fn(&str) -> bool,
+    ) -> HashMap<String, RuleCoverage> {
+        let mut applicable: Vec<usize> = vec![0; self.rules.len()];
+        let mut failed: Vec<HashMap<String, usize>> = vec![HashMap::new(); self.rules.len()];
+        for asset in assets.iter().filter(|a| !is_excluded(&a.path)) {
+            for (i, rule) in self.rules.iter().enumerate() {
+                if rule.applies_to(asset) {
+                    applicable[i] += 1;
+                    if let Some(issue) = rule.check(asset) {
+                        *failed[i].entry(issue.rule_id).or_default() += 1;
+                    }
+                }
+            }
+        }
+
+        let mut coverage = HashMap::new();
+        for ((rule, applicable), mut failed) in self.rules.iter().zip(applicable).zip(failed) {
+            for entry in rule.describe() {
+                failed.entry(entry.rule_id.to_string()).or_default();
+            }
+            for (rule_id, failed) in failed {
+                let pass_rate =
+                    (applicable > 0).then(|| (applicable - failed) as f64 / applicable as f64);
+                coverage.insert(
+                    rule_id,
+                    RuleCoverage {
+                        applicable,
+                        failed,
+                        pass_rate,
+                    },
+                );
+            }
+        }
+        coverage
+    }
+
     /// Analyze all assets in a scan result
     pub fn analyze(&self, scan_result: &ScanResult) -> AnalysisResult {
+        self.analyze_with_progress(scan_result, None)
+    }
+
+    /// `analyze`, counting each checked asset into `tracker`.
+    pub fn analyze_with_progress(
+        &self,
+        scan_result: &ScanResult,
+        tracker: Option<&AnalysisTracker>,
+    ) -> AnalysisResult {
         let mut result = AnalysisResult::new();
+        if let Some(tracker) = tracker {
+            tracker.checked.store(0, Ordering::Relaxed);
+            tracker
+                .total
+                .store(scan_result.assets.len() as u64, Ordering::Relaxed);
+        }
 
         for asset in &scan_result.assets {
             for issue in self.analyze_asset(asset) {
                 result.add_issue(issue);
             }
+            if let Some(tracker) = tracker {
+                tracker.checked.fetch_add(1, Ordering::Relaxed);
+            }
         }
 
         result
     }
 
-    /// Check for duplicate files across all assets
-    pub fn find_duplicates(&self, scan_result: &ScanResult) -> AnalysisResult {
-        rules::duplicate::find_duplicates(&scan_result.assets, &scan_result.root_path)
+    /// Check for duplicate files across all assets, hashing with the
+    /// configured algorithm. Setting `cancel` abandons the pass; `progress`
+    /// counts the bytes read; `cache` supplies and receives content hashes.
+    pub fn find_duplicates(
+        &self,
+        scan_result: &ScanResult,
+        config: &rules::duplicate::DuplicateConfig,
+        cancel: Option<&AtomicBool>,
+        progress: Option<&rules::duplicate::HashProgress>,
+        cache: Option<&mut ScanCache>,
+    ) -> Result<AnalysisResult, Cancelled> {
+        rules::duplicate::find_duplicates(
+            &scan_result.assets,
+            &scan_result.root_path,
+            config,
+            cancel,
+            progress,
+            cache,
+        )
     }
 
     /// Check for Unity GUID references that don't resolve to any asset in
@@ -217,6 +467,52 @@ impl Default for Analyzer {
     }
 }
 
+/// Every rule id the analyzer can emit, with its default severity. Built
+/// from default configs regardless of `enabled` so the settings UI can
+/// list rules the user has switched off.
+pub fn rule_catalog() -> Vec<rules::RuleDescription> {
+    let config = RuleConfig::default();
+    let per_asset: Vec<Box<dyn Rule>> = vec![
+        Box::new(rules::naming::NamingRule::new(config.naming)),
+        Box::new(rules::texture::TextureRule::new(config.texture.clone())),
+        Box::new(rules::texture_colorspace::TextureColorSpaceRule),
+        Box::new(rules::texture_import_size::TextureImportSizeRule::new(
+            config.texture.import_size,
+        )),
+        Box::new(rules::texture_format::TextureFormatRule::new(
+            config.texture.format_rules,
+            "",
+        )),
+        Box::new(rules::texture_mipmap::TextureMipmapRule::new(
+            config.texture.mipmaps,
+            "",
+        )),
+        Box::new(rules::texture_bit_depth::TextureBitDepthRule::new(
+            config.texture.bit_depth,
+        )),
+        Box::new(rules::model::ModelRule::new(config.model)),
+        Box::new(rules::audio::AudioRule::new(config.audio)),
+        Box::new(rules::empty_asset::EmptyAssetRule),
+    ];
+
+    let mut catalog: Vec<_> = per_asset.iter().flat_map(|r| r.describe()).collect();
+    catalog.extend(rules::duplicate::describe());
+    catalog.extend(rules::missing_reference::describe());
+    catalog.extend(rules::pbr_set::describe());
+    catalog.extend(rules::dcc_source::describe());
+    catalog.extend(rules::lod_chain::describe());
+    catalog.extend(rules::extension_mismatch::describe());
+    catalog.extend(rules::missing_meta::describe());
+    catalog.extend(rules::case_collision::describe());
+    catalog.extend(rules::text_duplicate::describe());
+    catalog.extend(rules::line_endings::describe());
+    catalog.extend(rules::manifest::describe());
+    catalog.extend(crate::unity_material_textures::describe());
+    catalog.extend(crate::unity_missing_scripts::describe());
+    catalog.extend(crate::unity_unused_scripts::describe());
+    catalog
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -351,12 +647,67 @@ mod tests {
     #[test]
     fn test_analyzer_with_default_config() {
         let config = RuleConfig::default();
-        let analyzer = Analyzer::with_config(&config);
+        let analyzer = Analyzer::with_config(&config, "/test");
 
         // Should have rules added
         assert!(!analyzer.rules.is_empty());
     }
 
+    #[test]
+    fn test_rule_catalog_ids_are_unique() {
+        let catalog = rule_catalog();
+        let mut ids: Vec<_> = catalog.iter().map(|d| d.rule_id).collect();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), catalog.len());
+
+        let pot = catalog.iter().find(|d| d.rule_id == "texture.pot").unwrap();
+        assert_eq!(pot.category, "texture");
+        assert_eq!(pot.default_severity, Severity::Warning);
+        assert!(ids.contains(&"duplicate"));
+        assert!(ids.contains(&"dcc_source.outdated_export"));
+        assert!(ids.contains(&"empty_asset"));
+    }
+
+    #[test]
+    fn test_rule_catalog_lists_every_emitted_rule_id() {
+        // Every rule id literal in non-test source: `rule_id: "..."` in an
+        // `Issue`, or the first argument of an issue-building helper.
+        let catalog = rule_catalog();
+        let mut dirs = vec![std::path::PathBuf::from(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src"
+        ))];
+        let mut missing = Vec::new();
+        while let Some(dir) = dirs.pop() {
+            for entry in std::fs::read_dir(&dir).unwrap().flatten() {
+                let path = entry.path();
+                if path.is_dir() {
+                    dirs.push(path);
+                    continue;
+                }
+                if path.extension().map_or(true, |e| e != "rs") {
+                    continue;
+                }
+                let source = std::fs::read_to_string(&path).unwrap();
+                let code = source.split("#[cfg(test)]").next().unwrap();
+                for marker in ["rule_id: ", "lod_issue(", "violation("] {
+                    for (at, _) in code.match_indices(marker) {
+                        let Some(rest) = code[at + marker.len()..].trim_start().strip_prefix('"')
+                        else {
+                            continue;
+                        };
+                        let id = &rest[..rest.find('"').unwrap()];
+                        if !catalog.iter().any(|d| d.rule_id == id) {
+                            missing.push(format!("{} ({})", id, path.display()));
+                        }
+                    }
+                }
+            }
+        }
+        assert!(missing.is_empty(), "not in rule_catalog: {:?}", missing);
+    }
+
     #[test]
     fn test_severity_equality() {
         assert_eq!(Severity::Error, Severity::Error);
@@ -405,4 +756,103 @@ mod tests {
         assert_eq!(*result.by_rule.get("rule_a").unwrap(), 2);
         assert_eq!(*result.by_rule.get("rule_b").unwrap(), 1);
     }
+
+    #[test]
+    fn test_unanalyzed_groups_uncovered_extensions() {
+        let mut analyzer = Analyzer::new();
+        analyzer.add_rule(Box::new(rules::naming::NamingRule::new(
+            rules::naming::NamingConfig::default(),
+        )));
+        analyzer.add_rule(Box::new(rules::texture::TextureRule::new(
+            rules::texture::TextureConfig::default(),
+        )));
+        let assets = vec![
+            create_texture_with_dimensions("a.png", 64, 64),
+            create_test_asset("Lit.shader", AssetType::Other),
+            create_test_asset("Common.HLSL", AssetType::Other),
+            create_test_asset("Unlit.shader", AssetType::Other),
+        ];
+
+        let groups = analyzer.unanalyzed(&assets);
+        let summary: Vec<(&str, usize, u64)> = groups
+            .iter()
+            .map(|g| (g.extension.as_str(), g.count, g.total_size))
+            .collect();
+        // Naming applies to everything but isn't type coverage.
+        assert_eq!(summary, vec![("shader", 2, 2048), ("hlsl", 1, 1024)]);
+        assert_eq!(groups[0].paths, ["/test/Lit.shader", "/test/Unlit.shader"]);
+    }
+
+    #[test]
+    fn test_coverage_counts_applicable_and_failed() {
+        let mut config = RuleConfig::default();
+        config.texture.enabled = true;
+        config.model.enabled = true;
+        let analyzer = Analyzer::with_config(&config, "/test");
+        let assets = vec![
+            create_texture_with_dimensions("ok.png", 512, 512),
+            create_texture_with_dimensions("npot.png", 300, 200),
+            create_texture_with_dimensions("vendor.png", 300, 200),
+            create_test_asset("sound.wav", AssetType::Audio),
+        ];
+
+        let coverage = analyzer.coverage(&assets, |path| path.contains("vendor"));
+        let npot = analyzer.analyze_asset(&assets[1]);
+        assert!(npot.iter().any(|i| i.rule_id == "texture.pot"));
+        let pot = &coverage["texture.pot"];
+        assert_eq!(pot.applicable, 2);
+        assert_eq!(pot.failed, 1);
+        assert_eq!(pot.pass_rate, Some(0.5));
+        let max_size = &coverage["texture.max_size"];
+        assert_eq!((max_size.applicable, max_size.failed), (2, 0));
+        assert!(!coverage.contains_key("texture"));
+        assert!(!coverage.contains_key("duplicate"));
+        let model = &coverage["model.vertices"];
+        assert_eq!(model.applicable, 0);
+        assert_eq!(model.pass_rate, None);
+    }
+
+    #[test]
+    fn test_analyze_with_progress_counts_assets() {
+        let assets = vec![
+            create_texture_with_dimensions("a.png", 100, 100),
+            create_test_asset("b.wav", AssetType::Audio),
+            create_test_asset("c.fbx", AssetType::Model),
+        ];
+        let scan = ScanResult {
+            root_path: "/test".to_string(),
+            directory_tree: crate::scanner::DirectoryNode {
+                name: "test".to_string(),
+                path: "/test".to_string(),
+                children: vec![],
+                file_count: assets.len(),
+                total_size: 0,
+            },
+            total_count: assets.len(),
+            total_size: 0,
+            type_counts: HashMap::new(),
+            project_type: None,
+            timings: None,
+            metadata_skipped: false,
+            project_info: None,
+            assets,
+        };
+        let analyzer = Analyzer::with_config(&RuleConfig::default(), "/test");
+        let tracker = AnalysisTracker::default();
+
+        let tracked = analyzer.analyze_with_progress(&scan, Some(&tracker));
+        assert_eq!(
+            tracker.snapshot(),
+            AnalysisProgress {
+                phase: AnalysisPhase::Rules,
+                current: 3,
+                total: 3,
+            }
+        );
+        assert_eq!(tracked.issue_count, analyzer.analyze(&scan).issue_count);
+
+        tracker.set_phase(AnalysisPhase::Duplicates);
+        let hashing = tracker.snapshot();
+        assert_eq!((hashing.current, hashing.total), (0, 0));
+    }
 }
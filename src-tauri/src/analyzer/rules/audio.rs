@@ -2,7 +2,7 @@ use crate::analyzer::{Issue, Severity};
 use crate::scanner::{AssetInfo, AssetType};
 use serde::{Deserialize, Serialize};
 
-use super::Rule;
+use super::{Rule, RuleDescription};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AudioConfig {
@@ -24,6 +24,44 @@ pub struct AudioConfig {
     /// Warn about mono vs stereo
     #[serde(default)]
     pub prefer_mono_for_sfx: bool,
+
+    /// Suggest compressing a non-SFX `.wav` longer than this (seconds).
+    /// 0 disables the duration trigger.
+    #[serde(default = "default_warn_uncompressed_over_secs")]
+    pub warn_uncompressed_over_secs: f64,
+
+    /// Suggest compressing a non-SFX `.wav` larger than this (bytes).
+    /// 0 disables the size trigger.
+    #[serde(default = "default_warn_uncompressed_over_bytes")]
+    pub warn_uncompressed_over_bytes: u64,
+
+    /// Per-category expectations (music / ambient / voice / ...). The first
+    /// category whose patterns match a clip's filename decides its duration
+    /// and sample-rate checks; clips matching none fall back to the SFX
+    /// heuristic and `max_sfx_duration` above.
+    #[serde(default)]
+    pub category_rules: Vec<AudioCategoryRule>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AudioCategoryRule {
+    /// Display label — appears in issue messages ("Voice clip is 14.2s ...").
+    pub name: String,
+    /// Filename tokens that put a clip in this category, compared
+    /// case-insensitively against whole words of the name (same tokenizer
+    /// as the SFX heuristic, so `vo` matches `VO_Intro_01.wav` but not
+    /// `volcano.wav`).
+    pub patterns: Vec<String>,
+    /// Clips shorter than this (seconds) are flagged as likely misfiled.
+    #[serde(default)]
+    pub min_duration: Option<f64>,
+    /// Clips longer than this (seconds) are flagged.
+    #[serde(default)]
+    pub max_duration: Option<f64>,
+    /// Overrides the top-level `allowed_sample_rates` for this category.
+    /// `None` inherits it; an empty list disables the check.
+    #[serde(default)]
+    pub allowed_sample_rates: Option<Vec<u32>>,
 }
 
 fn default_enabled() -> bool {
@@ -44,6 +82,16 @@ fn default_max_file_size() -> u64 {
     20 * 1024 * 1024 // 20 MB
 }
 
+fn default_warn_uncompressed_over_secs() -> f64 {
+    // Past this a clip is music / ambience / VO, where streaming a
+    // compressed file costs nothing audible.
+    10.0
+}
+
+fn default_warn_uncompressed_over_bytes() -> u64 {
+    2 * 1024 * 1024 // 2 MB ≈ 12s of 44.1 kHz 16-bit stereo PCM
+}
+
 impl Default for AudioConfig {
     fn default() -> Self {
         Self {
@@ -52,6 +100,9 @@ impl Default for AudioConfig {
             max_sfx_duration: 30.0,
             max_file_size: 20 * 1024 * 1024,
             prefer_mono_for_sfx: false,
+            warn_uncompressed_over_secs: default_warn_uncompressed_over_secs(),
+            warn_uncompressed_over_bytes: default_warn_uncompressed_over_bytes(),
+            category_rules: Vec::new(),
         }
     }
 }
@@ -75,6 +126,21 @@ impl AudioRule {
         const SFX_TOKENS: [&str; 6] = ["sfx", "sound", "effect", "hit", "click", "ui"];
         sfx_name_tokens(&asset.name).any(|tok| SFX_TOKENS.contains(&tok.as_str()))
     }
+
+    /// First configured category whose patterns match one of the filename's
+    /// tokens. Declaration order is the priority order.
+    fn category_for(&self, asset: &AssetInfo) -> Option<&AudioCategoryRule> {
+        if self.config.category_rules.is_empty() {
+            return None;
+        }
+        let tokens: Vec<String> = sfx_name_tokens(&asset.name).collect();
+        self.config.category_rules.iter().find(|category| {
+            category
+                .patterns
+                .iter()
+                .any(|p| tokens.iter().any(|t| t.eq_ignore_ascii_case(p)))
+        })
+    }
 }
 
 /// Split a filename into lowercase word tokens: separators are any
@@ -118,6 +184,53 @@ impl Rule for AudioRule {
         "Audio Standards"
     }
 
+    fn describe(&self) -> Vec<RuleDescription> {
+        vec![
+            RuleDescription::new(
+                "audio.sample_rate",
+                "Non-Standard Sample Rate",
+                Severity::Info,
+                false,
+                "Sample rate is not in allowed_sample_rates (or the matched category's list).",
+            ),
+            RuleDescription::new(
+                "audio.category_duration",
+                "Audio Duration Out of Range",
+                Severity::Warning,
+                false,
+                "Clip length falls outside the min/max duration of its configured category.",
+            ),
+            RuleDescription::new(
+                "audio.sfx_duration",
+                "Long Sound Effect",
+                Severity::Warning,
+                false,
+                "A clip whose name marks it as a sound effect runs longer than max_sfx_duration.",
+            ),
+            RuleDescription::new(
+                "audio.stereo_sfx",
+                "Stereo Sound Effect",
+                Severity::Info,
+                false,
+                "A sound effect is stereo while prefer_mono_for_sfx is on.",
+            ),
+            RuleDescription::new(
+                "audio.file_size",
+                "Large Audio File",
+                Severity::Warning,
+                false,
+                "File is larger than max_file_size.",
+            ),
+            RuleDescription::new(
+                "audio.uncompressed",
+                "Uncompressed Long Audio",
+                Severity::Info,
+                false,
+                "A non-SFX WAV runs past warn_uncompressed_over_secs or warn_uncompressed_over_bytes.",
+            ),
+        ]
+    }
+
     fn applies_to(&self, asset: &AssetInfo) -> bool {
         matches!(asset.asset_type, AssetType::Audio)
     }
@@ -125,21 +238,25 @@ impl Rule for AudioRule {
     fn check(&self, asset: &AssetInfo) -> Option<Issue> {
         let metadata = asset.metadata.as_ref()?;
 
+        let category = self.category_for(asset);
+        let allowed_sample_rates = category
+            .and_then(|c| c.allowed_sample_rates.as_ref())
+            .unwrap_or(&self.config.allowed_sample_rates);
+
         // Check sample rate. An empty allow-list means "no constraint" —
         // skip entirely rather than flag every rate (indexing [0] below
         // used to panic on `allowed_sample_rates = []` in tidycraft.toml).
-        if let (Some(sample_rate), Some(&preferred)) = (
-            metadata.sample_rate,
-            self.config.allowed_sample_rates.first(),
-        ) {
-            if !self.config.allowed_sample_rates.contains(&sample_rate) {
+        if let (Some(sample_rate), Some(&preferred)) =
+            (metadata.sample_rate, allowed_sample_rates.first())
+        {
+            if !allowed_sample_rates.contains(&sample_rate) {
                 return Some(Issue {
                     rule_id: "audio.sample_rate".to_string(),
                     rule_name: "Non-Standard Sample Rate".to_string(),
                     severity: Severity::Info,
                     message: format!(
                         "Audio sample rate {} Hz is not standard (expected {:?})",
-                        sample_rate, self.config.allowed_sample_rates
+                        sample_rate, allowed_sample_rates
                     ),
                     asset_path: asset.path.clone(),
                     suggestion: Some(format!("Consider resampling to {} Hz", preferred)),
@@ -149,8 +266,49 @@ impl Rule for AudioRule {
             }
         }
 
-        // Check SFX duration
-        if let Some(duration) = metadata.duration_secs {
+        // A matched category replaces the SFX heuristics entirely: a clip
+        // filed as voice or music is judged by that category's bounds only.
+        if let Some(category) = category {
+            if let Some(duration) = metadata.duration_secs {
+                if let Some(max) = category.max_duration.filter(|&max| duration > max) {
+                    return Some(Issue {
+                        rule_id: "audio.category_duration".to_string(),
+                        rule_name: "Audio Duration Out of Range".to_string(),
+                        severity: Severity::Warning,
+                        message: format!(
+                            "{} clip is {:.1}s long, maximum for this category is {:.1}s",
+                            category.name, duration, max
+                        ),
+                        asset_path: asset.path.clone(),
+                        suggestion: Some(format!(
+                            "Trim the clip or check that it belongs in \"{}\"",
+                            category.name
+                        )),
+                        auto_fixable: false,
+                        related_paths: None,
+                    });
+                }
+                if let Some(min) = category.min_duration.filter(|&min| duration < min) {
+                    return Some(Issue {
+                        rule_id: "audio.category_duration".to_string(),
+                        rule_name: "Audio Duration Out of Range".to_string(),
+                        severity: Severity::Warning,
+                        message: format!(
+                            "{} clip is only {:.1}s long, minimum for this category is {:.1}s",
+                            category.name, duration, min
+                        ),
+                        asset_path: asset.path.clone(),
+                        suggestion: Some(format!(
+                            "Check that this clip belongs in \"{}\"",
+                            category.name
+                        )),
+                        auto_fixable: false,
+                        related_paths: None,
+                    });
+                }
+            }
+        } else if let Some(duration) = metadata.duration_secs {
+            // No category matched: fall back to the SFX duration check.
             if self.is_likely_sfx(asset) && duration > self.config.max_sfx_duration {
                 return Some(Issue {
                     rule_id: "audio.sfx_duration".to_string(),
@@ -169,7 +327,7 @@ impl Rule for AudioRule {
         }
 
         // Check stereo for SFX
-        if self.config.prefer_mono_for_sfx {
+        if self.config.prefer_mono_for_sfx && category.is_none() {
             if let Some(channels) = metadata.channels {
                 if self.is_likely_sfx(asset) && channels > 1 {
                     return Some(Issue {
@@ -205,6 +363,35 @@ impl Rule for AudioRule {
             });
         }
 
+        // Unlike the size cap above this is about the format: long PCM
+        // should ship compressed. SFX stay WAV — they're short and need
+        // low-latency playback, so the heuristic exempts them.
+        if asset.extension.eq_ignore_ascii_case("wav") && !self.is_likely_sfx(asset) {
+            let secs = self.config.warn_uncompressed_over_secs;
+            let bytes = self.config.warn_uncompressed_over_bytes;
+            let too_long = metadata.duration_secs.filter(|&d| secs > 0.0 && d > secs);
+            let too_big = bytes > 0 && asset.size > bytes;
+            if too_long.is_some() || too_big {
+                let reason = match too_long {
+                    Some(duration) => format!("{:.1}s", duration),
+                    None => format!("{:.2} MB", asset.size as f64 / 1024.0 / 1024.0),
+                };
+                return Some(Issue {
+                    rule_id: "audio.uncompressed".to_string(),
+                    rule_name: "Uncompressed Long Audio".to_string(),
+                    severity: Severity::Info,
+                    message: format!("Uncompressed WAV ({}) is likely music or ambience", reason),
+                    asset_path: asset.path.clone(),
+                    suggestion: Some(
+                        "Convert to OGG Vorbis; keep WAV only for short, latency-sensitive SFX"
+                            .to_string(),
+                    ),
+                    auto_fixable: false,
+                    related_paths: None,
+                });
+            }
+        }
+
         None
     }
 }
@@ -249,6 +436,109 @@ mod tests {
         assert_eq!(issue.rule_id, "audio.sample_rate");
         assert!(issue.suggestion.expect("has suggestion").contains("44100"));
     }
+
+    fn clip(name: &str, duration: f64, sample_rate: u32) -> AssetInfo {
+        AssetInfo {
+            path: format!("audio/{}", name),
+            name: name.to_string(),
+            metadata: Some(AssetMetadata {
+                sample_rate: Some(sample_rate),
+                duration_secs: Some(duration),
+                ..Default::default()
+            }),
+            ..audio_asset(sample_rate)
+        }
+    }
+
+    fn category(name: &str, patterns: &[&str]) -> AudioCategoryRule {
+        AudioCategoryRule {
+            name: name.to_string(),
+            patterns: patterns.iter().map(|p| p.to_string()).collect(),
+            min_duration: None,
+            max_duration: None,
+            allowed_sample_rates: None,
+        }
+    }
+
+    fn categorized_rule() -> AudioRule {
+        AudioRule::new(AudioConfig {
+            category_rules: vec![
+                AudioCategoryRule {
+                    max_duration: Some(10.0),
+                    allowed_sample_rates: Some(vec![22050]),
+                    ..category("Voice", &["vo", "voice"])
+                },
+                AudioCategoryRule {
+                    min_duration: Some(30.0),
+                    ..category("Music", &["music", "bgm"])
+                },
+            ],
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn category_bounds_flag_misfiled_clips() {
+        let rule = categorized_rule();
+        let long_vo = rule.check(&clip("VO_Intro_01.wav", 14.0, 22050)).unwrap();
+        assert_eq!(long_vo.rule_id, "audio.category_duration");
+        assert!(long_vo.message.starts_with("Voice"));
+
+        let short_bgm = rule.check(&clip("bgm_menu.ogg", 8.0, 48000)).unwrap();
+        assert_eq!(short_bgm.rule_id, "audio.category_duration");
+        assert!(rule.check(&clip("bgm_menu.ogg", 95.0, 48000)).is_none());
+    }
+
+    #[test]
+    fn category_sample_rates_override_the_global_list() {
+        let rule = categorized_rule();
+        // 22.05 kHz is outside the global [44100, 48000] but allowed for VO.
+        assert!(rule.check(&clip("vo_line.wav", 3.0, 22050)).is_none());
+        let issue = rule.check(&clip("vo_line.wav", 3.0, 48000)).unwrap();
+        assert_eq!(issue.rule_id, "audio.sample_rate");
+    }
+
+    #[test]
+    fn uncategorized_clips_keep_the_sfx_heuristic() {
+        let rule = categorized_rule();
+        // `volcano` contains "vo" only as a substring — not a Voice clip, and
+        // the SFX path still judges `sword_hit` against max_sfx_duration.
+        // (.ogg so the long-WAV compression hint stays out of the way.)
+        assert!(rule.check(&clip("volcano_loop.ogg", 60.0, 48000)).is_none());
+        let issue = rule.check(&clip("sword_hit.wav", 45.0, 48000)).unwrap();
+        assert_eq!(issue.rule_id, "audio.sfx_duration");
+    }
+
+    #[test]
+    fn long_or_large_wav_is_pointed_at_ogg() {
+        let rule = AudioRule::new(AudioConfig::default());
+        let issue = rule
+            .check(&clip("forest_ambience.wav", 95.0, 48000))
+            .unwrap();
+        assert_eq!(issue.rule_id, "audio.uncompressed");
+        assert_eq!(issue.severity, Severity::Info);
+        assert!(issue.suggestion.unwrap().contains("OGG"));
+
+        // Size alone triggers it when the duration is unknown.
+        let big = AssetInfo {
+            size: 8 * 1024 * 1024,
+            ..audio_asset(48000)
+        };
+        assert_eq!(rule.check(&big).unwrap().rule_id, "audio.uncompressed");
+
+        // Short clips, already-compressed formats and SFX are left alone.
+        assert!(rule.check(&clip("door_open.wav", 2.0, 48000)).is_none());
+        assert!(rule
+            .check(&clip("forest_ambience.ogg", 95.0, 48000))
+            .is_none());
+        let long_sfx = AudioRule::new(AudioConfig {
+            max_sfx_duration: 60.0,
+            ..Default::default()
+        });
+        assert!(long_sfx
+            .check(&clip("sfx_rumble.wav", 40.0, 48000))
+            .is_none());
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,106 @@
+//! Asset paths that differ only in letter case.
+//!
+//! `Texture.png` and `texture.PNG` are two files on a case-sensitive
+//! filesystem (Linux CI) but one on Windows' and macOS's defaults: cloning
+//! the repository there keeps whichever is written last and the other is
+//! silently lost. Reported as errors, one per colliding group.
+//!
+//! Runs on demand (`find_case_collisions`), not as part of analysis.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::analyzer::rules::RuleDescription;
+use crate::analyzer::{AnalysisResult, Issue, Severity};
+use crate::scanner::{self, AssetInfo};
+
+/// Rule catalog entry for the case-collision check.
+pub fn describe() -> Vec<RuleDescription> {
+    vec![RuleDescription::new(
+        "case_collision",
+        "Case-Insensitive Path Collision",
+        Severity::Error,
+        false,
+        "Two or more asset paths differ only in letter case, so one is lost on a case-insensitive filesystem.",
+    )]
+}
+
+pub fn find_case_collisions(assets: &[AssetInfo], root: &str) -> AnalysisResult {
+    let root = Path::new(root);
+    let mut by_folded: HashMap<String, Vec<String>> = HashMap::new();
+    for asset in assets {
+        let path = Path::new(&asset.path);
+        let rel = scanner::path_to_string(path.strip_prefix(root).unwrap_or(path));
+        by_folded.entry(rel.to_lowercase()).or_default().push(rel);
+    }
+
+    let mut groups: Vec<Vec<String>> = by_folded
+        .into_values()
+        .filter(|paths| paths.len() > 1)
+        .map(|mut paths| {
+            paths.sort();
+            paths
+        })
+        .collect();
+    groups.sort();
+
+    let mut result = AnalysisResult::new();
+    for group in groups {
+        result.add_issue(Issue {
+            rule_id: "case_collision".to_string(),
+            rule_name: "Case-Insensitive Path Collision".to_string(),
+            severity: Severity::Error,
+            message: format!(
+                "{} paths differ only in letter case; on Windows and macOS only one survives a checkout",
+                group.len()
+            ),
+            asset_path: scanner::path_to_string(&root.join(&group[1])),
+            suggestion: Some(
+                "Rename or remove all but one so the paths differ by more than case".to_string(),
+            ),
+            auto_fixable: false,
+            related_paths: Some(group),
+        });
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::AssetType;
+
+    fn asset(rel: &str) -> AssetInfo {
+        AssetInfo {
+            path: format!("/p/{}", rel),
+            name: rel.rsplit('/').next().unwrap().to_string(),
+            extension: "png".to_string(),
+            asset_type: AssetType::Texture,
+            size: 1,
+            modified: 0,
+            metadata: None,
+            unity_guid: None,
+        }
+    }
+
+    #[test]
+    fn groups_paths_equal_ignoring_case() {
+        let assets = vec![
+            asset("Art/texture.PNG"),
+            asset("Art/Texture.png"),
+            asset("Art/other.png"),
+            // Different folders, same name: not a collision.
+            asset("UI/other.png"),
+        ];
+
+        let result = find_case_collisions(&assets, "/p");
+        assert_eq!(result.issues.len(), 1);
+        let issue = &result.issues[0];
+        assert_eq!(issue.severity, Severity::Error);
+        assert_eq!(
+            issue.related_paths.as_deref(),
+            Some(&["Art/Texture.png".to_string(), "Art/texture.PNG".to_string()][..])
+        );
+        assert_eq!(issue.asset_path, "/p/Art/texture.PNG");
+    }
+}
@@ -0,0 +1,227 @@
+//! Comment-preserving `tidycraft.toml` generation and upgrades.
+//!
+//! The plain `toml` crate serializes a `RuleConfig` faithfully but bare: no
+//! section headers, no explanation of a field or its default. Teams check
+//! the file in and annotate it, so anything we write must keep that prose.
+//! Both operations here go through `toml_edit`:
+//!
+//! - `documented` lays a config's values over `DEFAULT_CONFIG_TEMPLATE`,
+//!   keeping the template's per-section comments. Values equal to the
+//!   template's keep their exact spelling (and inline comment).
+//! - `fill_missing` adds to a user's file only the settings it lacks,
+//!   copied with their template comments. Existing keys, values, comments
+//!   and ordering are left alone.
+//!
+//! Comparisons are by value, not text, so `30.0` vs `30.0  # seconds` or a
+//! multi-line array vs a one-line one never count as a change.
+
+use toml_edit::{DocumentMut, Item, Table, TableLike, Value};
+
+use super::config_template::DEFAULT_CONFIG_TEMPLATE;
+
+/// `values` (a serialized `RuleConfig`) written over the annotated default
+/// template. Top-level tables the config doesn't own (`[project]`) are kept;
+/// below that, a template key missing from `values` is an unset optional
+/// and is dropped.
+pub fn documented(values: &str) -> Result<DocumentMut, toml_edit::TomlError> {
+    let mut doc: DocumentMut = DEFAULT_CONFIG_TEMPLATE.parse()?;
+    let values: DocumentMut = values.parse()?;
+    let mut next = end_position(doc.as_table());
+    overlay(doc.as_table_mut(), values.as_table(), false, &mut next);
+    Ok(doc)
+}
+
+/// Insert into `user` every key of `full` it doesn't have, recursing into
+/// tables both sides define. Arrays, arrays of tables and inline tables are
+/// one user-owned value and never merged into. Returns the dotted paths
+/// added, outermost only (`duplicate`, not also `duplicate.algorithm`).
+pub fn fill_missing(user: &mut DocumentMut, full: &DocumentMut) -> Vec<String> {
+    let mut next = end_position(user.as_table());
+    let mut added = Vec::new();
+    fill_table(
+        user.as_table_mut(),
+        full.as_table(),
+        "",
+        &mut next,
+        &mut added,
+    );
+    added
+}
+
+fn overlay(doc: &mut Table, values: &Table, prune: bool, next: &mut usize) {
+    if prune {
+        let stale: Vec<String> = doc
+            .iter()
+            .filter(|(key, _)| !values.contains_key(key))
+            .map(|(key, _)| key.to_string())
+            .collect();
+        for key in stale {
+            doc.remove(&key);
+        }
+    }
+
+    let pos = doc.position();
+    for (key, new) in values.iter() {
+        let Some(old) = doc.get_mut(key) else {
+            let mut new = new.clone();
+            place(&mut new, pos.unwrap_or_else(|| take(next)));
+            doc.insert(key, new);
+            continue;
+        };
+        if same_item(old, new) {
+            continue;
+        }
+        match (old, new) {
+            (Item::Value(old), Item::Value(new)) => {
+                let decor = old.decor().clone();
+                *old = new.clone();
+                *old.decor_mut() = decor;
+            }
+            (Item::Table(old), Item::Table(new)) => overlay(old, new, true, next),
+            (old, new) => {
+                let at = first_position(old).or(pos).unwrap_or_else(|| take(next));
+                *old = new.clone();
+                place(old, at);
+            }
+        }
+    }
+}
+
+fn fill_table(
+    user: &mut Table,
+    full: &Table,
+    path: &str,
+    next: &mut usize,
+    added: &mut Vec<String>,
+) {
+    let pos = user.position();
+    for (key, item) in full.iter() {
+        let key_path = if path.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}.{}", path, key)
+        };
+        if let (Some(Item::Table(existing)), Item::Table(item)) = (user.get_mut(key), item) {
+            fill_table(existing, item, &key_path, next, added);
+            continue;
+        }
+        if user.contains_key(key) {
+            continue;
+        }
+        let mut item = item.clone();
+        place(&mut item, pos.unwrap_or_else(|| take(next)));
+        if let Item::Table(table) = &mut item {
+            own_comment(table);
+        }
+        // Carry the key's own decor: the comment lines above a plain
+        // `key = value` live there, not on the value.
+        match full.get_key_value(key) {
+            Some((formatted, _)) => user.insert_formatted(formatted, item),
+            None => user.insert(key, item),
+        };
+        added.push(key_path);
+    }
+}
+
+/// Keep only the comment paragraph directly above a copied table. The
+/// template's first section also carries the file header, which would
+/// otherwise land in the middle of the user's file.
+fn own_comment(table: &mut Table) {
+    let decor = table.decor_mut();
+    let Some(prefix) = decor.prefix().and_then(|p| p.as_str()) else {
+        return;
+    };
+    let Some(start) = prefix.trim_end().rfind("\n\n") else {
+        return;
+    };
+    let own = format!("\n{}", &prefix[start + 2..]);
+    decor.set_prefix(own);
+}
+
+fn take(next: &mut usize) -> usize {
+    *next += 1;
+    *next - 1
+}
+
+/// One past the highest table position in `table`, where copied-in
+/// top-level tables go so they render after everything already there.
+fn end_position(table: &Table) -> usize {
+    fn max_in(item: &Item) -> usize {
+        match item {
+            Item::Table(t) => t.position().unwrap_or(0).max(max_of(t)),
+            Item::ArrayOfTables(a) => a
+                .iter()
+                .map(|t| t.position().unwrap_or(0).max(max_of(t)))
+                .max()
+                .unwrap_or(0),
+            _ => 0,
+        }
+    }
+    fn max_of(table: &Table) -> usize {
+        table
+            .iter()
+            .map(|(_, item)| max_in(item))
+            .max()
+            .unwrap_or(0)
+    }
+    max_of(table) + 1
+}
+
+fn first_position(item: &Item) -> Option<usize> {
+    match item {
+        Item::Table(t) => t.position(),
+        Item::ArrayOfTables(a) => a.iter().next().and_then(Table::position),
+        _ => None,
+    }
+}
+
+/// Give every table in `item` document position `pos`. Tables copied from
+/// another document keep that document's positions, which would scatter
+/// them among unrelated sections; equal positions render in tree order.
+fn place(item: &mut Item, pos: usize) {
+    let place_table = |table: &mut Table| {
+        table.set_position(pos);
+        for (_, child) in table.iter_mut() {
+            place(child, pos);
+        }
+    };
+    match item {
+        Item::Table(t) => place_table(t),
+        Item::ArrayOfTables(a) => a.iter_mut().for_each(place_table),
+        _ => {}
+    }
+}
+
+fn same_item(a: &Item, b: &Item) -> bool {
+    match (a, b) {
+        (Item::Value(a), Item::Value(b)) => same_value(a, b),
+        (Item::ArrayOfTables(a), Item::ArrayOfTables(b)) => {
+            a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| same_table(a, b))
+        }
+        _ => match (a.as_table_like(), b.as_table_like()) {
+            (Some(a), Some(b)) => same_table(a, b),
+            _ => false,
+        },
+    }
+}
+
+fn same_table(a: &dyn TableLike, b: &dyn TableLike) -> bool {
+    a.len() == b.len()
+        && a.iter()
+            .all(|(key, item)| b.get(key).is_some_and(|other| same_item(item, other)))
+}
+
+fn same_value(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::String(a), Value::String(b)) => a.value() == b.value(),
+        (Value::Integer(a), Value::Integer(b)) => a.value() == b.value(),
+        (Value::Float(a), Value::Float(b)) => a.value() == b.value(),
+        (Value::Boolean(a), Value::Boolean(b)) => a.value() == b.value(),
+        (Value::Datetime(a), Value::Datetime(b)) => a.value() == b.value(),
+        (Value::Array(a), Value::Array(b)) => {
+            a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| same_value(a, b))
+        }
+        (Value::InlineTable(a), Value::InlineTable(b)) => same_table(a, b),
+        _ => false,
+    }
+}
@@ -16,8 +16,9 @@ pub const DEFAULT_CONFIG_TEMPLATE: &str = r##"# Tidycraft analysis rules.
 # See docs/analyzer-rules.md for what each rule does and when to relax it.
 #
 # OUT-OF-BOX DEFAULTS ARE DELIBERATELY MINIMAL.
-# Only naming.forbidden_chars + texture.color_space + duplicate (always-on) +
-# missing_reference (Unity-only, always-on) fire by default. Every other
+# Only naming.forbidden_chars + texture.color_space + empty_asset +
+# duplicate (always-on) + missing_reference (Unity-only, always-on) fire
+# by default. Every other
 # section below ships with `enabled = false`; flip them to `true` to opt in.
 
 # ─── Project metadata ─── (consumed by AI Learning)
@@ -62,9 +63,15 @@ case_style = "any"
 enabled = false
 # Power-of-two dimensions. UI / icon textures and HDRIs often need this off.
 require_pot = true
+# Width and height divisible by 4, as BC / DXT / ASTC block compression
+# needs. Looser than require_pot, for pipelines compressing NPOT textures.
+require_multiple_of_4 = false
 # Maximum width or height in pixels. Hero assets / cinematic textures
 # may justify raising to 8192.
 max_size = 4096
+# Check against a [texture.platforms] profile's max_size instead, e.g.
+# "mobile". Unset uses max_size above; a name no profile has is an error.
+# target_platform = "mobile"
 # Minimum width or height. Below this triggers an info-severity issue.
 min_size = 4
 # Warn when not square. Most texture pipelines accept rectangular.
@@ -73,6 +80,19 @@ warn_non_square = false
 # assets, lower for mobile-targeted projects.
 max_file_size = 10485760
 
+# ─── Texture Platform Profiles ───
+# Named max_size budgets for [texture].target_platform. Built-ins: desktop
+# 4096, mobile 2048 (many mobile GPUs struggle above it), switch 2048.
+# Add a table to define another platform or override one of these.
+[texture.platforms.desktop]
+max_size = 4096
+
+[texture.platforms.mobile]
+max_size = 2048
+
+[texture.platforms.switch]
+max_size = 2048
+
 # ─── Texture Color Space ─── (applies to image assets)
 # DEFAULT: enabled. Catches a real corruption bug — engine de-gammas
 # sRGB-flagged data textures (normal / roughness / metallic / AO).
@@ -81,6 +101,56 @@ max_file_size = 10485760
 [texture.color_space]
 enabled = true
 
+# ─── Texture Import Size ─── (Unity textures)
+# DEFAULT: disabled. Info when the source's larger dimension is at least
+# `min_ratio` times the `.meta` maxTextureSize — the editor downsamples on
+# import, so the extra resolution only costs disk and VCS bandwidth.
+[texture.import_size]
+enabled = false
+min_ratio = 4
+
+# ─── Texture Format ─── (applies to image assets)
+# DEFAULT: disabled. Flags textures whose role calls for a lossless format
+# but that are stored lossy — JPEG normal maps, JPEG UI / sprite art. A
+# role is matched by stem suffix, stem prefix or a folder below the
+# project root;
+# `allowed` (if set) lists the only acceptable extensions, `disallowed`
+# the forbidden ones. Listing any roles replaces the two built-ins below.
+[texture.format_rules]
+enabled = false
+
+[[texture.format_rules.roles]]
+name = "normal map"
+suffixes = ["_n", "_normal", "_norm", "_nrm"]
+disallowed = ["jpg", "jpeg"]
+suggest = ["png", "tga"]
+
+[[texture.format_rules.roles]]
+name = "UI / sprite texture"
+prefixes = ["ui_", "icon_", "spr_", "sprite_"]
+directories = ["ui", "sprites", "icons"]
+disallowed = ["jpg", "jpeg"]
+suggest = ["png"]
+
+# ─── Texture Mipmaps ─── (Unity textures)
+# DEFAULT: disabled. Info when a UI texture (under one of `ui_folders`, or
+# a stem ending in one of `ui_suffixes`) has mipmaps enabled in its `.meta`
+# — a third more memory for nothing — or any other texture has them off.
+[texture.mipmaps]
+enabled = false
+ui_folders = ["UI"]
+ui_suffixes = ["_UI"]
+
+# ─── Texture Bit Depth ─── (applies to image assets)
+# DEFAULT: disabled. Info when a texture is stored above 8 bits per
+# channel (16-bit PNG, float TIFF) — twice the size or more for precision
+# albedo / mask / UI art loses on compression. HDR formats and stems
+# ending in a height / normal suffix are exempt.
+[texture.bit_depth]
+enabled = false
+exempt_extensions = ["exr", "hdr"]
+exempt_suffixes = ["_n", "_normal", "_norm", "_nrm", "_h", "_height", "_disp", "_displacement", "_bump", "_hdr"]
+
 # ─── Model Standards ─── (applies to 3D model assets)
 # DEFAULT: disabled. Vertex / face / material limits are per-project
 # budgets — opt in by flipping `enabled` to true.
@@ -89,6 +159,9 @@ enabled = false
 max_vertices = 100000
 max_faces = 100000
 max_materials = 10
+# Used by the LOD chain check (run on demand, ignores `enabled`): a model
+# above this many vertices with no `_LOD<n>` siblings is reported.
+lod_vertex_threshold = 20000
 
 # ─── Audio Standards ─── (applies to audio assets)
 # DEFAULT: disabled. Sample rate / duration / mono limits are
@@ -101,6 +174,47 @@ allowed_sample_rates = [44100, 48000]
 max_sfx_duration = 30.0
 max_file_size = 20971520         # 20 MB
 prefer_mono_for_sfx = false
+# Suggest OGG for a .wav longer / larger than these (0 = off). Files
+# the SFX heuristic matches stay WAV for low-latency playback.
+warn_uncompressed_over_secs = 10.0
+warn_uncompressed_over_bytes = 2097152   # 2 MB
+# Optional per-category bounds. The first category whose `patterns`
+# match a whole word of the filename decides the duration and
+# sample-rate checks for that clip (and skips the SFX heuristic above).
+# `allowed_sample_rates` here overrides the top-level list.
+# [[audio.category_rules]]
+# name = "Voice"
+# patterns = ["vo", "voice", "dialog"]
+# max_duration = 10.0
+#
+# [[audio.category_rules]]
+# name = "Music"
+# patterns = ["music", "bgm"]
+# min_duration = 30.0
+
+# ─── Empty Assets ─── (applies to all assets)
+# DEFAULT: enabled. Errors on 0-byte files, images with a zero width or
+# height, and models with no vertices — interrupted exports and
+# placeholders that import fine and break later.
+[empty_asset]
+enabled = true
+
+# ─── Duplicate Detection ─── (cross-asset: same size + same content hash)
+# Always on. `algorithm` picks the content hash: "blake3" (fastest),
+# "sha256", or "sha1". Results are identical; pick sha1 / sha256 only
+# to cross-reference hashes with an external dedupe tool.
+#
+# `sample_mode` trades certainty for speed on huge libraries: files of at
+# least `sample_min_size` bytes are compared on `sample_chunks` evenly
+# spaced chunks of `sample_chunk_size` bytes, and matches come back as
+# info-level `duplicate.likely` issues instead of warnings. Both chunk
+# settings must be at least 1.
+[duplicate]
+algorithm = "blake3"
+sample_mode = false
+sample_min_size = 67108864      # 64 MB
+sample_chunks = 16
+sample_chunk_size = 65536       # 64 KB
 
 # ─── PBR Set Completeness ─── (cross-asset: groups textures by directory + base name)
 # DEFAULT: disabled. Opinionated about which channels make a "complete"
@@ -224,6 +338,46 @@ exports = ["png", "jpg", "tga", "webp"]
 same_dir = true
 sibling_dirs = ["sources", "_source", "src"]
 
+# ─── Vendored Folders ─── (third-party content you didn't author)
+# Vendored assets are skipped by analysis and never reported as unused.
+# Leave `patterns` unset for the per-engine defaults (Unity
+# "Packages/**", Unreal "Plugins/**"); a list replaces them and `[]`
+# turns vendoring off.
+[vendor]
+# patterns = ["Packages/**", "ThirdParty/**"]
+
+# ─── Extension Overrides ─── (how files are classified at scan time)
+# Map an extension to texture / model / audio / video / animation /
+# material / prefab / scene / script / data / other, ahead of the
+# built-in table. Applied on the next scan, not on Run Analysis.
+[scan.extension_overrides]
+# bytes = "data"
+# svg = "data"                  # stop treating SVG UI art as textures
+
+# ─── Build Size Estimate ─── (Statistics → estimated build size)
+# Rough factors turning source files into shipped bytes. Not a rule; read
+# each time the estimate is requested. Textures without dimensions and
+# audio without a duration use `fallback_ratio` of their source size.
+[build_estimate]
+texture_opaque_bytes_per_pixel = 0.5   # BC1 / ETC2 RGB
+texture_alpha_bytes_per_pixel = 1.0    # BC3 / BC7 / ETC2 RGBA
+texture_mipmaps = true                 # +1/3 for the mip chain
+audio_kbps = 128                       # encoded bitrate; smaller sources kept as-is
+model_ratio = 0.5                      # left after import strips authoring data
+fallback_ratio = 1.0                   # every other type
+
+# ─── Git LFS ─── (Git → large files outside LFS)
+# Files at least this big that no `.gitattributes` LFS pattern covers are
+# reported with the pattern to add. Not a rule; 5 MB by default.
+[lfs]
+threshold_bytes = 5242880
+
+# ─── Line Endings ─── (Text → mixed line endings)
+# What `fix_mixed_line_endings` normalizes text files to: "lf", "crlf" or
+# "cr". Not a rule; the fix can also be given a one-off ending.
+[line_endings]
+target = "lf"
+
 # ─── Ignore Patterns ─── (skip matched assets entirely)
 # Globs matched against asset paths RELATIVE to project root.
 # Useful for vendored packages, legacy folders, or generated artifacts.
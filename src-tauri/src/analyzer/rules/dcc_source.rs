@@ -41,6 +41,7 @@ use std::time::UNIX_EPOCH;
 
 use serde::{Deserialize, Serialize};
 
+use crate::analyzer::rules::RuleDescription;
 use crate::analyzer::{AnalysisResult, Issue, Severity};
 use crate::scanner::AssetInfo;
 
@@ -288,6 +289,17 @@ fn humanize_seconds(secs: u64) -> String {
     }
 }
 
+/// Rule catalog entry for the DCC source check.
+pub fn describe() -> Vec<RuleDescription> {
+    vec![RuleDescription::new(
+        "dcc_source.outdated_export",
+        "Outdated DCC Export",
+        Severity::Warning,
+        false,
+        "An exported model or texture is older than the DCC source file it was built from.",
+    )]
+}
+
 pub fn find_dcc_source_issues(
     assets: &[AssetInfo],
     config: &DccSourceConfig,
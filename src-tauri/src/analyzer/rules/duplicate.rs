@@ -1,28 +1,273 @@
-use crate::analyzer::{AnalysisResult, Issue, Severity};
+use crate::analyzer::rules::RuleDescription;
+use crate::analyzer::{AnalysisResult, Cancelled, Issue, Severity};
+use crate::cache::ScanCache;
 use crate::scanner::AssetInfo;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use sha1::Sha1;
 use sha2::{Digest, Sha256};
 use std::collections::HashMap;
 use std::fs::File;
-use std::io::{BufReader, Read};
+use std::io::{BufReader, Read, Seek, SeekFrom};
 use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 
-/// Calculate SHA256 hash of a file
-fn calculate_file_hash(path: &Path) -> Option<String> {
+/// Content hash used to confirm same-size duplicates. Grouping is identical
+/// for all three; the choice only matters when cross-referencing hashes
+/// with external dedupe tools (most speak SHA1 or SHA256).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HashAlgo {
+    Sha256,
+    Sha1,
+    Blake3,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicateConfig {
+    #[serde(default = "default_algorithm")]
+    pub algorithm: HashAlgo,
+    /// Approximate mode: files of at least `sample_min_size` bytes are
+    /// compared on `sample_chunks` evenly spaced chunks of
+    /// `sample_chunk_size` bytes instead of their full content, and
+    /// matches are reported as likely (info) rather than confirmed.
+    #[serde(default)]
+    pub sample_mode: bool,
+    #[serde(default = "default_sample_min_size")]
+    pub sample_min_size: u64,
+    #[serde(default = "default_sample_chunks")]
+    pub sample_chunks: u32,
+    #[serde(default = "default_sample_chunk_size")]
+    pub sample_chunk_size: u64,
+}
+
+fn default_algorithm() -> HashAlgo {
+    // Several times faster than SHA256 on large binaries, and nothing
+    // outside the analyzer sees the hash unless a team opts into one.
+    HashAlgo::Blake3
+}
+
+fn default_sample_min_size() -> u64 {
+    64 * 1024 * 1024
+}
+
+fn default_sample_chunks() -> u32 {
+    16
+}
+
+fn default_sample_chunk_size() -> u64 {
+    64 * 1024
+}
+
+impl Default for DuplicateConfig {
+    fn default() -> Self {
+        Self {
+            algorithm: default_algorithm(),
+            sample_mode: false,
+            sample_min_size: default_sample_min_size(),
+            sample_chunks: default_sample_chunks(),
+            sample_chunk_size: default_sample_chunk_size(),
+        }
+    }
+}
+
+impl DuplicateConfig {
+    /// Whether `size` bytes are sampled rather than hashed in full. Files
+    /// the samples would cover entirely gain nothing from sampling.
+    fn samples(&self, size: u64) -> bool {
+        self.sample_mode
+            && size >= self.sample_min_size
+            && size > (self.sample_chunks as u64).saturating_mul(self.sample_chunk_size)
+    }
+
+    /// Rejects sampling settings that would read nothing: zero chunks, or
+    /// chunks of zero bytes, would make every same-size pair a match.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.sample_chunks == 0 {
+            return Err("[duplicate].sample_chunks must be at least 1".to_string());
+        }
+        if self.sample_chunk_size == 0 {
+            return Err("[duplicate].sample_chunk_size must be at least 1 byte".to_string());
+        }
+        Ok(())
+    }
+}
+
+fn is_cancelled(cancel: Option<&AtomicBool>) -> bool {
+    cancel.is_some_and(|c| c.load(Ordering::Relaxed))
+}
+
+/// Bytes the hashing pass has read against the bytes it will read, shared
+/// with whoever reports progress. Counted per chunk, so a single huge video
+/// moves the bar while it's being read; cached hashes are left out of both
+/// sides.
+#[derive(Debug, Default)]
+pub struct HashProgress {
+    hashed: AtomicU64,
+    total: AtomicU64,
+}
+
+/// Payload of the `duplicate-progress-{project_id}` event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct DuplicateProgress {
+    pub bytes_hashed: u64,
+    pub bytes_total: u64,
+}
+
+impl HashProgress {
+    fn start(&self, total: u64) {
+        self.hashed.store(0, Ordering::Relaxed);
+        self.total.store(total, Ordering::Relaxed);
+    }
+
+    fn advance(&self, bytes: usize) {
+        self.hashed.fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+
+    pub fn snapshot(&self) -> DuplicateProgress {
+        let bytes_total = self.total.load(Ordering::Relaxed);
+        DuplicateProgress {
+            // A file that grew since the scan can push the count past the
+            // scan-time total.
+            bytes_hashed: self.hashed.load(Ordering::Relaxed).min(bytes_total),
+            bytes_total,
+        }
+    }
+}
+
+/// Feed a file to `update` in 8 KB chunks. Gives up (`None`) once `cancel`
+/// is set, so one multi-gigabyte file can't hold a cancelled run hostage.
+fn read_chunks(
+    path: &Path,
+    cancel: Option<&AtomicBool>,
+    progress: Option<&HashProgress>,
+    mut update: impl FnMut(&[u8]),
+) -> Option<()> {
     let file = File::open(path).ok()?;
     let mut reader = BufReader::new(file);
-    let mut hasher = Sha256::new();
     let mut buffer = [0u8; 8192];
 
     loop {
+        if is_cancelled(cancel) {
+            return None;
+        }
         let bytes_read = reader.read(&mut buffer).ok()?;
         if bytes_read == 0 {
             break;
         }
-        hasher.update(&buffer[..bytes_read]);
+        update(&buffer[..bytes_read]);
+        if let Some(progress) = progress {
+            progress.advance(bytes_read);
+        }
     }
 
-    let hash = hasher.finalize();
-    Some(format!("{:x}", hash))
+    Some(())
+}
+
+fn digest_file<D: Digest>(
+    path: &Path,
+    cancel: Option<&AtomicBool>,
+    progress: Option<&HashProgress>,
+) -> Option<String> {
+    let mut hasher = D::new();
+    read_chunks(path, cancel, progress, |chunk| hasher.update(chunk))?;
+    Some(format!("{:x}", hasher.finalize()))
+}
+
+/// Lowercase hex hash of a file's content with `algorithm`
+fn calculate_file_hash(
+    path: &Path,
+    algorithm: HashAlgo,
+    cancel: Option<&AtomicBool>,
+    progress: Option<&HashProgress>,
+) -> Option<String> {
+    match algorithm {
+        HashAlgo::Sha256 => digest_file::<Sha256>(path, cancel, progress),
+        HashAlgo::Sha1 => digest_file::<Sha1>(path, cancel, progress),
+        HashAlgo::Blake3 => {
+            let mut hasher = blake3::Hasher::new();
+            read_chunks(path, cancel, progress, |chunk| {
+                hasher.update(chunk);
+            })?;
+            Some(hasher.finalize().to_hex().to_string())
+        }
+    }
+}
+
+/// BLAKE3 over `config.sample_chunks` evenly spaced chunks of the file,
+/// first and last included. Never cached or shown: it only groups files
+/// within one pass.
+fn sampled_file_hash(path: &Path, size: u64, config: &DuplicateConfig) -> Option<String> {
+    let mut file = File::open(path).ok()?;
+    let chunk_size = config.sample_chunk_size.min(size);
+    let chunks = config.sample_chunks as u64;
+    let span = size - chunk_size;
+    let mut buffer = vec![0u8; chunk_size as usize];
+    let mut hasher = blake3::Hasher::new();
+    for i in 0..chunks {
+        let offset = match chunks {
+            1 => 0,
+            _ => span * i / (chunks - 1),
+        };
+        file.seek(SeekFrom::Start(offset)).ok()?;
+        file.read_exact(&mut buffer).ok()?;
+        hasher.update(&buffer);
+    }
+    Some(hasher.finalize().to_hex().to_string())
+}
+
+/// Content hash of each of `assets`, in order; `None` for a file that
+/// couldn't be read or when `cancel` was set mid-read. Hashes `cache` holds
+/// for an unchanged file are reused and fresh ones stored back, as in
+/// `find_duplicates`; `progress` covers only the files actually read.
+pub fn hash_assets(
+    assets: &[&AssetInfo],
+    algorithm: HashAlgo,
+    cancel: Option<&AtomicBool>,
+    progress: Option<&HashProgress>,
+    mut cache: Option<&mut ScanCache>,
+) -> Vec<Option<String>> {
+    let cached: Vec<Option<String>> = assets
+        .iter()
+        .map(|asset| {
+            cache
+                .as_deref()
+                .and_then(|c| c.cached_hash(asset, algorithm))
+                .map(str::to_string)
+        })
+        .collect();
+    if let Some(progress) = progress {
+        let to_read = assets
+            .iter()
+            .zip(&cached)
+            .filter(|(_, cached)| cached.is_none())
+            .map(|(asset, _)| asset.size)
+            .sum();
+        progress.start(to_read);
+    }
+
+    let hashes: Vec<Option<String>> = assets
+        .par_iter()
+        .zip(cached.par_iter())
+        .map(|(asset, cached)| {
+            if cached.is_some() {
+                return cached.clone();
+            }
+            if is_cancelled(cancel) {
+                return None;
+            }
+            calculate_file_hash(Path::new(&asset.path), algorithm, cancel, progress)
+        })
+        .collect();
+
+    if let Some(cache) = cache.as_deref_mut() {
+        for ((asset, hash), cached) in assets.iter().zip(&hashes).zip(&cached) {
+            if let (Some(hash), None) = (hash, cached) {
+                cache.store_hash(asset, algorithm, hash.clone());
+            }
+        }
+    }
+    hashes
 }
 
 /// Root-relative form of `path` for user-facing text. Both sides come from
@@ -35,82 +280,573 @@ fn rel<'a>(path: &'a str, root: &str) -> &'a str {
         .unwrap_or(path)
 }
 
-/// Find duplicate files based on content hash. `root` is the scan root —
-/// group paths and suggestions are reported root-relative so the frontend
-/// and exports never show machine-specific prefixes.
-pub fn find_duplicates(assets: &[AssetInfo], root: &str) -> AnalysisResult {
-    let mut result = AnalysisResult::new();
+/// Rule catalog entries for the duplicate check.
+pub fn describe() -> Vec<RuleDescription> {
+    vec![
+        RuleDescription::new(
+            "duplicate",
+            "Duplicate File",
+            Severity::Warning,
+            false,
+            "Two or more files have byte-identical content.",
+        ),
+        RuleDescription::new(
+            "duplicate.likely",
+            "Likely Duplicate File",
+            Severity::Info,
+            false,
+            "Two or more large files agree on every sampled chunk (`[duplicate].sample_mode`).",
+        ),
+    ]
+}
 
-    // Group files by size first (optimization)
-    let mut by_size: HashMap<u64, Vec<&AssetInfo>> = HashMap::new();
+/// Files of one size sharing one content hash, path-ordered.
+struct ContentGroup<'a> {
+    hash: String,
+    members: Vec<&'a AssetInfo>,
+}
+
+/// The content groups of two or more files among `assets`, in no particular
+/// order. Only files sharing a size are hashed; `cancel`, `progress` and
+/// `cache` behave as in `find_duplicates`.
+fn content_groups<'a>(
+    assets: &'a [AssetInfo],
+    config: &DuplicateConfig,
+    cancel: Option<&AtomicBool>,
+    progress: Option<&HashProgress>,
+    cache: Option<&mut ScanCache>,
+) -> Result<Vec<ContentGroup<'a>>, Cancelled> {
+    // Only files sharing a size with another file can be duplicates, so
+    // only those get hashed (optimization)
+    let mut size_counts: HashMap<u64, usize> = HashMap::new();
     for asset in assets {
-        by_size.entry(asset.size).or_default().push(asset);
+        *size_counts.entry(asset.size).or_default() += 1;
     }
+    let candidates: Vec<&AssetInfo> = assets
+        .iter()
+        .filter(|a| size_counts[&a.size] >= 2)
+        .collect();
 
-    // For files with same size, calculate hash
-    for (_, same_size_assets) in by_size {
-        if same_size_assets.len() < 2 {
-            continue;
-        }
+    // Same size means same side of the sampling threshold, so a content
+    // group is either all sampled or all fully hashed.
+    let (sampled, exact): (Vec<&AssetInfo>, Vec<&AssetInfo>) =
+        candidates.into_iter().partition(|a| config.samples(a.size));
 
-        // Calculate hashes for potential duplicates
-        let mut by_hash: HashMap<String, Vec<&AssetInfo>> = HashMap::new();
-        for asset in same_size_assets {
-            if let Some(hash) = calculate_file_hash(Path::new(&asset.path)) {
-                by_hash.entry(hash).or_default().push(asset);
+    // Parallel over files rather than size groups: one huge group (a
+    // library of same-size tiles) would otherwise serialize on one thread.
+    let hashes = hash_assets(&exact, config.algorithm, cancel, progress, cache);
+    let sampled_hashes: Vec<Option<String>> = sampled
+        .par_iter()
+        .map(|asset| {
+            if is_cancelled(cancel) {
+                return None;
             }
+            sampled_file_hash(Path::new(&asset.path), asset.size, config)
+        })
+        .collect();
+    if is_cancelled(cancel) {
+        return Err(Cancelled);
+    }
+
+    let mut by_content: HashMap<(u64, String), Vec<&AssetInfo>> = HashMap::new();
+    let hashed = exact
+        .into_iter()
+        .zip(hashes)
+        .chain(sampled.into_iter().zip(sampled_hashes));
+    for (asset, hash) in hashed {
+        if let Some(hash) = hash {
+            by_content
+                .entry((asset.size, hash))
+                .or_default()
+                .push(asset);
         }
+    }
 
-        // Report duplicates (ordering fixed after the loops — both grouping
-        // maps iterate in random order)
-        for (_hash, duplicates) in by_hash {
-            if duplicates.len() < 2 {
-                continue;
+    Ok(by_content
+        .into_iter()
+        .filter(|(_, members)| members.len() >= 2)
+        .map(|((_, hash), members)| ContentGroup { hash, members })
+        .collect())
+}
+
+/// One set of identical files, as returned by `get_duplicate_groups`.
+#[derive(Debug, Clone, Serialize)]
+pub struct DuplicateGroup {
+    /// Content hash the members share (sampled chunks only when `sampled`).
+    pub hash: String,
+    /// Size of each member.
+    pub size: u64,
+    /// Bytes freed by keeping a single copy: `size × (count − 1)`.
+    pub wasted_bytes: u64,
+    /// Matched on sampled chunks (`sample_min_size`), not full content.
+    pub sampled: bool,
+    /// Every member, absolute and path-sorted.
+    pub paths: Vec<String>,
+}
+
+/// The duplicate check's content groups as data rather than issues, for a
+/// view that lets the user pick the copy to keep. Most wasted bytes first.
+/// `cancel` and `cache` behave as in `find_duplicates`.
+pub fn duplicate_groups(
+    assets: &[AssetInfo],
+    config: &DuplicateConfig,
+    cancel: Option<&AtomicBool>,
+    cache: Option<&mut ScanCache>,
+) -> Result<Vec<DuplicateGroup>, Cancelled> {
+    let groups = content_groups(assets, config, cancel, None, cache)?;
+    let mut groups: Vec<DuplicateGroup> = groups
+        .into_iter()
+        .map(|group| {
+            let size = group.members[0].size;
+            let mut paths: Vec<String> = group.members.iter().map(|a| a.path.clone()).collect();
+            paths.sort();
+            DuplicateGroup {
+                hash: group.hash,
+                size,
+                wasted_bytes: size * (paths.len() as u64 - 1),
+                sampled: config.samples(size),
+                paths,
             }
+        })
+        .collect();
+    groups.sort_by(|a, b| {
+        b.wasted_bytes
+            .cmp(&a.wasted_bytes)
+            .then_with(|| a.paths[0].cmp(&b.paths[0]))
+    });
+    Ok(groups)
+}
 
-            // ONE issue per content group, carrying the full member list
-            // (original first — the group arrives path-sorted from the
-            // scan). An earlier revision emitted one issue per extra copy
-            // with the member list cloned onto each: quadratic in group
-            // size, and a real asset library (Kenney all-in-one: one 3178-
-            // file group) ballooned the IPC payload past 1 GB and OOM'd
-            // the webview. The group card in the UI never needed per-copy
-            // issues anyway.
-            let original = duplicates[0];
-            let first_copy = duplicates[1];
-            let group: Vec<String> = duplicates
-                .iter()
-                .map(|a| rel(&a.path, root).to_string())
-                .collect();
-            result.add_issue(Issue {
-                rule_id: "duplicate".to_string(),
-                rule_name: "Duplicate File".to_string(),
-                severity: Severity::Warning,
-                message: format!(
-                    "{} files share identical content (original: '{}')",
-                    duplicates.len(),
-                    original.name
-                ),
-                // Anchor on the first redundant copy — "locate" should land
-                // on a file the user can act on, not the one to keep.
-                asset_path: first_copy.path.clone(),
-                suggestion: Some(format!(
-                    "Keep '{}' and remove or consolidate the other {} file(s)",
-                    rel(&original.path, root),
-                    duplicates.len() - 1
-                )),
-                auto_fixable: false,
-                related_paths: Some(group),
-            });
-        }
+/// Find duplicate files based on content hash. `root` is the scan root —
+/// group paths and suggestions are reported root-relative so the frontend
+/// and exports never show machine-specific prefixes.
+///
+/// Hashing runs on the rayon pool and polls `cancel` between files and
+/// between chunks of each file; once it's set the pass stops and returns
+/// `Err(Cancelled)` rather than a partial — and therefore misleading —
+/// duplicate list.
+///
+/// With a `cache`, hashes it holds for an unchanged file (same size and
+/// mtime, same algorithm) are reused and only the rest are read; fresh
+/// hashes are stored back for the caller to persist.
+///
+/// `progress`, when given, is reset to the byte total of the files that
+/// need reading before hashing starts and advances as they're read.
+pub fn find_duplicates(
+    assets: &[AssetInfo],
+    root: &str,
+    config: &DuplicateConfig,
+    cancel: Option<&AtomicBool>,
+    progress: Option<&HashProgress>,
+    cache: Option<&mut ScanCache>,
+) -> Result<AnalysisResult, Cancelled> {
+    let mut result = AnalysisResult::new();
+
+    // Report duplicates (ordering fixed after the loop — the grouping map
+    // iterates in random order)
+    for group in content_groups(assets, config, cancel, progress, cache)? {
+        let duplicates = group.members;
+
+        // ONE issue per content group, carrying the full member list
+        // (original first — the group arrives path-sorted from the
+        // scan). An earlier revision emitted one issue per extra copy
+        // with the member list cloned onto each: quadratic in group
+        // size, and a real asset library (Kenney all-in-one: one 3178-
+        // file group) ballooned the IPC payload past 1 GB and OOM'd
+        // the webview. The group card in the UI never needed per-copy
+        // issues anyway.
+        let original = duplicates[0];
+        let first_copy = duplicates[1];
+        let group: Vec<String> = duplicates
+            .iter()
+            .map(|a| rel(&a.path, root).to_string())
+            .collect();
+        // Sampled groups share only the chunks that were read, so they get
+        // their own id: the group card's cleanup keys on `duplicate` and
+        // must never offer to delete files that were never fully compared.
+        let (rule_id, rule_name, severity, verb) = if config.samples(original.size) {
+            (
+                "duplicate.likely",
+                "Likely Duplicate File",
+                Severity::Info,
+                "likely share",
+            )
+        } else {
+            ("duplicate", "Duplicate File", Severity::Warning, "share")
+        };
+        result.add_issue(Issue {
+            rule_id: rule_id.to_string(),
+            rule_name: rule_name.to_string(),
+            severity,
+            message: format!(
+                "{} files {} identical content (original: '{}')",
+                duplicates.len(),
+                verb,
+                original.name
+            ),
+            // Anchor on the first redundant copy — "locate" should land
+            // on a file the user can act on, not the one to keep.
+            asset_path: first_copy.path.clone(),
+            suggestion: Some(format!(
+                "Keep '{}' and remove or consolidate the other {} file(s)",
+                rel(&original.path, root),
+                duplicates.len() - 1
+            )),
+            auto_fixable: false,
+            related_paths: Some(group),
+        });
     }
 
-    // Both grouping maps above are HashMaps, so issue order was random per
+    // The grouping map above is a HashMap, so issue order was random per
     // run — the report reshuffled on every analysis while every sibling rule
     // emits deterministically. Pin it by path. (Members within a group are
     // already path-ordered: `assets` arrives sorted from the scan, so each
     // group's "original" is the lexicographically first path.)
     result.issues.sort_by(|a, b| a.asset_path.cmp(&b.asset_path));
 
-    result
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::AssetType;
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn asset(path: &Path) -> AssetInfo {
+        AssetInfo {
+            path: crate::scanner::path_to_string(path),
+            name: path.file_name().unwrap().to_string_lossy().to_string(),
+            extension: "png".to_string(),
+            asset_type: AssetType::Texture,
+            size: fs::metadata(path).unwrap().len(),
+            modified: 0,
+            metadata: None,
+            unity_guid: None,
+        }
+    }
+
+    #[test]
+    fn hashes_match_reference_tools() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("abc.txt");
+        fs::write(&path, "abc").unwrap();
+
+        assert_eq!(
+            calculate_file_hash(&path, HashAlgo::Sha1, None, None).as_deref(),
+            Some("a9993e364706816aba3e25717850c26c9cd0d89d")
+        );
+        assert_eq!(
+            calculate_file_hash(&path, HashAlgo::Sha256, None, None).as_deref(),
+            Some("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")
+        );
+        assert_eq!(
+            calculate_file_hash(&path, HashAlgo::Blake3, None, None).as_deref(),
+            Some("6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85")
+        );
+    }
+
+    #[test]
+    fn duplicate_groups_list_every_member_with_wasted_bytes() {
+        let dir = tempdir().unwrap();
+        let root = crate::scanner::path_to_string(dir.path());
+        fs::create_dir(dir.path().join("copies")).unwrap();
+        let files = [
+            ("rock.png", "rock-pixels"),
+            ("copies/rock_old.png", "rock-pixels"),
+            ("copies/stone.png", "rock-pixels"),
+            ("tree.png", "tree"),
+            ("tree2.png", "tree"),
+            // Same size as the trees, different content.
+            ("bush.png", "bush"),
+        ];
+        for (name, body) in files {
+            fs::write(dir.path().join(name), body).unwrap();
+        }
+        let assets: Vec<AssetInfo> = files
+            .iter()
+            .map(|(n, _)| asset(&dir.path().join(n)))
+            .collect();
+
+        let groups = duplicate_groups(&assets, &DuplicateConfig::default(), None, None).unwrap();
+        let summary: Vec<(u64, Vec<&str>)> = groups
+            .iter()
+            .map(|g| {
+                let names = g.paths.iter().map(|p| rel(p, &root)).collect();
+                (g.wasted_bytes, names)
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    22,
+                    vec!["copies/rock_old.png", "copies/stone.png", "rock.png"]
+                ),
+                (4, vec!["tree.png", "tree2.png"]),
+            ]
+        );
+        assert!(groups.iter().all(|g| !g.sampled && g.hash.len() == 64));
+    }
+
+    #[test]
+    fn grouping_is_identical_for_every_algorithm() {
+        let dir = tempdir().unwrap();
+        let root = crate::scanner::path_to_string(dir.path());
+        for (name, body) in [("a.png", "same"), ("b.png", "same"), ("c.png", "diff")] {
+            fs::write(dir.path().join(name), body).unwrap();
+        }
+        let assets: Vec<AssetInfo> = ["a.png", "b.png", "c.png"]
+            .iter()
+            .map(|n| asset(&dir.path().join(n)))
+            .collect();
+
+        for algorithm in [HashAlgo::Sha256, HashAlgo::Sha1, HashAlgo::Blake3] {
+            let result = find_duplicates(
+                &assets,
+                &root,
+                &DuplicateConfig {
+                    algorithm,
+                    ..Default::default()
+                },
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+            assert_eq!(result.issues.len(), 1);
+            assert_eq!(
+                result.issues[0].related_paths.as_deref(),
+                Some(&["a.png".to_string(), "b.png".to_string()][..])
+            );
+        }
+    }
+
+    #[test]
+    fn sample_mode_reports_large_matches_as_likely() {
+        let dir = tempdir().unwrap();
+        let root = crate::scanner::path_to_string(dir.path());
+        let big = vec![7u8; 4096];
+        let mut differs_unsampled = big.clone();
+        differs_unsampled[100] = 0;
+        for (name, body) in [
+            ("a.bin", &big),
+            ("b.bin", &big),
+            ("c.bin", &differs_unsampled),
+        ] {
+            fs::write(dir.path().join(name), body).unwrap();
+        }
+        for name in ["x.png", "y.png"] {
+            fs::write(dir.path().join(name), "same").unwrap();
+        }
+        let assets: Vec<AssetInfo> = ["a.bin", "b.bin", "c.bin", "x.png", "y.png"]
+            .iter()
+            .map(|n| asset(&dir.path().join(n)))
+            .collect();
+        // Four 16-byte chunks at 0, 1360, 2720 and 4080: byte 100 is never
+        // read.
+        let config = DuplicateConfig {
+            sample_mode: true,
+            sample_min_size: 1024,
+            sample_chunks: 4,
+            sample_chunk_size: 16,
+            ..Default::default()
+        };
+
+        let result = find_duplicates(&assets, &root, &config, None, None, None).unwrap();
+        let found: Vec<(&str, Severity, usize)> = result
+            .issues
+            .iter()
+            .map(|i| {
+                let members = i.related_paths.as_ref().unwrap().len();
+                (i.rule_id.as_str(), i.severity.clone(), members)
+            })
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("duplicate.likely", Severity::Info, 3),
+                ("duplicate", Severity::Warning, 2)
+            ]
+        );
+
+        let exact = find_duplicates(
+            &assets,
+            &root,
+            &DuplicateConfig::default(),
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(exact.issues[0].related_paths.as_ref().unwrap().len(), 2);
+        assert_eq!(exact.issues[0].severity, Severity::Warning);
+    }
+
+    #[test]
+    fn config_rejects_sampling_that_reads_nothing() {
+        use crate::analyzer::rules::RuleConfig;
+        for setting in ["sample_chunks = 0", "sample_chunk_size = 0"] {
+            let toml = format!("[duplicate]\nsample_mode = true\n{setting}\n");
+            assert!(RuleConfig::from_toml(&toml).is_err(), "{setting}");
+        }
+        assert!(RuleConfig::from_toml("[duplicate]\nsample_chunks = 1\n").is_ok());
+    }
+
+    #[test]
+    fn oversized_sampling_settings_do_not_overflow() {
+        let config = DuplicateConfig {
+            sample_mode: true,
+            sample_min_size: 0,
+            sample_chunks: u32::MAX,
+            sample_chunk_size: u64::MAX,
+            ..Default::default()
+        };
+        assert!(!config.samples(u64::MAX));
+    }
+
+    #[test]
+    fn cancelled_pass_reports_cancelled_not_a_partial_list() {
+        let dir = tempdir().unwrap();
+        let root = crate::scanner::path_to_string(dir.path());
+        for name in ["a.png", "b.png"] {
+            fs::write(dir.path().join(name), "same").unwrap();
+        }
+        let assets: Vec<AssetInfo> = ["a.png", "b.png"]
+            .iter()
+            .map(|n| asset(&dir.path().join(n)))
+            .collect();
+
+        let cancel = AtomicBool::new(true);
+        assert!(find_duplicates(
+            &assets,
+            &root,
+            &DuplicateConfig::default(),
+            Some(&cancel),
+            None,
+            None
+        )
+        .is_err());
+
+        cancel.store(false, Ordering::Relaxed);
+        let result = find_duplicates(
+            &assets,
+            &root,
+            &DuplicateConfig::default(),
+            Some(&cancel),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(result.issues.len(), 1);
+    }
+
+    #[test]
+    fn cached_hashes_give_the_same_groups_as_a_full_pass() {
+        let dir = tempdir().unwrap();
+        let root = crate::scanner::path_to_string(dir.path());
+        for (name, body) in [
+            ("a.png", "same"),
+            ("b.png", "same"),
+            ("c.png", "diff"),
+            ("d.png", "same"),
+        ] {
+            fs::write(dir.path().join(name), body).unwrap();
+        }
+        let assets: Vec<AssetInfo> = ["a.png", "b.png", "c.png", "d.png"]
+            .iter()
+            .map(|n| asset(&dir.path().join(n)))
+            .collect();
+        let config = DuplicateConfig::default();
+        let groups = |result: &AnalysisResult| -> Vec<Option<Vec<String>>> {
+            result
+                .issues
+                .iter()
+                .map(|i| i.related_paths.clone())
+                .collect()
+        };
+        let full = find_duplicates(&assets, &root, &config, None, None, None).unwrap();
+
+        // Cold cache: everything is hashed and stored.
+        let mut cache = ScanCache::new(&root);
+        for a in &assets {
+            cache.update_entry(a.clone(), a.modified, None);
+        }
+        let cold = find_duplicates(&assets, &root, &config, None, None, Some(&mut cache)).unwrap();
+        assert_eq!(groups(&cold), groups(&full));
+        assert!(assets
+            .iter()
+            .all(|a| cache.cached_hash(a, config.algorithm).is_some()));
+
+        // Warm cache: stored hashes are used as-is. Plant a wrong one for
+        // d.png to prove it's read rather than recomputed.
+        cache.store_hash(&assets[3], config.algorithm, "planted".to_string());
+        let warm = find_duplicates(&assets, &root, &config, None, None, Some(&mut cache)).unwrap();
+        assert_eq!(
+            warm.issues[0].related_paths.as_deref(),
+            Some(&["a.png".to_string(), "b.png".to_string()][..])
+        );
+
+        // d.png changed since it was cached (mtime moved): re-hashed, and
+        // the result matches a full pass again.
+        let mut touched = assets.clone();
+        touched[3].modified = 1;
+        let stale =
+            find_duplicates(&touched, &root, &config, None, None, Some(&mut cache)).unwrap();
+        assert_eq!(groups(&stale), groups(&full));
+    }
+
+    #[test]
+    fn progress_counts_bytes_of_uncached_candidates() {
+        let dir = tempdir().unwrap();
+        let root = crate::scanner::path_to_string(dir.path());
+        for (name, body) in [("a.png", "same"), ("b.png", "same"), ("c.png", "unique!")] {
+            fs::write(dir.path().join(name), body).unwrap();
+        }
+        let assets: Vec<AssetInfo> = ["a.png", "b.png", "c.png"]
+            .iter()
+            .map(|n| asset(&dir.path().join(n)))
+            .collect();
+        let config = DuplicateConfig::default();
+
+        // c.png has no same-size partner, so it's never read.
+        let progress = HashProgress::default();
+        find_duplicates(&assets, &root, &config, None, Some(&progress), None).unwrap();
+        let expected = DuplicateProgress {
+            bytes_hashed: 8,
+            bytes_total: 8,
+        };
+        assert_eq!(progress.snapshot(), expected);
+
+        // A cached hash is neither read nor counted.
+        let mut cache = ScanCache::new(&root);
+        for a in &assets {
+            cache.update_entry(a.clone(), a.modified, None);
+        }
+        cache.store_hash(&assets[0], config.algorithm, "cached".to_string());
+        find_duplicates(
+            &assets,
+            &root,
+            &config,
+            None,
+            Some(&progress),
+            Some(&mut cache),
+        )
+        .unwrap();
+        let expected = DuplicateProgress {
+            bytes_hashed: 4,
+            bytes_total: 4,
+        };
+        assert_eq!(progress.snapshot(), expected);
+    }
+
+    #[test]
+    fn algorithm_round_trips_through_toml() {
+        let config: crate::analyzer::rules::RuleConfig =
+            toml::from_str("[duplicate]\nalgorithm = \"sha1\"\n").unwrap();
+        assert_eq!(config.duplicate.algorithm, HashAlgo::Sha1);
+        let default: crate::analyzer::rules::RuleConfig = toml::from_str("").unwrap();
+        assert_eq!(default.duplicate.algorithm, HashAlgo::Blake3);
+    }
 }
@@ -0,0 +1,197 @@
+//! Flag empty assets: 0-byte files, 0×0 images, models with nothing in them.
+//!
+//! These come from interrupted exports, placeholder files checked in "for
+//! later", or a DCC tool that wrote a header and crashed. Engines import
+//! them without complaint and they fail much later, far from the cause.
+//! Works purely from the size and metadata the scan already collected.
+
+use serde::{Deserialize, Serialize};
+
+use crate::analyzer::{Issue, Severity};
+use crate::scanner::{AssetInfo, AssetType};
+
+use super::{Rule, RuleDescription};
+
+/// `[empty_asset]`. Default ON: an empty asset is always a broken one.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmptyAssetConfig {
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+impl Default for EmptyAssetConfig {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+pub struct EmptyAssetRule;
+
+impl EmptyAssetRule {
+    /// What's empty about `asset`, if anything. Unknown dimensions or
+    /// vertex counts (parser couldn't read the file) don't count, and
+    /// neither do vertex-less models holding faces, materials, animations
+    /// or objects — animation-only and rig-only exports are valid.
+    fn emptiness(asset: &AssetInfo) -> Option<String> {
+        if asset.size == 0 {
+            return Some("is a 0-byte file".to_string());
+        }
+        let metadata = asset.metadata.as_ref()?;
+        match asset.asset_type {
+            AssetType::Texture => {
+                let (width, height) = (metadata.width?, metadata.height?);
+                (width == 0 || height == 0)
+                    .then(|| format!("has zero dimensions ({}×{})", width, height))
+            }
+            AssetType::Model => {
+                let holds_something = [
+                    metadata.face_count,
+                    metadata.material_count,
+                    metadata.animation_count,
+                    metadata.object_count,
+                ]
+                .iter()
+                .any(|count| count.is_some_and(|n| n > 0));
+                (metadata.vertex_count? == 0 && !holds_something)
+                    .then(|| "has no vertices, faces, materials or animations".to_string())
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Rule for EmptyAssetRule {
+    fn id(&self) -> &str {
+        "empty_asset"
+    }
+
+    fn name(&self) -> &str {
+        "Empty Asset"
+    }
+
+    fn describe(&self) -> Vec<RuleDescription> {
+        vec![RuleDescription::new(
+            "empty_asset",
+            "Empty Asset",
+            Severity::Error,
+            false,
+            "A 0-byte file, a 0×0 image, or a model with no vertices and nothing else in it.",
+        )]
+    }
+
+    fn applies_to(&self, _asset: &AssetInfo) -> bool {
+        true
+    }
+
+    fn type_specific(&self) -> bool {
+        false
+    }
+
+    fn check(&self, asset: &AssetInfo) -> Option<Issue> {
+        let what = Self::emptiness(asset)?;
+        Some(Issue {
+            rule_id: "empty_asset".to_string(),
+            rule_name: "Empty Asset".to_string(),
+            severity: Severity::Error,
+            message: format!("'{}' {}", asset.name, what),
+            asset_path: asset.path.clone(),
+            suggestion: Some(
+                "Re-export it from the source file, or remove it if it's a leftover placeholder"
+                    .to_string(),
+            ),
+            auto_fixable: false,
+            related_paths: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::{self, AssetMetadata};
+
+    fn asset(asset_type: AssetType, size: u64, metadata: Option<AssetMetadata>) -> AssetInfo {
+        AssetInfo {
+            path: "/test/a".to_string(),
+            name: "a".to_string(),
+            extension: String::new(),
+            asset_type,
+            size,
+            modified: 0,
+            metadata,
+            unity_guid: None,
+        }
+    }
+
+    #[test]
+    fn flags_zero_size_dimensions_and_vertices_only() {
+        let rule = EmptyAssetRule;
+        let texture = |width, height| {
+            Some(AssetMetadata {
+                width: Some(width),
+                height: Some(height),
+                ..Default::default()
+            })
+        };
+        let model = |vertex_count| {
+            Some(AssetMetadata {
+                vertex_count,
+                ..Default::default()
+            })
+        };
+
+        let flagged = |asset: AssetInfo| rule.check(&asset).is_some();
+
+        assert!(flagged(asset(AssetType::Data, 0, None)));
+        assert!(flagged(asset(AssetType::Texture, 10, texture(0, 64))));
+        assert!(flagged(asset(AssetType::Model, 10, model(Some(0)))));
+
+        assert!(!flagged(asset(AssetType::Texture, 10, texture(64, 64))));
+        // Unparsed metadata isn't evidence of emptiness.
+        assert!(!flagged(asset(AssetType::Texture, 10, None)));
+        assert!(!flagged(asset(AssetType::Model, 10, model(None))));
+        assert!(!flagged(asset(AssetType::Data, 10, None)));
+    }
+
+    #[test]
+    fn animation_only_gltf_is_not_empty() {
+        // One node and a translation clip, no meshes: a valid animation
+        // export whose vertex count is 0.
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("Walk.gltf");
+        std::fs::write(
+            &path,
+            r#"{
+              "asset": {"version": "2.0"},
+              "nodes": [{"name": "Hips"}],
+              "scenes": [{"nodes": [0]}],
+              "animations": [{
+                "channels": [{"sampler": 0, "target": {"node": 0, "path": "translation"}}],
+                "samplers": [{"input": 0, "output": 1}]
+              }],
+              "accessors": [
+                {"bufferView": 0, "componentType": 5126, "count": 2, "type": "SCALAR",
+                 "min": [0.0], "max": [1.0]},
+                {"bufferView": 1, "componentType": 5126, "count": 2, "type": "VEC3"}
+              ],
+              "bufferViews": [
+                {"buffer": 0, "byteLength": 8},
+                {"buffer": 0, "byteOffset": 8, "byteLength": 24}
+              ],
+              "buffers": [{"byteLength": 32}]
+            }"#,
+        )
+        .unwrap();
+
+        let asset =
+            scanner::parse_asset_file(&path, &None, &Default::default(), None, true).unwrap();
+        let metadata = asset.metadata.as_ref().unwrap();
+        assert_eq!(metadata.vertex_count, Some(0));
+        assert_eq!(metadata.animation_count, Some(1));
+        assert!(EmptyAssetRule.check(&asset).is_none());
+    }
+}
@@ -0,0 +1,238 @@
+//! Files whose content is a different format than their extension claims.
+//!
+//! A `.jpg` renamed to `.png` without re-encoding opens fine in most image
+//! viewers (they sniff), but engine importers pick the decoder by extension
+//! and fail. We read the first bytes and compare against a small signature
+//! table. Only formats with an unambiguous signature are checked: TGA has
+//! no magic, SVG / ASCII FBX are free-form text, and AAC / WMA containers
+//! are too varied to call reliably.
+//!
+//! Runs on demand (`find_extension_mismatches`), not as part of analysis:
+//! it opens every candidate file, which the per-asset rules never do.
+
+use rayon::prelude::*;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use crate::analyzer::rules::RuleDescription;
+use crate::analyzer::{AnalysisResult, Issue, Severity};
+use crate::scanner::AssetInfo;
+
+/// Larger files are skipped. Renamed-without-re-encoding mistakes happen to
+/// textures and clips, not multi-GB captures.
+const SNIFF_SIZE_CAP: u64 = 256 << 20;
+
+/// Enough for every signature below (WebP / WAVE / AIFF need 12).
+const HEADER_LEN: usize = 16;
+
+/// Format an extension promises, for extensions we can verify.
+pub fn expected_format(extension: &str) -> Option<&'static str> {
+    Some(match extension.to_lowercase().as_str() {
+        "png" => "png",
+        "jpg" | "jpeg" => "jpeg",
+        "gif" => "gif",
+        "bmp" => "bmp",
+        "webp" => "webp",
+        "tif" | "tiff" => "tiff",
+        "psd" => "psd",
+        "dds" => "dds",
+        "exr" => "exr",
+        "hdr" => "hdr",
+        "wav" => "wav",
+        "ogg" => "ogg",
+        "flac" => "flac",
+        "mp3" => "mp3",
+        "aiff" => "aiff",
+        "glb" => "glb",
+        "gltf" => "json",
+        _ => return None,
+    })
+}
+
+/// Identify a header by signature. `None` = nothing recognized, which is
+/// never reported — a truncated or unusual-but-valid file isn't a mismatch.
+fn sniff_format(header: &[u8]) -> Option<&'static str> {
+    let riff_form = |tag: &[u8]| header.get(8..12) == Some(tag);
+    let format = if header.starts_with(b"\x89PNG\r\n\x1a\n") {
+        "png"
+    } else if header.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        "jpeg"
+    } else if header.starts_with(b"GIF87a") || header.starts_with(b"GIF89a") {
+        "gif"
+    } else if header.starts_with(b"RIFF") && riff_form(b"WEBP") {
+        "webp"
+    } else if header.starts_with(b"RIFF") && riff_form(b"WAVE") {
+        "wav"
+    } else if header.starts_with(b"FORM") && (riff_form(b"AIFF") || riff_form(b"AIFC")) {
+        "aiff"
+    } else if header.starts_with(b"II*\0") || header.starts_with(b"MM\0*") {
+        "tiff"
+    } else if header.starts_with(b"8BPS") {
+        "psd"
+    } else if header.starts_with(b"DDS ") {
+        "dds"
+    } else if header.starts_with(&[0x76, 0x2F, 0x31, 0x01]) {
+        "exr"
+    } else if header.starts_with(b"#?RADIANCE") || header.starts_with(b"#?RGBE") {
+        "hdr"
+    } else if header.starts_with(b"OggS") {
+        "ogg"
+    } else if header.starts_with(b"fLaC") {
+        "flac"
+    } else if header.starts_with(b"ID3") {
+        "mp3"
+    } else if header.starts_with(b"glTF") {
+        "glb"
+    } else if header.starts_with(b"BM") && header.len() >= 14 {
+        // Two bytes is a weak signature; require the 14-byte file header.
+        "bmp"
+    } else if header.len() >= 2 && header[0] == 0xFF && header[1] & 0xE0 == 0xE0 {
+        // Bare MPEG audio frame sync (MP3 without an ID3 tag). Checked
+        // last: it's the loosest pattern in the table.
+        "mp3"
+    } else if header
+        .iter()
+        .find(|b| !b.is_ascii_whitespace())
+        .is_some_and(|&b| b == b'{')
+    {
+        "json"
+    } else {
+        return None;
+    };
+    Some(format)
+}
+
+fn display_name(format: &str) -> String {
+    match format {
+        "json" => "glTF JSON".to_string(),
+        "glb" => "binary glTF".to_string(),
+        other => other.to_uppercase(),
+    }
+}
+
+/// Sniff one asset. `Some((expected, actual))` when they disagree.
+fn check_asset(asset: &AssetInfo) -> Option<(&'static str, &'static str)> {
+    let expected = expected_format(&asset.extension)?;
+    if asset.size > SNIFF_SIZE_CAP {
+        return None;
+    }
+    let mut header = Vec::with_capacity(HEADER_LEN);
+    File::open(Path::new(&asset.path))
+        .ok()?
+        .take(HEADER_LEN as u64)
+        .read_to_end(&mut header)
+        .ok()?;
+    let actual = sniff_format(&header)?;
+    (actual != expected).then_some((expected, actual))
+}
+
+/// Rule catalog entry for the extension-mismatch check.
+pub fn describe() -> Vec<RuleDescription> {
+    vec![RuleDescription::new(
+        "extension_mismatch",
+        "Extension Mismatch",
+        Severity::Error,
+        false,
+        "A file's content signature is a different format than its extension claims.",
+    )]
+}
+
+pub fn find_extension_mismatches(assets: &[AssetInfo]) -> AnalysisResult {
+    let mut found: Vec<(&AssetInfo, &str, &str)> = assets
+        .par_iter()
+        .filter_map(|asset| {
+            let (expected, actual) = check_asset(asset)?;
+            Some((asset, expected, actual))
+        })
+        .collect();
+    found.sort_by(|a, b| a.0.path.cmp(&b.0.path));
+
+    let mut result = AnalysisResult::new();
+    for (asset, expected, actual) in found {
+        result.add_issue(Issue {
+            rule_id: "extension_mismatch".to_string(),
+            rule_name: "Extension Mismatch".to_string(),
+            severity: Severity::Error,
+            message: format!(
+                "Content is {} but the .{} extension says {}",
+                display_name(actual),
+                asset.extension,
+                display_name(expected)
+            ),
+            asset_path: asset.path.clone(),
+            suggestion: Some(format!(
+                "Re-export the file as {}, or rename it to match its {} content",
+                display_name(expected),
+                display_name(actual)
+            )),
+            auto_fixable: false,
+            related_paths: None,
+        });
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::AssetType;
+    use tempfile::tempdir;
+
+    fn asset_on_disk(dir: &Path, name: &str, content: &[u8]) -> AssetInfo {
+        let path = dir.join(name);
+        std::fs::write(&path, content).unwrap();
+        AssetInfo {
+            path: crate::scanner::path_to_string(&path),
+            name: name.to_string(),
+            extension: name.rsplit('.').next().unwrap().to_string(),
+            asset_type: AssetType::Texture,
+            size: content.len() as u64,
+            modified: 0,
+            metadata: None,
+            unity_guid: None,
+        }
+    }
+
+    #[test]
+    fn sniffs_common_signatures() {
+        assert_eq!(sniff_format(b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR"), Some("png"));
+        assert_eq!(sniff_format(&[0xFF, 0xD8, 0xFF, 0xE0]), Some("jpeg"));
+        assert_eq!(sniff_format(b"RIFF\0\0\0\0WEBPVP8 "), Some("webp"));
+        assert_eq!(sniff_format(b"RIFF\0\0\0\0WAVEfmt "), Some("wav"));
+        assert_eq!(sniff_format(b"glTF\x02\0\0\0"), Some("glb"));
+        assert_eq!(sniff_format(b"  {\"asset\":"), Some("json"));
+        assert_eq!(sniff_format(&[0xFF, 0xFB, 0x90, 0x64]), Some("mp3"));
+        // Too short to trust the BMP magic; plain text is unknown.
+        assert_eq!(sniff_format(b"BM"), None);
+        assert_eq!(sniff_format(b"hello"), None);
+    }
+
+    #[test]
+    fn reports_only_known_disagreements() {
+        let dir = tempdir().unwrap();
+        let assets = vec![
+            // A JPEG renamed to .png — the case that fails engine import.
+            asset_on_disk(
+                dir.path(),
+                "renamed.png",
+                &[0xFF, 0xD8, 0xFF, 0xE0, 0, 0x10],
+            ),
+            asset_on_disk(dir.path(), "ok.png", b"\x89PNG\r\n\x1a\n"),
+            // .JPEG spelling and case both map to the same format.
+            asset_on_disk(dir.path(), "photo.JPEG", &[0xFF, 0xD8, 0xFF, 0xDB]),
+            // Unrecognized content isn't a mismatch; TGA isn't checked.
+            asset_on_disk(dir.path(), "truncated.png", b"\0\0"),
+            asset_on_disk(dir.path(), "hero.tga", b"\x89PNG\r\n\x1a\n"),
+        ];
+
+        let result = find_extension_mismatches(&assets);
+        assert_eq!(result.error_count, 1);
+        let issue = &result.issues[0];
+        assert!(issue.asset_path.ends_with("renamed.png"));
+        assert_eq!(
+            issue.message,
+            "Content is JPEG but the .png extension says PNG"
+        );
+    }
+}
@@ -0,0 +1,235 @@
+//! Text files mixing CRLF, LF (and stray CR) line endings.
+//!
+//! One file edited on Windows and on macOS without a `.gitattributes` eol
+//! rule ends up with both: every touched line shows in diffs, and some
+//! shader compilers and data loaders report the wrong line for errors. The
+//! candidates are the text files `text_duplicate` compares (same types,
+//! same `MAX_TEXT_SIZE` cap, UTF-8 only); each is reported as info with the
+//! ending most of its lines use.
+//!
+//! Runs on demand (`find_mixed_line_endings`), not as part of analysis.
+//! `fix_mixed_line_endings` rewrites files with `normalize`, to
+//! `[line_endings] target` unless told otherwise.
+
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fs;
+
+use crate::analyzer::rules::RuleDescription;
+use crate::analyzer::{AnalysisResult, Issue, Severity};
+use crate::scanner::AssetInfo;
+
+use super::text_duplicate::is_text_candidate;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LineEnding {
+    Lf,
+    Crlf,
+    Cr,
+}
+
+impl LineEnding {
+    fn as_str(self) -> &'static str {
+        match self {
+            LineEnding::Lf => "\n",
+            LineEnding::Crlf => "\r\n",
+            LineEnding::Cr => "\r",
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            LineEnding::Lf => "LF",
+            LineEnding::Crlf => "CRLF",
+            LineEnding::Cr => "CR",
+        }
+    }
+}
+
+/// `[line_endings]` in `tidycraft.toml`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LineEndingConfig {
+    /// What `fix_mixed_line_endings` normalizes to when not given one.
+    #[serde(default = "default_target")]
+    pub target: LineEnding,
+}
+
+fn default_target() -> LineEnding {
+    LineEnding::Lf
+}
+
+impl Default for LineEndingConfig {
+    fn default() -> Self {
+        Self {
+            target: default_target(),
+        }
+    }
+}
+
+/// How many lines of a text end each way.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EndingCounts {
+    pub lf: usize,
+    pub crlf: usize,
+    pub cr: usize,
+}
+
+impl EndingCounts {
+    pub fn of(text: &str) -> Self {
+        let mut counts = Self::default();
+        let mut bytes = text.bytes().peekable();
+        while let Some(byte) = bytes.next() {
+            match byte {
+                b'\r' if bytes.peek() == Some(&b'\n') => {
+                    bytes.next();
+                    counts.crlf += 1;
+                }
+                b'\r' => counts.cr += 1,
+                b'\n' => counts.lf += 1,
+                _ => {}
+            }
+        }
+        counts
+    }
+
+    fn by_ending(self) -> [(LineEnding, usize); 3] {
+        [
+            (LineEnding::Lf, self.lf),
+            (LineEnding::Crlf, self.crlf),
+            (LineEnding::Cr, self.cr),
+        ]
+    }
+
+    /// More than one kind of ending occurs.
+    pub fn is_mixed(self) -> bool {
+        self.by_ending().iter().filter(|(_, n)| *n > 0).count() > 1
+    }
+
+    /// The most used ending; ties go to LF, then CRLF.
+    pub fn dominant(self) -> LineEnding {
+        self.by_ending()
+            .into_iter()
+            .rev()
+            .max_by_key(|(_, n)| *n)
+            .map(|(ending, _)| ending)
+            .unwrap_or(LineEnding::Lf)
+    }
+}
+
+/// `text` with every line ending replaced by `ending`.
+pub fn normalize(text: &str, ending: LineEnding) -> String {
+    let unified = text.replace("\r\n", "\n").replace('\r', "\n");
+    match ending {
+        LineEnding::Lf => unified,
+        _ => unified.replace('\n', ending.as_str()),
+    }
+}
+
+/// Rule catalog entry for the line-ending check.
+pub fn describe() -> Vec<RuleDescription> {
+    vec![RuleDescription::new(
+        "text.mixed_line_endings",
+        "Mixed Line Endings",
+        Severity::Info,
+        true,
+        "A text file mixes CRLF, LF or CR line endings.",
+    )]
+}
+
+/// One info per text file with mixed endings, sorted by path. Files
+/// `is_excluded` accepts aren't read.
+pub fn find_mixed_line_endings(
+    assets: &[AssetInfo],
+    is_excluded: impl Fn(&str) -> bool + Sync,
+) -> AnalysisResult {
+    let mut mixed: Vec<(&AssetInfo, EndingCounts)> = assets
+        .par_iter()
+        .filter(|a| is_text_candidate(a) && !is_excluded(&a.path))
+        .filter_map(|asset| {
+            let bytes = fs::read(&asset.path).ok()?;
+            let counts = EndingCounts::of(std::str::from_utf8(&bytes).ok()?);
+            counts.is_mixed().then_some((asset, counts))
+        })
+        .collect();
+    mixed.sort_by(|a, b| a.0.path.cmp(&b.0.path));
+
+    let mut result = AnalysisResult::new();
+    for (asset, counts) in mixed {
+        let present: Vec<String> = counts
+            .by_ending()
+            .iter()
+            .filter(|(_, n)| *n > 0)
+            .map(|(ending, n)| format!("{} {}", n, ending.label()))
+            .collect();
+        result.add_issue(Issue {
+            rule_id: "text.mixed_line_endings".to_string(),
+            rule_name: "Mixed Line Endings".to_string(),
+            severity: Severity::Info,
+            message: format!(
+                "Line endings are mixed ({}); mostly {}",
+                present.join(", "),
+                counts.dominant().label()
+            ),
+            asset_path: asset.path.clone(),
+            suggestion: Some(
+                "Normalize the file to one ending, and add an eol rule to .gitattributes so it stays that way"
+                    .to_string(),
+            ),
+            auto_fixable: true,
+            related_paths: None,
+        });
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::{self, AssetType};
+    use std::path::Path;
+    use tempfile::tempdir;
+
+    fn file(dir: &Path, name: &str, content: &[u8]) -> AssetInfo {
+        let path = dir.join(name);
+        fs::write(&path, content).unwrap();
+        AssetInfo {
+            path: scanner::path_to_string(&path),
+            name: name.to_string(),
+            extension: name.rsplit('.').next().unwrap().to_string(),
+            asset_type: AssetType::Other,
+            size: content.len() as u64,
+            modified: 0,
+            metadata: None,
+            unity_guid: None,
+        }
+    }
+
+    #[test]
+    fn reports_files_mixing_endings_with_the_dominant_one() {
+        let dir = tempdir().unwrap();
+        let dir = dir.path();
+        let assets = vec![
+            file(dir, "Lit.shader", b"Shader {\r\n  Pass {\r\n  }\n}\r\n"),
+            file(dir, "clean.json", b"{\n}\n"),
+            file(dir, "Vendor.hlsl", b"a\r\nb\n"),
+            // Not a text type.
+            file(dir, "icon.png", b"\r\n\n"),
+        ];
+
+        let result = find_mixed_line_endings(&assets, |path| path.ends_with("Vendor.hlsl"));
+        assert_eq!(result.issues.len(), 1);
+        let issue = &result.issues[0];
+        assert!(issue.asset_path.ends_with("Lit.shader"));
+        assert_eq!(issue.severity, Severity::Info);
+        assert_eq!(
+            issue.message,
+            "Line endings are mixed (1 LF, 3 CRLF); mostly CRLF"
+        );
+
+        let text = "a\r\nb\nc\rd";
+        assert_eq!(normalize(text, LineEnding::Lf), "a\nb\nc\nd");
+        assert_eq!(normalize(text, LineEnding::Crlf), "a\r\nb\r\nc\r\nd");
+        assert!(!EndingCounts::of(&normalize(text, LineEnding::Crlf)).is_mixed());
+    }
+}
@@ -0,0 +1,298 @@
+//! LOD chain grouping and completeness.
+//!
+//! Models named `<base>_LOD<n>` in the same directory form one chain. A
+//! sibling with the bare `<base>` name counts as LOD0 — Unreal imports the
+//! base mesh that way and many exporters only suffix the reductions. Three
+//! findings:
+//! - `lod.gap` — a level between the lowest and highest is missing
+//!   (`_LOD0` and `_LOD2` but no `_LOD1`).
+//! - `lod.missing_lod0` — reductions exist but the full-detail mesh doesn't.
+//! - `lod.none` — a lone model above `[model].lod_vertex_threshold`
+//!   vertices with no LODs at all.
+//!
+//! Cross-asset like the PBR set check, but run on demand through
+//! `analyze_lod_chains` rather than inside `analyze_assets`: the result is
+//! the chains themselves, with issues attached.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::analyzer::rules::RuleDescription;
+use crate::analyzer::{Issue, Severity};
+use crate::scanner::{AssetInfo, AssetType};
+
+use super::model::ModelConfig;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LodMember {
+    pub path: String,
+    /// `None` for the unsuffixed base model (treated as LOD0).
+    pub level: Option<u32>,
+    pub vertex_count: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LodChain {
+    /// Base name without the LOD suffix, in the first member's casing.
+    pub base_name: String,
+    pub directory: String,
+    /// Ordered by level, base model first.
+    pub members: Vec<LodMember>,
+    pub issues: Vec<Issue>,
+}
+
+/// Split `Rock_LOD2` into (`Rock`, Some(2)). The separator must be `_` and
+/// the suffix is case-insensitive; anything else is (`stem`, None).
+fn parse_lod_suffix(stem: &str) -> (&str, Option<u32>) {
+    let Some(at) = stem.rfind('_') else {
+        return (stem, None);
+    };
+    let suffix = &stem[at + 1..];
+    let level = suffix
+        .get(..3)
+        .filter(|prefix| prefix.eq_ignore_ascii_case("lod"))
+        .and_then(|_| suffix.get(3..))
+        .filter(|digits| !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()))
+        .and_then(|digits| digits.parse().ok());
+    match level {
+        Some(level) if at > 0 => (&stem[..at], Some(level)),
+        _ => (stem, None),
+    }
+}
+
+fn lod_issue(
+    rule_id: &str,
+    rule_name: &str,
+    severity: Severity,
+    message: String,
+    path: &str,
+    suggestion: &str,
+) -> Issue {
+    Issue {
+        rule_id: rule_id.to_string(),
+        rule_name: rule_name.to_string(),
+        severity,
+        message,
+        asset_path: path.to_string(),
+        suggestion: Some(suggestion.to_string()),
+        auto_fixable: false,
+        related_paths: None,
+    }
+}
+
+/// Rule catalog entry for the LOD chain check.
+pub fn describe() -> Vec<RuleDescription> {
+    vec![
+        RuleDescription::new(
+            "lod.none",
+            "No LODs",
+            Severity::Info,
+            false,
+            "A model above [model].lod_vertex_threshold vertices has no LOD chain.",
+        ),
+        RuleDescription::new(
+            "lod.missing_lod0",
+            "Missing LOD0",
+            Severity::Warning,
+            false,
+            "A LOD chain has reductions but no full-detail mesh.",
+        ),
+        RuleDescription::new(
+            "lod.gap",
+            "LOD Chain Gap",
+            Severity::Warning,
+            false,
+            "A LOD chain skips a level between its lowest and highest.",
+        ),
+    ]
+}
+
+/// Group models into LOD chains. Returns every chain with at least one
+/// suffixed member, plus lone high-poly models (as one-member chains
+/// carrying `lod.none`). Sorted by directory, then base name.
+pub fn find_lod_chains(assets: &[AssetInfo], config: &ModelConfig) -> Vec<LodChain> {
+    // (directory, lowercased base) → (display base, members)
+    let mut groups: BTreeMap<(String, String), (String, Vec<LodMember>)> = BTreeMap::new();
+    for asset in assets {
+        if !matches!(asset.asset_type, AssetType::Model) {
+            continue;
+        }
+        let Some(stem) = Path::new(&asset.name).file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        let directory = Path::new(&asset.path)
+            .parent()
+            .and_then(|p| p.to_str())
+            .unwrap_or("")
+            .to_string();
+        let (base, level) = parse_lod_suffix(stem);
+        let entry = groups
+            .entry((directory, base.to_lowercase()))
+            .or_insert_with(|| (base.to_string(), Vec::new()));
+        entry.1.push(LodMember {
+            path: asset.path.clone(),
+            level,
+            vertex_count: asset.metadata.as_ref().and_then(|m| m.vertex_count),
+        });
+    }
+
+    let mut chains = Vec::new();
+    for ((directory, _), (base_name, mut members)) in groups {
+        members.sort_by(|a, b| (a.level, &a.path).cmp(&(b.level, &b.path)));
+        let levels: BTreeSet<u32> = members.iter().filter_map(|m| m.level).collect();
+        let has_base = members.iter().any(|m| m.level.is_none());
+        let mut issues = Vec::new();
+
+        if levels.is_empty() {
+            // No suffixed members: only a lone, heavy model is worth a chain.
+            let [member] = members.as_slice() else {
+                continue;
+            };
+            match member.vertex_count {
+                Some(count) if count > config.lod_vertex_threshold => issues.push(lod_issue(
+                    "lod.none",
+                    "No LODs",
+                    Severity::Info,
+                    format!(
+                        "Model has {} vertices and no LOD chain (threshold {})",
+                        count, config.lod_vertex_threshold
+                    ),
+                    &member.path,
+                    "Export reduced meshes as _LOD1, _LOD2, … next to it",
+                )),
+                _ => continue,
+            }
+        } else {
+            let anchor = members[0].path.clone();
+            if !has_base && !levels.contains(&0) {
+                issues.push(lod_issue(
+                    "lod.missing_lod0",
+                    "Missing LOD0",
+                    Severity::Warning,
+                    format!("LOD chain '{}' has no LOD0", base_name),
+                    &anchor,
+                    "Add the full-detail mesh as _LOD0 (or with the bare base name)",
+                ));
+            }
+            // A bare base model fills level 0 for gap purposes.
+            let lowest = if has_base {
+                0
+            } else {
+                *levels.first().unwrap()
+            };
+            let highest = *levels.last().unwrap();
+            let missing: Vec<String> = (lowest..highest)
+                .filter(|l| !levels.contains(l) && !(*l == 0 && has_base))
+                .map(|l| format!("LOD{}", l))
+                .collect();
+            if !missing.is_empty() {
+                issues.push(lod_issue(
+                    "lod.gap",
+                    "LOD Chain Gap",
+                    Severity::Warning,
+                    format!("LOD chain '{}' skips {}", base_name, missing.join(", ")),
+                    &anchor,
+                    "Add the missing levels or renumber the chain so levels are contiguous",
+                ));
+            }
+        }
+
+        chains.push(LodChain {
+            base_name,
+            directory,
+            members,
+            issues,
+        });
+    }
+    chains
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::AssetMetadata;
+
+    fn model(path: &str, vertices: Option<u32>) -> AssetInfo {
+        AssetInfo {
+            path: path.to_string(),
+            name: path.rsplit('/').next().unwrap().to_string(),
+            extension: "fbx".to_string(),
+            asset_type: AssetType::Model,
+            size: 1024,
+            modified: 0,
+            metadata: vertices.map(|v| AssetMetadata {
+                vertex_count: Some(v),
+                ..Default::default()
+            }),
+            unity_guid: None,
+        }
+    }
+
+    fn rule_ids(chain: &LodChain) -> Vec<&str> {
+        chain.issues.iter().map(|i| i.rule_id.as_str()).collect()
+    }
+
+    #[test]
+    fn parses_lod_suffixes() {
+        assert_eq!(parse_lod_suffix("Rock_LOD2"), ("Rock", Some(2)));
+        assert_eq!(parse_lod_suffix("rock_lod10"), ("rock", Some(10)));
+        assert_eq!(parse_lod_suffix("Rock_LOD"), ("Rock_LOD", None));
+        assert_eq!(parse_lod_suffix("RockLOD1"), ("RockLOD1", None));
+        assert_eq!(parse_lod_suffix("_LOD1"), ("_LOD1", None));
+        assert_eq!(parse_lod_suffix("Rock_Lodge"), ("Rock_Lodge", None));
+    }
+
+    #[test]
+    fn groups_chain_and_reports_gap() {
+        let assets = vec![
+            model("/p/Rock_LOD2.fbx", Some(500)),
+            model("/p/Rock_LOD0.fbx", Some(5000)),
+            model("/p/rock_lod3.fbx", Some(100)),
+            // Same base in another directory is a different chain.
+            model("/q/Rock_LOD0.fbx", Some(5000)),
+        ];
+        let chains = find_lod_chains(&assets, &ModelConfig::default());
+        assert_eq!(chains.len(), 2);
+        let chain = &chains[0];
+        assert_eq!(chain.directory, "/p");
+        let levels: Vec<Option<u32>> = chain.members.iter().map(|m| m.level).collect();
+        assert_eq!(levels, vec![Some(0), Some(2), Some(3)]);
+        assert_eq!(rule_ids(chain), vec!["lod.gap"]);
+        assert!(chain.issues[0].message.ends_with("skips LOD1"));
+        assert!(chains[1].issues.is_empty());
+    }
+
+    #[test]
+    fn bare_base_counts_as_lod0() {
+        let assets = vec![
+            model("/p/Tree.fbx", Some(8000)),
+            model("/p/Tree_LOD1.fbx", Some(2000)),
+        ];
+        let chains = find_lod_chains(&assets, &ModelConfig::default());
+        assert_eq!(chains.len(), 1);
+        assert_eq!(chains[0].members[0].level, None);
+        assert!(chains[0].issues.is_empty());
+
+        let chains = find_lod_chains(&[model("/p/Tree_LOD1.fbx", None)], &ModelConfig::default());
+        assert_eq!(rule_ids(&chains[0]), vec!["lod.missing_lod0"]);
+    }
+
+    #[test]
+    fn lone_high_poly_model_needs_lods() {
+        let config = ModelConfig {
+            lod_vertex_threshold: 10_000,
+            ..Default::default()
+        };
+        let assets = vec![
+            model("/p/Statue.fbx", Some(50_000)),
+            model("/p/Cup.fbx", Some(300)),
+            model("/p/Unknown.fbx", None),
+        ];
+        let chains = find_lod_chains(&assets, &config);
+        assert_eq!(chains.len(), 1);
+        assert_eq!(chains[0].base_name, "Statue");
+        assert_eq!(rule_ids(&chains[0]), vec!["lod.none"]);
+    }
+}
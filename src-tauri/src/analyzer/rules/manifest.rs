@@ -0,0 +1,265 @@
+//! Asset inventory checked against a policy manifest.
+//!
+//! CI wants to assert what may exist in a project, not just how good each
+//! asset is: "no `.psd` outside `ArtSource/`", "no executables", "these
+//! folders must be there". The manifest is a small TOML document, separate
+//! from `tidycraft.toml` so a pipeline can keep one policy for many
+//! projects. Patterns are globs relative to the project root, matched like
+//! `[ignore].patterns` but case-insensitively — `**/*.exe` must catch
+//! `Setup.EXE` on the filesystems that don't care. Every violation is an
+//! error.
+//!
+//! Runs on demand (`validate_against_manifest`), not as part of analysis.
+
+use serde::Deserialize;
+use std::path::Path;
+
+use crate::analyzer::rules::RuleDescription;
+use crate::analyzer::{AnalysisResult, Issue, Severity};
+use crate::scanner::{self, AssetInfo};
+
+/// The manifest's TOML layout. Every field is optional.
+///
+/// ```toml
+/// allowed_extensions = ["png", "fbx", "wav", "psd"]
+/// forbidden_paths = ["**/*.exe", "**/*.dll"]
+/// required_folders = ["Assets/Art", "ArtSource"]
+///
+/// [[placement]]
+/// extensions = ["psd"]
+/// paths = ["ArtSource/**"]
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AssetManifest {
+    /// Extensions (case-insensitive, no dot) an asset may have. Empty
+    /// allows any.
+    #[serde(default)]
+    pub allowed_extensions: Vec<String>,
+    /// Globs no asset may match.
+    #[serde(default)]
+    pub forbidden_paths: Vec<String>,
+    /// Folders, relative to the root, that must exist.
+    #[serde(default)]
+    pub required_folders: Vec<String>,
+    #[serde(default)]
+    pub placement: Vec<PlacementRule>,
+}
+
+/// Assets with one of `extensions` must match one of `paths`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PlacementRule {
+    pub extensions: Vec<String>,
+    pub paths: Vec<String>,
+}
+
+impl AssetManifest {
+    pub fn from_toml(content: &str) -> Result<Self, String> {
+        toml::from_str(content).map_err(|e| format!("Invalid manifest: {}", e))
+    }
+}
+
+fn glob_set(patterns: &[String]) -> Result<globset::GlobSet, String> {
+    super::build_glob_set(patterns, "manifest", true)
+}
+
+fn has_extension(list: &[String], extension: &str) -> bool {
+    list.iter()
+        .any(|e| e.trim_start_matches('.').eq_ignore_ascii_case(extension))
+}
+
+fn violation(rule_id: &str, rule_name: &str, message: String, path: String) -> Issue {
+    Issue {
+        rule_id: rule_id.to_string(),
+        rule_name: rule_name.to_string(),
+        severity: Severity::Error,
+        message,
+        asset_path: path,
+        suggestion: Some("Move or remove it, or update the manifest".to_string()),
+        auto_fixable: false,
+        related_paths: None,
+    }
+}
+
+/// Rule catalog entry for the manifest check.
+pub fn describe() -> Vec<RuleDescription> {
+    vec![
+        RuleDescription::new(
+            "manifest.extension",
+            "Extension Not Allowed",
+            Severity::Error,
+            false,
+            "An asset's extension is not in the manifest's allowed_extensions.",
+        ),
+        RuleDescription::new(
+            "manifest.forbidden_path",
+            "Forbidden Path",
+            Severity::Error,
+            false,
+            "An asset matches one of the manifest's forbidden_paths.",
+        ),
+        RuleDescription::new(
+            "manifest.placement",
+            "Misplaced Asset",
+            Severity::Error,
+            false,
+            "An asset lies outside the folders a manifest placement rule allows for its extension.",
+        ),
+        RuleDescription::new(
+            "manifest.required_folder",
+            "Missing Required Folder",
+            Severity::Error,
+            false,
+            "A folder the manifest requires does not exist.",
+        ),
+    ]
+}
+
+/// One issue per violated constraint per asset, plus one per missing
+/// required folder. A malformed glob is an `Err`, not a silent pass.
+pub fn validate_against_manifest(
+    assets: &[AssetInfo],
+    root: &str,
+    manifest: &AssetManifest,
+) -> Result<AnalysisResult, String> {
+    let forbidden = glob_set(&manifest.forbidden_paths)?;
+    let placements = manifest
+        .placement
+        .iter()
+        .map(|rule| Ok((rule, glob_set(&rule.paths)?)))
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let root = Path::new(root);
+    let mut result = AnalysisResult::new();
+    for asset in assets {
+        let path = Path::new(&asset.path);
+        let rel = path.strip_prefix(root).unwrap_or(path);
+
+        if !manifest.allowed_extensions.is_empty()
+            && !has_extension(&manifest.allowed_extensions, &asset.extension)
+        {
+            result.add_issue(violation(
+                "manifest.extension",
+                "Extension Not Allowed",
+                format!("'.{}' is not an allowed extension", asset.extension),
+                asset.path.clone(),
+            ));
+        }
+        if forbidden.is_match(rel) {
+            result.add_issue(violation(
+                "manifest.forbidden_path",
+                "Forbidden Path",
+                format!(
+                    "'{}' matches a forbidden path",
+                    scanner::path_to_string(rel)
+                ),
+                asset.path.clone(),
+            ));
+        }
+        for (rule, allowed) in &placements {
+            if has_extension(&rule.extensions, &asset.extension) && !allowed.is_match(rel) {
+                result.add_issue(violation(
+                    "manifest.placement",
+                    "Misplaced Asset",
+                    format!(
+                        "'.{}' files belong under {}",
+                        asset.extension,
+                        rule.paths.join(", ")
+                    ),
+                    asset.path.clone(),
+                ));
+            }
+        }
+    }
+
+    for folder in &manifest.required_folders {
+        let dir = root.join(folder.trim_matches('/'));
+        if !dir.is_dir() {
+            result.add_issue(Issue {
+                suggestion: Some("Create the folder, or update the manifest".to_string()),
+                ..violation(
+                    "manifest.required_folder",
+                    "Missing Required Folder",
+                    format!("Required folder '{}' does not exist", folder),
+                    scanner::path_to_string(&dir),
+                )
+            });
+        }
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::AssetType;
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn asset(root: &str, rel: &str) -> AssetInfo {
+        let name = rel.rsplit('/').next().unwrap().to_string();
+        AssetInfo {
+            path: format!("{}/{}", root, rel),
+            extension: name.rsplit('.').next().unwrap().to_string(),
+            name,
+            asset_type: AssetType::Other,
+            size: 1,
+            modified: 0,
+            metadata: None,
+            unity_guid: None,
+        }
+    }
+
+    #[test]
+    fn reports_each_kind_of_violation() {
+        let dir = tempdir().unwrap();
+        let root = scanner::path_to_string(dir.path());
+        fs::create_dir_all(dir.path().join("ArtSource")).unwrap();
+        let assets = vec![
+            asset(&root, "ArtSource/hero.psd"),
+            asset(&root, "Assets/Art/hero.PNG"),
+            asset(&root, "Assets/Art/hero.psd"),
+            asset(&root, "Tools/build.exe"),
+            asset(&root, "Tools/Setup.EXE"),
+            asset(&root, "Assets/notes.txt"),
+        ];
+        let manifest = AssetManifest::from_toml(
+            r#"
+allowed_extensions = ["png", "psd", "exe"]
+forbidden_paths = ["**/*.exe"]
+required_folders = ["ArtSource", "Assets/Audio"]
+
+[[placement]]
+extensions = ["psd"]
+paths = ["ArtSource/**"]
+"#,
+        )
+        .unwrap();
+
+        let result = validate_against_manifest(&assets, &root, &manifest).unwrap();
+        let found: Vec<(&str, &str)> = result
+            .issues
+            .iter()
+            .map(|i| (i.rule_id.as_str(), &i.asset_path[root.len() + 1..]))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("manifest.placement", "Assets/Art/hero.psd"),
+                ("manifest.forbidden_path", "Tools/build.exe"),
+                ("manifest.forbidden_path", "Tools/Setup.EXE"),
+                ("manifest.extension", "Assets/notes.txt"),
+                ("manifest.required_folder", "Assets/Audio"),
+            ]
+        );
+        assert!(result.issues.iter().all(|i| i.severity == Severity::Error));
+
+        assert!(AssetManifest::from_toml("allowed = []").is_err());
+        let bad_glob = AssetManifest {
+            forbidden_paths: vec!["[".to_string()],
+            ..Default::default()
+        };
+        assert!(validate_against_manifest(&assets, &root, &bad_glob).is_err());
+    }
+}
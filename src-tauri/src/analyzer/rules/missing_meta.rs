@@ -0,0 +1,152 @@
+//! Unity assets committed without their `.meta` sidecar.
+//!
+//! The reverse of an orphaned `.meta`: when an asset reaches version control
+//! without its sidecar, the next import on another machine generates a fresh
+//! GUID for it and every scene, prefab and material referencing the old one
+//! silently breaks. Reported as errors — it's the usual Unity CI gate.
+//!
+//! Runs on demand (`find_missing_meta`), not as part of analysis: it stats a
+//! sidecar path per asset, which the per-asset rules never do.
+
+use std::path::Path;
+
+use crate::analyzer::rules::RuleDescription;
+use crate::analyzer::{AnalysisResult, Issue, Severity};
+use crate::scanner::{self, AssetInfo, ProjectType};
+
+/// Whether Unity imports `rel` (project-relative) and so writes a `.meta`
+/// for it: anything under `Assets/`, or inside an embedded package under
+/// `Packages/<name>/`. Unity skips names that are hidden (`.git`), end in
+/// `~` (`Samples~`), are `cvs`, or end in `.tmp` — and everything below them.
+fn imported_by_unity(rel: &Path) -> bool {
+    let parts: Vec<&str> = rel
+        .components()
+        .filter_map(|c| c.as_os_str().to_str())
+        .collect();
+    let in_tree = match parts.first() {
+        Some(&"Assets") => parts.len() >= 2,
+        // `Packages/manifest.json` itself has no sidecar; package contents do.
+        Some(&"Packages") => parts.len() >= 3,
+        _ => false,
+    };
+    in_tree
+        && !parts.iter().any(|part| {
+            part.starts_with('.')
+                || part.ends_with('~')
+                || part.eq_ignore_ascii_case("cvs")
+                || part.to_lowercase().ends_with(".tmp")
+        })
+}
+
+/// Rule catalog entry for the missing-meta check.
+pub fn describe() -> Vec<RuleDescription> {
+    vec![RuleDescription::new(
+        "missing_meta",
+        "Missing .meta File",
+        Severity::Error,
+        false,
+        "A Unity asset has no .meta sidecar, so its GUID is regenerated on the next import.",
+    )]
+}
+
+pub fn find_missing_meta(
+    assets: &[AssetInfo],
+    root: &str,
+    project_type: &Option<ProjectType>,
+) -> AnalysisResult {
+    let mut result = AnalysisResult::new();
+    if !matches!(project_type, Some(ProjectType::Unity)) {
+        return result;
+    }
+
+    let root = Path::new(root);
+    for asset in assets {
+        let path = Path::new(&asset.path);
+        let Ok(rel) = path.strip_prefix(root) else {
+            continue;
+        };
+        if !imported_by_unity(rel) || scanner::unity_meta_path(path).is_some() {
+            continue;
+        }
+        result.add_issue(Issue {
+            rule_id: "missing_meta".to_string(),
+            rule_name: "Missing .meta File".to_string(),
+            severity: Severity::Error,
+            message: format!(
+                "'{}' has no .meta file; Unity will assign it a new GUID and break references to it",
+                asset.name
+            ),
+            asset_path: asset.path.clone(),
+            suggestion: Some(
+                "Commit the .meta Unity generated for it, or restore the original from version control"
+                    .to_string(),
+            ),
+            auto_fixable: false,
+            related_paths: None,
+        });
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::AssetType;
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn asset(root: &Path, rel: &str) -> AssetInfo {
+        let path = root.join(rel);
+        AssetInfo {
+            path: scanner::path_to_string(&path),
+            name: path.file_name().unwrap().to_string_lossy().to_string(),
+            extension: path.extension().unwrap().to_string_lossy().to_string(),
+            asset_type: AssetType::Other,
+            size: 1,
+            modified: 0,
+            metadata: None,
+            unity_guid: None,
+        }
+    }
+
+    #[test]
+    fn flags_only_imported_assets_without_a_sidecar() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        let files = [
+            "Assets/Art/hero.png",
+            "Assets/Art/hero.png.meta",
+            "Assets/Art/villain.png",
+            "Assets/Samples~/demo.png",
+            "Packages/com.studio.tools/icon.png",
+            "Packages/manifest.json",
+            "ProjectSettings/TagManager.asset",
+        ];
+        for rel in files {
+            let path = root.join(rel);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(&path, "x").unwrap();
+        }
+        let assets: Vec<AssetInfo> = files
+            .iter()
+            .filter(|rel| !rel.ends_with(".meta"))
+            .map(|rel| asset(root, rel))
+            .collect();
+        let root = scanner::path_to_string(root);
+
+        let result = find_missing_meta(&assets, &root, &Some(ProjectType::Unity));
+        let flagged: Vec<&str> = result
+            .issues
+            .iter()
+            .map(|i| i.asset_path.as_str())
+            .collect();
+        assert_eq!(flagged.len(), 2);
+        assert!(flagged[0].ends_with("Assets/Art/villain.png"));
+        assert!(flagged[1].ends_with("Packages/com.studio.tools/icon.png"));
+        assert!(result.issues.iter().all(|i| i.severity == Severity::Error));
+
+        assert!(find_missing_meta(&assets, &root, &Some(ProjectType::Godot))
+            .issues
+            .is_empty());
+    }
+}
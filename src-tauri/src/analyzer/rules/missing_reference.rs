@@ -9,6 +9,7 @@
 use std::collections::HashSet;
 use std::path::Path;
 
+use crate::analyzer::rules::RuleDescription;
 use crate::analyzer::{AnalysisResult, Issue, Severity};
 use crate::scanner::{AssetInfo, ProjectType};
 use crate::unity;
@@ -16,6 +17,17 @@ use crate::unity;
 /// Extensions that Unity stores as YAML with GUID references.
 const REFERENCEABLE_EXTS: &[&str] = &["prefab", "unity", "mat", "controller", "asset"];
 
+/// Rule catalog entry for the missing-reference check.
+pub fn describe() -> Vec<RuleDescription> {
+    vec![RuleDescription::new(
+        "missing_reference",
+        "Missing Reference",
+        Severity::Warning,
+        false,
+        "A Unity scene, prefab or material references a GUID that no .meta file in the project defines.",
+    )]
+}
+
 pub fn find_missing_references(
     assets: &[AssetInfo],
     project_type: &Option<ProjectType>,
@@ -93,7 +105,7 @@ pub fn find_missing_references(
                         .to_string(),
                 ),
                 auto_fixable: false,
-            related_paths: None,
+                related_paths: None,
             });
         }
     }
@@ -1,17 +1,32 @@
 pub mod audio;
+pub mod case_collision;
+pub mod config_edit;
 pub mod config_template;
 pub mod dcc_source;
 pub mod duplicate;
+pub mod empty_asset;
+pub mod extension_mismatch;
+pub mod line_endings;
+pub mod lod_chain;
+pub mod manifest;
+pub mod missing_meta;
 pub mod missing_reference;
 pub mod model;
 pub mod naming;
 pub mod pbr_set;
+pub mod text_duplicate;
 pub mod texture;
+pub mod texture_bit_depth;
 pub mod texture_colorspace;
+pub mod texture_format;
+pub mod texture_import_size;
+pub mod texture_mipmap;
 
-use crate::analyzer::Issue;
-use crate::scanner::AssetInfo;
+use crate::analyzer::{Issue, Severity};
+use crate::scanner::{AssetInfo, AssetType, ExtensionOverrides, ProjectType};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct IgnoreConfig {
@@ -23,6 +38,128 @@ pub struct IgnoreConfig {
     pub patterns: Vec<String>,
 }
 
+/// Compile `patterns` into one set; `kind` names them in errors
+/// (`Invalid ignore pattern '...'`). `[ignore].patterns` stay
+/// case-sensitive as documented; policy globs like the manifest's match
+/// any case, so `**/*.exe` also catches `Setup.EXE`.
+pub fn build_glob_set(
+    patterns: &[String],
+    kind: &str,
+    case_insensitive: bool,
+) -> Result<globset::GlobSet, String> {
+    let mut builder = globset::GlobSetBuilder::new();
+    for pattern in patterns {
+        let glob = globset::GlobBuilder::new(pattern)
+            .case_insensitive(case_insensitive)
+            .build()
+            .map_err(|e| format!("Invalid {} pattern '{}': {}", kind, pattern, e))?;
+        builder.add(glob);
+    }
+    builder
+        .build()
+        .map_err(|e| format!("Failed to build {} patterns: {}", kind, e))
+}
+
+/// The folders between `root` and the file at `path`, outermost first.
+/// Folders above the project root (`/home/ui/...`) say nothing about an
+/// asset's role; a path outside `root` yields every folder it has.
+pub(crate) fn folders_under<'a>(root: &Path, path: &'a str) -> impl Iterator<Item = &'a str> {
+    let path = Path::new(path);
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .parent()
+        .into_iter()
+        .flat_map(|p| p.components())
+        .filter_map(|c| c.as_os_str().to_str())
+}
+
+/// `[scan]` — how files are classified before any rule sees them.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ScanConfig {
+    /// Extension → asset type, consulted before the built-in table. Lets a
+    /// project treat e.g. `.bytes` as data or `.tex` as a texture. Keys are
+    /// matched case-insensitively, with or without a leading dot.
+    #[serde(default)]
+    pub extension_overrides: HashMap<String, AssetType>,
+}
+
+impl ScanConfig {
+    /// Overrides keyed the way the scanner looks them up: lowercase, no dot.
+    pub fn normalized_overrides(&self) -> ExtensionOverrides {
+        self.extension_overrides
+            .iter()
+            .map(|(ext, ty)| (ext.trim_start_matches('.').to_lowercase(), ty.clone()))
+            .filter(|(ext, _)| !ext.is_empty())
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct VendorConfig {
+    /// Glob patterns, relative to the project root, for third-party folders
+    /// the team didn't author. Vendored assets are skipped by analysis and
+    /// never reported unused. Absent = the project type's defaults (see
+    /// `default_vendor_patterns`); an explicit list replaces them and `[]`
+    /// turns vendoring off.
+    #[serde(default)]
+    pub patterns: Option<Vec<String>>,
+}
+
+/// Out-of-box vendored folders: Unity's embedded/local packages and
+/// Unreal's plugin directory.
+pub fn default_vendor_patterns(project_type: Option<&ProjectType>) -> Vec<String> {
+    match project_type {
+        Some(ProjectType::Unity) => vec!["Packages/**".to_string()],
+        Some(ProjectType::Unreal) => vec!["Plugins/**".to_string()],
+        _ => Vec::new(),
+    }
+}
+
+impl VendorConfig {
+    /// Compile the effective patterns for a project rooted at `root`.
+    pub fn matcher(
+        &self,
+        root: &str,
+        project_type: Option<&ProjectType>,
+    ) -> Result<VendorMatcher, String> {
+        let patterns = match &self.patterns {
+            Some(patterns) => patterns.clone(),
+            None => default_vendor_patterns(project_type),
+        };
+        let set = if patterns.is_empty() {
+            None
+        } else {
+            Some(build_glob_set(&patterns, "vendor", false)?)
+        };
+        Ok(VendorMatcher {
+            root: PathBuf::from(root),
+            set,
+        })
+    }
+}
+
+/// Compiled `[vendor]` patterns for one project — the single answer to
+/// "is this a folder we didn't author?" for analysis and unused detection.
+pub struct VendorMatcher {
+    root: PathBuf,
+    set: Option<globset::GlobSet>,
+}
+
+impl VendorMatcher {
+    pub fn is_active(&self) -> bool {
+        self.set.is_some()
+    }
+
+    /// Whether the absolute asset `path` falls under a vendored folder.
+    pub fn is_vendored(&self, path: &str) -> bool {
+        let Some(set) = &self.set else {
+            return false;
+        };
+        let path = Path::new(path);
+        set.is_match(path.strip_prefix(&self.root).unwrap_or(path))
+    }
+}
+
 /// Trait for all analysis rules. `id` and `name` are part of the public
 /// interface for future diagnostics output (UI grouping, error messages)
 /// even though no caller in lib.rs reads them yet.
@@ -37,8 +174,52 @@ pub trait Rule: Send + Sync {
     /// Check if this rule applies to a given asset type
     fn applies_to(&self, asset: &AssetInfo) -> bool;
 
+    /// Whether the rule judges an asset as its type (dimensions, format,
+    /// import settings). Rules applying to every file by name or size say
+    /// no, so they don't count as coverage in `Analyzer::unanalyzed`.
+    fn type_specific(&self) -> bool {
+        true
+    }
+
     /// Run the check and return an issue if found
     fn check(&self, asset: &AssetInfo) -> Option<Issue>;
+
+    /// One entry per `rule_id` this rule can emit, for the rule catalog.
+    fn describe(&self) -> Vec<RuleDescription>;
+}
+
+/// Catalog entry for one sub-rule, as returned by `get_rule_catalog`.
+#[derive(Debug, Clone, Serialize)]
+pub struct RuleDescription {
+    /// The `Issue::rule_id` this entry documents, e.g. `texture.pot`.
+    pub rule_id: &'static str,
+    pub name: &'static str,
+    /// Rule family — the part of `rule_id` before the first dot
+    /// (`texture`, `naming`, `duplicate`).
+    pub category: &'static str,
+    /// What the check looks at, in one sentence.
+    pub description: &'static str,
+    pub default_severity: Severity,
+    pub auto_fixable: bool,
+}
+
+impl RuleDescription {
+    pub fn new(
+        rule_id: &'static str,
+        name: &'static str,
+        default_severity: Severity,
+        auto_fixable: bool,
+        description: &'static str,
+    ) -> Self {
+        Self {
+            rule_id,
+            name,
+            category: rule_id.split('.').next().unwrap_or(rule_id),
+            description,
+            default_severity,
+            auto_fixable,
+        }
+    }
 }
 
 /// Configuration for all rules
@@ -53,11 +234,25 @@ pub struct RuleConfig {
     #[serde(default)]
     pub audio: audio::AudioConfig,
     #[serde(default)]
+    pub empty_asset: empty_asset::EmptyAssetConfig,
+    #[serde(default)]
+    pub duplicate: duplicate::DuplicateConfig,
+    #[serde(default)]
     pub pbr_set: pbr_set::PbrSetConfig,
     #[serde(default)]
     pub dcc_source: dcc_source::DccSourceConfig,
     #[serde(default)]
     pub ignore: IgnoreConfig,
+    #[serde(default)]
+    pub vendor: VendorConfig,
+    #[serde(default)]
+    pub scan: ScanConfig,
+    #[serde(default)]
+    pub build_estimate: crate::build_estimate::BuildEstimateConfig,
+    #[serde(default)]
+    pub lfs: crate::git::LfsConfig,
+    #[serde(default)]
+    pub line_endings: line_endings::LineEndingConfig,
 }
 
 impl Default for RuleConfig {
@@ -67,16 +262,258 @@ impl Default for RuleConfig {
             texture: texture::TextureConfig::default(),
             model: model::ModelConfig::default(),
             audio: audio::AudioConfig::default(),
+            empty_asset: empty_asset::EmptyAssetConfig::default(),
+            duplicate: duplicate::DuplicateConfig::default(),
             pbr_set: pbr_set::PbrSetConfig::default(),
             dcc_source: dcc_source::DccSourceConfig::default(),
             ignore: IgnoreConfig::default(),
+            vendor: VendorConfig::default(),
+            scan: ScanConfig::default(),
+            build_estimate: crate::build_estimate::BuildEstimateConfig::default(),
+            lfs: crate::git::LfsConfig::default(),
+            line_endings: line_endings::LineEndingConfig::default(),
         }
     }
 }
 
 impl RuleConfig {
-    /// Load config from TOML string
+    /// Load config from TOML string. Settings that parse but can't work
+    /// (see `DuplicateConfig::validate`, `TextureConfig::validate`) are
+    /// rejected here as well.
     pub fn from_toml(content: &str) -> Result<Self, toml::de::Error> {
-        toml::from_str(content)
+        use serde::de::Error as _;
+        let config: Self = toml::from_str(content)?;
+        config
+            .duplicate
+            .validate()
+            .and_then(|()| config.texture.validate())
+            .map_err(toml::de::Error::custom)?;
+        Ok(config)
+    }
+
+    /// Every setting, defaults included, as TOML laid out like the default
+    /// template — its section comments explain each field and its default.
+    /// Unset optional fields (no value means "off") are omitted, as TOML
+    /// has no null.
+    pub fn to_toml(&self) -> Result<String, toml::ser::Error> {
+        use serde::ser::Error as _;
+        let values = toml::to_string(self)?;
+        config_edit::documented(&values)
+            .map(|doc| doc.to_string())
+            .map_err(|e| toml::ser::Error::custom(e.to_string()))
+    }
+
+    /// `existing` (a user's `tidycraft.toml`) with every setting it doesn't
+    /// spell out added at its default, commented as in the template.
+    /// Nothing the user wrote — values, comments, order — is rewritten.
+    /// Also returns the dotted paths added; empty = already complete.
+    pub fn fill_missing_toml(existing: &str) -> Result<(String, Vec<String>), String> {
+        let config = Self::from_toml(existing).map_err(|e| format!("Invalid config: {}", e))?;
+        let full = config
+            .to_toml()
+            .map_err(|e| format!("Failed to serialize config: {}", e))?;
+        let full: toml_edit::DocumentMut = full
+            .parse()
+            .map_err(|e: toml_edit::TomlError| format!("Failed to parse config: {}", e))?;
+        let mut doc: toml_edit::DocumentMut = existing
+            .parse()
+            .map_err(|e: toml_edit::TomlError| format!("Invalid config: {}", e))?;
+        let added = config_edit::fill_missing(&mut doc, &full);
+        Ok((doc.to_string(), added))
+    }
+
+    /// Every leaf setting where `self` differs from `RuleConfig::default()`,
+    /// sorted by dotted path.
+    pub fn diff_from_default(&self) -> Vec<ConfigDifference> {
+        // Both sides go through serde, so the paths are exactly the keys a
+        // user writes in tidycraft.toml. A RuleConfig always serializes.
+        let default = serde_json::to_value(RuleConfig::default()).unwrap_or_default();
+        let current = serde_json::to_value(self).unwrap_or_default();
+        let mut out = Vec::new();
+        diff_values(String::new(), &default, &current, &mut out);
+        out
+    }
+}
+
+/// One setting that differs from its default. `null` stands for "unset"
+/// (an optional field, or a map key present on only one side).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ConfigDifference {
+    /// Dotted key path, e.g. `texture.max_size`.
+    pub path: String,
+    pub default: serde_json::Value,
+    pub current: serde_json::Value,
+}
+
+/// Recurse through tables; anything else (arrays included) is compared
+/// whole and reported at its own path.
+fn diff_values(
+    path: String,
+    default: &serde_json::Value,
+    current: &serde_json::Value,
+    out: &mut Vec<ConfigDifference>,
+) {
+    use serde_json::Value;
+    if let (Value::Object(d), Value::Object(c)) = (default, current) {
+        let keys: std::collections::BTreeSet<&String> = d.keys().chain(c.keys()).collect();
+        for key in keys {
+            let child = if path.is_empty() {
+                key.clone()
+            } else {
+                format!("{}.{}", path, key)
+            };
+            let d = d.get(key).unwrap_or(&Value::Null);
+            let c = c.get(key).unwrap_or(&Value::Null);
+            diff_values(child, d, c, out);
+        }
+    } else if default != current {
+        out.push(ConfigDifference {
+            path,
+            default: default.clone(),
+            current: current.clone(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vendor_defaults_follow_project_type() {
+        let config = VendorConfig::default();
+        let unity = config.matcher("/proj", Some(&ProjectType::Unity)).unwrap();
+        assert!(unity.is_vendored("/proj/Packages/com.vendor.tool/Icon.png"));
+        assert!(!unity.is_vendored("/proj/Assets/Icon.png"));
+
+        let unreal = config.matcher("/proj", Some(&ProjectType::Unreal)).unwrap();
+        assert!(unreal.is_vendored("/proj/Plugins/Foo/Content/T_Foo.uasset"));
+
+        let generic = config
+            .matcher("/proj", Some(&ProjectType::Generic))
+            .unwrap();
+        assert!(!generic.is_active());
+    }
+
+    #[test]
+    fn explicit_vendor_patterns_replace_defaults() {
+        let config: RuleConfig =
+            toml::from_str("[vendor]\npatterns = [\"ThirdParty/**\"]\n").unwrap();
+        let m = config
+            .vendor
+            .matcher("/proj", Some(&ProjectType::Unity))
+            .unwrap();
+        assert!(m.is_vendored("/proj/ThirdParty/lib/a.png"));
+        assert!(!m.is_vendored("/proj/Packages/a.png"));
+
+        let off: RuleConfig = toml::from_str("[vendor]\npatterns = []\n").unwrap();
+        let m = off
+            .vendor
+            .matcher("/proj", Some(&ProjectType::Unity))
+            .unwrap();
+        assert!(!m.is_vendored("/proj/Packages/a.png"));
+
+        let bad = VendorConfig {
+            patterns: Some(vec!["[".to_string()]),
+        };
+        assert!(bad.matcher("/proj", None).is_err());
+    }
+
+    #[test]
+    fn extension_overrides_round_trip_and_normalize() {
+        let config: RuleConfig = toml::from_str(
+            "[scan.extension_overrides]\n\".TEX\" = \"texture\"\nbytes = \"data\"\n",
+        )
+        .unwrap();
+        let reparsed: RuleConfig = toml::from_str(&toml::to_string(&config).unwrap()).unwrap();
+        assert_eq!(
+            reparsed.scan.extension_overrides,
+            config.scan.extension_overrides
+        );
+
+        let overrides = config.scan.normalized_overrides();
+        assert_eq!(overrides.get("tex"), Some(&AssetType::Texture));
+        assert_eq!(overrides.get("bytes"), Some(&AssetType::Data));
+        assert!(RuleConfig::default().scan.extension_overrides.is_empty());
+    }
+
+    #[test]
+    fn diff_reports_dotted_paths_of_changed_settings() {
+        assert!(RuleConfig::default().diff_from_default().is_empty());
+
+        let config = RuleConfig::from_toml(
+            "[texture]\nmax_size = 1024\n[duplicate]\nalgorithm = \"sha1\"\n\
+             [scan.extension_overrides]\ntex = \"texture\"\n",
+        )
+        .unwrap();
+        let diff = config.diff_from_default();
+        let paths: Vec<&str> = diff.iter().map(|d| d.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "duplicate.algorithm",
+                "scan.extension_overrides.tex",
+                "texture.max_size"
+            ]
+        );
+        assert_eq!(diff[0].default, serde_json::json!("blake3"));
+        assert_eq!(diff[0].current, serde_json::json!("sha1"));
+        assert_eq!(diff[1].default, serde_json::Value::Null);
+        assert_eq!(diff[2].current, serde_json::json!(1024));
+    }
+
+    #[test]
+    fn partial_config_resolves_to_a_full_round_trippable_toml() {
+        let partial = RuleConfig::from_toml("[texture]\nmax_size = 1024\n").unwrap();
+        let resolved = partial.to_toml().unwrap();
+        // The override and untouched defaults are both spelled out.
+        assert!(resolved.contains("max_size = 1024"));
+        assert!(resolved.contains("[duplicate]"));
+
+        let reparsed = RuleConfig::from_toml(&resolved).unwrap();
+        assert_eq!(reparsed.diff_from_default(), partial.diff_from_default());
+    }
+
+    #[test]
+    fn generated_toml_keeps_the_template_comments() {
+        let partial = RuleConfig::from_toml("[texture]\nmax_size = 1024\n").unwrap();
+        let resolved = partial.to_toml().unwrap();
+        assert!(resolved.contains("# ─── Texture Standards ───"));
+        // Only the changed value is rewritten; its neighbors keep their
+        // template spelling and inline comments.
+        assert!(resolved.contains("max_size = 1024\n"));
+        assert!(resolved.contains("max_file_size = 20971520         # 20 MB"));
+        assert_eq!(
+            RuleConfig::default().to_toml().unwrap(),
+            RuleConfig::from_toml(&RuleConfig::default().to_toml().unwrap())
+                .unwrap()
+                .to_toml()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn filling_missing_settings_leaves_user_text_alone() {
+        let existing = "# Art team budgets — ask before raising.\n\
+                        [texture]\n\
+                        enabled = true\n\
+                        max_size = 2048 # mobile\n";
+        let (filled, added) = RuleConfig::fill_missing_toml(existing).unwrap();
+        assert!(filled.starts_with(existing));
+        assert!(added.contains(&"texture.min_size".to_string()));
+        assert!(added.contains(&"duplicate".to_string()));
+        assert!(!added.iter().any(|p| p == "texture.max_size"));
+        // Added settings come with their template comments, but not the
+        // template's file header.
+        assert!(filled.contains("# ─── Duplicate Detection ───"));
+        assert!(!filled.contains("# Tidycraft analysis rules."));
+
+        let before = RuleConfig::from_toml(existing).unwrap();
+        let after = RuleConfig::from_toml(&filled).unwrap();
+        assert_eq!(after.diff_from_default(), before.diff_from_default());
+        // A complete file has nothing left to add.
+        let (again, added) = RuleConfig::fill_missing_toml(&filled).unwrap();
+        assert_eq!(again, filled);
+        assert!(added.is_empty());
     }
 }
@@ -2,7 +2,7 @@ use crate::analyzer::{Issue, Severity};
 use crate::scanner::{AssetInfo, AssetType};
 use serde::{Deserialize, Serialize};
 
-use super::Rule;
+use super::{Rule, RuleDescription};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ModelConfig {
@@ -20,6 +20,12 @@ pub struct ModelConfig {
     /// Maximum material count
     #[serde(default = "default_max_materials")]
     pub max_materials: u32,
+
+    /// Vertex count above which a model with no LOD chain is reported by
+    /// `analyze_lod_chains`. Independent of `enabled`: that command is
+    /// run on demand.
+    #[serde(default = "default_lod_vertex_threshold")]
+    pub lod_vertex_threshold: u32,
 }
 
 fn default_enabled() -> bool {
@@ -40,6 +46,10 @@ fn default_max_materials() -> u32 {
     10
 }
 
+fn default_lod_vertex_threshold() -> u32 {
+    20_000
+}
+
 impl Default for ModelConfig {
     fn default() -> Self {
         Self {
@@ -47,6 +57,7 @@ impl Default for ModelConfig {
             max_vertices: 100_000,
             max_faces: 100_000,
             max_materials: 10,
+            lod_vertex_threshold: default_lod_vertex_threshold(),
         }
     }
 }
@@ -70,6 +81,32 @@ impl Rule for ModelRule {
         "Model Standards"
     }
 
+    fn describe(&self) -> Vec<RuleDescription> {
+        vec![
+            RuleDescription::new(
+                "model.vertices",
+                "High Vertex Count",
+                Severity::Warning,
+                false,
+                "Mesh vertex count exceeds max_vertices.",
+            ),
+            RuleDescription::new(
+                "model.faces",
+                "High Face Count",
+                Severity::Warning,
+                false,
+                "Mesh face count exceeds max_faces.",
+            ),
+            RuleDescription::new(
+                "model.materials",
+                "Too Many Materials",
+                Severity::Warning,
+                false,
+                "Model references more than max_materials materials.",
+            ),
+        ]
+    }
+
     fn applies_to(&self, asset: &AssetInfo) -> bool {
         matches!(asset.asset_type, AssetType::Model)
     }
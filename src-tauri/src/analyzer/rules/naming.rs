@@ -2,7 +2,7 @@ use crate::analyzer::{Issue, Severity};
 use crate::scanner::{AssetInfo, AssetType};
 use serde::{Deserialize, Serialize};
 
-use super::Rule;
+use super::{Rule, RuleDescription};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NamingConfig {
@@ -262,10 +262,54 @@ impl Rule for NamingRule {
         "Naming Convention"
     }
 
+    fn describe(&self) -> Vec<RuleDescription> {
+        vec![
+            RuleDescription::new(
+                "naming.length",
+                "Name Too Long",
+                Severity::Warning,
+                false,
+                "File name is longer than max_length.",
+            ),
+            RuleDescription::new(
+                "naming.forbidden_char",
+                "Forbidden Character",
+                Severity::Warning,
+                true,
+                "File name contains one of forbidden_chars.",
+            ),
+            RuleDescription::new(
+                "naming.chinese",
+                "Chinese Characters",
+                Severity::Warning,
+                false,
+                "File name contains CJK characters while forbid_chinese is on.",
+            ),
+            RuleDescription::new(
+                "naming.prefix",
+                "Missing Prefix",
+                Severity::Warning,
+                true,
+                "File name lacks the prefix configured for its asset type.",
+            ),
+            RuleDescription::new(
+                "naming.case",
+                "Naming Case",
+                Severity::Info,
+                true,
+                "File name doesn't follow the configured case_style.",
+            ),
+        ]
+    }
+
     fn applies_to(&self, _asset: &AssetInfo) -> bool {
         true // Applies to all assets
     }
 
+    fn type_specific(&self) -> bool {
+        false
+    }
+
     fn check(&self, asset: &AssetInfo) -> Option<Issue> {
         let name = &asset.name;
         let name_without_ext = name.rsplit_once('.').map(|(n, _)| n).unwrap_or(name);
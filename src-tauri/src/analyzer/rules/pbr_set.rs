@@ -22,6 +22,7 @@
 use std::collections::{HashMap, HashSet};
 use std::path::Path;
 
+use crate::analyzer::rules::RuleDescription;
 use crate::analyzer::{AnalysisResult, Issue, Severity};
 use crate::scanner::{AssetInfo, AssetType};
 use serde::{Deserialize, Serialize};
@@ -149,6 +150,17 @@ fn parse_stem(
     None
 }
 
+/// Rule catalog entry for the PBR set check.
+pub fn describe() -> Vec<RuleDescription> {
+    vec![RuleDescription::new(
+        "pbr_set.incomplete",
+        "Incomplete PBR Set",
+        Severity::Warning,
+        false,
+        "A texture set has its trigger channel but lacks one of the required maps.",
+    )]
+}
+
 /// Run the cross-asset PBR set completeness check.
 ///
 /// Returns issues for every set that contains the trigger channel but
@@ -0,0 +1,171 @@
+//! Text files that are duplicates once line endings and BOM are ignored.
+//!
+//! A JSON table or shader copied between machines often comes back with
+//! CRLF line endings or a UTF-8 BOM prepended; the bytes differ, so the
+//! duplicate check's content hash never matches them. This pass hashes
+//! text with the BOM stripped and `\r\n` / `\r` folded to `\n`, and reports
+//! the groups the byte-exact check can't see (groups whose members are all
+//! byte-identical are left to it). Only UTF-8 files up to
+//! `MAX_TEXT_SIZE` are considered. Reported as info, one per group.
+//!
+//! Runs on demand (`find_text_duplicates`), not as part of analysis.
+
+use rayon::prelude::*;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::analyzer::rules::RuleDescription;
+use crate::analyzer::{AnalysisResult, Issue, Severity};
+use crate::scanner::{self, AssetInfo, AssetType};
+
+/// Larger files are skipped: text assets this big are generated data, not
+/// hand-copied clones, and reading them whole isn't free.
+pub const MAX_TEXT_SIZE: u64 = 4 * 1024 * 1024;
+
+/// Text formats outside `Data` / `Script` worth comparing.
+const TEXT_EXTS: &[&str] = &[
+    "txt", "md", "ini", "cfg", "toml", "shader", "hlsl", "cginc", "glsl", "compute", "uss", "uxml",
+    "gdshader", "html", "css",
+];
+
+pub(crate) fn is_text_candidate(asset: &AssetInfo) -> bool {
+    asset.size <= MAX_TEXT_SIZE
+        && (matches!(asset.asset_type, AssetType::Data | AssetType::Script)
+            || TEXT_EXTS.contains(&asset.extension.to_lowercase().as_str()))
+}
+
+/// `(normalized hash, raw hash)`, or `None` for unreadable / non-UTF-8
+/// files.
+fn text_hashes(path: &Path) -> Option<(String, String)> {
+    let bytes = fs::read(path).ok()?;
+    let text = std::str::from_utf8(&bytes).ok()?;
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let normalized = text.replace("\r\n", "\n").replace('\r', "\n");
+    Some((
+        blake3::hash(normalized.as_bytes()).to_hex().to_string(),
+        blake3::hash(&bytes).to_hex().to_string(),
+    ))
+}
+
+/// Rule catalog entry for the text duplicate check.
+pub fn describe() -> Vec<RuleDescription> {
+    vec![RuleDescription::new(
+        "text_duplicate",
+        "Near-Duplicate Text File",
+        Severity::Info,
+        false,
+        "Text files are identical once line endings and a UTF-8 BOM are ignored.",
+    )]
+}
+
+pub fn find_text_duplicates(assets: &[AssetInfo], root: &str) -> AnalysisResult {
+    let candidates: Vec<&AssetInfo> = assets.iter().filter(|a| is_text_candidate(a)).collect();
+    let hashes: Vec<Option<(String, String)>> = candidates
+        .par_iter()
+        .map(|asset| text_hashes(Path::new(&asset.path)))
+        .collect();
+
+    let mut by_text: HashMap<String, Vec<(&AssetInfo, String)>> = HashMap::new();
+    for (asset, hashes) in candidates.into_iter().zip(hashes) {
+        if let Some((normalized, raw)) = hashes {
+            by_text.entry(normalized).or_default().push((asset, raw));
+        }
+    }
+
+    let root_path = Path::new(root);
+    let rel = |path: &str| {
+        let p = Path::new(path);
+        scanner::path_to_string(p.strip_prefix(root_path).unwrap_or(p))
+    };
+    let mut result = AnalysisResult::new();
+    for mut members in by_text.into_values() {
+        // Byte-identical groups belong to the duplicate check.
+        if members.len() < 2 || members.iter().all(|(_, raw)| *raw == members[0].1) {
+            continue;
+        }
+        members.sort_by(|a, b| a.0.path.cmp(&b.0.path));
+        let (original, first_copy) = (members[0].0, members[1].0);
+        result.add_issue(Issue {
+            rule_id: "text_duplicate".to_string(),
+            rule_name: "Near-Duplicate Text File".to_string(),
+            severity: Severity::Info,
+            message: format!(
+                "{} files have the same text apart from line endings or BOM (original: '{}')",
+                members.len(),
+                original.name
+            ),
+            asset_path: first_copy.path.clone(),
+            suggestion: Some(format!(
+                "Keep '{}' and remove or consolidate the other {} file(s)",
+                rel(&original.path),
+                members.len() - 1
+            )),
+            auto_fixable: false,
+            related_paths: Some(members.iter().map(|(a, _)| rel(&a.path)).collect()),
+        });
+    }
+    result
+        .issues
+        .sort_by(|a, b| a.asset_path.cmp(&b.asset_path));
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn file(dir: &Path, name: &str, content: &[u8]) -> AssetInfo {
+        let path = dir.join(name);
+        fs::write(&path, content).unwrap();
+        AssetInfo {
+            path: scanner::path_to_string(&path),
+            name: name.to_string(),
+            extension: name.rsplit('.').next().unwrap().to_string(),
+            asset_type: if name.ends_with(".shader") {
+                AssetType::Other
+            } else {
+                AssetType::Data
+            },
+            size: content.len() as u64,
+            modified: 0,
+            metadata: None,
+            unity_guid: None,
+        }
+    }
+
+    #[test]
+    fn groups_line_ending_and_bom_variants_only() {
+        let dir = tempdir().unwrap();
+        let dir = dir.path();
+        let root = scanner::path_to_string(dir);
+        let assets = vec![
+            file(dir, "a.json", b"{\n  \"hp\": 3\n}\n"),
+            file(dir, "b.json", b"{\r\n  \"hp\": 3\r\n}\r\n"),
+            file(dir, "c.json", b"\xEF\xBB\xBF{\n  \"hp\": 3\n}\n"),
+            // Byte-identical pair: the duplicate check's job.
+            file(dir, "Lit.shader", b"Shader \"Lit\" {}\n"),
+            file(dir, "Lit copy.shader", b"Shader \"Lit\" {}\n"),
+            // Not UTF-8.
+            file(dir, "x.csv", b"\xFF\xFE1,2\n"),
+            file(dir, "y.csv", b"\xFF\xFE1,2\r\n"),
+        ];
+
+        let result = find_text_duplicates(&assets, &root);
+        assert_eq!(result.issues.len(), 1);
+        let issue = &result.issues[0];
+        assert_eq!(issue.severity, Severity::Info);
+        assert_eq!(
+            issue.related_paths.as_deref(),
+            Some(
+                &[
+                    "a.json".to_string(),
+                    "b.json".to_string(),
+                    "c.json".to_string()
+                ][..]
+            )
+        );
+        assert_eq!(issue.asset_path, assets[1].path);
+    }
+}
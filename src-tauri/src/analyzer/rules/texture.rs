@@ -1,9 +1,14 @@
 use crate::analyzer::{Issue, Severity};
 use crate::scanner::{AssetInfo, AssetType};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
+use super::texture_bit_depth::TextureBitDepthConfig;
 use super::texture_colorspace::TextureColorSpaceConfig;
-use super::Rule;
+use super::texture_format::TextureFormatRulesConfig;
+use super::texture_import_size::TextureImportSizeConfig;
+use super::texture_mipmap::TextureMipmapConfig;
+use super::{Rule, RuleDescription};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TextureConfig {
@@ -14,10 +19,28 @@ pub struct TextureConfig {
     #[serde(default = "default_require_pot")]
     pub require_pot: bool,
 
+    /// Require width and height divisible by 4, the block size of BC /
+    /// DXT / ASTC 4x4 compression. Looser than `require_pot`, for
+    /// pipelines that compress non-power-of-two textures.
+    #[serde(default)]
+    pub require_multiple_of_4: bool,
+
     /// Maximum texture size (width or height)
     #[serde(default = "default_max_size")]
     pub max_size: u32,
 
+    /// Name of the `platforms` profile whose `max_size` replaces the one
+    /// above. `None` keeps `max_size`; a name no profile has is rejected
+    /// when the config loads (`validate`).
+    #[serde(default)]
+    pub target_platform: Option<String>,
+
+    /// Named size budgets, under `[texture.platforms.<name>]`. The
+    /// built-ins (`desktop`, `mobile`, `switch`) still resolve when a
+    /// user's table leaves them out.
+    #[serde(default = "default_platforms")]
+    pub platforms: BTreeMap<String, TexturePlatformProfile>,
+
     /// Minimum texture size
     #[serde(default = "default_min_size")]
     pub min_size: u32,
@@ -36,6 +59,86 @@ pub struct TextureConfig {
     /// also losing the sRGB-data-texture safety net.
     #[serde(default)]
     pub color_space: TextureColorSpaceConfig,
+
+    /// Source-vs-import-cap check for Unity textures, under
+    /// `[texture.import_size]`. Gated by its own `enabled` flag like
+    /// `color_space`.
+    #[serde(default)]
+    pub import_size: TextureImportSizeConfig,
+
+    /// Role-vs-file-format check (JPEG normal maps, ...), under
+    /// `[texture.format_rules]`. Its own `enabled` flag, default off.
+    #[serde(default)]
+    pub format_rules: TextureFormatRulesConfig,
+
+    /// Mipmap-vs-role check for Unity textures, under
+    /// `[texture.mipmaps]`. Its own `enabled` flag, default off.
+    #[serde(default)]
+    pub mipmaps: TextureMipmapConfig,
+
+    /// Over-8-bits-per-channel check, under `[texture.bit_depth]`. Its own
+    /// `enabled` flag, default off.
+    #[serde(default)]
+    pub bit_depth: TextureBitDepthConfig,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TexturePlatformProfile {
+    /// Maximum width or height on this platform.
+    pub max_size: u32,
+}
+
+/// `(name, max_size)`: desktop keeps the general default; many mobile
+/// GPUs (and Switch memory budgets) struggle above 2048.
+const BUILTIN_PLATFORMS: &[(&str, u32)] = &[("desktop", 4096), ("mobile", 2048), ("switch", 2048)];
+
+fn default_platforms() -> BTreeMap<String, TexturePlatformProfile> {
+    BUILTIN_PLATFORMS
+        .iter()
+        .map(|&(name, max_size)| (name.to_string(), TexturePlatformProfile { max_size }))
+        .collect()
+}
+
+impl TextureConfig {
+    /// The active size limit and, when a profile supplies it, its name.
+    pub fn effective_max_size(&self) -> (u32, Option<&str>) {
+        let Some(name) = self.target_platform.as_deref() else {
+            return (self.max_size, None);
+        };
+        let profile = self.platforms.get(name).map(|p| p.max_size).or_else(|| {
+            BUILTIN_PLATFORMS
+                .iter()
+                .find(|(builtin, _)| *builtin == name)
+                .map(|&(_, max_size)| max_size)
+        });
+        match profile {
+            Some(max_size) => (max_size, Some(name)),
+            None => (self.max_size, None),
+        }
+    }
+
+    /// Rejects a `target_platform` no profile defines, naming the ones
+    /// that exist — a typo would otherwise silently keep `max_size`.
+    pub fn validate(&self) -> Result<(), String> {
+        let Some(name) = self.target_platform.as_deref() else {
+            return Ok(());
+        };
+        let mut known: Vec<&str> = BUILTIN_PLATFORMS
+            .iter()
+            .map(|&(builtin, _)| builtin)
+            .collect();
+        known.extend(self.platforms.keys().map(String::as_str));
+        known.sort_unstable();
+        known.dedup();
+        if known.contains(&name) {
+            return Ok(());
+        }
+        Err(format!(
+            "[texture].target_platform '{}' names no platform profile (known: {})",
+            name,
+            known.join(", ")
+        ))
+    }
 }
 
 fn default_enabled() -> bool {
@@ -67,11 +170,18 @@ impl Default for TextureConfig {
         Self {
             enabled: false,
             require_pot: true,
+            require_multiple_of_4: false,
             max_size: 4096,
+            target_platform: None,
+            platforms: default_platforms(),
             min_size: 4,
             warn_non_square: false,
             max_file_size: 10 * 1024 * 1024,
             color_space: TextureColorSpaceConfig::default(),
+            import_size: TextureImportSizeConfig::default(),
+            format_rules: TextureFormatRulesConfig::default(),
+            mipmaps: TextureMipmapConfig::default(),
+            bit_depth: TextureBitDepthConfig::default(),
         }
     }
 }
@@ -99,6 +209,60 @@ impl Rule for TextureRule {
         "Texture Standards"
     }
 
+    fn describe(&self) -> Vec<RuleDescription> {
+        vec![
+            RuleDescription::new(
+                "texture.file_size",
+                "Large File Size",
+                Severity::Warning,
+                false,
+                "Texture file is larger than max_file_size.",
+            ),
+            RuleDescription::new(
+                "texture.pot",
+                "Non-POT Texture",
+                Severity::Warning,
+                false,
+                "Width or height is not a power of two while require_pot is on.",
+            ),
+            RuleDescription::new(
+                "texture.multiple_of_4",
+                "Not Block-Compressible",
+                Severity::Warning,
+                false,
+                "Width or height is not a multiple of 4 while require_multiple_of_4 is on.",
+            ),
+            RuleDescription::new(
+                "texture.max_size",
+                "Texture Too Large",
+                Severity::Warning,
+                false,
+                "Width or height exceeds max_size, or the target_platform profile's.",
+            ),
+            RuleDescription::new(
+                "texture.min_size",
+                "Texture Too Small",
+                Severity::Info,
+                false,
+                "Width or height is below min_size.",
+            ),
+            RuleDescription::new(
+                "texture.non_square",
+                "Non-Square Texture",
+                Severity::Info,
+                false,
+                "Width and height differ while warn_non_square is on.",
+            ),
+            RuleDescription::new(
+                "texture.no_mipmaps",
+                "No Mipmap Chain",
+                Severity::Info,
+                false,
+                "A DDS texture ships only its base level.",
+            ),
+        ]
+    }
+
     fn applies_to(&self, asset: &AssetInfo) -> bool {
         matches!(asset.asset_type, AssetType::Texture)
     }
@@ -175,22 +339,42 @@ impl TextureRule {
             }
         }
 
-        // Check max size
-        if width > self.config.max_size || height > self.config.max_size {
+        // Check block-compression alignment
+        if self.config.require_multiple_of_4 && (width % 4 != 0 || height % 4 != 0) {
             return Some(Issue {
-                rule_id: "texture.max_size".to_string(),
-                rule_name: "Texture Too Large".to_string(),
+                rule_id: "texture.multiple_of_4".to_string(),
+                rule_name: "Not Block-Compressible".to_string(),
                 severity: Severity::Warning,
                 message: format!(
-                    "Texture {}x{} exceeds maximum size {}",
-                    width, height, self.config.max_size
+                    "Texture dimensions {}x{} are not multiples of 4; block compression (BC / DXT / ASTC) pads or rejects it",
+                    width, height
                 ),
                 asset_path: asset.path.clone(),
                 suggestion: Some(format!(
-                    "Resize to {}x{} or smaller",
-                    self.config.max_size, self.config.max_size
+                    "Resize to {}x{}",
+                    nearest_multiple_of_4(width),
+                    nearest_multiple_of_4(height)
                 )),
                 auto_fixable: false,
+                related_paths: None,
+            });
+        }
+
+        // Check max size
+        let (max_size, platform) = self.config.effective_max_size();
+        if width > max_size || height > max_size {
+            let limit = match platform {
+                Some(name) => format!("{} maximum size {}", name, max_size),
+                None => format!("maximum size {}", max_size),
+            };
+            return Some(Issue {
+                rule_id: "texture.max_size".to_string(),
+                rule_name: "Texture Too Large".to_string(),
+                severity: Severity::Warning,
+                message: format!("Texture {}x{} exceeds {}", width, height, limit),
+                asset_path: asset.path.clone(),
+                suggestion: Some(format!("Resize to {}x{} or smaller", max_size, max_size)),
+                auto_fixable: false,
             related_paths: None,
             });
         }
@@ -259,6 +443,11 @@ impl TextureRule {
     }
 }
 
+/// Closest multiple of 4 (halfway rounds up), at least 4.
+fn nearest_multiple_of_4(n: u32) -> u32 {
+    (n.saturating_add(2) / 4 * 4).max(4)
+}
+
 fn next_power_of_two(n: u32) -> u32 {
     if n == 0 {
         return 1;
@@ -282,6 +471,7 @@ fn next_power_of_two(n: u32) -> u32 {
 
 #[cfg(test)]
 mod tests {
+    use super::super::RuleConfig;
     use super::*;
     use crate::scanner::{AssetMetadata, AssetType};
 
@@ -299,6 +489,23 @@ mod tests {
         }
     }
 
+    fn texture(width: u32, height: u32) -> AssetInfo {
+        AssetInfo {
+            path: "/p/rock.png".to_string(),
+            name: "rock.png".to_string(),
+            extension: "png".to_string(),
+            asset_type: AssetType::Texture,
+            size: 1024,
+            modified: 0,
+            metadata: Some(AssetMetadata {
+                width: Some(width),
+                height: Some(height),
+                ..Default::default()
+            }),
+            unity_guid: None,
+        }
+    }
+
     #[test]
     fn file_size_check_covers_textures_without_dimensions() {
         let rule = TextureRule::new(TextureConfig::default());
@@ -309,4 +516,73 @@ mod tests {
         // Under the cap: silent.
         assert!(rule.check(&psd_without_dims(1024)).is_none());
     }
+
+    #[test]
+    fn multiple_of_4_flags_unaligned_sides_with_nearest_size() {
+        let rule = TextureRule::new(TextureConfig {
+            enabled: true,
+            require_pot: false,
+            require_multiple_of_4: true,
+            ..Default::default()
+        });
+
+        let issue = rule.check(&texture(130, 97)).expect("130x97 isn't aligned");
+        assert_eq!(issue.rule_id, "texture.multiple_of_4");
+        assert!(issue.message.contains("130x97"));
+        assert_eq!(issue.suggestion.as_deref(), Some("Resize to 132x96"));
+        assert!(rule.check(&texture(300, 100)).is_none());
+        assert_eq!(nearest_multiple_of_4(1), 4);
+    }
+
+    #[test]
+    fn target_platform_profile_sets_the_size_limit() {
+        let config = RuleConfig::from_toml(
+            "[texture]\nenabled = true\ntarget_platform = \"mobile\"\n\n\
+             [texture.platforms.webgl]\nmax_size = 1024\n",
+        )
+        .unwrap();
+        // Built-ins left out of the user's table still resolve.
+        assert_eq!(config.texture.effective_max_size(), (2048, Some("mobile")));
+        let issue = TextureRule::new(config.texture.clone())
+            .check(&texture(4096, 4096))
+            .expect("4096 is over the mobile budget");
+        assert_eq!(
+            issue.message,
+            "Texture 4096x4096 exceeds mobile maximum size 2048"
+        );
+
+        let round_trip = RuleConfig::from_toml(&config.to_toml().unwrap()).unwrap();
+        assert_eq!(
+            round_trip.texture.target_platform.as_deref(),
+            Some("mobile")
+        );
+        assert_eq!(round_trip.texture.platforms["webgl"].max_size, 1024);
+
+        let mut config = TextureConfig {
+            target_platform: Some("webgl".to_string()),
+            ..config.texture
+        };
+        assert_eq!(config.effective_max_size(), (1024, Some("webgl")));
+        config.target_platform = Some("console".to_string());
+        assert_eq!(config.effective_max_size(), (4096, None));
+        assert!(TextureRule::new(config)
+            .check(&texture(4096, 4096))
+            .is_none());
+    }
+
+    #[test]
+    fn unknown_target_platform_is_rejected_on_load() {
+        let err = RuleConfig::from_toml(
+            "[texture]\ntarget_platform = \"console\"\n\n\
+             [texture.platforms.webgl]\nmax_size = 1024\n",
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(
+            err.contains("'console'") && err.contains("desktop, mobile, switch, webgl"),
+            "{err}"
+        );
+        assert!(RuleConfig::from_toml("[texture]\ntarget_platform = \"webgl\"\n").is_err());
+        assert!(RuleConfig::from_toml("[texture]\ntarget_platform = \"switch\"\n").is_ok());
+    }
 }
@@ -0,0 +1,186 @@
+//! Flag textures stored at more than 8 bits per channel where 8 would do.
+//!
+//! A 16-bit PNG is twice the size of its 8-bit twin (a 32-bit float image
+//! four times), and for albedo, masks or UI art the extra precision is lost
+//! the moment the engine compresses it. Height and normal maps do band at
+//! 8 bits and HDR images need the range, so those are exempt by role: an
+//! extension in `exempt_extensions` or a stem ending with one of
+//! `exempt_suffixes`.
+//!
+//! Reads `AssetMetadata::bits_per_channel`, filled from the decoder's color
+//! type for the formats the `image` crate opens.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::analyzer::{Issue, Severity};
+use crate::scanner::{AssetInfo, AssetType};
+
+use super::{Rule, RuleDescription};
+
+/// Lives under `[texture.bit_depth]`. Default OFF: some 16-bit sources
+/// (smooth gradients, LUTs) are deliberate.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TextureBitDepthConfig {
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Extensions (without the dot, case-insensitive) never flagged: HDR
+    /// formats exist for their range.
+    #[serde(default = "default_exempt_extensions")]
+    pub exempt_extensions: Vec<String>,
+
+    /// A file stem ending with one of these (case-insensitive) is a role
+    /// that needs the precision — height / normal / HDR maps.
+    #[serde(default = "default_exempt_suffixes")]
+    pub exempt_suffixes: Vec<String>,
+}
+
+fn default_exempt_extensions() -> Vec<String> {
+    ["exr", "hdr"].iter().map(|s| s.to_string()).collect()
+}
+
+fn default_exempt_suffixes() -> Vec<String> {
+    [
+        "_n",
+        "_normal",
+        "_norm",
+        "_nrm",
+        "_h",
+        "_height",
+        "_disp",
+        "_displacement",
+        "_bump",
+        "_hdr",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect()
+}
+
+impl Default for TextureBitDepthConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            exempt_extensions: default_exempt_extensions(),
+            exempt_suffixes: default_exempt_suffixes(),
+        }
+    }
+}
+
+pub struct TextureBitDepthRule {
+    config: TextureBitDepthConfig,
+}
+
+impl TextureBitDepthRule {
+    pub fn new(config: TextureBitDepthConfig) -> Self {
+        Self { config }
+    }
+
+    fn is_exempt(&self, asset: &AssetInfo) -> bool {
+        let stem = Path::new(&asset.name)
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or_default()
+            .to_lowercase();
+        self.config
+            .exempt_extensions
+            .iter()
+            .any(|e| e.eq_ignore_ascii_case(&asset.extension))
+            || self
+                .config
+                .exempt_suffixes
+                .iter()
+                .any(|s| stem.ends_with(&s.to_lowercase()))
+    }
+}
+
+impl Rule for TextureBitDepthRule {
+    fn id(&self) -> &str {
+        "texture.bit_depth"
+    }
+
+    fn name(&self) -> &str {
+        "High Bit Depth Texture"
+    }
+
+    fn describe(&self) -> Vec<RuleDescription> {
+        vec![RuleDescription::new(
+            "texture.bit_depth",
+            "High Bit Depth Texture",
+            Severity::Info,
+            false,
+            "A texture that isn't an HDR, normal or height map is stored above 8 bits per channel.",
+        )]
+    }
+
+    fn applies_to(&self, asset: &AssetInfo) -> bool {
+        matches!(asset.asset_type, AssetType::Texture)
+    }
+
+    fn check(&self, asset: &AssetInfo) -> Option<Issue> {
+        let bits = asset.metadata.as_ref()?.bits_per_channel?;
+        if bits <= 8 || self.is_exempt(asset) {
+            return None;
+        }
+
+        Some(Issue {
+            rule_id: "texture.bit_depth".to_string(),
+            rule_name: "High Bit Depth Texture".to_string(),
+            severity: Severity::Info,
+            message: format!(
+                "Texture is stored at {} bits per channel: {}x the size of an 8-bit copy, for precision its role doesn't need",
+                bits,
+                bits / 8
+            ),
+            asset_path: asset.path.clone(),
+            suggestion: Some(
+                "Re-export at 8 bits per channel, or add its suffix to exempt_suffixes if it's a height / normal map"
+                    .to_string(),
+            ),
+            auto_fixable: false,
+            related_paths: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::AssetMetadata;
+
+    fn texture(name: &str, bits: Option<u8>) -> AssetInfo {
+        AssetInfo {
+            path: format!("/p/{}", name),
+            name: name.to_string(),
+            extension: name.rsplit('.').next().unwrap().to_string(),
+            asset_type: AssetType::Texture,
+            size: 1024,
+            modified: 0,
+            metadata: Some(AssetMetadata {
+                bits_per_channel: bits,
+                ..Default::default()
+            }),
+            unity_guid: None,
+        }
+    }
+
+    #[test]
+    fn flags_deep_textures_outside_exempt_roles() {
+        let rule = TextureBitDepthRule::new(TextureBitDepthConfig {
+            enabled: true,
+            ..Default::default()
+        });
+
+        let issue = rule.check(&texture("Rock_albedo.png", Some(16))).unwrap();
+        assert_eq!(issue.rule_id, "texture.bit_depth");
+        assert!(issue.message.contains("16 bits per channel"));
+        assert!(rule.check(&texture("Rock_albedo.png", Some(8))).is_none());
+        assert!(rule.check(&texture("Rock_Height.png", Some(16))).is_none());
+        assert!(rule.check(&texture("Rock_N.png", Some(16))).is_none());
+        assert!(rule.check(&texture("Sky.EXR", Some(32))).is_none());
+        // Formats without a decoded color type: nothing to judge.
+        assert!(rule.check(&texture("Rock.dds", None)).is_none());
+    }
+}
@@ -17,7 +17,7 @@ use serde::{Deserialize, Serialize};
 use crate::analyzer::{Issue, Severity};
 use crate::scanner::{AssetInfo, AssetType};
 
-use super::Rule;
+use super::{Rule, RuleDescription};
 
 /// Color-space rule lives under `[texture.color_space]` in the TOML —
 /// it used to share `[texture]`'s enabled flag, but disabling the size
@@ -72,6 +72,16 @@ impl Rule for TextureColorSpaceRule {
         "Texture Color Space"
     }
 
+    fn describe(&self) -> Vec<RuleDescription> {
+        vec![RuleDescription::new(
+            "texture.color_space",
+            "Suspicious Color Space",
+            Severity::Warning,
+            false,
+            "A data map (normal, roughness, ORM, ...) is tagged sRGB instead of linear.",
+        )]
+    }
+
     fn applies_to(&self, asset: &AssetInfo) -> bool {
         matches!(asset.asset_type, AssetType::Texture)
     }
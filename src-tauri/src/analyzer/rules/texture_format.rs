@@ -0,0 +1,262 @@
+//! Flag textures stored in a file format that damages what they're used for.
+//!
+//! Classic avoidable bug: a normal map exported as JPEG. JPEG's block
+//! artifacts in a color texture are barely visible; in a normal map they
+//! become lumpy shading, because every pixel is a direction vector. UI and
+//! sprite art suffers the same way — JPEG rings around the hard edges and
+//! has no alpha. Roles are guessed from names and folders, so like the
+//! size / PoT budgets in `[texture]` the check is opt-in.
+//!
+//! A texture's role is read from its name (stem suffix or prefix) or a
+//! folder below the project root; each role lists the formats it must or
+//! mustn't use.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::analyzer::{Issue, Severity};
+use crate::scanner::{AssetInfo, AssetType};
+
+use super::{folders_under, Rule, RuleDescription};
+
+/// Lives under `[texture.format_rules]`. Gated by its own `enabled` flag
+/// like `color_space`: turning the `[texture]` budgets off shouldn't also
+/// drop this check. Writing any `[[texture.format_rules.roles]]` replaces
+/// the built-in roles.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TextureFormatRulesConfig {
+    #[serde(default)]
+    pub enabled: bool,
+
+    #[serde(default = "default_roles")]
+    pub roles: Vec<TextureFormatRole>,
+}
+
+/// One texture role and the formats it may be stored in. A texture takes
+/// the first role it matches; matching is case-insensitive throughout.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TextureFormatRole {
+    /// Shown in the issue message, e.g. "normal map".
+    pub name: String,
+    /// The file stem ends with one of these (`_n`, `_normal`).
+    #[serde(default)]
+    pub suffixes: Vec<String>,
+    /// The file stem starts with one of these (`ui_`, `icon_`).
+    #[serde(default)]
+    pub prefixes: Vec<String>,
+    /// A folder on the asset's path below the project root is named one
+    /// of these (`UI`, `Sprites`).
+    #[serde(default)]
+    pub directories: Vec<String>,
+    /// Extensions the role must use; empty = anything not disallowed.
+    #[serde(default)]
+    pub allowed: Vec<String>,
+    /// Extensions the role must not use.
+    #[serde(default)]
+    pub disallowed: Vec<String>,
+    /// Formats named in the suggestion.
+    #[serde(default)]
+    pub suggest: Vec<String>,
+}
+
+fn strings(items: &[&str]) -> Vec<String> {
+    items.iter().map(|s| s.to_string()).collect()
+}
+
+/// Normal maps by the same suffixes `texture.color_space` treats as normal
+/// data (`_n` included — see `DATA_HINTS`), and UI / sprite art by the
+/// usual prefixes and folders. Both must stay lossless.
+fn default_roles() -> Vec<TextureFormatRole> {
+    vec![
+        TextureFormatRole {
+            name: "normal map".to_string(),
+            suffixes: strings(&["_n", "_normal", "_norm", "_nrm"]),
+            prefixes: Vec::new(),
+            directories: Vec::new(),
+            allowed: Vec::new(),
+            disallowed: strings(&["jpg", "jpeg"]),
+            suggest: strings(&["png", "tga"]),
+        },
+        TextureFormatRole {
+            name: "UI / sprite texture".to_string(),
+            suffixes: Vec::new(),
+            prefixes: strings(&["ui_", "icon_", "spr_", "sprite_"]),
+            directories: strings(&["ui", "sprites", "icons"]),
+            allowed: Vec::new(),
+            disallowed: strings(&["jpg", "jpeg"]),
+            suggest: strings(&["png"]),
+        },
+    ]
+}
+
+impl Default for TextureFormatRulesConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            roles: default_roles(),
+        }
+    }
+}
+
+fn eq_ext(list: &[String], ext: &str) -> bool {
+    list.iter()
+        .any(|e| e.trim_start_matches('.').eq_ignore_ascii_case(ext))
+}
+
+impl TextureFormatRole {
+    fn matches(&self, asset: &AssetInfo, stem_lower: &str, root: &Path) -> bool {
+        self.suffixes
+            .iter()
+            .any(|s| stem_lower.ends_with(&s.to_lowercase()))
+            || self
+                .prefixes
+                .iter()
+                .any(|p| stem_lower.starts_with(&p.to_lowercase()))
+            || (!self.directories.is_empty()
+                && folders_under(root, &asset.path)
+                    .any(|dir| self.directories.iter().any(|d| d.eq_ignore_ascii_case(dir))))
+    }
+
+    fn permits(&self, ext: &str) -> bool {
+        (self.allowed.is_empty() || eq_ext(&self.allowed, ext)) && !eq_ext(&self.disallowed, ext)
+    }
+}
+
+pub struct TextureFormatRule {
+    config: TextureFormatRulesConfig,
+    root: PathBuf,
+}
+
+impl TextureFormatRule {
+    /// `root` is the project root; only folders below it count for
+    /// `directories`.
+    pub fn new(config: TextureFormatRulesConfig, root: &str) -> Self {
+        Self {
+            config,
+            root: PathBuf::from(root),
+        }
+    }
+}
+
+impl Rule for TextureFormatRule {
+    fn id(&self) -> &str {
+        "texture.format"
+    }
+
+    fn name(&self) -> &str {
+        "Texture Format"
+    }
+
+    fn describe(&self) -> Vec<RuleDescription> {
+        vec![RuleDescription::new(
+            "texture.format",
+            "Wrong Format For Texture Role",
+            Severity::Warning,
+            false,
+            "A texture's role (normal map, UI sprite, ...) calls for a format it isn't stored in.",
+        )]
+    }
+
+    fn applies_to(&self, asset: &AssetInfo) -> bool {
+        matches!(asset.asset_type, AssetType::Texture)
+    }
+
+    fn check(&self, asset: &AssetInfo) -> Option<Issue> {
+        let stem_lower = Path::new(&asset.name)
+            .file_stem()
+            .and_then(|s| s.to_str())?
+            .to_lowercase();
+        let role = self
+            .config
+            .roles
+            .iter()
+            .find(|role| role.matches(asset, &stem_lower, &self.root))?;
+        if role.permits(&asset.extension) {
+            return None;
+        }
+
+        let suggestion = if role.suggest.is_empty() {
+            "Re-export in a lossless format.".to_string()
+        } else {
+            format!(
+                "Re-export the {} as {}.",
+                role.name,
+                role.suggest
+                    .iter()
+                    .map(|e| e.trim_start_matches('.').to_uppercase())
+                    .collect::<Vec<_>>()
+                    .join(" or ")
+            )
+        };
+        Some(Issue {
+            rule_id: "texture.format".to_string(),
+            rule_name: "Wrong Format For Texture Role".to_string(),
+            severity: Severity::Warning,
+            message: format!(
+                "Looks like a {} but is stored as .{}, which this role shouldn't use.",
+                role.name,
+                asset.extension.to_lowercase()
+            ),
+            asset_path: asset.path.clone(),
+            suggestion: Some(suggestion),
+            auto_fixable: false,
+            related_paths: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texture(path: &str) -> AssetInfo {
+        let name = path.rsplit('/').next().unwrap().to_string();
+        AssetInfo {
+            path: path.to_string(),
+            extension: name.rsplit('.').next().unwrap().to_string(),
+            name,
+            asset_type: AssetType::Texture,
+            size: 1024,
+            modified: 0,
+            metadata: None,
+            unity_guid: None,
+        }
+    }
+
+    #[test]
+    fn flags_jpeg_normal_maps_and_ui_art() {
+        let rule = TextureFormatRule::new(TextureFormatRulesConfig::default(), "/p");
+        let issue = rule.check(&texture("/p/Rock_Normal.JPG")).unwrap();
+        assert!(issue.message.contains("normal map"));
+        assert!(issue.suggestion.unwrap().contains("PNG or TGA"));
+        assert!(rule.check(&texture("/p/rock_n.jpeg")).is_some());
+        assert!(rule.check(&texture("/p/Art/UI/button.jpg")).is_some());
+        assert!(rule.check(&texture("/p/icon_sword.jpg")).is_some());
+
+        assert!(rule.check(&texture("/p/Rock_Normal.png")).is_none());
+        assert!(rule.check(&texture("/p/Rock_Albedo.jpg")).is_none());
+        // A folder name only counts as a whole path component.
+        assert!(rule.check(&texture("/p/Build/photo.jpg")).is_none());
+    }
+
+    #[test]
+    fn folders_above_the_project_root_do_not_set_a_role() {
+        let rule = TextureFormatRule::new(TextureFormatRulesConfig::default(), "/home/ui/game");
+        assert!(rule.check(&texture("/home/ui/game/photo.jpg")).is_none());
+        assert!(rule.check(&texture("/home/ui/game/UI/photo.jpg")).is_some());
+    }
+
+    #[test]
+    fn allowed_list_restricts_a_custom_role() {
+        let config: TextureFormatRulesConfig = toml::from_str(
+            "[[roles]]\nname = \"mask\"\nsuffixes = [\"_mask\"]\nallowed = [\"png\"]\n",
+        )
+        .unwrap();
+        let rule = TextureFormatRule::new(config, "/p");
+        assert!(rule.check(&texture("/p/leaf_mask.tga")).is_some());
+        assert!(rule.check(&texture("/p/leaf_mask.png")).is_none());
+        // Custom roles replace the built-ins.
+        assert!(rule.check(&texture("/p/rock_n.jpg")).is_none());
+    }
+}
@@ -0,0 +1,167 @@
+//! Flag Unity textures whose source is far larger than the size they're
+//! imported at.
+//!
+//! A 4096² PSD imported with `maxTextureSize: 512` is fine at runtime — the
+//! editor downsamples on import — but every clone, checkout and reimport
+//! still moves the full-resolution file. When the cap is that far below the
+//! source, nobody is using the extra detail and the source is worth
+//! downsizing. The reverse (small source, generous cap) costs nothing and
+//! is never flagged.
+//!
+//! Joins two scan-time signals per asset: the decoded image dimensions and
+//! the `.meta` TextureImporter cap (`AssetMetadata::import_max_size`, only
+//! filled for Unity projects).
+
+use serde::{Deserialize, Serialize};
+
+use crate::analyzer::{Issue, Severity};
+use crate::scanner::{AssetInfo, AssetType};
+
+use super::{Rule, RuleDescription};
+
+/// Lives under `[texture.import_size]`. Default OFF: oversized sources are
+/// a disk/VCS budget question, not a bug.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TextureImportSizeConfig {
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Flag when the source's larger dimension is at least this many times
+    /// the import cap.
+    #[serde(default = "default_min_ratio")]
+    pub min_ratio: u32,
+}
+
+fn default_min_ratio() -> u32 {
+    4
+}
+
+impl Default for TextureImportSizeConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            min_ratio: default_min_ratio(),
+        }
+    }
+}
+
+pub struct TextureImportSizeRule {
+    config: TextureImportSizeConfig,
+}
+
+impl TextureImportSizeRule {
+    pub fn new(config: TextureImportSizeConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl Rule for TextureImportSizeRule {
+    fn id(&self) -> &str {
+        "texture.import_size"
+    }
+
+    fn name(&self) -> &str {
+        "Texture Import Size"
+    }
+
+    fn describe(&self) -> Vec<RuleDescription> {
+        vec![RuleDescription::new(
+            "texture.import_size",
+            "Oversized Texture Source",
+            Severity::Info,
+            false,
+            "Unity imports the texture at a maxTextureSize far below the source resolution.",
+        )]
+    }
+
+    fn applies_to(&self, asset: &AssetInfo) -> bool {
+        matches!(asset.asset_type, AssetType::Texture)
+    }
+
+    fn check(&self, asset: &AssetInfo) -> Option<Issue> {
+        let metadata = asset.metadata.as_ref()?;
+        let cap = metadata.import_max_size.filter(|&c| c > 0)?;
+        let width = metadata.width?;
+        let height = metadata.height?;
+        let source = width.max(height);
+
+        // u64 so a pathological cap can't overflow the product.
+        if (source as u64) < cap as u64 * self.config.min_ratio.max(1) as u64 {
+            return None;
+        }
+
+        Some(Issue {
+            rule_id: "texture.import_size".to_string(),
+            rule_name: "Oversized Texture Source".to_string(),
+            severity: Severity::Info,
+            message: format!(
+                "Source is {}x{} but imported at maxTextureSize {} ({}x smaller)",
+                width,
+                height,
+                cap,
+                source / cap
+            ),
+            asset_path: asset.path.clone(),
+            suggestion: Some(format!(
+                "Downsize the source to about {}px, or raise maxTextureSize if the detail is needed",
+                cap
+            )),
+            auto_fixable: false,
+            related_paths: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::AssetMetadata;
+
+    fn texture(width: u32, height: u32, cap: Option<u32>) -> AssetInfo {
+        AssetInfo {
+            path: "/test/T_Rock.png".to_string(),
+            name: "T_Rock.png".to_string(),
+            extension: "png".to_string(),
+            asset_type: AssetType::Texture,
+            size: 1024,
+            modified: 0,
+            metadata: Some(AssetMetadata {
+                width: Some(width),
+                height: Some(height),
+                import_max_size: cap,
+                ..Default::default()
+            }),
+            unity_guid: None,
+        }
+    }
+
+    fn rule() -> TextureImportSizeRule {
+        TextureImportSizeRule::new(TextureImportSizeConfig {
+            enabled: true,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn flags_source_four_times_the_cap() {
+        let issue = rule().check(&texture(4096, 4096, Some(512))).unwrap();
+        assert_eq!(issue.severity, Severity::Info);
+        assert!(rule().check(&texture(2048, 1024, Some(512))).is_some());
+    }
+
+    #[test]
+    fn ignores_moderate_or_reverse_discrepancies() {
+        assert!(rule().check(&texture(2048, 2048, Some(1024))).is_none());
+        // Small source, generous cap: harmless.
+        assert!(rule().check(&texture(256, 256, Some(8192))).is_none());
+    }
+
+    #[test]
+    fn needs_both_dimensions_and_an_import_cap() {
+        // Non-Unity project: no `.meta`, no cap.
+        assert!(rule().check(&texture(4096, 4096, None)).is_none());
+        let mut asset = texture(4096, 4096, Some(512));
+        asset.metadata.as_mut().unwrap().width = None;
+        assert!(rule().check(&asset).is_none());
+    }
+}
@@ -0,0 +1,224 @@
+//! Flag Unity textures whose mipmap setting doesn't fit their role.
+//!
+//! Mipmaps add a third to a texture's memory. A world texture needs them —
+//! without, it shimmers at a distance and samples its full-size level from
+//! afar — but UI art drawn at native size never reads a smaller level, so
+//! for it the third is pure waste. The role is a heuristic: a texture under
+//! one of `ui_folders` (below the project root) or whose stem ends with one
+//! of `ui_suffixes` is UI, anything else is world.
+//!
+//! Reads the `.meta` TextureImporter's `enableMipMap`
+//! (`AssetMetadata::import_mipmaps`, only filled for Unity projects).
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::analyzer::{Issue, Severity};
+use crate::scanner::{AssetInfo, AssetType};
+
+use super::{folders_under, Rule, RuleDescription};
+
+/// Lives under `[texture.mipmaps]`. Default OFF: a memory / quality
+/// tradeoff, and 2D projects legitimately ship sprites without mipmaps.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TextureMipmapConfig {
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// A folder on the path below the project root named one of these
+    /// (case-insensitive, whole component) marks a UI texture.
+    #[serde(default = "default_ui_folders")]
+    pub ui_folders: Vec<String>,
+
+    /// A file stem ending with one of these (case-insensitive) marks a UI
+    /// texture.
+    #[serde(default = "default_ui_suffixes")]
+    pub ui_suffixes: Vec<String>,
+}
+
+fn default_ui_folders() -> Vec<String> {
+    vec!["UI".to_string()]
+}
+
+fn default_ui_suffixes() -> Vec<String> {
+    vec!["_UI".to_string()]
+}
+
+impl Default for TextureMipmapConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            ui_folders: default_ui_folders(),
+            ui_suffixes: default_ui_suffixes(),
+        }
+    }
+}
+
+pub struct TextureMipmapRule {
+    config: TextureMipmapConfig,
+    root: PathBuf,
+}
+
+impl TextureMipmapRule {
+    /// `root` is the project root; only folders below it count for
+    /// `ui_folders`.
+    pub fn new(config: TextureMipmapConfig, root: &str) -> Self {
+        Self {
+            config,
+            root: PathBuf::from(root),
+        }
+    }
+
+    fn is_ui(&self, asset: &AssetInfo) -> bool {
+        let path = Path::new(&asset.path);
+        let stem = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or_default()
+            .to_lowercase();
+        self.config
+            .ui_suffixes
+            .iter()
+            .any(|s| stem.ends_with(&s.to_lowercase()))
+            || folders_under(&self.root, &asset.path).any(|dir| {
+                self.config
+                    .ui_folders
+                    .iter()
+                    .any(|f| f.eq_ignore_ascii_case(dir))
+            })
+    }
+}
+
+impl Rule for TextureMipmapRule {
+    fn id(&self) -> &str {
+        "texture.mipmaps"
+    }
+
+    fn name(&self) -> &str {
+        "Texture Mipmaps"
+    }
+
+    fn describe(&self) -> Vec<RuleDescription> {
+        vec![
+            RuleDescription::new(
+                "texture.mipmaps.ui",
+                "Mipmaps on UI Texture",
+                Severity::Info,
+                false,
+                "A UI texture (by folder or suffix) is imported with mipmaps it never samples.",
+            ),
+            RuleDescription::new(
+                "texture.mipmaps.world",
+                "World Texture Without Mipmaps",
+                Severity::Info,
+                false,
+                "A non-UI texture is imported without mipmaps and will alias at a distance.",
+            ),
+        ]
+    }
+
+    fn applies_to(&self, asset: &AssetInfo) -> bool {
+        matches!(asset.asset_type, AssetType::Texture)
+    }
+
+    fn check(&self, asset: &AssetInfo) -> Option<Issue> {
+        let mipmaps = asset.metadata.as_ref()?.import_mipmaps?;
+        let (rule_id, rule_name, message, suggestion) = match (self.is_ui(asset), mipmaps) {
+            (true, true) => (
+                "texture.mipmaps.ui",
+                "Mipmaps on UI Texture",
+                "UI texture is imported with mipmaps: about 33% more memory for levels a UI drawn at native size never samples",
+                "Turn off Generate Mip Maps in its import settings",
+            ),
+            (false, false) => (
+                "texture.mipmaps.world",
+                "World Texture Without Mipmaps",
+                "Texture is imported without mipmaps: it saves about 33% memory but aliases and samples the full-size level at a distance",
+                "Turn on Generate Mip Maps, or move it under a UI folder if it's interface art",
+            ),
+            _ => return None,
+        };
+
+        Some(Issue {
+            rule_id: rule_id.to_string(),
+            rule_name: rule_name.to_string(),
+            severity: Severity::Info,
+            message: message.to_string(),
+            asset_path: asset.path.clone(),
+            suggestion: Some(suggestion.to_string()),
+            auto_fixable: false,
+            related_paths: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::AssetMetadata;
+
+    fn texture(path: &str, mipmaps: Option<bool>) -> AssetInfo {
+        AssetInfo {
+            path: path.to_string(),
+            name: path.rsplit('/').next().unwrap().to_string(),
+            extension: "png".to_string(),
+            asset_type: AssetType::Texture,
+            size: 1024,
+            modified: 0,
+            metadata: Some(AssetMetadata {
+                import_mipmaps: mipmaps,
+                ..Default::default()
+            }),
+            unity_guid: None,
+        }
+    }
+
+    #[test]
+    fn flags_mipmaps_against_the_texture_role() {
+        let rule = TextureMipmapRule::new(
+            TextureMipmapConfig {
+                enabled: true,
+                ..Default::default()
+            },
+            "/p",
+        );
+        let flagged = |path: &str, mipmaps| {
+            rule.check(&texture(path, mipmaps))
+                .map(|issue| issue.rule_id)
+        };
+
+        assert_eq!(
+            flagged("/p/Assets/ui/Button.png", Some(true)).as_deref(),
+            Some("texture.mipmaps.ui")
+        );
+        assert_eq!(
+            flagged("/p/Assets/Art/Health_ui.png", Some(true)).as_deref(),
+            Some("texture.mipmaps.ui")
+        );
+        assert_eq!(
+            flagged("/p/Assets/Art/Rock.png", Some(false)).as_deref(),
+            Some("texture.mipmaps.world")
+        );
+        assert_eq!(flagged("/p/Assets/UI/Button.png", Some(false)), None);
+        assert_eq!(flagged("/p/Assets/Art/Rock.png", Some(true)), None);
+        // `Build` is not `UI`: whole components only.
+        assert_eq!(flagged("/p/Assets/Build/Rock.png", Some(true)), None);
+        // No `.meta` (or not Unity): nothing to judge.
+        assert_eq!(flagged("/p/Assets/UI/Button.png", None), None);
+    }
+
+    #[test]
+    fn folders_above_the_project_root_are_not_ui() {
+        let config = TextureMipmapConfig {
+            enabled: true,
+            ..Default::default()
+        };
+        let rule = TextureMipmapRule::new(config, "/home/UI/game");
+        let rock = texture("/home/UI/game/Assets/Rock.png", Some(false));
+        assert_eq!(
+            rule.check(&rock).map(|issue| issue.rule_id).as_deref(),
+            Some("texture.mipmaps.world")
+        );
+    }
+}
@@ -540,6 +540,9 @@ mod tests {
             total_size: 0,
             type_counts: std::collections::HashMap::new(),
             project_type: None,
+            timings: None,
+            metadata_skipped: false,
+            project_info: None,
             assets,
         }
     }
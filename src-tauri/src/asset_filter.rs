@@ -0,0 +1,200 @@
+//! Server-side asset filter for bulk operations.
+//!
+//! Mirrors the asset list's type pills and advanced filters closely enough
+//! that "everything I'm looking at" can be expressed without shipping every
+//! path across IPC. Every criterion is optional and they AND together; an
+//! empty filter matches every asset. Also the `when` side of persisted
+//! auto-tag rules (`tags::AutoTagRule`), hence `Serialize`.
+
+use serde::{Deserialize, Serialize};
+
+use crate::scanner::{AssetInfo, AssetType};
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AssetFilter {
+    /// Union of asset types; empty = any.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub asset_types: Vec<AssetType>,
+    /// Extensions without the dot, case-insensitive; empty = any.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub extensions: Vec<String>,
+    /// Only assets below this directory (absolute path, any separator).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub directory: Option<String>,
+    /// Case-insensitive substring of the file name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_contains: Option<String>,
+    /// Size bounds in bytes, inclusive.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_size: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_size: Option<u64>,
+    /// Pixel bounds, inclusive. Assets without dimensions never match a
+    /// set bound.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_width: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_width: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_height: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_height: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_alpha: Option<bool>,
+}
+
+impl AssetFilter {
+    /// Copy with `directory` read relative to `root`: `Deprecated` and
+    /// `/Deprecated` both mean `<root>/Deprecated`. Persisted rules store
+    /// project-relative directories so they survive the project moving.
+    pub fn rooted_at(&self, root: &str) -> AssetFilter {
+        let mut filter = self.clone();
+        if let Some(dir) = &self.directory {
+            let rel = dir.replace('\\', "/");
+            let rel = rel.trim_matches('/');
+            let root = root.trim_end_matches(|c| c == '/' || c == '\\');
+            filter.directory = Some(if rel.is_empty() {
+                root.to_string()
+            } else {
+                format!("{}/{}", root, rel)
+            });
+        }
+        filter
+    }
+
+    pub fn matches(&self, asset: &AssetInfo) -> bool {
+        if !self.asset_types.is_empty() && !self.asset_types.contains(&asset.asset_type) {
+            return false;
+        }
+        if !self.extensions.is_empty()
+            && !self.extensions.iter().any(|ext| {
+                ext.trim_start_matches('.')
+                    .eq_ignore_ascii_case(&asset.extension)
+            })
+        {
+            return false;
+        }
+        if let Some(dir) = &self.directory {
+            let dir = dir.replace('\\', "/");
+            let dir = dir.trim_end_matches('/');
+            let path = asset.path.replace('\\', "/");
+            if !path
+                .strip_prefix(dir)
+                .is_some_and(|rest| rest.starts_with('/'))
+            {
+                return false;
+            }
+        }
+        if let Some(needle) = &self.name_contains {
+            if !asset.name.to_lowercase().contains(&needle.to_lowercase()) {
+                return false;
+            }
+        }
+        if !within(Some(asset.size), self.min_size, self.max_size) {
+            return false;
+        }
+
+        let metadata = asset.metadata.as_ref();
+        let width = metadata.and_then(|m| m.width);
+        let height = metadata.and_then(|m| m.height);
+        if !within(width, self.min_width, self.max_width)
+            || !within(height, self.min_height, self.max_height)
+        {
+            return false;
+        }
+        match self.has_alpha {
+            Some(wanted) => metadata.and_then(|m| m.has_alpha) == Some(wanted),
+            None => true,
+        }
+    }
+}
+
+/// `value` lies within the inclusive bounds. With no bounds anything
+/// passes; with a bound, a missing value fails.
+fn within<T: PartialOrd + Copy>(value: Option<T>, min: Option<T>, max: Option<T>) -> bool {
+    if min.is_none() && max.is_none() {
+        return true;
+    }
+    let Some(value) = value else {
+        return false;
+    };
+    min.map_or(true, |min| value >= min) && max.map_or(true, |max| value <= max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::AssetMetadata;
+
+    fn asset(path: &str, asset_type: AssetType, size: u64, width: Option<u32>) -> AssetInfo {
+        let name = path.rsplit('/').next().unwrap().to_string();
+        AssetInfo {
+            path: path.to_string(),
+            extension: name.rsplit('.').next().unwrap().to_string(),
+            name,
+            asset_type,
+            size,
+            modified: 0,
+            metadata: width.map(|w| AssetMetadata {
+                width: Some(w),
+                height: Some(w),
+                ..Default::default()
+            }),
+            unity_guid: None,
+        }
+    }
+
+    #[test]
+    fn criteria_and_together() {
+        let big_texture = asset("/p/Art/Rock.PNG", AssetType::Texture, 5 << 20, Some(4096));
+        let small_texture = asset("/p/Art/Icon.png", AssetType::Texture, 1024, Some(64));
+        let music = asset("/p/Music/Theme.ogg", AssetType::Audio, 3 << 20, None);
+
+        assert!(AssetFilter::default().matches(&music));
+
+        let over_4mb_textures = AssetFilter {
+            asset_types: vec![AssetType::Texture],
+            min_size: Some(4 << 20),
+            ..Default::default()
+        };
+        assert!(over_4mb_textures.matches(&big_texture));
+        assert!(!over_4mb_textures.matches(&small_texture));
+        assert!(!over_4mb_textures.matches(&music));
+
+        let in_music = AssetFilter {
+            directory: Some("/p/Music/".to_string()),
+            ..Default::default()
+        };
+        assert!(in_music.matches(&music));
+        assert!(!in_music.matches(&big_texture));
+        // A sibling sharing the prefix isn't inside the directory.
+        let in_mus = AssetFilter {
+            directory: Some("/p/Mus".to_string()),
+            ..Default::default()
+        };
+        assert!(!in_mus.matches(&music));
+
+        let png_over_1k_wide = AssetFilter {
+            extensions: vec![".png".to_string()],
+            min_width: Some(1024),
+            ..Default::default()
+        };
+        assert!(png_over_1k_wide.matches(&big_texture));
+        assert!(!png_over_1k_wide.matches(&small_texture));
+        // No dimensions: a width bound can't be satisfied.
+        let wide = AssetFilter {
+            min_width: Some(1),
+            ..Default::default()
+        };
+        assert!(!wide.matches(&music));
+
+        let rooted = AssetFilter {
+            directory: Some("/Music".to_string()),
+            ..Default::default()
+        }
+        .rooted_at("/p/");
+        assert_eq!(rooted.directory.as_deref(), Some("/p/Music"));
+        assert!(rooted.matches(&music));
+    }
+}
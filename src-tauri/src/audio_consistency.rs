@@ -0,0 +1,148 @@
+//! Sample rates across a project's audio, as one distribution.
+//!
+//! `AudioRule` judges each file against `allowed_sample_rates`, so a
+//! project mixing 44.1 kHz and 48 kHz passes it as long as both are
+//! allowed — yet every file not at the mixer's rate gets resampled at
+//! runtime, and the mix points at assets exported with the wrong
+//! template. When one rate covers most of the audio, the files at other
+//! rates are the ones to look at. Reads `AssetMetadata::sample_rate`.
+
+use serde::Serialize;
+use std::collections::HashMap;
+
+use crate::scanner::{AssetInfo, AssetType};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SampleRateCount {
+    pub sample_rate: u32,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SampleRateOutlier {
+    pub path: String,
+    pub sample_rate: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SampleRateConsistency {
+    /// Most used rate first, ties by rate.
+    pub rates: Vec<SampleRateCount>,
+    /// The rate more than half of the audio uses; `None` when no rate does.
+    pub dominant_rate: Option<u32>,
+    /// Audio not at `dominant_rate`, by path. Empty without a dominant rate:
+    /// with no majority there's no telling which side is the odd one out.
+    pub inconsistent: Vec<SampleRateOutlier>,
+    /// Audio whose sample rate the scan couldn't read.
+    pub unknown_count: usize,
+}
+
+pub fn check(assets: &[AssetInfo]) -> SampleRateConsistency {
+    let audio: Vec<&AssetInfo> = assets
+        .iter()
+        .filter(|a| matches!(a.asset_type, AssetType::Audio))
+        .collect();
+    let rated: Vec<(&AssetInfo, u32)> = audio
+        .iter()
+        .filter_map(|a| Some((*a, a.metadata.as_ref()?.sample_rate?)))
+        .collect();
+
+    let mut counts: HashMap<u32, usize> = HashMap::new();
+    for (_, rate) in &rated {
+        *counts.entry(*rate).or_default() += 1;
+    }
+    let mut rates: Vec<SampleRateCount> = counts
+        .into_iter()
+        .map(|(sample_rate, count)| SampleRateCount { sample_rate, count })
+        .collect();
+    rates.sort_by(|a, b| {
+        b.count
+            .cmp(&a.count)
+            .then_with(|| a.sample_rate.cmp(&b.sample_rate))
+    });
+
+    let dominant_rate = rates
+        .first()
+        .filter(|top| top.count * 2 > rated.len())
+        .map(|top| top.sample_rate);
+    let mut inconsistent: Vec<SampleRateOutlier> = match dominant_rate {
+        Some(dominant) => rated
+            .iter()
+            .filter(|(_, rate)| *rate != dominant)
+            .map(|(asset, rate)| SampleRateOutlier {
+                path: asset.path.clone(),
+                sample_rate: *rate,
+            })
+            .collect(),
+        None => Vec::new(),
+    };
+    inconsistent.sort_by(|a, b| a.path.cmp(&b.path));
+
+    SampleRateConsistency {
+        rates,
+        dominant_rate,
+        inconsistent,
+        unknown_count: audio.len() - rated.len(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::AssetMetadata;
+
+    fn audio(path: &str, sample_rate: Option<u32>) -> AssetInfo {
+        AssetInfo {
+            path: path.to_string(),
+            name: path.rsplit('/').next().unwrap().to_string(),
+            extension: "wav".to_string(),
+            asset_type: AssetType::Audio,
+            size: 1024,
+            modified: 0,
+            metadata: Some(AssetMetadata {
+                sample_rate,
+                ..Default::default()
+            }),
+            unity_guid: None,
+        }
+    }
+
+    #[test]
+    fn flags_minority_rates_only_under_a_majority() {
+        let mut assets = vec![
+            audio("/p/a.wav", Some(48000)),
+            audio("/p/b.wav", Some(48000)),
+            audio("/p/c.wav", Some(44100)),
+            audio("/p/d.wav", None),
+        ];
+        let report = check(&assets);
+        assert_eq!(report.dominant_rate, Some(48000));
+        assert_eq!(
+            report.rates,
+            vec![
+                SampleRateCount {
+                    sample_rate: 48000,
+                    count: 2
+                },
+                SampleRateCount {
+                    sample_rate: 44100,
+                    count: 1
+                },
+            ]
+        );
+        assert_eq!(
+            report.inconsistent,
+            vec![SampleRateOutlier {
+                path: "/p/c.wav".to_string(),
+                sample_rate: 44100
+            }]
+        );
+        assert_eq!(report.unknown_count, 1);
+
+        // Two against two: no majority, nothing flagged.
+        assets.push(audio("/p/e.wav", Some(44100)));
+        let split = check(&assets);
+        assert_eq!(split.dominant_rate, None);
+        assert!(split.inconsistent.is_empty());
+    }
+}
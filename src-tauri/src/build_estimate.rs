@@ -0,0 +1,261 @@
+//! Rough shipped-build size, per asset type.
+//!
+//! `ScanResult::total_size` counts source files: a 4096² PSD, a 10-minute
+//! WAV, an FBX full of authoring data. What ships is what the engine makes
+//! of them on import — block-compressed textures, encoded audio, stripped
+//! meshes — which is usually a fraction of that. This estimates the
+//! shipped size from what the scan already knows (dimensions, alpha,
+//! duration) and a handful of factors from `[build_estimate]`. It's a
+//! producer's ballpark, not a packer: no engine-side deduplication, no
+//! stripping of unreferenced assets, no platform overrides.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::scanner::{AssetInfo, AssetType};
+
+/// `[build_estimate]` in `tidycraft.toml`. Defaults describe a desktop
+/// build with BC-compressed textures and Vorbis audio.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuildEstimateConfig {
+    /// Shipped bytes per pixel of an opaque texture. 0.5 = BC1 / ETC2 RGB
+    /// (4 bits per pixel).
+    #[serde(default = "default_opaque_bytes_per_pixel")]
+    pub texture_opaque_bytes_per_pixel: f64,
+
+    /// Shipped bytes per pixel of a texture with alpha. 1.0 = BC3 / BC7 /
+    /// ETC2 RGBA (8 bits per pixel).
+    #[serde(default = "default_alpha_bytes_per_pixel")]
+    pub texture_alpha_bytes_per_pixel: f64,
+
+    /// Count a full mip chain (+1/3 of the base level).
+    #[serde(default = "default_true")]
+    pub texture_mipmaps: bool,
+
+    /// Encoded audio bitrate in kbit/s. 128 ≈ Vorbis quality 0.5 stereo.
+    #[serde(default = "default_audio_kbps")]
+    pub audio_kbps: u32,
+
+    /// Fraction of a model's source file left once import strips authoring
+    /// data (history, unused UV sets, embedded media).
+    #[serde(default = "default_model_ratio")]
+    pub model_ratio: f64,
+
+    /// Fraction kept for every other type, and for textures / audio whose
+    /// dimensions or duration the scan couldn't read.
+    #[serde(default = "default_fallback_ratio")]
+    pub fallback_ratio: f64,
+}
+
+fn default_opaque_bytes_per_pixel() -> f64 {
+    0.5
+}
+
+fn default_alpha_bytes_per_pixel() -> f64 {
+    1.0
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_audio_kbps() -> u32 {
+    128
+}
+
+fn default_model_ratio() -> f64 {
+    0.5
+}
+
+fn default_fallback_ratio() -> f64 {
+    1.0
+}
+
+impl Default for BuildEstimateConfig {
+    fn default() -> Self {
+        Self {
+            texture_opaque_bytes_per_pixel: default_opaque_bytes_per_pixel(),
+            texture_alpha_bytes_per_pixel: default_alpha_bytes_per_pixel(),
+            texture_mipmaps: true,
+            audio_kbps: default_audio_kbps(),
+            model_ratio: default_model_ratio(),
+            fallback_ratio: default_fallback_ratio(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BuildSizeEstimate {
+    /// Estimated shipped bytes: the sum of `by_type`. Approximate — see
+    /// `BuildEstimateConfig` for the assumptions behind each type.
+    pub estimated_size: u64,
+    /// On-disk bytes of the same assets, for comparison.
+    pub source_size: u64,
+    /// Largest estimate first.
+    pub by_type: Vec<TypeBuildEstimate>,
+    /// Textures without dimensions and audio without a duration; these
+    /// fall back to `fallback_ratio` of their source size, which for
+    /// PSDs and WAVs overstates the build.
+    pub fallback_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TypeBuildEstimate {
+    pub asset_type: AssetType,
+    pub asset_count: usize,
+    pub source_size: u64,
+    /// Textures: pixels × bytes per pixel (alpha-aware), plus mips.
+    /// Audio: duration × bitrate, never more than the source file.
+    /// Models: source × `model_ratio`. Everything else: source ×
+    /// `fallback_ratio`.
+    pub estimated_size: u64,
+}
+
+/// Estimated shipped size of one asset, and whether it had to fall back
+/// to a plain ratio because the scan lacked the signal the type needs.
+fn estimate_asset(asset: &AssetInfo, config: &BuildEstimateConfig) -> (u64, bool) {
+    let fallback = || (asset.size as f64 * config.fallback_ratio) as u64;
+    let metadata = asset.metadata.as_ref();
+    match asset.asset_type {
+        AssetType::Texture => {
+            let Some((width, height)) = metadata.and_then(|m| Some((m.width?, m.height?))) else {
+                return (fallback(), true);
+            };
+            let bytes_per_pixel = if metadata.and_then(|m| m.has_alpha) == Some(true) {
+                config.texture_alpha_bytes_per_pixel
+            } else {
+                config.texture_opaque_bytes_per_pixel
+            };
+            let mut bytes = width as f64 * height as f64 * bytes_per_pixel;
+            if config.texture_mipmaps {
+                bytes *= 4.0 / 3.0;
+            }
+            (bytes as u64, false)
+        }
+        AssetType::Audio => {
+            let Some(duration) = metadata.and_then(|m| m.duration_secs) else {
+                return (fallback(), true);
+            };
+            // kbit/s → bytes/s. Already-compressed sources below the
+            // target bitrate ship as they are.
+            let encoded = (duration * config.audio_kbps as f64 * 125.0) as u64;
+            (encoded.min(asset.size), false)
+        }
+        AssetType::Model => ((asset.size as f64 * config.model_ratio) as u64, false),
+        _ => (fallback(), false),
+    }
+}
+
+pub fn estimate(assets: &[AssetInfo], config: &BuildEstimateConfig) -> BuildSizeEstimate {
+    let mut by_type: HashMap<AssetType, TypeBuildEstimate> = HashMap::new();
+    let mut fallback_count = 0;
+    for asset in assets {
+        let (estimated, fell_back) = estimate_asset(asset, config);
+        fallback_count += fell_back as usize;
+        let entry = by_type
+            .entry(asset.asset_type.clone())
+            .or_insert_with(|| TypeBuildEstimate {
+                asset_type: asset.asset_type.clone(),
+                asset_count: 0,
+                source_size: 0,
+                estimated_size: 0,
+            });
+        entry.asset_count += 1;
+        entry.source_size += asset.size;
+        entry.estimated_size += estimated;
+    }
+
+    let mut by_type: Vec<TypeBuildEstimate> = by_type.into_values().collect();
+    by_type.sort_by(|a, b| {
+        b.estimated_size
+            .cmp(&a.estimated_size)
+            .then_with(|| a.asset_count.cmp(&b.asset_count))
+    });
+    BuildSizeEstimate {
+        estimated_size: by_type.iter().map(|t| t.estimated_size).sum(),
+        source_size: by_type.iter().map(|t| t.source_size).sum(),
+        by_type,
+        fallback_count,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::AssetMetadata;
+
+    fn asset(asset_type: AssetType, size: u64, metadata: Option<AssetMetadata>) -> AssetInfo {
+        AssetInfo {
+            path: "/p/a".to_string(),
+            name: "a".to_string(),
+            extension: String::new(),
+            asset_type,
+            size,
+            modified: 0,
+            metadata,
+            unity_guid: None,
+        }
+    }
+
+    #[test]
+    fn applies_per_type_factors() {
+        let texture = |has_alpha| {
+            Some(AssetMetadata {
+                width: Some(1024),
+                height: Some(1024),
+                has_alpha: Some(has_alpha),
+                ..Default::default()
+            })
+        };
+        let audio = |secs| {
+            Some(AssetMetadata {
+                duration_secs: Some(secs),
+                ..Default::default()
+            })
+        };
+        let assets = vec![
+            // 1 MiB pixels: 0.5 MiB opaque, 1 MiB with alpha, then +1/3 mips.
+            asset(AssetType::Texture, 3 << 20, texture(false)),
+            asset(AssetType::Texture, 4 << 20, texture(true)),
+            // No dimensions (a PSD): source size, counted as a fallback.
+            asset(AssetType::Texture, 50 << 20, None),
+            // 10 s at 128 kbps = 160 000 bytes; a smaller source stays as is.
+            asset(AssetType::Audio, 2_000_000, audio(10.0)),
+            asset(AssetType::Audio, 100_000, audio(10.0)),
+            asset(AssetType::Model, 1000, None),
+            asset(AssetType::Data, 300, None),
+        ];
+        let estimate = estimate(&assets, &BuildEstimateConfig::default());
+
+        let of = |ty: AssetType| {
+            estimate
+                .by_type
+                .iter()
+                .find(|t| t.asset_type == ty)
+                .unwrap()
+                .estimated_size
+        };
+        let mips = |bytes: u64| bytes * 4 / 3;
+        assert_eq!(
+            of(AssetType::Texture),
+            mips(512 * 1024) + mips(1024 * 1024) + (50 << 20)
+        );
+        assert_eq!(of(AssetType::Audio), 160_000 + 100_000);
+        assert_eq!(of(AssetType::Model), 500);
+        assert_eq!(of(AssetType::Data), 300);
+        assert_eq!(estimate.fallback_count, 1);
+        assert_eq!(
+            estimate.source_size,
+            assets.iter().map(|a| a.size).sum::<u64>()
+        );
+        assert_eq!(
+            estimate.estimated_size,
+            estimate
+                .by_type
+                .iter()
+                .map(|t| t.estimated_size)
+                .sum::<u64>()
+        );
+        assert_eq!(estimate.by_type[0].asset_type, AssetType::Texture);
+    }
+}
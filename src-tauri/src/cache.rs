@@ -5,7 +5,8 @@ use std::fs;
 use std::path::{Path, PathBuf};
 use std::time::SystemTime;
 
-use crate::scanner::AssetInfo;
+use crate::analyzer::rules::duplicate::HashAlgo;
+use crate::scanner::{AssetInfo, ExtensionOverrides};
 
 /// Cache entry for a single file
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,6 +23,17 @@ pub struct CacheEntry {
     /// until the asset body changes or the cache is cleared.
     pub meta_modified: Option<u64>,
     pub asset: AssetInfo,
+    /// Full-content hash from the last duplicate pass that hashed this
+    /// file. Never carried across a re-parse: `update_entry` replaces the
+    /// entry, so a changed file starts without one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_hash: Option<ContentHash>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContentHash {
+    pub algorithm: HashAlgo,
+    pub hex: String,
 }
 
 /// Project scan cache
@@ -31,6 +43,14 @@ pub struct ScanCache {
     pub project_path: String,
     pub created: u64,
     pub entries: HashMap<String, CacheEntry>,
+    /// `[scan].extension_overrides` the entries were classified under.
+    /// The scanner drops every entry when this no longer matches.
+    #[serde(default)]
+    pub extension_overrides: ExtensionOverrides,
+    /// Set when `store_hash` added something, so callers only rewrite the
+    /// cache file when there is something new in it.
+    #[serde(skip)]
+    hashes_added: bool,
 }
 
 impl ScanCache {
@@ -39,7 +59,15 @@ impl ScanCache {
     /// SVG dimensions before the 2026-04 pass) get rejected and re-scanned.
     /// v5: entries carry the `.meta` sidecar mtime in the invalidation key.
     /// v6: `AssetInfo` gained the required `modified` field.
-    const CACHE_VERSION: u32 = 6;
+    /// v7: DDS entries carry `texture_format`; TGA dims come from the header.
+    /// v8: Unity textures carry `import_max_size` from their `.meta`.
+    /// v9: PNG / JPEG entries carry `dpi`.
+    /// v10: `.blend` entries carry mesh totals, `object_count` and
+    /// `dcc_version`.
+    /// v11: Unity textures carry `import_mipmaps` from their `.meta`.
+    /// v12: decoded images carry `bits_per_channel`.
+    /// v13: glTF / FBX entries carry `object_count` and `animation_count`.
+    const CACHE_VERSION: u32 = 13;
 
     /// Create a new empty cache
     pub fn new(project_path: &str) -> Self {
@@ -51,19 +79,14 @@ impl ScanCache {
                 .map(|d| d.as_secs())
                 .unwrap_or(0),
             entries: HashMap::new(),
+            extension_overrides: ExtensionOverrides::new(),
+            hashes_added: false,
         }
     }
 
     /// Get the cache file path for a project
     pub fn cache_path(project_path: &str) -> Option<PathBuf> {
-        let cache_dir = dirs::cache_dir()?.join("tidycraft").join("scans");
-
-        // Create hash of project path for cache filename
-        let mut hasher = Sha256::new();
-        hasher.update(project_path.as_bytes());
-        let hash = format!("{:x}", hasher.finalize());
-
-        Some(cache_dir.join(format!("{}.json", &hash[..16])))
+        cache_file_path(project_path, "json")
     }
 
     /// Load cache from disk
@@ -125,32 +148,92 @@ impl ScanCache {
             size: asset.size,
             meta_modified,
             asset,
+            content_hash: None,
         };
         self.entries.insert(entry.path.clone(), entry);
     }
 
+    /// The entry for `asset`, if it still describes the same file version.
+    /// The scan may be newer than the cache on disk (watcher updates only
+    /// touch the in-memory scan), so size and mtime are checked again.
+    fn fresh_entry(&self, asset: &AssetInfo) -> Option<&CacheEntry> {
+        self.entries
+            .get(&asset.path)
+            .filter(|e| e.size == asset.size && e.modified == asset.modified)
+    }
+
+    /// Cached content hash for `asset` computed with `algorithm`.
+    pub fn cached_hash(&self, asset: &AssetInfo, algorithm: HashAlgo) -> Option<&str> {
+        self.fresh_entry(asset)?
+            .content_hash
+            .as_ref()
+            .filter(|h| h.algorithm == algorithm)
+            .map(|h| h.hex.as_str())
+    }
+
+    /// Remember `hex` for `asset`. Ignored when the entry is missing or
+    /// describes a different file version.
+    pub fn store_hash(&mut self, asset: &AssetInfo, algorithm: HashAlgo, hex: String) {
+        if self.fresh_entry(asset).is_none() {
+            return;
+        }
+        if let Some(entry) = self.entries.get_mut(&asset.path) {
+            entry.content_hash = Some(ContentHash { algorithm, hex });
+            self.hashes_added = true;
+        }
+    }
+
+    pub fn hashes_added(&self) -> bool {
+        self.hashes_added
+    }
+
     /// Remove entries for files that no longer exist
     pub fn prune(&mut self, existing_paths: &[String]) {
         let existing_set: std::collections::HashSet<&String> = existing_paths.iter().collect();
         self.entries.retain(|path, _| existing_set.contains(path));
     }
 
+    /// Drop the entries whose path starts with `prefix`, so the next
+    /// incremental scan re-parses just those files. Returns how many went.
+    pub fn invalidate_prefix(&mut self, prefix: &str) -> usize {
+        let before = self.entries.len();
+        self.entries.retain(|path, _| !path.starts_with(prefix));
+        before - self.entries.len()
+    }
+
     /// Get all cached assets
     pub fn get_assets(&self) -> Vec<AssetInfo> {
         self.entries.values().map(|e| e.asset.clone()).collect()
     }
 
-    /// Clear the cache
+    /// Clear the cache, and the Unity reference index persisted next to it
     pub fn clear(project_path: &str) -> Result<(), std::io::Error> {
-        if let Some(cache_path) = Self::cache_path(project_path) {
-            if cache_path.exists() {
-                fs::remove_file(cache_path)?;
+        let paths = [
+            Self::cache_path(project_path),
+            crate::unity_index::ReferenceIndex::index_path(project_path),
+        ];
+        for path in paths.into_iter().flatten() {
+            if path.exists() {
+                fs::remove_file(path)?;
             }
         }
         Ok(())
     }
 }
 
+/// `<cache dir>/tidycraft/scans/<hash of key>.<suffix>`. Files persisted
+/// alongside a scan cache share its stem, so they're keyed identically.
+pub fn cache_file_path(project_path: &str, suffix: &str) -> Option<PathBuf> {
+    let cache_dir = dirs::cache_dir()?.join("tidycraft").join("scans");
+
+    // Create hash of project path for cache filename
+    let mut hasher = Sha256::new();
+    hasher.update(project_path.as_bytes());
+    let hash = format!("{:x}", hasher.finalize());
+
+    Some(cache_dir.join(format!("{}.{}", &hash[..16], suffix)))
+}
+
 /// Get file modification time as unix timestamp
 pub fn get_modified_time(path: &Path) -> Option<u64> {
     fs::metadata(path)
@@ -208,4 +291,39 @@ mod tests {
         assert!(!cache.needs_rescan("/test/new.png", 111, 500, None));
         assert!(cache.needs_rescan("/test/new.png", 111, 500, Some(70)));
     }
+
+    #[test]
+    fn content_hash_is_dropped_when_the_file_changes() {
+        let mut cache = ScanCache::new("/test");
+        let asset = dummy_asset("/test/file.png", 1000);
+        cache.update_entry(asset.clone(), 0, None);
+        cache.store_hash(&asset, HashAlgo::Blake3, "abc".to_string());
+        assert!(cache.hashes_added());
+        assert_eq!(cache.cached_hash(&asset, HashAlgo::Blake3), Some("abc"));
+        // Another algorithm's hash doesn't count.
+        assert_eq!(cache.cached_hash(&asset, HashAlgo::Sha256), None);
+
+        // Size moved since the entry was written (e.g. a watcher update).
+        let grown = dummy_asset("/test/file.png", 2000);
+        assert_eq!(cache.cached_hash(&grown, HashAlgo::Blake3), None);
+
+        // A re-parse replaces the entry and its hash with it.
+        cache.update_entry(asset.clone(), 0, None);
+        assert_eq!(cache.cached_hash(&asset, HashAlgo::Blake3), None);
+    }
+
+    #[test]
+    fn invalidate_prefix_keeps_entries_outside_the_subtree() {
+        let mut cache = ScanCache::new("/test");
+        for path in [
+            "/test/Chars/a.png",
+            "/test/Chars/b/c.png",
+            "/test/Characters2/d.png",
+        ] {
+            cache.update_entry(dummy_asset(path, 1), 0, None);
+        }
+        assert_eq!(cache.invalidate_prefix("/test/Chars/"), 2);
+        assert!(cache.needs_rescan("/test/Chars/a.png", 0, 1, None));
+        assert!(!cache.needs_rescan("/test/Characters2/d.png", 0, 1, None));
+    }
 }
@@ -0,0 +1,281 @@
+//! Grayscale mask textures that could share one RGBA texture.
+//!
+//! Roughness, metallic and ambient occlusion are often exported as separate
+//! grayscale images — a sampler and a full texture's memory each for one
+//! channel of data. Textures in one folder sharing a base name and ending in
+//! a mask suffix (`_R`, `_M`, `_AO`, ...) are grouped; the ones that really
+//! are grayscale (a luma image, or RGB whose channels agree on every pixel)
+//! are suggested for packing in the glTF ORM layout — occlusion in R,
+//! roughness in G, metallic in B — with height or smoothness in A.
+//!
+//! Memory is estimated as uncompressed RGBA8, which is how engines import a
+//! grayscale PNG unless told otherwise: each separate mask costs a full
+//! texture, the packed one a single texture at the largest member's size.
+
+use rayon::prelude::*;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::scanner::{AssetInfo, AssetType};
+
+/// Mask roles in packed-channel order, each with the channel it packs into
+/// and the stem suffixes naming it (compared case-insensitively). Height and
+/// smoothness compete for A; height wins when a set has both.
+const MASK_ROLES: &[(&str, char, &[&str])] = &[
+    ("ao", 'R', &["AO", "AmbientOcclusion", "Occlusion", "Occ"]),
+    ("roughness", 'G', &["R", "Rough", "Roughness"]),
+    ("metallic", 'B', &["M", "Metal", "Metallic", "Metalness"]),
+    ("height", 'A', &["H", "Height", "Disp", "Displacement"]),
+    ("smoothness", 'A', &["S", "Smooth", "Smoothness", "Gloss"]),
+];
+
+/// Bytes per pixel of the uncompressed RGBA8 estimate.
+const BYTES_PER_PIXEL: u64 = 4;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ChannelAssignment {
+    pub channel: char,
+    /// `ao`, `roughness`, `metallic`, `height` or `smoothness`.
+    pub role: String,
+    pub path: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ChannelPackingGroup {
+    /// Folder and base name the masks share, e.g. `/p/Rock/T_Rock`.
+    pub base: String,
+    /// One mask per channel, in R, G, B, A order.
+    pub channels: Vec<ChannelAssignment>,
+    /// Estimated memory of the masks as separate textures.
+    pub separate_bytes: u64,
+    /// Estimated memory of the packed texture.
+    pub packed_bytes: u64,
+    pub saved_bytes: u64,
+}
+
+/// The base stem and mask role index of `stem`, from the suffix after its
+/// last `_`.
+fn mask_role(stem: &str) -> Option<(&str, usize)> {
+    let (base, suffix) = stem.rsplit_once('_')?;
+    if base.is_empty() {
+        return None;
+    }
+    let index = MASK_ROLES
+        .iter()
+        .position(|(_, _, suffixes)| suffixes.iter().any(|s| s.eq_ignore_ascii_case(suffix)))?;
+    Some((base, index))
+}
+
+/// The size of the image at `path` when it holds one value per pixel.
+fn grayscale_size(path: &str) -> Option<(u32, u32)> {
+    let image = image::open(path).ok()?;
+    let grayscale = image.color().channel_count() <= 2
+        || image
+            .to_rgb8()
+            .pixels()
+            .all(|p| p[0] == p[1] && p[1] == p[2]);
+    grayscale.then(|| (image.width(), image.height()))
+}
+
+/// Groups of two or more grayscale masks in `assets` worth packing, most
+/// memory saved first. Textures `is_excluded` accepts aren't considered.
+pub fn suggest_channel_packing(
+    assets: &[AssetInfo],
+    is_excluded: impl Fn(&str) -> bool,
+) -> Vec<ChannelPackingGroup> {
+    // (folder, lowercased base) → (display base, role, asset)
+    let mut candidates: HashMap<(String, String), Vec<(String, usize, &AssetInfo)>> =
+        HashMap::new();
+    for asset in assets {
+        if asset.asset_type != AssetType::Texture || is_excluded(&asset.path) {
+            continue;
+        }
+        let path = Path::new(&asset.path);
+        let Some(stem) = path.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        let Some((base, role)) = mask_role(stem) else {
+            continue;
+        };
+        let folder = path
+            .parent()
+            .map(|p| p.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let display = format!("{}/{}", folder, base);
+        candidates
+            .entry((folder, base.to_lowercase()))
+            .or_default()
+            .push((display, role, asset));
+    }
+
+    let mut groups: Vec<ChannelPackingGroup> = candidates
+        .into_values()
+        .filter_map(|mut members| {
+            members.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.2.path.cmp(&b.2.path)));
+            let mut named: Vec<char> = members.iter().map(|m| MASK_ROLES[m.1].1).collect();
+            named.dedup();
+            if named.len() < 2 {
+                return None;
+            }
+            // Only decoded once the names alone make a group, and before
+            // picking one texture per channel: a colored `_R` mustn't take
+            // G from a grayscale `_Roughness` beside it.
+            let mut masks: Vec<(usize, &AssetInfo, (u32, u32))> = members
+                .par_iter()
+                .filter_map(|(_, role, asset)| Some((*role, *asset, grayscale_size(&asset.path)?)))
+                .collect();
+            // Sorted by role, so this keeps the first role, then the first
+            // path, claiming each channel.
+            masks.dedup_by_key(|m| MASK_ROLES[m.0].1);
+            if masks.len() < 2 {
+                return None;
+            }
+
+            let separate_bytes = masks
+                .iter()
+                .map(|(_, _, (w, h))| *w as u64 * *h as u64 * BYTES_PER_PIXEL)
+                .sum::<u64>();
+            let width = masks.iter().map(|(_, _, (w, _))| *w).max()? as u64;
+            let height = masks.iter().map(|(_, _, (_, h))| *h).max()? as u64;
+            let packed_bytes = width * height * BYTES_PER_PIXEL;
+            Some(ChannelPackingGroup {
+                base: members[0].0.clone(),
+                channels: masks
+                    .iter()
+                    .map(|(role, asset, _)| ChannelAssignment {
+                        channel: MASK_ROLES[*role].1,
+                        role: MASK_ROLES[*role].0.to_string(),
+                        path: asset.path.clone(),
+                    })
+                    .collect(),
+                separate_bytes,
+                packed_bytes,
+                saved_bytes: separate_bytes.saturating_sub(packed_bytes),
+            })
+        })
+        .collect();
+    groups.sort_by(|a, b| {
+        b.saved_bytes
+            .cmp(&a.saved_bytes)
+            .then_with(|| a.base.cmp(&b.base))
+    });
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner;
+    use image::{GrayImage, Luma, Rgb, RgbImage};
+    use tempfile::tempdir;
+
+    fn texture(path: &Path) -> AssetInfo {
+        AssetInfo {
+            path: scanner::path_to_string(path),
+            name: path.file_name().unwrap().to_string_lossy().into_owned(),
+            extension: "png".to_string(),
+            asset_type: AssetType::Texture,
+            size: 0,
+            modified: 0,
+            metadata: None,
+            unity_guid: None,
+        }
+    }
+
+    fn gray(dir: &Path, name: &str, size: u32) -> AssetInfo {
+        let path = dir.join(name);
+        GrayImage::from_pixel(size, size, Luma([128]))
+            .save(&path)
+            .unwrap();
+        texture(&path)
+    }
+
+    fn rgb(dir: &Path, name: &str, pixel: [u8; 3]) -> AssetInfo {
+        let path = dir.join(name);
+        RgbImage::from_pixel(4, 4, Rgb(pixel)).save(&path).unwrap();
+        texture(&path)
+    }
+
+    fn layout(group: &ChannelPackingGroup) -> Vec<(char, &str, &str)> {
+        group
+            .channels
+            .iter()
+            .map(|c| {
+                (
+                    c.channel,
+                    c.role.as_str(),
+                    c.path.rsplit('/').next().unwrap(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn packs_grayscale_masks_sharing_a_base_name() {
+        let dir = tempdir().unwrap();
+        let dir = dir.path();
+        let assets = vec![
+            gray(dir, "T_Rock_AO.png", 8),
+            // Saved as RGB, but every channel agrees: still a mask.
+            rgb(dir, "T_Rock_M.png", [40, 40, 40]),
+            gray(dir, "T_Rock_R.png", 8),
+            gray(dir, "T_Rock_Roughness.png", 8),
+            // Colored: `_R` here isn't a mask.
+            rgb(dir, "T_Door_R.png", [200, 10, 10]),
+            gray(dir, "T_Door_AO.png", 4),
+            gray(dir, "T_Lone_AO.png", 4),
+        ];
+
+        let groups = suggest_channel_packing(&assets, |_| false);
+        assert_eq!(groups.len(), 1);
+        let group = &groups[0];
+        assert!(group.base.ends_with("/T_Rock"));
+        assert_eq!(
+            layout(group),
+            [
+                ('R', "ao", "T_Rock_AO.png"),
+                ('G', "roughness", "T_Rock_R.png"),
+                ('B', "metallic", "T_Rock_M.png"),
+            ]
+        );
+        assert_eq!(group.separate_bytes, (64 + 64 + 16) * 4);
+        assert_eq!(group.packed_bytes, 64 * 4);
+        assert_eq!(group.saved_bytes, 80 * 4);
+    }
+
+    #[test]
+    fn each_role_keeps_its_channel_in_a_partial_set() {
+        let dir = tempdir().unwrap();
+        let dir = dir.path();
+        let assets = vec![
+            gray(dir, "T_Moss_AO.png", 4),
+            gray(dir, "T_Moss_M.png", 4),
+            gray(dir, "T_Moss_Smooth.png", 4),
+            // A colored `_R` drops out before one texture is picked per
+            // channel, so the grayscale roughness beside it still gets G.
+            rgb(dir, "T_Moss_R.png", [200, 10, 10]),
+            gray(dir, "T_Moss_Roughness.png", 4),
+        ];
+
+        let groups = suggest_channel_packing(&assets, |_| false);
+        assert_eq!(
+            layout(&groups[0]),
+            [
+                ('R', "ao", "T_Moss_AO.png"),
+                ('G', "roughness", "T_Moss_Roughness.png"),
+                ('B', "metallic", "T_Moss_M.png"),
+                ('A', "smoothness", "T_Moss_Smooth.png"),
+            ]
+        );
+
+        let groups = suggest_channel_packing(&assets[..2], |_| false);
+        assert_eq!(
+            layout(&groups[0]),
+            [
+                ('R', "ao", "T_Moss_AO.png"),
+                ('B', "metallic", "T_Moss_M.png")
+            ]
+        );
+    }
+}
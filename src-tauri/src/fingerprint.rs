@@ -0,0 +1,131 @@
+//! One digest over the whole project, for "are these two checkouts
+//! identical?" and "has anything changed since the snapshot?".
+//!
+//! Each asset contributes its root-relative path and content hash; the
+//! pairs are sorted by path and folded into a single SHA-256, so the
+//! result doesn't depend on scan order, the checkout's location or the
+//! platform's separators. Per-file hashes come from the duplicate pass's
+//! cache where the file is unchanged, and files are streamed, never held
+//! in memory whole. Tidycraft's own sidecar files
+//! (`scanner::SIDECAR_NAMES`) are left out.
+//!
+//! Content hashes use the project's `[duplicate].algorithm`, so only
+//! fingerprints taken with the same algorithm compare.
+
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::path::Path;
+
+use crate::analyzer::rules::duplicate::{self, HashAlgo};
+use crate::cache::ScanCache;
+use crate::scanner::{self, AssetInfo};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ProjectFingerprint {
+    /// Lowercase hex SHA-256.
+    pub fingerprint: String,
+    pub file_count: usize,
+    /// Per-file content hash folded into `fingerprint`.
+    pub algorithm: HashAlgo,
+}
+
+/// Fingerprint `assets` as of the scan they came from. A file that can't
+/// be read (deleted since the scan) is an error: a fingerprint that
+/// silently skipped it would match a checkout without it.
+pub fn compute(
+    assets: &[AssetInfo],
+    root: &str,
+    algorithm: HashAlgo,
+    cache: Option<&mut ScanCache>,
+) -> Result<ProjectFingerprint, String> {
+    let root = Path::new(root);
+    let mut included: Vec<(String, &AssetInfo)> = assets
+        .iter()
+        .map(|asset| {
+            let path = Path::new(&asset.path);
+            let rel = scanner::path_to_string(path.strip_prefix(root).unwrap_or(path));
+            (rel, asset)
+        })
+        // Scans skip sidecars already; a cached scan from before one was
+        // registered may still list it.
+        .filter(|(rel, _)| !rel.split('/').any(scanner::is_sidecar_name))
+        .collect();
+    included.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let files: Vec<&AssetInfo> = included.iter().map(|(_, asset)| *asset).collect();
+    let hashes = duplicate::hash_assets(&files, algorithm, None, None, cache);
+
+    let mut hasher = Sha256::new();
+    for ((rel, _), hash) in included.iter().zip(&hashes) {
+        let hash = hash
+            .as_deref()
+            .ok_or_else(|| format!("Failed to read '{}'; rescan and try again", rel))?;
+        // NUL can't occur in a path, so entries can't run into each other.
+        hasher.update(rel.as_bytes());
+        hasher.update([0]);
+        hasher.update(hash.as_bytes());
+        hasher.update([0]);
+    }
+
+    Ok(ProjectFingerprint {
+        fingerprint: format!("{:x}", hasher.finalize()),
+        file_count: included.len(),
+        algorithm,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::AssetType;
+    use crate::tags;
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn write(root: &Path, rel: &str, content: &str) -> AssetInfo {
+        let path = root.join(rel);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, content).unwrap();
+        AssetInfo {
+            path: scanner::path_to_string(&path),
+            name: rel.rsplit('/').next().unwrap().to_string(),
+            extension: rel.rsplit('.').next().unwrap().to_string(),
+            asset_type: AssetType::Other,
+            size: content.len() as u64,
+            modified: 0,
+            metadata: None,
+            unity_guid: None,
+        }
+    }
+
+    #[test]
+    fn stable_across_locations_and_order_but_not_content() {
+        let (a, b) = (tempdir().unwrap(), tempdir().unwrap());
+        let fingerprint = |dir: &Path, hero: &str| {
+            let mut assets = vec![
+                write(dir, "Art/hero.png", hero),
+                write(dir, "Audio/step.wav", "wav"),
+                write(dir, tags::TAGS_FILE, "{}"),
+            ];
+            let root = scanner::path_to_string(dir);
+            let forward = compute(&assets, &root, HashAlgo::Blake3, None).unwrap();
+            assets.reverse();
+            assert_eq!(
+                compute(&assets, &root, HashAlgo::Blake3, None).unwrap(),
+                forward
+            );
+            forward
+        };
+
+        let first = fingerprint(a.path(), "png");
+        assert_eq!(first.file_count, 2);
+        assert_eq!(fingerprint(b.path(), "png"), first);
+        assert_ne!(fingerprint(b.path(), "PNG"), first);
+
+        // Deleted since the scan: an error, not a smaller fingerprint.
+        let assets = vec![write(b.path(), "Audio/step.wav", "wav")];
+        fs::remove_file(b.path().join("Audio/step.wav")).unwrap();
+        let root = scanner::path_to_string(b.path());
+        assert!(compute(&assets, &root, HashAlgo::Blake3, None).is_err());
+    }
+}
@@ -1,9 +1,12 @@
-use git2::{Repository, Status, StatusOptions};
-use serde::Serialize;
+use git2::{AttrCheckFlags, Repository, Status, StatusOptions};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::sync::mpsc;
 
-#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+use crate::scanner::AssetInfo;
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "snake_case")]
 pub enum GitFileStatus {
     New,
@@ -54,6 +57,15 @@ pub struct GitInfo {
     pub behind: u32,
 }
 
+/// A status pass started before anyone asked for it — see
+/// `lib.rs::spawn_git_prewarm`. `root` is the path it was started for; the
+/// manager arrives on `rx` once the pass is done, and the channel closes
+/// without one when `root` isn't in a repository.
+pub struct StatusPrewarm {
+    pub root: String,
+    pub rx: mpsc::Receiver<GitManager>,
+}
+
 pub struct GitManager {
     repo: Option<Repository>,
     root_path: PathBuf,
@@ -85,7 +97,6 @@ impl GitManager {
     }
 
     /// Check if this is a git repository
-    #[allow(dead_code)]
     pub fn is_repo(&self) -> bool {
         self.repo.is_some()
     }
@@ -104,7 +115,10 @@ impl GitManager {
             };
         };
 
-        self.load_statuses();
+        // A pre-warmed manager already holds an unconsumed pass.
+        if !self.statuses_fresh {
+            self.load_statuses();
+        }
         // Includes untracked files, matching `git status` (and the per-file
         // badges this map feeds). The old `statuses(None)` used libgit2's
         // raw defaults, which exclude untracked — an "untracked-only" repo
@@ -167,6 +181,12 @@ impl GitManager {
             .unwrap_or((0, 0))
     }
 
+    /// Run the status pass ahead of time. The next `get_info` /
+    /// `get_all_statuses` consume it instead of querying again.
+    pub fn prewarm(&mut self) {
+        self.load_statuses();
+    }
+
     /// Run the full status query into `status_cache` and mark it fresh.
     fn load_statuses(&mut self) {
         self.status_cache.clear();
@@ -207,6 +227,19 @@ impl GitManager {
         &self.status_cache
     }
 
+    /// Absolute paths of every file git reports as conflicted (unmerged),
+    /// sorted. Empty outside a repo or when no merge is in progress.
+    pub fn conflicted_paths(&mut self) -> Vec<PathBuf> {
+        let mut paths: Vec<PathBuf> = self
+            .get_all_statuses()
+            .iter()
+            .filter(|(_, status)| **status == GitFileStatus::Conflicted)
+            .map(|(path, _)| path.clone())
+            .collect();
+        paths.sort();
+        paths
+    }
+
     /// Check if a path should be ignored according to .gitignore. Currently
     /// unused but kept as a primitive for future scanner integration that
     /// would honor `.gitignore` (e.g. an opt-in "skip ignored files" mode).
@@ -223,15 +256,271 @@ impl GitManager {
 
         repo.is_path_ignored(relative_path).unwrap_or(false)
     }
+
+    /// Whether `.gitattributes` puts `path` through the LFS filter
+    /// (`filter=lfs`, what `git lfs track` writes). libgit2 resolves the
+    /// attributes, so nested `.gitattributes` files and later lines
+    /// overriding earlier ones behave as in git.
+    pub fn is_lfs_tracked(&self, path: &Path) -> bool {
+        let Some(repo) = &self.repo else {
+            return false;
+        };
+        let Ok(relative_path) = path.strip_prefix(&self.root_path) else {
+            return false;
+        };
+        matches!(
+            repo.get_attr(relative_path, "filter", AttrCheckFlags::FILE_THEN_INDEX),
+            Ok(Some("lfs"))
+        )
+    }
+}
+
+/// `[lfs]` in `tidycraft.toml`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LfsConfig {
+    /// Files at or above this many bytes belong in LFS.
+    #[serde(default = "default_lfs_threshold")]
+    pub threshold_bytes: u64,
+}
+
+fn default_lfs_threshold() -> u64 {
+    5 * 1024 * 1024
+}
+
+impl Default for LfsConfig {
+    fn default() -> Self {
+        Self {
+            threshold_bytes: default_lfs_threshold(),
+        }
+    }
+}
+
+/// A large file git would store as a plain blob.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct LargeUntrackedAsset {
+    pub path: String,
+    pub size: u64,
+    pub extension: String,
+    /// The `.gitattributes` line `git lfs track` would add for the
+    /// extension as written — git patterns are case-sensitive — or for the
+    /// file itself when it has none.
+    pub suggested_pattern: String,
+}
+
+/// Assets of at least `threshold` bytes no LFS pattern covers, largest
+/// first. Once committed, a blob stays in history even if it's later
+/// moved to LFS, so these are worth catching before the commit. Files
+/// `.gitignore` keeps out of the repository never get committed and are
+/// left out.
+pub fn large_assets_outside_lfs(
+    manager: &GitManager,
+    assets: &[AssetInfo],
+    threshold: u64,
+) -> Vec<LargeUntrackedAsset> {
+    let mut found: Vec<LargeUntrackedAsset> = assets
+        .iter()
+        .filter(|a| {
+            let path = Path::new(&a.path);
+            a.size >= threshold && !manager.is_lfs_tracked(path) && !manager.is_ignored(path)
+        })
+        .map(|a| {
+            let pattern = if a.extension.is_empty() {
+                let path = Path::new(&a.path);
+                crate::scanner::path_to_string(
+                    path.strip_prefix(&manager.root_path).unwrap_or(path),
+                )
+            } else {
+                format!("*.{}", a.extension)
+            };
+            LargeUntrackedAsset {
+                path: a.path.clone(),
+                size: a.size,
+                extension: a.extension.clone(),
+                suggested_pattern: format!("{} filter=lfs diff=lfs merge=lfs -text", pattern),
+            }
+        })
+        .collect();
+    found.sort_by(|a, b| b.size.cmp(&a.size).then_with(|| a.path.cmp(&b.path)));
+    found
+}
+
+/// One `<<<<<<<` … `>>>>>>>` block in a conflicted file. Line numbers are
+/// 1-based.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct ConflictRegion {
+    pub start_line: usize,
+    /// The `=======` line splitting ours from theirs. `None` if the block
+    /// was truncated before it.
+    pub separator_line: Option<usize>,
+    pub end_line: usize,
+}
+
+/// Locate conflict-marker blocks in `content`. Markers only count at the
+/// start of a line and followed by a space or end of line, so YAML
+/// scalars or Markdown rules that happen to contain `=======` mid-line
+/// don't match. diff3's `|||||||` base section is accepted and skipped.
+/// An unterminated block at end of file is reported up to the last line.
+pub fn find_conflict_regions(content: &str) -> Vec<ConflictRegion> {
+    fn is_marker(line: &str, ch: char) -> bool {
+        let line = line.trim_end_matches('\r');
+        let run = line.chars().take_while(|&c| c == ch).count();
+        run == 7 && matches!(line[7..].chars().next(), None | Some(' '))
+    }
+
+    let mut regions = Vec::new();
+    let mut open: Option<(usize, Option<usize>)> = None;
+    let mut last_line = 0;
+
+    for (index, line) in content.lines().enumerate() {
+        let line_no = index + 1;
+        last_line = line_no;
+        match open {
+            None if is_marker(line, '<') => open = Some((line_no, None)),
+            Some((start, None)) if is_marker(line, '=') => open = Some((start, Some(line_no))),
+            Some((start, separator)) if is_marker(line, '>') => {
+                regions.push(ConflictRegion {
+                    start_line: start,
+                    separator_line: separator,
+                    end_line: line_no,
+                });
+                open = None;
+            }
+            _ => {}
+        }
+    }
+
+    if let Some((start, separator)) = open {
+        regions.push(ConflictRegion {
+            start_line: start,
+            separator_line: separator,
+            end_line: last_line,
+        });
+    }
+
+    regions
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn finds_conflict_regions_with_line_numbers() {
+        let content = "a\n<<<<<<< HEAD\nours\n=======\ntheirs\n>>>>>>> feature\nb\n\
+                       <<<<<<< HEAD\nx\n||||||| base\ny\n=======\nz\n>>>>>>> other\n";
+        assert_eq!(
+            find_conflict_regions(content),
+            vec![
+                ConflictRegion {
+                    start_line: 2,
+                    separator_line: Some(4),
+                    end_line: 6,
+                },
+                ConflictRegion {
+                    start_line: 8,
+                    separator_line: Some(12),
+                    end_line: 14,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn conflict_markers_must_be_exact_and_line_leading() {
+        // 8 `=`s, an indented marker and a mid-line run are all plain text.
+        let content = "title: a ======= b\n========\n  <<<<<<< HEAD\n<<<<<<<<x\n";
+        assert!(find_conflict_regions(content).is_empty());
+
+        // Unterminated block runs to the last line.
+        let truncated = "<<<<<<< HEAD\r\nours\r\n=======\r\n";
+        assert_eq!(
+            find_conflict_regions(truncated),
+            vec![ConflictRegion {
+                start_line: 1,
+                separator_line: Some(3),
+                end_line: 3,
+            }]
+        );
+    }
+
     #[test]
     fn test_non_git_directory() {
         let manager = GitManager::open(Path::new("/tmp"));
         assert!(!manager.is_repo());
     }
+
+    #[test]
+    fn prewarmed_pass_is_consumed_once() {
+        let dir = tempfile::tempdir().unwrap();
+        Repository::init(dir.path()).unwrap();
+        std::fs::write(dir.path().join("a.png"), "a").unwrap();
+
+        let mut manager = GitManager::open(dir.path());
+        manager.prewarm();
+        // Written after the pass: the warm `get_info` / `get_all_statuses`
+        // pair doesn't see it...
+        std::fs::write(dir.path().join("b.png"), "b").unwrap();
+        assert!(manager.get_info().has_changes);
+        assert_eq!(manager.get_all_statuses().len(), 1);
+        // ...and the next standalone call re-queries.
+        assert_eq!(manager.get_all_statuses().len(), 2);
+    }
+
+    fn asset(root: &Path, name: &str, size: u64) -> AssetInfo {
+        AssetInfo {
+            path: crate::scanner::path_to_string(&root.join(name)),
+            name: name.to_string(),
+            extension: name
+                .rsplit_once('.')
+                .map(|(_, e)| e)
+                .unwrap_or("")
+                .to_string(),
+            asset_type: crate::scanner::AssetType::Other,
+            size,
+            modified: 0,
+            metadata: None,
+            unity_guid: None,
+        }
+    }
+
+    #[test]
+    fn large_assets_outside_lfs_patterns_are_reported() {
+        let dir = tempfile::tempdir().unwrap();
+        Repository::init(dir.path()).unwrap();
+        std::fs::write(
+            dir.path().join(".gitattributes"),
+            "*.psd filter=lfs diff=lfs merge=lfs -text\n",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join(".gitignore"), "*.pak\n").unwrap();
+        let manager = GitManager::open(dir.path());
+        let assets = vec![
+            asset(&manager.root_path, "Hero.psd", 50),
+            // Ignored: never committed, so never a plain blob.
+            asset(&manager.root_path, "Game.pak", 90),
+            asset(&manager.root_path, "Hero.TGA", 20),
+            asset(&manager.root_path, "Intro.mp4", 80),
+            asset(&manager.root_path, "small.png", 5),
+            asset(&manager.root_path, "LICENSE", 30),
+        ];
+
+        let found = large_assets_outside_lfs(&manager, &assets, 10);
+        let summary: Vec<(&str, &str)> = found
+            .iter()
+            .map(|f| {
+                (
+                    f.path.rsplit('/').next().unwrap(),
+                    f.suggested_pattern.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("Intro.mp4", "*.mp4 filter=lfs diff=lfs merge=lfs -text"),
+                ("LICENSE", "LICENSE filter=lfs diff=lfs merge=lfs -text"),
+                ("Hero.TGA", "*.TGA filter=lfs diff=lfs merge=lfs -text"),
+            ]
+        );
+    }
 }
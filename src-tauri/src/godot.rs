@@ -520,6 +520,144 @@ pub fn godot_dependency_edges(root: &Path, assets: &[AssetInfo]) -> Vec<(String,
     edges
 }
 
+// ============ Broken references ============
+
+/// Why an `ext_resource` doesn't load.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BrokenReferenceReason {
+    /// The path (or the file its UID maps to) doesn't exist.
+    Missing,
+    /// Only a `uid://` is given and there's no `.godot/uid_cache.bin` to
+    /// look it up in, or the cache doesn't know it.
+    UnresolvableUid,
+}
+
+/// One `ext_resource` of a scene or resource that Godot would fail to load
+/// ("Resource file not found").
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BrokenGodotReference {
+    /// The `.tscn` / `.tres` holding the reference.
+    pub source_path: String,
+    /// The reference as written: its `path`, or its `uid` when it has none.
+    pub reference: String,
+    /// The `type` attribute (`Texture2D`, `PackedScene`, ...).
+    pub resource_type: Option<String>,
+    pub reason: BrokenReferenceReason,
+}
+
+/// Godot's UID text form → its numeric id (`ResourceUID::text_to_id`):
+/// base 36, `a`–`z` before `0`–`9`.
+fn uid_text_to_id(text: &str) -> Option<u64> {
+    let digits = text.strip_prefix("uid://")?;
+    let mut id: u64 = 0;
+    for c in digits.bytes() {
+        let value = match c {
+            b'a'..=b'z' => c - b'a',
+            b'0'..=b'9' => c - b'0' + 26,
+            _ => return None,
+        };
+        id = id.wrapping_mul(36).wrapping_add(value as u64);
+    }
+    Some(id & 0x7FFF_FFFF_FFFF_FFFF)
+}
+
+/// `.godot/uid_cache.bin` (`ResourceUID::save_to_cache`): a little-endian
+/// u32 count, then per entry a u64 id, a u32 byte length and the UTF-8
+/// `res://` path. `None` when the file is absent or truncated.
+fn read_uid_cache(root: &Path) -> Option<HashMap<u64, String>> {
+    let bytes = fs::read(root.join(".godot").join("uid_cache.bin")).ok()?;
+    let mut at = 0usize;
+    let mut take = |n: usize| {
+        let slice = bytes.get(at..at + n)?;
+        at += n;
+        Some(slice)
+    };
+    let count = u32::from_le_bytes(take(4)?.try_into().ok()?);
+    let mut cache = HashMap::new();
+    for _ in 0..count {
+        let id = u64::from_le_bytes(take(8)?.try_into().ok()?);
+        let len = u32::from_le_bytes(take(4)?.try_into().ok()?) as usize;
+        let path = String::from_utf8_lossy(take(len)?).into_owned();
+        cache.insert(id & 0x7FFF_FFFF_FFFF_FFFF, path);
+    }
+    Some(cache)
+}
+
+/// Every `[ext_resource ...]` in `.tscn` / `.tres` files checked against
+/// the disk. A `res://` path resolves against the project root, any other
+/// non-scheme path against the referencing file's folder. A reference whose
+/// path is gone but whose `uid` still maps to an existing file loads fine
+/// (Godot prefers the UID) and isn't reported.
+pub fn find_broken_godot_references(
+    root: &Path,
+    assets: &[AssetInfo],
+) -> Vec<BrokenGodotReference> {
+    let attr = regex::Regex::new(r#"(\w+)="([^"]*)""#).expect("static regex compiles");
+    let uid_cache = read_uid_cache(root);
+    let exists = |res: &str| res_path_to_abs(res, root).is_some_and(|p| p.exists());
+    // `Some(true/false)` when the UID resolves, `None` when it can't be.
+    let uid_target_exists = |uid: &str| {
+        let path = uid_cache.as_ref()?.get(&uid_text_to_id(uid)?)?;
+        Some(exists(path))
+    };
+
+    let mut broken = Vec::new();
+    for asset in assets {
+        let ext = asset.extension.to_lowercase();
+        if ext != "tscn" && ext != "tres" {
+            continue;
+        }
+        let Ok(content) = fs::read_to_string(&asset.path) else {
+            continue;
+        };
+        for line in content.lines().filter(|l| l.starts_with("[ext_resource")) {
+            let attrs: HashMap<&str, &str> = attr
+                .captures_iter(line)
+                .filter_map(|c| Some((c.get(1)?.as_str(), c.get(2)?.as_str())))
+                .collect();
+            let uid = attrs
+                .get("uid")
+                .copied()
+                .filter(|u| u.starts_with("uid://"));
+            let reason = match attrs.get("path").copied() {
+                Some(path) => {
+                    let found = if path.starts_with("res://") {
+                        exists(path)
+                    } else if path.contains("://") {
+                        // user:// and friends only exist at runtime.
+                        true
+                    } else {
+                        Path::new(&asset.path)
+                            .parent()
+                            .is_some_and(|dir| dir.join(path).exists())
+                    };
+                    let rescued = uid.and_then(uid_target_exists) == Some(true);
+                    (!found && !rescued).then_some(BrokenReferenceReason::Missing)
+                }
+                None => match uid.map(uid_target_exists) {
+                    Some(Some(true)) | None => None,
+                    Some(Some(false)) => Some(BrokenReferenceReason::Missing),
+                    Some(None) => Some(BrokenReferenceReason::UnresolvableUid),
+                },
+            };
+            if let Some(reason) = reason {
+                broken.push(BrokenGodotReference {
+                    source_path: asset.path.clone(),
+                    reference: attrs
+                        .get("path")
+                        .or(uid.as_ref())
+                        .map(|r| r.to_string())
+                        .unwrap_or_default(),
+                    resource_type: attrs.get("type").map(|t| t.to_string()),
+                    reason,
+                });
+            }
+        }
+    }
+    broken
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -883,4 +1021,86 @@ config/name="Minimal"
         assert_eq!(edges[0].0, "res://main.tscn");
         assert_eq!(edges[0].1, "res://hero.png");
     }
+
+    #[test]
+    fn test_find_broken_godot_references() {
+        use crate::scanner::AssetType;
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("levels")).unwrap();
+        fs::write(root.join("hero.png"), "x").unwrap();
+        fs::write(root.join("levels/tiles.png"), "x").unwrap();
+        fs::write(
+            root.join("levels/main.tscn"),
+            [
+                r#"[gd_scene load_steps=6 format=3 uid="uid://main"]"#,
+                r#"[ext_resource type="Texture2D" path="res://hero.png" id="1"]"#,
+                r#"[ext_resource type="Texture2D" path="tiles.png" id="2"]"#,
+                r#"[ext_resource type="AudioStream" path="res://gone.ogg" id="3"]"#,
+                r#"[ext_resource type="Texture2D" uid="uid://b" path="res://old.png" id="4"]"#,
+                r#"[ext_resource type="PackedScene" uid="uid://c" id="5"]"#,
+            ]
+            .join("\n"),
+        )
+        .unwrap();
+        let assets = vec![AssetInfo {
+            path: root.join("levels/main.tscn").to_string_lossy().to_string(),
+            name: "main.tscn".to_string(),
+            extension: "tscn".to_string(),
+            asset_type: AssetType::Scene,
+            size: 1,
+            modified: 0,
+            metadata: None,
+            unity_guid: None,
+        }];
+        let found = |root: &Path| -> Vec<(String, Option<String>, BrokenReferenceReason)> {
+            find_broken_godot_references(root, &assets)
+                .into_iter()
+                .map(|b| (b.reference, b.resource_type, b.reason))
+                .collect()
+        };
+        let missing = |r: &str, t: &str| {
+            (
+                r.to_string(),
+                Some(t.to_string()),
+                BrokenReferenceReason::Missing,
+            )
+        };
+
+        // No UID cache: the moved texture is missing, the uid-only scene
+        // can't be resolved.
+        assert_eq!(
+            found(root),
+            vec![
+                missing("res://gone.ogg", "AudioStream"),
+                missing("res://old.png", "Texture2D"),
+                (
+                    "uid://c".to_string(),
+                    Some("PackedScene".to_string()),
+                    BrokenReferenceReason::UnresolvableUid
+                ),
+            ]
+        );
+
+        // uid://b now maps to hero.png (rescuing old.png), uid://c to a
+        // scene that's gone.
+        let mut cache = 2u32.to_le_bytes().to_vec();
+        for (uid, path) in [
+            ("uid://b", "res://hero.png"),
+            ("uid://c", "res://boss.tscn"),
+        ] {
+            cache.extend(uid_text_to_id(uid).unwrap().to_le_bytes());
+            cache.extend((path.len() as u32).to_le_bytes());
+            cache.extend(path.as_bytes());
+        }
+        fs::create_dir_all(root.join(".godot")).unwrap();
+        fs::write(root.join(".godot/uid_cache.bin"), cache).unwrap();
+        assert_eq!(
+            found(root),
+            vec![
+                missing("res://gone.ogg", "AudioStream"),
+                missing("uid://c", "PackedScene"),
+            ]
+        );
+    }
 }
@@ -0,0 +1,172 @@
+//! Analysis issues rolled up over the directory tree.
+//!
+//! `AnalysisResult` is a flat list, which answers "what's wrong" but not
+//! "where": on a large project the useful question is which folders carry
+//! the problems, so cleanup can be handed out by area. Each issue counts
+//! against the directory holding its `asset_path` and every ancestor of
+//! it, so a node's counts cover its whole subtree.
+
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+
+use crate::analyzer::{Issue, Severity};
+use crate::scanner::DirectoryNode;
+
+/// A `DirectoryNode` with issue counts attached. Subtrees without any
+/// issue are left out; the root is always present.
+#[derive(Debug, Clone, Serialize)]
+pub struct DirectoryIssues {
+    pub name: String,
+    pub path: String,
+    pub children: Vec<DirectoryIssues>,
+    pub error_count: usize,
+    pub warning_count: usize,
+    pub info_count: usize,
+}
+
+impl DirectoryIssues {
+    pub fn issue_count(&self) -> usize {
+        self.error_count + self.warning_count + self.info_count
+    }
+
+    fn add(&mut self, severity: &Severity) {
+        match severity {
+            Severity::Error => self.error_count += 1,
+            Severity::Warning => self.warning_count += 1,
+            Severity::Info => self.info_count += 1,
+        }
+    }
+}
+
+/// Parent directory of a `/`-separated path, or `None` at the top.
+fn parent(path: &str) -> Option<&str> {
+    path.rfind('/').map(|i| &path[..i])
+}
+
+/// Roll `issues` up over `tree`. An issue whose directory isn't in the
+/// tree (its file went away since the scan) counts against the deepest
+/// ancestor that is, so the root's counts always match the issue list.
+pub fn issues_by_directory(tree: &DirectoryNode, issues: &[Issue]) -> DirectoryIssues {
+    let mut known: HashSet<&str> = HashSet::new();
+    collect_paths(tree, &mut known);
+
+    let mut direct: HashMap<&str, Vec<&Severity>> = HashMap::new();
+    for issue in issues {
+        let mut dir = parent(&issue.asset_path);
+        while let Some(d) = dir {
+            if known.contains(d) {
+                break;
+            }
+            dir = parent(d);
+        }
+        direct
+            .entry(dir.unwrap_or(tree.path.as_str()))
+            .or_default()
+            .push(&issue.severity);
+    }
+    roll_up(tree, &direct)
+}
+
+fn collect_paths<'a>(node: &'a DirectoryNode, out: &mut HashSet<&'a str>) {
+    out.insert(node.path.as_str());
+    for child in &node.children {
+        collect_paths(child, out);
+    }
+}
+
+fn roll_up(node: &DirectoryNode, direct: &HashMap<&str, Vec<&Severity>>) -> DirectoryIssues {
+    let mut out = DirectoryIssues {
+        name: node.name.clone(),
+        path: node.path.clone(),
+        children: Vec::new(),
+        error_count: 0,
+        warning_count: 0,
+        info_count: 0,
+    };
+    for severity in direct.get(node.path.as_str()).into_iter().flatten() {
+        out.add(severity);
+    }
+    for child in &node.children {
+        let child = roll_up(child, direct);
+        if child.issue_count() == 0 {
+            continue;
+        }
+        out.error_count += child.error_count;
+        out.warning_count += child.warning_count;
+        out.info_count += child.info_count;
+        out.children.push(child);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dir(path: &str, children: Vec<DirectoryNode>) -> DirectoryNode {
+        DirectoryNode {
+            name: path.rsplit('/').next().unwrap().to_string(),
+            path: path.to_string(),
+            children,
+            file_count: 0,
+            total_size: 0,
+        }
+    }
+
+    fn issue(asset_path: &str, severity: Severity) -> Issue {
+        Issue {
+            rule_id: "naming".to_string(),
+            rule_name: "Naming".to_string(),
+            severity,
+            message: String::new(),
+            asset_path: asset_path.to_string(),
+            suggestion: None,
+            auto_fixable: false,
+            related_paths: None,
+        }
+    }
+
+    #[test]
+    fn counts_roll_up_to_ancestors() {
+        let tree = dir(
+            "/p",
+            vec![
+                dir(
+                    "/p/Art",
+                    vec![dir("/p/Art/Tex", vec![]), dir("/p/Art/Mesh", vec![])],
+                ),
+                dir("/p/Audio", vec![]),
+            ],
+        );
+        let issues = vec![
+            issue("/p/Art/Tex/a.png", Severity::Error),
+            issue("/p/Art/Tex/b.png", Severity::Warning),
+            issue("/p/Art/c.psd", Severity::Info),
+            issue("/p/readme.txt", Severity::Warning),
+            // Folder deleted since the scan: lands on its nearest known
+            // ancestor.
+            issue("/p/Art/Gone/d.png", Severity::Error),
+        ];
+        let rollup = issues_by_directory(&tree, &issues);
+
+        assert_eq!(rollup.issue_count(), issues.len());
+        assert_eq!(
+            (rollup.error_count, rollup.warning_count, rollup.info_count),
+            (2, 2, 1)
+        );
+        // Mesh and Audio have nothing and are left out.
+        assert_eq!(rollup.children.len(), 1);
+        let art = &rollup.children[0];
+        assert_eq!(art.path, "/p/Art");
+        assert_eq!(
+            (art.error_count, art.warning_count, art.info_count),
+            (2, 1, 1)
+        );
+        assert_eq!(art.children.len(), 1);
+        let tex = &art.children[0];
+        assert_eq!(
+            (tex.error_count, tex.warning_count, tex.info_count),
+            (1, 1, 0)
+        );
+    }
+}
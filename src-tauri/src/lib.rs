@@ -1,22 +1,46 @@
 mod analyzer;
+mod asset_filter;
+mod audio_consistency;
+mod build_estimate;
 mod cache;
+mod channel_packing;
+mod fingerprint;
 mod fs_atomic;
 mod git;
 mod godot;
+mod issue_rollup;
 mod llm;
 mod meta_sidecar;
+mod name_collisions;
 mod project;
+mod sarif;
 mod scanner;
+mod stats_delta;
+mod symlinks;
 mod tags;
 mod thumbnail;
+mod tree_text;
 mod undo;
 mod unity;
+mod unity_animation;
+mod unity_atlas;
+mod unity_closure;
+mod unity_criticality;
+mod unity_importers;
+mod unity_index;
+mod unity_material_textures;
+mod unity_materials;
+mod unity_missing_scripts;
+mod unity_scripts;
+mod unity_shaders;
+mod unity_unused_scripts;
 mod unreal;
+mod unused_report;
 mod watcher;
 
-use analyzer::rules::RuleConfig;
+use analyzer::rules::{RuleConfig, VendorMatcher};
 use analyzer::tag_suggest::{HeuristicSuggester, TagGroup, TagSuggester};
-use analyzer::{AnalysisResult, Analyzer};
+use analyzer::{AnalysisPhase, AnalysisResult, AnalysisTracker, Analyzer};
 use cache::ScanCache;
 use git::{GitInfo, GitManager};
 use scanner::{IncrementalStats, ScanResult, ScanState};
@@ -76,6 +100,76 @@ fn spawn_progress_reporter(
     })
 }
 
+/// Emit `duplicate-progress-{project_id}` every 100ms while an analysis
+/// hashes, until the caller flips `stop`. Silent before the duplicate
+/// pass has sized its work and when nothing moved since the last tick, so
+/// a fully cached run sends nothing.
+fn spawn_hash_progress_reporter(
+    app: AppHandle,
+    project_id: String,
+    tracker: Arc<AnalysisTracker>,
+    stop: Arc<AtomicBool>,
+) -> thread::JoinHandle<()> {
+    let event_name = format!("duplicate-progress-{}", project_id);
+    thread::spawn(move || {
+        let mut last = None;
+        loop {
+            let stopping = stop.load(Ordering::SeqCst);
+            let snapshot = tracker.hashing.snapshot();
+            if snapshot.bytes_total > 0 && last != Some(snapshot) {
+                let _ = app.emit(&event_name, &snapshot);
+                last = Some(snapshot);
+            }
+            if stopping {
+                break;
+            }
+            thread::sleep(Duration::from_millis(100));
+        }
+    })
+}
+
+/// Emit `analysis-progress-{project_id}` every 100ms for
+/// `analyze_assets_async`, until the run completes or the caller flips
+/// `stop` (an analysis that fails never reaches `Completed`).
+fn spawn_analysis_progress_reporter(
+    app: AppHandle,
+    project_id: String,
+    tracker: Arc<AnalysisTracker>,
+    stop: Arc<AtomicBool>,
+) -> thread::JoinHandle<()> {
+    let event_name = format!("analysis-progress-{}", project_id);
+    thread::spawn(move || loop {
+        let progress = tracker.snapshot();
+        let _ = app.emit(&event_name, &progress);
+
+        if progress.phase == AnalysisPhase::Completed || stop.load(Ordering::SeqCst) {
+            break;
+        }
+
+        thread::sleep(Duration::from_millis(100));
+    })
+}
+
+/// Emit `unused-scan-progress-{project_id}` every 100ms for
+/// `find_unused_assets_async` until the caller flips `stop`, plus one last
+/// snapshot after.
+fn spawn_unused_scan_progress_reporter(
+    app: AppHandle,
+    project_id: String,
+    progress: Arc<unity_index::RefreshProgress>,
+    stop: Arc<AtomicBool>,
+) -> thread::JoinHandle<()> {
+    let event_name = format!("unused-scan-progress-{}", project_id);
+    thread::spawn(move || loop {
+        let stopping = stop.load(Ordering::SeqCst);
+        let _ = app.emit(&event_name, &progress.snapshot());
+        if stopping {
+            break;
+        }
+        thread::sleep(Duration::from_millis(100));
+    })
+}
+
 #[tauri::command]
 fn cancel_scan(project_id: String) -> bool {
     project::with_ref(&project_id, |s| {
@@ -102,16 +196,123 @@ async fn scan_project_incremental(
     // `.git/`). Toggle exposed via Settings → Maintenance for users
     // who need full coverage on a project with gitignored asset folders.
     respect_gitignore: bool,
+    // Also walk dot-prefixed files and directories (`.git/` excepted) and
+    // show dot-dirs in the tree. Omitted = off.
+    include_hidden: Option<bool>,
+    // Diagnostics: fill `result.timings` with a per-extension / per-parser
+    // breakdown. Omitted = off.
+    collect_timings: Option<bool>,
+    // `false` = inventory only: paths, types, sizes and the tree, with no
+    // file decoded (`result.metadata_skipped`). Omitted = on.
+    metadata: Option<bool>,
 ) -> Result<IncrementalScanResult, String> {
     project::register(project_id.clone(), path.clone());
 
+    let cache_key = path.clone();
+    let include_hidden = include_hidden.unwrap_or(false);
+    let collect_timings = collect_timings.unwrap_or(false);
+    let parse_metadata = metadata.unwrap_or(true);
+    let (result, stats, _) = run_project_scan(
+        app,
+        &project_id,
+        cache_key,
+        respect_gitignore,
+        include_hidden,
+        move |state| {
+            scanner::scan_directory_incremental(
+                &path,
+                Some(state),
+                respect_gitignore,
+                include_hidden,
+                collect_timings,
+                parse_metadata,
+            )
+            .map(|(result, stats)| (result, stats, Vec::new()))
+        },
+    )
+    .await?;
+
+    Ok(IncrementalScanResult { result, stats })
+}
+
+#[derive(Serialize)]
+pub struct MultiScanResult {
+    pub result: ScanResult,
+    pub stats: IncrementalStats,
+    /// Detected project type of each root, in sorted root order.
+    pub project_types: Vec<scanner::RootProjectType>,
+}
+
+/// Scan several sibling roots as one logical project. The project is
+/// registered at the roots' common ancestor, which is also the merged
+/// result's `root_path` — see `scanner::scan_directories_incremental`.
+#[tauri::command]
+async fn scan_project_multi(
+    app: AppHandle,
+    project_id: String,
+    paths: Vec<String>,
+    respect_gitignore: bool,
+    include_hidden: Option<bool>,
+    collect_timings: Option<bool>,
+    metadata: Option<bool>,
+) -> Result<MultiScanResult, String> {
+    if paths.is_empty() {
+        return Err("No directories to scan".to_string());
+    }
+    project::register(project_id.clone(), scanner::multi_root_path(&paths));
+
+    let cache_key = scanner::multi_root_cache_key(&scanner::sorted_roots(&paths));
+    let include_hidden = include_hidden.unwrap_or(false);
+    let collect_timings = collect_timings.unwrap_or(false);
+    let parse_metadata = metadata.unwrap_or(true);
+    let (result, stats, project_types) = run_project_scan(
+        app,
+        &project_id,
+        cache_key,
+        respect_gitignore,
+        include_hidden,
+        move |state| {
+            scanner::scan_directories_incremental(
+                &paths,
+                Some(state),
+                respect_gitignore,
+                include_hidden,
+                collect_timings,
+                parse_metadata,
+            )
+        },
+    )
+    .await?;
+
+    Ok(MultiScanResult {
+        result,
+        stats,
+        project_types,
+    })
+}
+
+/// Shared lifecycle for the scan commands: claim the project's in-flight
+/// slot, report progress while `scan` runs on the blocking pool, then
+/// release the slot and cache the result. `cache_key` is the `ScanCache`
+/// key `scan` persists under, recorded so analysis can reuse its hashes.
+async fn run_project_scan<F>(
+    app: AppHandle,
+    project_id: &str,
+    cache_key: String,
+    respect_gitignore: bool,
+    include_hidden: bool,
+    scan: F,
+) -> Result<MultiScanOutput, String>
+where
+    F: FnOnce(Arc<ScanState>) -> Result<MultiScanOutput, scanner::ScanError> + Send + 'static,
+{
     let state = Arc::new(ScanState::new());
     // In-flight guard: `scan_state` being `Some` means another scan already
     // owns this project. Reject the second one rather than overwriting the
     // first's state (which would drop its cancellation, interleave the two
     // progress reporters, and let an older scan's result clobber a newer one).
     // The check + set is atomic under the project lock held by `with_mut`.
-    let already = project::with_mut(&project_id, |s| {
+    let already = project::with_mut(project_id, |s| {
         if s.scan_state.is_some() {
             return Ok(true);
         }
@@ -122,16 +323,13 @@ async fn scan_project_incremental(
         return Err("A scan is already in progress for this project".to_string());
     }
 
+    spawn_git_prewarm(app.clone(), project_id)?;
+
     let stop = Arc::new(AtomicBool::new(false));
     let progress_handle =
-        spawn_progress_reporter(app.clone(), project_id.clone(), state.clone(), stop.clone());
+        spawn_progress_reporter(app, project_id.to_string(), state.clone(), stop.clone());
 
-    let state_for_scan = state.clone();
-    let path_for_scan = path.clone();
-    let join_result = tokio::task::spawn_blocking(move || {
-        scanner::scan_directory_incremental(&path_for_scan, Some(state_for_scan), respect_gitignore)
-    })
-    .await;
+    let join_result = tokio::task::spawn_blocking(move || scan(state)).await;
 
     // Stop the reporter and join it BEFORE propagating any error: the scan's
     // early `Err` paths (e.g. the project folder was moved/deleted) never mark a
@@ -140,39 +338,150 @@ async fn scan_project_incremental(
     stop.store(true, Ordering::SeqCst);
     let _ = progress_handle.join();
 
-    let _ = project::with_mut(&project_id, |s| {
+    let scan_failed = !matches!(join_result, Ok(Ok(_)));
+    let _ = project::with_mut(project_id, |s| {
         s.scan_state = None;
+        // Nobody refreshes git after a failed scan, so the pass would sit
+        // there until some much later refresh consumed it as current.
+        if scan_failed {
+            s.git_prewarm = None;
+        }
         Ok(())
     });
 
-    let (scan_result, stats) = join_result
+    let output = join_result
         .map_err(|e| e.to_string())?
         .map_err(|e| e.to_string())?;
 
-    project::with_mut(&project_id, |s| {
-        s.cached_scan = Some(scan_result.clone());
+    project::with_mut(project_id, |s| {
+        s.cached_scan = Some(output.0.clone());
         s.respect_gitignore = respect_gitignore;
+        s.include_hidden = include_hidden;
+        s.scan_cache_key = Some(cache_key);
         Ok(())
     })?;
 
-    Ok(IncrementalScanResult {
-        result: scan_result,
-        stats,
-    })
+    Ok(output)
+}
+
+/// Run the git status pass concurrently with a scan, so the `get_git_info`
+/// the frontend sends when the scan finishes finds it done (or waits for
+/// it) instead of starting a multi-second pass of its own. Emits
+/// `git-statuses-ready-{project_id}` once the pass lands; nothing for a
+/// project outside a git repository.
+fn spawn_git_prewarm(app: AppHandle, project_id: &str) -> Result<(), String> {
+    let (tx, rx) = std::sync::mpsc::channel();
+    let root = project::with_mut(project_id, |s| {
+        s.git_prewarm = Some(git::StatusPrewarm {
+            root: s.root_path.clone(),
+            rx,
+        });
+        Ok(s.root_path.clone())
+    })?;
+
+    let event_name = format!("git-statuses-ready-{}", project_id);
+    thread::spawn(move || {
+        let mut manager = GitManager::open(Path::new(&root));
+        if !manager.is_repo() {
+            // Dropping `tx` closes the channel; `get_git_info` falls back.
+            return;
+        }
+        manager.prewarm();
+        // `send` fails only when the slot was dropped (failed scan, or
+        // superseded by the next scan) — nobody is waiting for this pass.
+        if tx.send(manager).is_ok() {
+            let _ = app.emit(&event_name, ());
+        }
+    });
+    Ok(())
 }
 
+/// `scanner::scan_directories_incremental`'s output; single-root scans
+/// report no per-root types.
+type MultiScanOutput = (ScanResult, IncrementalStats, Vec<scanner::RootProjectType>);
+
 #[tauri::command]
 fn clear_scan_cache(path: String) -> Result<(), String> {
     ScanCache::clear(&path).map_err(|e| e.to_string())
 }
 
+/// Files added, modified and deleted under `path` since its scan cache was
+/// last saved, judged from mtimes and sizes alone — a cheap "what's dirty"
+/// check for a status indicator; nothing is parsed and the cache isn't
+/// touched. `respect_gitignore` and `include_hidden` should match the
+/// scan's (default true and false).
+// `(async)`: walks the whole tree and stats every file.
+#[tauri::command(async)]
+fn get_changes_since_cache(
+    path: String,
+    respect_gitignore: Option<bool>,
+    include_hidden: Option<bool>,
+) -> Result<scanner::CacheChanges, String> {
+    scanner::changes_since_cache(
+        &path,
+        respect_gitignore.unwrap_or(true),
+        include_hidden.unwrap_or(false),
+    )
+    .map_err(|e| e.to_string())
+}
+
+/// Symbolic links and junctions under `path`, which the scan walks past
+/// without following; each is flagged when its target is outside the
+/// project or missing. `respect_gitignore` defaults to true.
+// `(async)`: walks the whole tree.
+#[tauri::command(async)]
+fn find_symlinks(
+    path: String,
+    respect_gitignore: Option<bool>,
+) -> Result<Vec<symlinks::SymlinkInfo>, String> {
+    symlinks::find_symlinks(&path, respect_gitignore.unwrap_or(true)).map_err(|e| e.to_string())
+}
+
+/// Drop the scan cache entries under `prefix` (a folder, relative to the
+/// project root) so the next incremental scan re-parses only that subtree.
+/// Returns the number of entries removed.
+#[tauri::command]
+fn clear_scan_cache_subtree(project_id: String, prefix: String) -> Result<usize, String> {
+    project::with_ref(&project_id, |state| {
+        // The scan writes its cache when it finishes; editing it underneath
+        // would be lost (or lose the scan's work).
+        if state.scan_state.is_some() {
+            return Err("A scan is in progress for this project".to_string());
+        }
+        let Some(mut scan_cache) = state.scan_cache_key.as_deref().and_then(ScanCache::load) else {
+            return Ok(0);
+        };
+        let dir = Path::new(&state.root_path).join(prefix.trim_matches(|c| c == '/' || c == '\\'));
+        // Trailing separator: `Assets/Char` must not take `Assets/Characters`.
+        let removed = scan_cache.invalidate_prefix(&format!("{}/", scanner::path_to_string(&dir)));
+        if removed > 0 {
+            scan_cache.save().map_err(|e| e.to_string())?;
+        }
+        Ok(removed)
+    })
+}
+
 // ============ Filesystem Watcher ============
 
+/// `config_watch` also follows `tidycraft.toml` (see `watcher::ConfigWatchMode`);
+/// omitted means off.
 #[tauri::command]
-fn start_watching(app: AppHandle, project_id: String) -> Result<(), String> {
-    let (root_path, respect_gitignore) =
-        project::with_ref(&project_id, |s| Ok((s.root_path.clone(), s.respect_gitignore)))?;
-    let w = watcher::start(app, project_id.clone(), root_path, respect_gitignore)?;
+fn start_watching(
+    app: AppHandle,
+    project_id: String,
+    config_watch: Option<watcher::ConfigWatchMode>,
+) -> Result<(), String> {
+    let (root_path, respect_gitignore, include_hidden) = project::with_ref(&project_id, |s| {
+        Ok((s.root_path.clone(), s.respect_gitignore, s.include_hidden))
+    })?;
+    let w = watcher::start(
+        app,
+        project_id.clone(),
+        root_path,
+        respect_gitignore,
+        include_hidden,
+        config_watch.unwrap_or_default(),
+    )?;
     project::with_mut(&project_id, |s| {
         s.watcher = Some(w);
         Ok(())
@@ -187,13 +496,19 @@ fn stop_watching(project_id: String) -> Result<(), String> {
     })
 }
 
+/// `sprite_grid` is an optional `[columns, rows]` hint: the thumbnail shows
+/// the sheet's first cell instead of the whole sheet.
 #[tauri::command]
-async fn get_thumbnail(path: String, size: u32) -> Result<String, String> {
+async fn get_thumbnail(
+    path: String,
+    size: u32,
+    sprite_grid: Option<(u32, u32)>,
+) -> Result<String, String> {
     // Decode + resize + PNG-encode is CPU-bound and synchronous; run it on the
     // blocking pool so fast gallery scrolling doesn't starve the async worker
     // threads every other IPC call shares.
     tokio::task::spawn_blocking(move || {
-        thumbnail::get_thumbnail_base64(&path, size).map_err(|e| e.to_string())
+        thumbnail::get_thumbnail_base64(&path, size, sprite_grid).map_err(|e| e.to_string())
     })
     .await
     .map_err(|e| format!("thumbnail task failed: {e}"))?
@@ -204,6 +519,14 @@ fn get_thumbnail_cache_size() -> u64 {
     thumbnail::get_cache_size()
 }
 
+/// Decode every cached thumbnail and delete the corrupt ones (torn by a
+/// crash mid-write), so they regenerate instead of rendering broken.
+// `(async)`: reads and decodes the whole cache directory.
+#[tauri::command(async)]
+fn verify_thumbnail_cache() -> Result<thumbnail::CacheRepair, String> {
+    thumbnail::verify_cache().map_err(|e| e.to_string())
+}
+
 /// Drop the entire on-disk thumbnail cache. Returns the number of bytes
 /// freed so the UI can show "Freed N MB" feedback.
 #[tauri::command]
@@ -307,6 +630,19 @@ fn html_escape(s: &str) -> String {
         .replace('\'', "&#39;")
 }
 
+/// Human-readable byte count for exported reports (`1.5 MB`).
+pub(crate) fn format_size(bytes: u64) -> String {
+    if bytes < 1024 {
+        format!("{} B", bytes)
+    } else if bytes < 1024 * 1024 {
+        format!("{:.1} KB", bytes as f64 / 1024.0)
+    } else if bytes < 1024 * 1024 * 1024 {
+        format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+    } else {
+        format!("{:.2} GB", bytes as f64 / (1024.0 * 1024.0 * 1024.0))
+    }
+}
+
 /// Main entry point for AI tagging. Loads thumbnails for the selected
 /// assets, gathers project context (theme/goal from tidycraft.toml +
 /// existing tags with up to 5 sample paths each), then dispatches to
@@ -410,7 +746,7 @@ async fn llm_suggest_tags(
                     // Thumbnail decode needs the real (absolute) path; the
                     // path we ship to the provider is project-relative so we
                     // never leak the user's drive / username / layout.
-                    let thumb = thumbnail::get_thumbnail_base64(&p, 256).ok();
+                    let thumb = thumbnail::get_thumbnail_base64(&p, 256, None).ok();
                     llm::AssetInput {
                         path: project_relative_path(&p, &root_for_thumbs),
                         filename,
@@ -787,6 +1123,16 @@ fn load_rule_config(root_path: &str) -> Result<RuleConfig, String> {
     }
 }
 
+/// `load_rule_config` plus the checks an analysis run makes before it
+/// starts, so a config that loads here won't fail on a bad `[ignore]` or
+/// `[vendor]` pattern later. Used by the watcher's config reload.
+fn load_validated_rule_config(root_path: &str) -> Result<RuleConfig, String> {
+    let config = load_rule_config(root_path)?;
+    build_ignore_set(&config)?;
+    config.vendor.matcher(root_path, None)?;
+    Ok(config)
+}
+
 /// Build a `GlobSet` from `[ignore].patterns`, or `None` when the list is
 /// empty. A malformed pattern surfaces as an `Err`; callers build this
 /// before taking the project lock so the error short-circuits early.
@@ -794,43 +1140,60 @@ fn build_ignore_set(config: &RuleConfig) -> Result<Option<globset::GlobSet>, Str
     if config.ignore.patterns.is_empty() {
         return Ok(None);
     }
-    let mut builder = globset::GlobSetBuilder::new();
-    for pattern in &config.ignore.patterns {
-        let glob = globset::Glob::new(pattern)
-            .map_err(|e| format!("Invalid ignore pattern '{}': {}", pattern, e))?;
-        builder.add(glob);
+    analyzer::rules::build_glob_set(&config.ignore.patterns, "ignore", false).map(Some)
+}
+
+/// Persist content hashes a pass added to `scan_cache`, unless a scan is in
+/// flight: it rewrites the cache when it finishes, so saving here would
+/// race it, and the hashes are only an optimization.
+fn save_hashes_if_idle(state: &project::ProjectState, scan_cache: Option<ScanCache>) {
+    if let Some(scan_cache) = scan_cache.filter(|c| c.hashes_added()) {
+        if state.scan_state.is_none() {
+            let _ = scan_cache.save();
+        }
     }
-    builder
-        .build()
-        .map(Some)
-        .map_err(|e| format!("Failed to build ignore set: {}", e))
 }
 
 /// The single source of truth for the analysis pipeline: apply the
-/// `[ignore].patterns` filter, then run every analyzer phase — per-asset
-/// rules plus the four cross-asset checks (duplicates, missing references,
-/// PBR set, DCC source). `analyze_assets` (UI) and both report exporters
-/// route through this so they always produce the same issue set for a given
-/// project + config.
+/// `[ignore].patterns` and `[vendor]` filters, then run every analyzer
+/// phase — per-asset rules plus the four cross-asset checks (duplicates,
+/// missing references, PBR set, DCC source). `analyze_assets` (UI) and both
+/// report exporters route through this so they always produce the same
+/// issue set for a given project + config. Only `analyze_assets` passes a
+/// `cancel` flag and a `tracker` for phase / hashing progress; a cancelled
+/// run is an `Err`, never a partial result. So is a run over an
+/// inventory-only scan: with no metadata decoded, every dimension, format
+/// and import-setting rule would pass vacuously.
 fn run_full_analysis(
-    scan_result: &ScanResult,
-    root_path: &str,
+    state: &project::ProjectState,
     config: &RuleConfig,
     ignore_set: Option<&globset::GlobSet>,
+    vendor: &VendorMatcher,
     package_index: &unity::PackageGuidIndex,
-) -> AnalysisResult {
+    cancel: Option<&AtomicBool>,
+    tracker: Option<&AnalysisTracker>,
+) -> Result<AnalysisResult, String> {
+    let scan_result = state.require_scan()?;
+    if scan_result.metadata_skipped {
+        return Err(
+            "The last scan was inventory-only (no file metadata). Run a full scan before analyzing."
+                .to_string(),
+        );
+    }
+    let root = Path::new(&state.root_path);
+    let is_excluded = |asset_path: &str| {
+        let path = Path::new(asset_path);
+        let rel = path.strip_prefix(root).unwrap_or(path);
+        ignore_set.is_some_and(|set| set.is_match(rel)) || vendor.is_vendored(asset_path)
+    };
     // Only clone the scan when there are patterns to apply; most projects
     // have none and analyze the cached scan reference in place.
-    let owned_filtered: Option<ScanResult> = ignore_set.map(|set| {
-        let root = Path::new(root_path);
+    let filtering = ignore_set.is_some() || vendor.is_active();
+    let owned_filtered: Option<ScanResult> = filtering.then(|| {
         let kept: Vec<scanner::AssetInfo> = scan_result
             .assets
             .iter()
-            .filter(|a| {
-                let path = Path::new(&a.path);
-                let rel = path.strip_prefix(root).unwrap_or(path);
-                !set.is_match(rel)
-            })
+            .filter(|a| !is_excluded(&a.path))
             .cloned()
             .collect();
         ScanResult {
@@ -841,21 +1204,49 @@ fn run_full_analysis(
             total_size: scan_result.total_size,
             type_counts: scan_result.type_counts.clone(),
             project_type: scan_result.project_type.clone(),
+            timings: None,
+            metadata_skipped: scan_result.metadata_skipped,
+            project_info: scan_result.project_info.clone(),
         }
     });
     let scan_to_analyze: &ScanResult = owned_filtered.as_ref().unwrap_or(scan_result);
 
-    let analyzer = Analyzer::with_config(config);
-    let mut result = analyzer.analyze(scan_to_analyze);
-    let duplicates = analyzer.find_duplicates(scan_to_analyze);
+    let analyzer = Analyzer::with_config(config, &scan_result.root_path);
+    let mut result = analyzer.analyze_with_progress(scan_to_analyze, tracker);
+    if let Some(tracker) = tracker {
+        tracker.set_phase(AnalysisPhase::Duplicates);
+    }
+    // Content hashes persist in the scan cache, so only files added or
+    // changed since the last duplicate pass get hashed again.
+    let mut scan_cache = state.scan_cache_key.as_deref().and_then(ScanCache::load);
+    let duplicates = analyzer
+        .find_duplicates(
+            scan_to_analyze,
+            &config.duplicate,
+            cancel,
+            tracker.map(|t| &t.hashing),
+            scan_cache.as_mut(),
+        )
+        .map_err(|e| e.to_string())?;
+    save_hashes_if_idle(state, scan_cache);
     result.merge(duplicates);
-    let missing = analyzer.find_missing_references(scan_to_analyze, package_index);
-    result.merge(missing);
+    if let Some(tracker) = tracker {
+        tracker.set_phase(AnalysisPhase::CrossAsset);
+    }
+    // Resolved against the whole scan, so references into vendored or
+    // ignored assets (an embedded package, say) still count as found;
+    // only the issues are filtered.
+    let missing = analyzer.find_missing_references(scan_result, package_index);
+    for issue in missing.issues {
+        if !is_excluded(&issue.asset_path) {
+            result.add_issue(issue);
+        }
+    }
     let pbr = analyzer.find_pbr_set_issues(scan_to_analyze, &config.pbr_set);
     result.merge(pbr);
     let dcc = analyzer.find_dcc_source_issues(scan_to_analyze, &config.dcc_source);
     result.merge(dcc);
-    result
+    Ok(result)
 }
 
 // `(async)` runs this on Tauri's thread pool instead of the main thread.
@@ -863,29 +1254,123 @@ fn run_full_analysis(
 // on the main thread it froze the whole UI (window drag/resize) for the
 // duration. The frontend contract is unchanged — `invoke` already awaits.
 #[tauri::command(async)]
-fn analyze_assets(project_id: String, config_toml: Option<String>) -> Result<AnalysisResult, String> {
+fn analyze_assets(
+    app: AppHandle,
+    project_id: String,
+    config_toml: Option<String>,
+) -> Result<AnalysisResult, String> {
+    let config = if let Some(toml_str) = config_toml {
+        RuleConfig::from_toml(&toml_str).map_err(|e| format!("Invalid config: {}", e))?
+    } else {
+        RuleConfig::default()
+    };
+    analyze_with_config(&app, &project_id, &config)
+}
+
+/// `analyze_assets`, but run on a blocking task while
+/// `analysis-progress-{project_id}` reports the phase and how far it got
+/// (assets checked, then bytes hashed) — the scan commands' progress
+/// pattern. `duplicate-progress-{project_id}` is still emitted alongside.
+#[tauri::command]
+async fn analyze_assets_async(
+    app: AppHandle,
+    project_id: String,
+    config_toml: Option<String>,
+) -> Result<AnalysisResult, String> {
     let config = if let Some(toml_str) = config_toml {
         RuleConfig::from_toml(&toml_str).map_err(|e| format!("Invalid config: {}", e))?
     } else {
         RuleConfig::default()
     };
 
+    let tracker = Arc::new(AnalysisTracker::default());
+    let stop = Arc::new(AtomicBool::new(false));
+    let progress_handle = spawn_analysis_progress_reporter(
+        app.clone(),
+        project_id.clone(),
+        tracker.clone(),
+        stop.clone(),
+    );
+
+    let join_result = tokio::task::spawn_blocking(move || {
+        analyze_with_tracker(&app, &project_id, &config, tracker)
+    })
+    .await;
+
+    // A failed run never reaches `Completed`; stop the reporter before
+    // propagating, as `run_project_scan` does.
+    stop.store(true, Ordering::SeqCst);
+    let _ = progress_handle.join();
+
+    join_result.map_err(|e| e.to_string())?
+}
+
+/// `analyze_assets` with the config already parsed; also the watcher's
+/// re-analysis on a `tidycraft.toml` change. Emits
+/// `duplicate-progress-{project_id}` while duplicate hashing reads files.
+fn analyze_with_config(
+    app: &AppHandle,
+    project_id: &str,
+    config: &RuleConfig,
+) -> Result<AnalysisResult, String> {
+    analyze_with_tracker(app, project_id, config, Arc::default())
+}
+
+/// `analyze_with_config` reporting into a caller-owned `tracker`, which is
+/// marked `Completed` once the result is stored.
+fn analyze_with_tracker(
+    app: &AppHandle,
+    project_id: &str,
+    config: &RuleConfig,
+    tracker: Arc<AnalysisTracker>,
+) -> Result<AnalysisResult, String> {
     // Build the ignore matcher up-front so a malformed pattern surfaces as
     // an error before we touch the per-project lock.
-    let ignore_set = build_ignore_set(&config)?;
+    let ignore_set = build_ignore_set(config)?;
     // Fetched before the lock below — see package_index_for.
-    let package_index = package_index_for(&project_id);
+    let package_index = package_index_for(project_id);
 
-    project::with_ref(&project_id, |state| {
+    // Registered before taking the project lock, so `cancel_analysis` can
+    // stop a run that is still waiting on it.
+    let analysis = project::begin_analysis(project_id);
+
+    let stop = Arc::new(AtomicBool::new(false));
+    let reporter = spawn_hash_progress_reporter(
+        app.clone(),
+        project_id.to_string(),
+        tracker.clone(),
+        stop.clone(),
+    );
+    let result = project::with_mut(project_id, |state| {
         let scan_result = state.require_scan()?;
-        Ok(run_full_analysis(
-            scan_result,
-            &state.root_path,
-            &config,
+        let vendor = config
+            .vendor
+            .matcher(&state.root_path, scan_result.project_type.as_ref())?;
+        let result = run_full_analysis(
+            state,
+            config,
             ignore_set.as_ref(),
+            &vendor,
             &package_index,
-        ))
-    })
+            Some(analysis.flag()),
+            Some(&tracker),
+        )?;
+        state.last_analysis = Some(result.clone());
+        tracker.set_phase(AnalysisPhase::Completed);
+        Ok(result)
+    });
+    stop.store(true, Ordering::SeqCst);
+    let _ = reporter.join();
+    result
+}
+
+/// Stop the project's in-flight `analyze_assets` or `get_duplicate_groups`,
+/// which then fails with "Analysis cancelled". Returns whether one was
+/// running. Currently only duplicate hashing — the long phase — polls the
+/// flag.
+#[tauri::command]
+fn cancel_analysis(project_id: String) -> bool {
+    project::cancel_analysis(&project_id)
 }
 
 /// Make sure `<project_root>/tidycraft.toml` exists, writing the commented
@@ -898,9 +1383,9 @@ fn ensure_project_config(project_id: String) -> Result<String, String> {
     project::with_ref(&project_id, |state| {
         let path = Path::new(&state.root_path).join("tidycraft.toml");
         if !path.exists() {
-            std::fs::write(
+            fs_atomic::write_atomic(
                 &path,
-                analyzer::rules::config_template::DEFAULT_CONFIG_TEMPLATE,
+                analyzer::rules::config_template::DEFAULT_CONFIG_TEMPLATE.as_bytes(),
             )
             .map_err(|e| format!("Failed to create tidycraft.toml: {}", e))?;
         }
@@ -908,6 +1393,37 @@ fn ensure_project_config(project_id: String) -> Result<String, String> {
     })
 }
 
+/// Add every setting the project's `tidycraft.toml` doesn't mention yet
+/// (new fields from an upgrade, sections the user deleted) at its default,
+/// with the template's comments. The user's own keys, comments and
+/// ordering are untouched, so an annotated, checked-in config survives.
+/// Returns the dotted paths added; the file is only rewritten when that
+/// list is non-empty. A missing file gets the full template.
+#[tauri::command]
+fn fill_project_config(project_id: String) -> Result<Vec<String>, String> {
+    project::with_ref(&project_id, |state| {
+        let path = Path::new(&state.root_path).join("tidycraft.toml");
+        if !path.exists() {
+            fs_atomic::write_atomic(
+                &path,
+                analyzer::rules::config_template::DEFAULT_CONFIG_TEMPLATE.as_bytes(),
+            )
+            .map_err(|e| format!("Failed to create tidycraft.toml: {}", e))?;
+            return Ok(Vec::new());
+        }
+        let existing = std::fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read tidycraft.toml: {}", e))?;
+        let (filled, added) = RuleConfig::fill_missing_toml(&existing)?;
+        if !added.is_empty() {
+            // Atomic: a crash mid-write must not truncate a hand-annotated
+            // config.
+            fs_atomic::write_atomic(&path, filled.as_bytes())
+                .map_err(|e| format!("Failed to write tidycraft.toml: {}", e))?;
+        }
+        Ok(added)
+    })
+}
+
 /// Read a project's `tidycraft.toml` from its registered root, if present.
 /// Returns `Ok(None)` when the file doesn't exist (a normal state — most
 /// projects use defaults), `Ok(Some(content))` on success, or `Err` for
@@ -925,6 +1441,36 @@ fn read_project_config(project_id: String) -> Result<Option<String>, String> {
     })
 }
 
+/// List every analyzer rule id with its name, category, default severity
+/// and whether Fix-it can repair it. Static — the settings UI calls this
+/// once to label rules instead of hard-coding them.
+#[tauri::command]
+fn get_rule_catalog() -> Vec<analyzer::rules::RuleDescription> {
+    analyzer::rule_catalog()
+}
+
+/// Settings in `config_toml` that differ from the built-in defaults, as
+/// dotted paths with both values — "you disabled the texture rule" at a
+/// glance when working out why something is or isn't flagged.
+#[tauri::command]
+fn diff_config(config_toml: String) -> Result<Vec<analyzer::rules::ConfigDifference>, String> {
+    let config =
+        RuleConfig::from_toml(&config_toml).map_err(|e| format!("Invalid config: {}", e))?;
+    Ok(config.diff_from_default())
+}
+
+/// `partial_toml` with every unspecified setting filled in from the
+/// defaults, as TOML — everything that will actually apply, where
+/// `diff_config` shows only what the user changed. Laid out and commented
+/// like the default template.
+#[tauri::command]
+fn resolve_config(partial_toml: String) -> Result<String, String> {
+    RuleConfig::from_toml(&partial_toml)
+        .map_err(|e| format!("Invalid config: {}", e))?
+        .to_toml()
+        .map_err(|e| format!("Failed to serialize config: {}", e))
+}
+
 // ============ Tag Suggestions ============
 
 #[tauri::command]
@@ -979,7 +1525,15 @@ fn suggest_tags(project_id: String) -> Result<Vec<TagGroup>, String> {
 // freeze the UI.
 #[tauri::command(async)]
 fn get_git_info(project_id: String, path: String) -> GitInfo {
-    let mut manager = GitManager::open(Path::new(&path));
+    // Pick up the pass the last scan started (waiting if it's still
+    // running) rather than running a second one — see spawn_git_prewarm.
+    let prewarm = project::with_mut(&project_id, |state| Ok(state.git_prewarm.take()))
+        .ok()
+        .flatten()
+        .filter(|p| p.root == path);
+    let mut manager = prewarm
+        .and_then(|p| p.rx.recv().ok())
+        .unwrap_or_else(|| GitManager::open(Path::new(&path)));
     let info = manager.get_info();
 
     let _ = project::with_mut(&project_id, |state| {
@@ -1025,56 +1579,317 @@ fn get_git_statuses(project_id: String) -> GitStatusMap {
     GitStatusMap { statuses }
 }
 
-// ============ Unity Commands ============
-
+/// Scanned assets bucketed by git status, for a "changes" panel. Only
+/// assets in the current scan appear — deleted files and untracked files
+/// the scan skipped (ignored, excluded extensions) aren't listed.
 #[derive(Serialize)]
-pub struct DependencyGraph {
-    pub nodes: Vec<DependencyNode>,
-    pub edges: Vec<DependencyEdge>,
+pub struct AssetsByGitStatus {
+    /// Paths per status, sorted. Clean assets aren't listed.
+    pub groups: HashMap<git::GitFileStatus, Vec<String>>,
+    pub counts: HashMap<git::GitFileStatus, usize>,
 }
 
-/// One node in a project's dependency graph. `id` is the engine-neutral graph
-/// identifier edges reference — a Unity GUID or a Godot `res://` path — while
-/// `path` is the absolute filesystem path the frontend uses to locate the asset.
-/// How firmly a graph node's identity resolves. From a disk scan this is a
-/// spectrum, not a boolean — the scan set undercounts what a project can
-/// legitimately reference (engine built-ins, package caches, gitignored
-/// files), so each variant asserts only what the evidence supports. Same
-/// doctrine as `missing_reference.rs`: "a miss is strong signal, not proof".
-#[derive(Serialize, Clone, Copy)]
-#[serde(rename_all = "snake_case")]
-pub enum DependencyNodeKind {
-    /// A scanned project asset — has a real `path`, clickable in the UI.
-    Asset,
-    /// Unity: a referenced GUID resolved through the `Library/PackageCache`
-    /// index — a package asset installed by the package manager. Known to
-    /// exist; simply not part of the project's own assets.
-    Package,
-    /// Unity: a referenced GUID with no scanned asset behind it and no
-    /// package-index hit. Ambiguous by construction — a package asset (when
-    /// no local `Library/` cache exists to resolve it), an ignore-excluded
-    /// file, and a genuinely broken reference are indistinguishable from a
-    /// disk scan. Rendered as a warning, never asserted broken.
-    Unresolved,
-    /// Godot: a `res://` target that exists on disk but sits outside the scan
-    /// set (gitignored / hidden directory). Not breakage.
-    Unscanned,
-    /// Godot: a `res://` target that does not exist on disk — confirmed broken.
-    Missing,
+// `(async)`: full-repo libgit2 status under the project lock, like
+// `get_git_statuses`.
+#[tauri::command(async)]
+fn get_assets_by_git_status(project_id: String) -> Result<AssetsByGitStatus, String> {
+    project::with_mut(&project_id, |state| {
+        state.require_scan()?;
+        let Some(manager) = state.git_manager.as_mut() else {
+            return Ok(group_assets_by_git_status(&[], &HashMap::new()));
+        };
+        // Same normalization as `get_git_statuses` so keys match asset paths.
+        let statuses: HashMap<String, git::GitFileStatus> = manager
+            .get_all_statuses()
+            .iter()
+            .map(|(path, status)| (scanner::path_to_string(path), status.clone()))
+            .collect();
+        let scan_result = state.require_scan()?;
+        Ok(group_assets_by_git_status(&scan_result.assets, &statuses))
+    })
+}
+
+fn group_assets_by_git_status(
+    assets: &[scanner::AssetInfo],
+    statuses: &HashMap<String, git::GitFileStatus>,
+) -> AssetsByGitStatus {
+    let mut groups: HashMap<git::GitFileStatus, Vec<String>> = HashMap::new();
+    for asset in assets {
+        match statuses.get(&asset.path) {
+            None | Some(git::GitFileStatus::Unchanged) => {}
+            Some(status) => groups
+                .entry(status.clone())
+                .or_default()
+                .push(asset.path.clone()),
+        }
+    }
+    for paths in groups.values_mut() {
+        paths.sort();
+    }
+    let counts = groups
+        .iter()
+        .map(|(status, paths)| (status.clone(), paths.len()))
+        .collect();
+    AssetsByGitStatus { groups, counts }
 }
 
+/// A file git reports as conflicted, with its marker blocks.
 #[derive(Serialize)]
-pub struct DependencyNode {
-    pub id: String,
+pub struct ConflictedAsset {
     pub path: String,
-    pub name: String,
-    pub file_type: String,
-    /// See `DependencyNodeKind`. Non-`asset` nodes carry an empty `path`
-    /// (nothing to locate) and are treated as BFS terminals by the frontend,
-    /// so a widely-shared unresolved GUID can't hub-connect its unrelated
-    /// referrers in the 2-hop view.
-    pub kind: DependencyNodeKind,
-    /// Secondary identity line for the tooltip — the package id for
+    pub region_count: usize,
+    /// Empty for conflicts without text markers (binary files,
+    /// modify/delete conflicts).
+    pub regions: Vec<git::ConflictRegion>,
+    /// `.prefab` / `.unity` / `.mat`: hand-merging serialized fileIDs
+    /// rarely yields a loadable asset, so the UI suggests taking one side
+    /// wholesale (`git checkout --ours/--theirs`) instead.
+    pub unity_yaml: bool,
+}
+
+const UNITY_WHOLESALE_MERGE_EXTS: &[&str] = &["prefab", "unity", "mat"];
+
+// `(async)`: full-repo status plus a read of every conflicted file.
+#[tauri::command(async)]
+fn find_conflicted_assets(project_id: String) -> Result<Vec<ConflictedAsset>, String> {
+    let paths = project::with_mut(&project_id, |state| {
+        let root = Path::new(&state.root_path);
+        let manager = state
+            .git_manager
+            .get_or_insert_with(|| GitManager::open(root));
+        Ok(manager.conflicted_paths())
+    })?;
+
+    // File reads happen outside the project lock.
+    Ok(paths
+        .iter()
+        .map(|path| {
+            let regions = std::fs::read_to_string(path)
+                .map(|content| git::find_conflict_regions(&content))
+                .unwrap_or_default();
+            let unity_yaml = path
+                .extension()
+                .and_then(|e| e.to_str())
+                .is_some_and(|ext| {
+                    UNITY_WHOLESALE_MERGE_EXTS.contains(&ext.to_ascii_lowercase().as_str())
+                });
+            ConflictedAsset {
+                path: scanner::path_to_string(path),
+                region_count: regions.len(),
+                regions,
+                unity_yaml,
+            }
+        })
+        .collect())
+}
+
+/// Assets at or above `threshold_bytes` (default: `[lfs] threshold_bytes`,
+/// 5 MB) that no `.gitattributes` LFS pattern covers, largest first, each
+/// with the pattern to add. A binary committed outside LFS stays in the
+/// history for good.
+#[tauri::command]
+fn find_untracked_large_assets(
+    project_id: String,
+    threshold_bytes: Option<u64>,
+) -> Result<Vec<git::LargeUntrackedAsset>, String> {
+    project::with_ref(&project_id, |state| {
+        let scan_result = state.require_scan()?;
+        let manager = GitManager::open(Path::new(&state.root_path));
+        if !manager.is_repo() {
+            return Err("Not a git repository".to_string());
+        }
+        let threshold = match threshold_bytes {
+            Some(threshold) => threshold,
+            None => load_rule_config(&state.root_path)?.lfs.threshold_bytes,
+        };
+        Ok(git::large_assets_outside_lfs(
+            &manager,
+            &scan_result.assets,
+            threshold,
+        ))
+    })
+}
+
+// ============ Asset Detail ============
+
+/// Everything the details panel shows for one asset, in one round-trip.
+#[derive(Serialize)]
+pub struct AssetDetail {
+    pub asset: scanner::AssetInfo,
+    /// Lowercased `GitFileStatus`, same spelling as `get_git_statuses`.
+    /// `None` when the file is clean or the project isn't a git repo.
+    pub git_status: Option<String>,
+    pub tags: Vec<tags::Tag>,
+    /// Per-asset rule findings only — the cross-asset checks (duplicates,
+    /// missing references, PBR sets, DCC sources) need the whole scan and
+    /// stay with `analyze_assets`.
+    pub issues: Vec<analyzer::Issue>,
+    pub unity_guid: Option<String>,
+    /// Distinct GUIDs this file references (Unity YAML assets only).
+    pub reference_count: Option<usize>,
+}
+
+// `(async)`: git status refresh + a Unity YAML parse under the project lock.
+#[tauri::command(async)]
+fn get_asset_detail(
+    project_id: String,
+    path: String,
+    config_toml: Option<String>,
+) -> Result<AssetDetail, String> {
+    let config = match config_toml {
+        Some(toml_str) => {
+            RuleConfig::from_toml(&toml_str).map_err(|e| format!("Invalid config: {}", e))?
+        }
+        None => RuleConfig::default(),
+    };
+    let ignore_set = build_ignore_set(&config)?;
+
+    project::with_mut(&project_id, |state| {
+        let scan_result = state.require_scan()?;
+        let asset = scan_result
+            .assets
+            .iter()
+            .find(|a| a.path == path)
+            .cloned()
+            .ok_or_else(|| format!("Asset not in scan: {}", path))?;
+        let vendor = config
+            .vendor
+            .matcher(&state.root_path, scan_result.project_type.as_ref())?;
+
+        let ignored = vendor.is_vendored(&asset.path)
+            || ignore_set.as_ref().is_some_and(|set| {
+                let p = Path::new(&asset.path);
+                set.is_match(p.strip_prefix(&state.root_path).unwrap_or(p))
+            });
+        let issues = if ignored {
+            Vec::new()
+        } else {
+            Analyzer::with_config(&config, &scan_result.root_path).analyze_asset(&asset)
+        };
+
+        let git_status = state.git_manager.as_mut().and_then(|manager| {
+            manager
+                .get_all_statuses()
+                .iter()
+                .find(|(p, _)| scanner::path_to_string(p) == asset.path)
+                .map(|(_, status)| format!("{:?}", status).to_lowercase())
+        });
+
+        let tags = state.ensure_tags().get_asset_tags(&asset.path);
+
+        let reference_count = unity::parse_unity_file(Path::new(&asset.path)).map(|info| {
+            info.references
+                .iter()
+                .filter(|r| !unity::is_null_guid(&r.guid))
+                .map(|r| r.guid.as_str())
+                .collect::<std::collections::HashSet<_>>()
+                .len()
+        });
+
+        Ok(AssetDetail {
+            unity_guid: asset.unity_guid.clone(),
+            asset,
+            git_status,
+            tags,
+            issues,
+            reference_count,
+        })
+    })
+}
+
+/// The per-asset rule issues for one file read straight from disk. A
+/// `.meta` beside it marks a Unity asset, so its import settings are read
+/// as a Unity scan would; extension overrides come from `config`.
+fn analyze_file(path: &Path, config: &RuleConfig) -> Result<Vec<analyzer::Issue>, String> {
+    if !path.is_file() {
+        return Err(format!("Not a file: {}", path.display()));
+    }
+    let project_type = scanner::unity_meta_path(path).map(|_| scanner::ProjectType::Unity);
+    let asset = scanner::parse_asset_file(
+        path,
+        &project_type,
+        &config.scan.normalized_overrides(),
+        None,
+        true,
+    )
+    .ok_or_else(|| format!("Not an asset (no extension): {}", path.display()))?;
+    // No scan, so no project root: only the file's name can give it a role.
+    let root = path
+        .parent()
+        .map(scanner::path_to_string)
+        .unwrap_or_default();
+    Ok(Analyzer::with_config(config, &root).analyze_asset(&asset))
+}
+
+/// Lint one file on demand — e.g. the one the watcher just reported —
+/// without a scan and without touching the cached one. Per-asset rules
+/// only: duplicates, missing references and the other cross-asset checks
+/// need the whole project.
+// `(async)`: decodes the file's metadata (a large PSD or FBX takes a while).
+#[tauri::command(async)]
+fn analyze_single_asset(
+    path: String,
+    config_toml: Option<String>,
+) -> Result<Vec<analyzer::Issue>, String> {
+    let config = match config_toml {
+        Some(toml_str) => {
+            RuleConfig::from_toml(&toml_str).map_err(|e| format!("Invalid config: {}", e))?
+        }
+        None => RuleConfig::default(),
+    };
+    analyze_file(Path::new(&path), &config)
+}
+
+// ============ Unity Commands ============
+
+#[derive(Serialize)]
+pub struct DependencyGraph {
+    pub nodes: Vec<DependencyNode>,
+    pub edges: Vec<DependencyEdge>,
+}
+
+/// One node in a project's dependency graph. `id` is the engine-neutral graph
+/// identifier edges reference — a Unity GUID or a Godot `res://` path — while
+/// `path` is the absolute filesystem path the frontend uses to locate the asset.
+/// How firmly a graph node's identity resolves. From a disk scan this is a
+/// spectrum, not a boolean — the scan set undercounts what a project can
+/// legitimately reference (engine built-ins, package caches, gitignored
+/// files), so each variant asserts only what the evidence supports. Same
+/// doctrine as `missing_reference.rs`: "a miss is strong signal, not proof".
+#[derive(Serialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum DependencyNodeKind {
+    /// A scanned project asset — has a real `path`, clickable in the UI.
+    Asset,
+    /// Unity: a referenced GUID resolved through the `Library/PackageCache`
+    /// index — a package asset installed by the package manager. Known to
+    /// exist; simply not part of the project's own assets.
+    Package,
+    /// Unity: a referenced GUID with no scanned asset behind it and no
+    /// package-index hit. Ambiguous by construction — a package asset (when
+    /// no local `Library/` cache exists to resolve it), an ignore-excluded
+    /// file, and a genuinely broken reference are indistinguishable from a
+    /// disk scan. Rendered as a warning, never asserted broken.
+    Unresolved,
+    /// Godot: a `res://` target that exists on disk but sits outside the scan
+    /// set (gitignored / hidden directory). Not breakage.
+    Unscanned,
+    /// Godot: a `res://` target that does not exist on disk — confirmed broken.
+    Missing,
+}
+
+#[derive(Serialize)]
+pub struct DependencyNode {
+    pub id: String,
+    pub path: String,
+    pub name: String,
+    pub file_type: String,
+    /// See `DependencyNodeKind`. Non-`asset` nodes carry an empty `path`
+    /// (nothing to locate) and are treated as BFS terminals by the frontend,
+    /// so a widely-shared unresolved GUID can't hub-connect its unrelated
+    /// referrers in the 2-hop view.
+    pub kind: DependencyNodeKind,
+    /// Secondary identity line for the tooltip — the package id for
     /// `package` nodes ("com.unity.render-pipelines.universal"). Absent
     /// elsewhere.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1103,38 +1918,106 @@ fn package_index_for(project_id: &str) -> std::sync::Arc<unity::PackageGuidIndex
     .unwrap_or_default()
 }
 
+/// This project's Unity reference index, refreshed against the current scan
+/// first: only files whose size or mtime moved since the last refresh are
+/// re-parsed, and the index is persisted when anything changed. Same lock
+/// discipline as `package_index_for` — grab it BEFORE your own `with_ref`.
+/// Non-Unity projects get an empty index that isn't cached.
+fn unity_reference_index_for(project_id: &str) -> Result<Arc<unity_index::ReferenceIndex>, String> {
+    unity_reference_index_tracked(project_id, None, None)?
+        .ok_or_else(|| "reference index refresh was interrupted".to_string())
+}
+
+/// `unity_reference_index_for` reporting parsed files into `progress` and
+/// stopping once `cancel` is set — then `Ok(None)`. What was parsed before
+/// the stop is still persisted and cached, so a retry resumes from there.
+fn unity_reference_index_tracked(
+    project_id: &str,
+    progress: Option<&unity_index::RefreshProgress>,
+    cancel: Option<&AtomicBool>,
+) -> Result<Option<Arc<unity_index::ReferenceIndex>>, String> {
+    project::with_mut(project_id, |state| {
+        let key = state
+            .scan_cache_key
+            .clone()
+            .unwrap_or_else(|| state.root_path.clone());
+        if !matches!(
+            state.require_scan()?.project_type,
+            Some(scanner::ProjectType::Unity)
+        ) {
+            return Ok(Some(Arc::new(unity_index::ReferenceIndex::new(&key))));
+        }
+        // Taken only past the checks above, so their early returns leave
+        // the cached index in place.
+        let cached = state
+            .unity_index
+            .take()
+            .filter(|index| index.project_key() == key);
+        let scan_result = state.require_scan()?;
+        let mut index = cached.unwrap_or_else(|| {
+            Arc::new(
+                unity_index::ReferenceIndex::load(&key)
+                    .unwrap_or_else(|| unity_index::ReferenceIndex::new(&key)),
+            )
+        });
+        let refreshed =
+            Arc::make_mut(&mut index).refresh_with_progress(&scan_result.assets, progress, cancel);
+        // A cancelled refresh may still have parsed files worth keeping.
+        if refreshed.map_or(true, |changed| changed > 0) {
+            if let Err(e) = index.save() {
+                eprintln!("[unity_index] failed to persist reference index: {}", e);
+            }
+        }
+        state.unity_index = Some(index.clone());
+        Ok(refreshed.map(|_| index))
+    })
+}
+
+#[derive(Serialize)]
+pub struct ReferenceIndexSummary {
+    /// Reference-source files (prefabs, scenes, materials, …) indexed.
+    pub files: usize,
+    /// Distinct referrer → target edges across those files.
+    pub edges: usize,
+}
+
+/// Build the Unity reference index for the current scan, or bring it up to
+/// date. Every reference command refreshes it on its own; calling this right
+/// after a scan moves the one full parse off the first query.
+// `(async)`: the first build parses every prefab / scene / material.
+#[tauri::command(async)]
+fn build_unity_reference_index(project_id: String) -> Result<ReferenceIndexSummary, String> {
+    project::with_ref(&project_id, |state| {
+        match state.require_scan()?.project_type {
+            Some(scanner::ProjectType::Unity) => Ok(()),
+            _ => Err("Not a Unity project".to_string()),
+        }
+    })?;
+    let index = unity_reference_index_for(&project_id)?;
+    Ok(ReferenceIndexSummary {
+        files: index.file_count(),
+        edges: index.edge_count(),
+    })
+}
+
 #[derive(Serialize)]
 pub struct DependencyEdge {
     pub from: String,
     pub to: String,
+    /// Structural when `from` is a variant of `to` or instances it; a file
+    /// that both instances and directly references a prefab gets the
+    /// structural kind — one edge per pair either way.
+    pub kind: unity::ReferenceKind,
 }
 
-/// Unity text files that carry GUID references to other assets. Both the
-/// dependency graph (`get_unity_dependencies`) and the unused-asset scan
-/// (`find_unused_assets`) walk this *same* set so their reference views never
-/// diverge (previously deps used prefab/unity/mat and unused added controller —
-/// so their results disagreed). Beyond prefab/scene/material/controller it adds:
-///   - `.asset` — ScriptableObjects + EditorBuildSettings (scene refs live here,
-///     so scenes were otherwise always flagged unused),
-///   - `.anim` — sprite-animation PPtr curves,
-///   - `.overridecontroller` — animator override controllers.
-/// `unity::parse_unity_file` recognizes each of these extensions.
-const UNITY_REFERENCEABLE_EXTS: &[&str] = &[
-    "prefab",
-    "unity",
-    "mat",
-    "controller",
-    "overridecontroller",
-    "asset",
-    "anim",
-];
-
-// `(async)`: re-reads + parses every prefab/scene/mat under the project lock —
-// off the main thread so a 10k-asset project doesn't freeze the window.
+// `(async)`: refreshing the reference index re-parses every changed
+// prefab/scene/mat — off the main thread so a 10k-asset project doesn't
+// freeze the window.
 #[tauri::command(async)]
 fn get_unity_dependencies(project_id: String) -> Result<DependencyGraph, String> {
     // Fetched before the lock below — see package_index_for.
     let package_index = package_index_for(&project_id);
+    let reference_index = unity_reference_index_for(&project_id)?;
     project::with_ref(&project_id, |state| {
         let scan_result = state.require_scan()?;
 
@@ -1174,46 +2057,40 @@ fn get_unity_dependencies(project_id: String) -> Result<DependencyGraph, String>
         // a warning with its edge intact, not an asserted breakage.
         let mut unresolved_guids: std::collections::HashSet<String> =
             std::collections::HashSet::new();
+        // The index already drops both exempt classes and merges each
+        // referrer → target pair into one edge with its structural kind.
         for asset in &scan_result.assets {
-            let ext = asset.extension.to_lowercase();
-            if UNITY_REFERENCEABLE_EXTS.contains(&ext.as_str()) {
-                if let Some(unity_info) = unity::parse_unity_file(Path::new(&asset.path)) {
-                    if let Some(ref from_guid) = asset.unity_guid {
-                        for reference in &unity_info.references {
-                            if unity::is_null_guid(&reference.guid)
-                                || unity::is_builtin_guid(&reference.guid)
-                            {
-                                continue;
-                            }
-                            if !guid_to_path.contains_key(&reference.guid)
-                                && unresolved_guids.insert(reference.guid.clone())
-                            {
-                                nodes.push(match package_index.get(&reference.guid) {
-                                    Some(pkg) => DependencyNode {
-                                        id: reference.guid.clone(),
-                                        path: String::new(),
-                                        name: pkg.file_name.clone(),
-                                        file_type: "package".to_string(),
-                                        kind: DependencyNodeKind::Package,
-                                        detail: Some(pkg.package.clone()),
-                                    },
-                                    None => DependencyNode {
-                                        id: reference.guid.clone(),
-                                        path: String::new(),
-                                        name: reference.guid.clone(),
-                                        file_type: "unresolved".to_string(),
-                                        kind: DependencyNodeKind::Unresolved,
-                                        detail: None,
-                                    },
-                                });
-                            }
-                            edges.push(DependencyEdge {
-                                from: from_guid.clone(),
-                                to: reference.guid.clone(),
-                            });
-                        }
-                    }
+            let Some(ref from_guid) = asset.unity_guid else {
+                continue;
+            };
+            for reference in reference_index.outbound(&asset.path) {
+                if !guid_to_path.contains_key(&reference.guid)
+                    && unresolved_guids.insert(reference.guid.clone())
+                {
+                    nodes.push(match package_index.get(&reference.guid) {
+                        Some(pkg) => DependencyNode {
+                            id: reference.guid.clone(),
+                            path: String::new(),
+                            name: pkg.file_name.clone(),
+                            file_type: "package".to_string(),
+                            kind: DependencyNodeKind::Package,
+                            detail: Some(pkg.package.clone()),
+                        },
+                        None => DependencyNode {
+                            id: reference.guid.clone(),
+                            path: String::new(),
+                            name: reference.guid.clone(),
+                            file_type: "unresolved".to_string(),
+                            kind: DependencyNodeKind::Unresolved,
+                            detail: None,
+                        },
+                    });
                 }
+                edges.push(DependencyEdge {
+                    from: from_guid.clone(),
+                    to: reference.guid.clone(),
+                    kind: reference.kind,
+                });
             }
         }
 
@@ -1221,66 +2098,754 @@ fn get_unity_dependencies(project_id: String) -> Result<DependencyGraph, String>
     })
 }
 
+/// Inbound / outbound reference tallies for one scanned Unity asset.
+#[derive(Serialize)]
+pub struct ReferenceCount {
+    pub path: String,
+    pub guid: String,
+    /// Distinct project assets referencing this one.
+    pub inbound_count: usize,
+    /// Distinct GUIDs this asset references, including package and
+    /// unresolved targets.
+    pub outbound_count: usize,
+}
+
+/// Per-asset reference counts over the same edges `get_unity_dependencies`
+/// draws (same file set, same null / built-in exemptions), counting each
+/// referrer → target pair once. Sorted by inbound count, highest first:
+/// the top is load-bearing shared content, the zero tail overlaps
+/// `find_unused_assets`.
+// `(async)`: same reference-index refresh as the dependency graph.
+#[tauri::command(async)]
+fn get_reference_counts(project_id: String) -> Result<Vec<ReferenceCount>, String> {
+    let reference_index = unity_reference_index_for(&project_id)?;
+    project::with_ref(&project_id, |state| {
+        let scan_result = state.require_scan()?;
+
+        if !matches!(scan_result.project_type, Some(scanner::ProjectType::Unity)) {
+            return Err("Not a Unity project".to_string());
+        }
+
+        let outbound: HashMap<&str, std::collections::HashSet<String>> = scan_result
+            .assets
+            .iter()
+            .filter(|asset| unity_index::is_reference_source(asset))
+            .filter_map(|asset| {
+                let guid = asset.unity_guid.as_deref()?;
+                let targets = reference_index
+                    .outbound(&asset.path)
+                    .iter()
+                    .map(|r| r.guid.clone())
+                    .collect();
+                Some((guid, targets))
+            })
+            .collect();
+
+        Ok(tally_reference_counts(&scan_result.assets, &outbound))
+    })
+}
+
+/// Build the sorted `ReferenceCount` list from each referrer's outbound
+/// GUID set (keyed by referrer GUID). Only scanned assets with a GUID are
+/// listed.
+fn tally_reference_counts(
+    assets: &[scanner::AssetInfo],
+    outbound: &HashMap<&str, std::collections::HashSet<String>>,
+) -> Vec<ReferenceCount> {
+    let mut inbound: HashMap<&str, usize> = HashMap::new();
+    for targets in outbound.values() {
+        for target in targets {
+            *inbound.entry(target.as_str()).or_insert(0) += 1;
+        }
+    }
+
+    let mut counts: Vec<ReferenceCount> = assets
+        .iter()
+        .filter_map(|asset| {
+            let guid = asset.unity_guid.as_deref()?;
+            Some(ReferenceCount {
+                path: asset.path.clone(),
+                guid: guid.to_string(),
+                inbound_count: inbound.get(guid).copied().unwrap_or(0),
+                outbound_count: outbound.get(guid).map_or(0, |t| t.len()),
+            })
+        })
+        .collect();
+    counts.sort_by(|a, b| {
+        b.inbound_count
+            .cmp(&a.inbound_count)
+            .then_with(|| a.path.cmp(&b.path))
+    });
+    counts
+}
+
+/// Every Unity asset with the number of project assets depending on it
+/// transitively — the blast radius of changing it. Sorted most critical
+/// first; `get_reference_counts` has the direct counts alone.
+// `(async)`: same reference-index refresh as the dependency graph, plus a
+// graph walk over every asset.
+#[tauri::command(async)]
+fn get_asset_criticality(
+    project_id: String,
+) -> Result<Vec<unity_criticality::AssetCriticality>, String> {
+    let reference_index = unity_reference_index_for(&project_id)?;
+    project::with_ref(&project_id, |state| {
+        let scan_result = state.require_scan()?;
+        if !matches!(scan_result.project_type, Some(scanner::ProjectType::Unity)) {
+            return Err("Not a Unity project".to_string());
+        }
+        Ok(unity_criticality::asset_criticality(
+            &scan_result.assets,
+            &reference_index,
+        ))
+    })
+}
+
+/// Every asset a Unity scene pulls in through GUID references — prefabs,
+/// their materials, those materials' textures and so on — with the scene
+/// itself and the combined size. What shipping that scene alone needs;
+/// the unused-asset check is the same walk from every scene at once.
+// `(async)`: same reference-index refresh as the dependency graph.
+#[tauri::command(async)]
+fn get_scene_asset_closure(
+    project_id: String,
+    scene_path: String,
+) -> Result<unity_closure::AssetClosure, String> {
+    let reference_index = unity_reference_index_for(&project_id)?;
+    project::with_ref(&project_id, |state| {
+        let scan_result = state.require_scan()?;
+        if !matches!(scan_result.project_type, Some(scanner::ProjectType::Unity)) {
+            return Err("Not a Unity project".to_string());
+        }
+        let is_scene = scan_result
+            .assets
+            .iter()
+            .any(|a| a.path == scene_path && a.extension.eq_ignore_ascii_case("unity"));
+        if !is_scene {
+            return Err(format!("Not a scanned Unity scene: {}", scene_path));
+        }
+        Ok(unity_closure::asset_closure(
+            &scan_result.assets,
+            &reference_index,
+            &scene_path,
+        ))
+    })
+}
+
+/// Vendored assets (`[vendor]` in tidycraft.toml) are never reported — they
+/// still count as reference sources, so what they use isn't flagged either.
+/// In Unity projects, assets C# scripts load by path or key count as used
+/// (see `scan_script_string_references`).
 // `(async)`: same heavy Unity/Godot re-parse under the lock as the dependency
 // graph — kept off the main thread.
 #[tauri::command(async)]
 fn find_unused_assets(project_id: String) -> Result<Vec<String>, String> {
+    // Empty (and free) for non-Unity projects.
+    let reference_index = unity_reference_index_for(&project_id)?;
+    project::with_ref(&project_id, |state| {
+        unused_asset_paths(state, &reference_index)
+    })
+}
+
+/// `find_unused_assets` on a blocking task, emitting
+/// `unused-scan-progress-{project_id}` (files parsed / files needing a
+/// parse) every 100ms while the reference index refreshes. Files the index
+/// already holds aren't re-parsed, so a warm index reports `0 / 0` and
+/// finishes at once. `cancel_unused_scan` stops it with "Unused-asset scan
+/// cancelled"; files parsed before that are kept for the next run.
+#[tauri::command]
+async fn find_unused_assets_async(
+    app: AppHandle,
+    project_id: String,
+) -> Result<Vec<String>, String> {
+    let progress = Arc::new(unity_index::RefreshProgress::default());
+    let stop = Arc::new(AtomicBool::new(false));
+    let reporter = spawn_unused_scan_progress_reporter(
+        app,
+        project_id.clone(),
+        progress.clone(),
+        stop.clone(),
+    );
+
+    let join_result = tokio::task::spawn_blocking(move || {
+        // Registered before the index refresh takes the project lock, so a
+        // cancel reaches a run still waiting on it.
+        let scan = project::begin_unused_scan(&project_id);
+        let cancelled = || "Unused-asset scan cancelled".to_string();
+        let reference_index =
+            unity_reference_index_tracked(&project_id, Some(&progress), Some(scan.flag()))?
+                .ok_or_else(cancelled)?;
+        let unused = project::with_ref(&project_id, |state| {
+            unused_asset_paths(state, &reference_index)
+        })?;
+        if scan.flag().load(Ordering::Relaxed) {
+            return Err(cancelled());
+        }
+        Ok(unused)
+    })
+    .await;
+
+    stop.store(true, Ordering::SeqCst);
+    let _ = reporter.join();
+    join_result.map_err(|e| e.to_string())?
+}
+
+/// Stop the project's in-flight `find_unused_assets_async`. Returns whether
+/// one was running.
+#[tauri::command]
+fn cancel_unused_scan(project_id: String) -> bool {
+    project::cancel_unused_scan(&project_id)
+}
+
+/// `find_unused_assets` grouped by asset type with counts and sizes, plus
+/// the total a cleanup would reclaim. Built from the same unused set, so
+/// the two never disagree.
+// `(async)`: same work as `find_unused_assets`.
+#[tauri::command(async)]
+fn get_unused_asset_report(project_id: String) -> Result<unused_report::UnusedAssetReport, String> {
+    let reference_index = unity_reference_index_for(&project_id)?;
+    project::with_ref(&project_id, |state| {
+        let unused = unused_asset_paths(state, &reference_index)?;
+        Ok(unused_report::build(&state.require_scan()?.assets, &unused))
+    })
+}
+
+/// The unused-asset set behind `find_unused_assets` and
+/// `get_unused_asset_report`.
+fn unused_asset_paths(
+    state: &project::ProjectState,
+    reference_index: &unity_index::ReferenceIndex,
+) -> Result<Vec<String>, String> {
+    let scan_result = state.require_scan()?;
+    let vendor = load_rule_config(&state.root_path)?
+        .vendor
+        .matcher(&state.root_path, scan_result.project_type.as_ref())?;
+
+    match scan_result.project_type {
+        // Godot uses res:// path refs, not GUIDs — dispatch to its own
+        // parser and return early.
+        Some(scanner::ProjectType::Godot) => {
+            let mut unused = godot::find_unused_godot_assets(&state.root_path, &scan_result.assets);
+            unused.retain(|path| !vendor.is_vendored(path));
+            return Ok(unused);
+        }
+        // Unity falls through to the GUID-based logic below.
+        Some(scanner::ProjectType::Unity) => {}
+        _ => return Err("Unused-asset detection supports Unity and Godot projects".to_string()),
+    }
+
+    let referenced_guids = reference_index.referenced_guids();
+    // Loaded by path / key from C# — never a GUID reference.
+    let script_loaded: std::collections::HashSet<String> =
+        unity_scripts::find_script_references(&state.root_path, &scan_result.assets)
+            .referenced_paths
+            .into_iter()
+            .collect();
+    let mut all_guids: HashMap<String, String> = HashMap::new();
+
+    for asset in &scan_result.assets {
+        // Scenes are graph roots (loaded via build settings / the editor /
+        // SceneManager.LoadScene by name), so having no incoming GUID
+        // reference doesn't make a scene unused — drop them as candidates.
+        // They're still parsed as reference *sources* below, so assets a
+        // scene references aren't falsely flagged.
+        if matches!(asset.asset_type, scanner::AssetType::Scene) || vendor.is_vendored(&asset.path)
+        {
+            continue;
+        }
+        if let Some(ref guid) = asset.unity_guid {
+            all_guids.insert(guid.clone(), asset.path.clone());
+        }
+    }
+
+    let unused: Vec<String> = all_guids
+        .iter()
+        .filter(|(guid, path)| {
+            !referenced_guids.contains(guid.as_str()) && !script_loaded.contains(*path)
+        })
+        .map(|(_guid, path)| path.clone())
+        .collect();
+
+    Ok(unused)
+}
+
+/// Shader → material tallies for a Unity project, built-in and package
+/// shaders included, plus the materials whose shader resolves nowhere.
+// `(async)`: reads every `.mat` file in the project.
+#[tauri::command(async)]
+fn get_shader_usage(project_id: String) -> Result<unity_shaders::ShaderUsage, String> {
+    // Fetched before the lock below — see package_index_for.
+    let package_index = package_index_for(&project_id);
     project::with_ref(&project_id, |state| {
         let scan_result = state.require_scan()?;
+        if !matches!(scan_result.project_type, Some(scanner::ProjectType::Unity)) {
+            return Err("Not a Unity project".to_string());
+        }
+        Ok(unity_shaders::shader_usage(
+            &scan_result.assets,
+            &package_index,
+        ))
+    })
+}
 
-        match scan_result.project_type {
-            // Godot uses res:// path refs, not GUIDs — dispatch to its own
-            // parser and return early.
-            Some(scanner::ProjectType::Godot) => {
-                return Ok(godot::find_unused_godot_assets(
-                    &state.root_path,
-                    &scan_result.assets,
-                ));
-            }
-            // Unity falls through to the GUID-based logic below.
-            Some(scanner::ProjectType::Unity) => {}
-            _ => {
-                return Err(
-                    "Unused-asset detection supports Unity and Godot projects".to_string(),
-                )
-            }
+/// Groups of Unity materials with the same shader, keywords and property
+/// values but saved as separate files — consolidation candidates the
+/// byte-level duplicate check can't see. Vendored materials are skipped.
+// `(async)`: reads every `.mat` file in the project.
+#[tauri::command(async)]
+fn find_duplicate_materials(
+    project_id: String,
+) -> Result<Vec<unity_materials::DuplicateMaterialGroup>, String> {
+    project::with_ref(&project_id, |state| {
+        let scan_result = state.require_scan()?;
+        if !matches!(scan_result.project_type, Some(scanner::ProjectType::Unity)) {
+            return Err("Not a Unity project".to_string());
+        }
+        let vendor = load_rule_config(&state.root_path)?
+            .vendor
+            .matcher(&state.root_path, scan_result.project_type.as_ref())?;
+        let own: Vec<scanner::AssetInfo> = scan_result
+            .assets
+            .iter()
+            .filter(|a| a.extension.eq_ignore_ascii_case("mat") && !vendor.is_vendored(&a.path))
+            .cloned()
+            .collect();
+        Ok(unity_materials::find_duplicate_materials(&own))
+    })
+}
+
+/// Unity materials with a texture slot whose GUID resolves to nothing in
+/// the project or its packages — slots that render empty. One warning per
+/// material naming the broken slots; vendored materials are skipped.
+// `(async)`: reads every `.mat` file in the project.
+#[tauri::command(async)]
+fn find_materials_with_missing_textures(project_id: String) -> Result<AnalysisResult, String> {
+    // Fetched before the lock below — see package_index_for.
+    let package_index = package_index_for(&project_id);
+    project::with_ref(&project_id, |state| {
+        let scan_result = state.require_scan()?;
+        if !matches!(scan_result.project_type, Some(scanner::ProjectType::Unity)) {
+            return Err("Not a Unity project".to_string());
         }
+        let vendor = load_rule_config(&state.root_path)?
+            .vendor
+            .matcher(&state.root_path, scan_result.project_type.as_ref())?;
+        Ok(
+            unity_material_textures::find_materials_with_missing_textures(
+                &scan_result.assets,
+                &package_index,
+                |path| vendor.is_vendored(path),
+            ),
+        )
+    })
+}
 
-        let mut referenced_guids: std::collections::HashSet<String> = std::collections::HashSet::new();
-        let mut all_guids: HashMap<String, String> = HashMap::new();
+/// Unity prefabs and scenes with components whose `m_Script` GUID resolves
+/// to no script in the project or its packages — behavior silently gone at
+/// runtime. One error per file with the unresolved GUIDs; vendored files
+/// are skipped.
+// `(async)`: reads every prefab and scene in the project.
+#[tauri::command(async)]
+fn find_missing_scripts(project_id: String) -> Result<AnalysisResult, String> {
+    // Fetched before the lock below — see package_index_for.
+    let package_index = package_index_for(&project_id);
+    project::with_ref(&project_id, |state| {
+        let scan_result = state.require_scan()?;
+        if !matches!(scan_result.project_type, Some(scanner::ProjectType::Unity)) {
+            return Err("Not a Unity project".to_string());
+        }
+        let vendor = load_rule_config(&state.root_path)?
+            .vendor
+            .matcher(&state.root_path, scan_result.project_type.as_ref())?;
+        Ok(unity_missing_scripts::find_missing_scripts(
+            &scan_result.assets,
+            &package_index,
+            |path| vendor.is_vendored(path),
+        ))
+    })
+}
 
-        for asset in &scan_result.assets {
-            // Scenes are graph roots (loaded via build settings / the editor /
-            // SceneManager.LoadScene by name), so having no incoming GUID
-            // reference doesn't make a scene unused — drop them as candidates.
-            // They're still parsed as reference *sources* below, so assets a
-            // scene references aren't falsely flagged.
-            if matches!(asset.asset_type, scanner::AssetType::Scene) {
-                continue;
-            }
-            if let Some(ref guid) = asset.unity_guid {
-                all_guids.insert(guid.clone(), asset.path.clone());
-            }
+/// Unity assets whose `.meta` importer implies a different type than the
+/// extension-based guess — a lightmap or mesh saved as `.asset`, a `.txt`
+/// imported as a TextAsset. Reported only; the cached scan keeps its
+/// classification.
+// `(async)`: reads the `.meta` of every asset in the project.
+#[tauri::command(async)]
+fn reconcile_asset_types(project_id: String) -> Result<Vec<unity_importers::TypeMismatch>, String> {
+    project::with_ref(&project_id, |state| {
+        let scan_result = state.require_scan()?;
+        if !matches!(scan_result.project_type, Some(scanner::ProjectType::Unity)) {
+            return Err("Not a Unity project".to_string());
         }
+        Ok(unity_importers::reconcile_asset_types(&scan_result.assets))
+    })
+}
 
-        for asset in &scan_result.assets {
-            let ext = asset.extension.to_lowercase();
-            if UNITY_REFERENCEABLE_EXTS.contains(&ext.as_str()) {
-                if let Some(unity_info) = unity::parse_unity_file(Path::new(&asset.path)) {
-                    for reference in &unity_info.references {
-                        referenced_guids.insert(reference.guid.clone());
-                    }
-                }
+/// Groups of small textures used together by one prefab's or scene's
+/// materials, with the atlas size each group would pack into — a draw-call
+/// optimization hint. `max_texture_size` (default 512) bounds "small".
+// `(async)`: walks the reference index for every prefab and scene.
+#[tauri::command(async)]
+fn suggest_atlas_candidates(
+    project_id: String,
+    max_texture_size: Option<u32>,
+) -> Result<Vec<unity_atlas::AtlasCandidate>, String> {
+    let reference_index = unity_reference_index_for(&project_id)?;
+    project::with_ref(&project_id, |state| {
+        let scan_result = state.require_scan()?;
+        if !matches!(scan_result.project_type, Some(scanner::ProjectType::Unity)) {
+            return Err("Not a Unity project".to_string());
+        }
+        Ok(unity_atlas::suggest_atlas_candidates(
+            &scan_result.assets,
+            &reference_index,
+            max_texture_size.unwrap_or(unity_atlas::DEFAULT_MAX_TEXTURE_SIZE),
+        ))
+    })
+}
+
+/// Grayscale mask textures (`_R`, `_M`, `_AO`, ...) sharing a folder and
+/// base name, with a suggested RGBA channel assignment and the memory
+/// packing them would save. Vendored textures are skipped.
+// `(async)`: decodes every candidate mask texture.
+#[tauri::command(async)]
+fn suggest_channel_packing(
+    project_id: String,
+) -> Result<Vec<channel_packing::ChannelPackingGroup>, String> {
+    project::with_ref(&project_id, |state| {
+        let scan_result = state.require_scan()?;
+        let vendor = load_rule_config(&state.root_path)?
+            .vendor
+            .matcher(&state.root_path, scan_result.project_type.as_ref())?;
+        Ok(channel_packing::suggest_channel_packing(
+            &scan_result.assets,
+            |path| vendor.is_vendored(path),
+        ))
+    })
+}
+
+/// Animation clips with curve bindings that don't resolve on the prefabs
+/// (and their models) that play them, plus clips with malformed paths.
+// `(async)`: reads every clip, controller and prefab in the project.
+#[tauri::command(async)]
+fn analyze_animation_bindings(
+    project_id: String,
+) -> Result<Vec<unity_animation::UnresolvedBindings>, String> {
+    project::with_ref(&project_id, |state| {
+        let scan_result = state.require_scan()?;
+        if !matches!(scan_result.project_type, Some(scanner::ProjectType::Unity)) {
+            return Err("Not a Unity project".to_string());
+        }
+        Ok(unity_animation::analyze_animation_bindings(
+            &scan_result.assets,
+        ))
+    })
+}
+
+/// C# scripts no scene, prefab or asset attaches and no other script names
+/// — likely dead code, reported as info. Editor scripts and vendored code
+/// are skipped.
+// `(async)`: reads every scene, prefab and asset and every `.cs` file.
+#[tauri::command(async)]
+fn find_unused_scripts(project_id: String) -> Result<AnalysisResult, String> {
+    project::with_ref(&project_id, |state| {
+        let scan_result = state.require_scan()?;
+        if !matches!(scan_result.project_type, Some(scanner::ProjectType::Unity)) {
+            return Err("Not a Unity project".to_string());
+        }
+        let vendor = load_rule_config(&state.root_path)?
+            .vendor
+            .matcher(&state.root_path, scan_result.project_type.as_ref())?;
+        Ok(unity_unused_scripts::find_unused_scripts(
+            &scan_result.assets,
+            |path| vendor.is_vendored(path),
+        ))
+    })
+}
+
+/// String-keyed loads in the project's C# scripts — `Resources.Load`,
+/// `AssetDatabase.LoadAssetAtPath`, Addressables keys, AssetBundle
+/// `LoadAsset` — resolved against the scan. `find_unused_assets` treats
+/// every resolved asset as used; dynamic keys are listed for review.
+// `(async)`: reads every `.cs` file in the project.
+#[tauri::command(async)]
+fn scan_script_string_references(
+    project_id: String,
+) -> Result<unity_scripts::ScriptReferences, String> {
+    project::with_ref(&project_id, |state| {
+        let scan_result = state.require_scan()?;
+        if !matches!(scan_result.project_type, Some(scanner::ProjectType::Unity)) {
+            return Err("Not a Unity project".to_string());
+        }
+        Ok(unity_scripts::find_script_references(
+            &state.root_path,
+            &scan_result.assets,
+        ))
+    })
+}
+
+/// Image / audio / glTF assets whose leading bytes are a different format
+/// than the extension claims (a JPEG renamed to `.png`). Reported as errors:
+/// engines pick the importer by extension, so these fail to import.
+// `(async)`: opens every checkable asset. The asset list is copied out so
+// the reads don't hold the project lock.
+#[tauri::command(async)]
+fn find_extension_mismatches(project_id: String) -> Result<AnalysisResult, String> {
+    let candidates: Vec<scanner::AssetInfo> = project::with_ref(&project_id, |state| {
+        Ok(state
+            .require_scan()?
+            .assets
+            .iter()
+            .filter(|asset| {
+                analyzer::rules::extension_mismatch::expected_format(&asset.extension).is_some()
+            })
+            .cloned()
+            .collect())
+    })?;
+    Ok(analyzer::rules::extension_mismatch::find_extension_mismatches(&candidates))
+}
+
+/// Unity assets with no `.meta` sidecar next to them — the next import
+/// would mint them a new GUID and break every reference. Reported as
+/// errors; a CI-style gate rather than an analysis phase.
+// `(async)`: stats a sidecar per asset under the project lock.
+#[tauri::command(async)]
+fn find_missing_meta(project_id: String) -> Result<AnalysisResult, String> {
+    project::with_ref(&project_id, |state| {
+        let scan_result = state.require_scan()?;
+        if !matches!(scan_result.project_type, Some(scanner::ProjectType::Unity)) {
+            return Err("Not a Unity project".to_string());
+        }
+        Ok(analyzer::rules::missing_meta::find_missing_meta(
+            &scan_result.assets,
+            &state.root_path,
+            &scan_result.project_type,
+        ))
+    })
+}
+
+/// Asset paths equal but for letter case, which collapse into one file on
+/// case-insensitive filesystems. One error per colliding group, members in
+/// `related_paths`.
+#[tauri::command]
+fn find_case_collisions(project_id: String) -> Result<AnalysisResult, String> {
+    project::with_ref(&project_id, |state| {
+        Ok(analyzer::rules::case_collision::find_case_collisions(
+            &state.require_scan()?.assets,
+            &state.root_path,
+        ))
+    })
+}
+
+/// File names owned by assets in more than one folder — ambiguous for
+/// `Resources.Load` and lookups by bare name. `same_extension` (default on)
+/// compares whole names; off, only stems. Vendored assets are skipped.
+#[tauri::command]
+fn find_name_collisions(
+    project_id: String,
+    same_extension: Option<bool>,
+) -> Result<Vec<name_collisions::NameCollision>, String> {
+    project::with_ref(&project_id, |state| {
+        let scan_result = state.require_scan()?;
+        let vendor = load_rule_config(&state.root_path)?
+            .vendor
+            .matcher(&state.root_path, scan_result.project_type.as_ref())?;
+        Ok(name_collisions::find_name_collisions(
+            &scan_result.assets,
+            same_extension.unwrap_or(true),
+            |path| vendor.is_vendored(path),
+        ))
+    })
+}
+
+/// Text assets (data, scripts, shaders) that match once line endings and
+/// BOM are normalized but differ byte-for-byte, so the duplicate check
+/// misses them. One info per group, members in `related_paths`.
+// `(async)`: reads every text asset up to the size cap.
+#[tauri::command(async)]
+fn find_text_duplicates(project_id: String) -> Result<AnalysisResult, String> {
+    project::with_ref(&project_id, |state| {
+        Ok(analyzer::rules::text_duplicate::find_text_duplicates(
+            &state.require_scan()?.assets,
+            &state.root_path,
+        ))
+    })
+}
+
+/// Text assets whose lines end more than one way (CRLF, LF, stray CR), one
+/// info each naming the dominant ending. Vendored files are skipped.
+// `(async)`: reads every text asset up to the size cap.
+#[tauri::command(async)]
+fn find_mixed_line_endings(project_id: String) -> Result<AnalysisResult, String> {
+    project::with_ref(&project_id, |state| {
+        let scan_result = state.require_scan()?;
+        let vendor = load_rule_config(&state.root_path)?
+            .vendor
+            .matcher(&state.root_path, scan_result.project_type.as_ref())?;
+        Ok(analyzer::rules::line_endings::find_mixed_line_endings(
+            &scan_result.assets,
+            |path| vendor.is_vendored(path),
+        ))
+    })
+}
+
+/// Rewrite `paths` with every line ending set to `ending` (default:
+/// `[line_endings] target`). Files already uniform are left alone; the rest
+/// are backed up and recorded as ONE undo batch, so a single undo restores
+/// their original bytes. Non-UTF-8 or unreadable files are reported in
+/// `errors`.
+// `(async)`: reads and rewrites every selected file under the project lock.
+#[tauri::command(async)]
+fn fix_mixed_line_endings(
+    project_id: String,
+    paths: Vec<String>,
+    ending: Option<analyzer::rules::line_endings::LineEnding>,
+) -> Result<BatchRenameResult, String> {
+    project::with_mut(&project_id, |state| {
+        let ending = match ending {
+            Some(ending) => ending,
+            None => load_rule_config(&state.root_path)?.line_endings.target,
+        };
+        let ts = unix_timestamp();
+        let mut file_ops: Vec<undo::FileOperation> = Vec::new();
+        let mut errors: Vec<String> = Vec::new();
+        for path in &paths {
+            match normalize_line_endings_in(&state.undo_manager, Path::new(path), ending) {
+                Ok(Some(backup)) => file_ops.push(undo::FileOperation {
+                    operation_type: undo::OperationType::Edit,
+                    original_path: path.clone(),
+                    new_path: Some(backup),
+                    timestamp: ts,
+                }),
+                Ok(None) => {}
+                Err(e) => errors.push(e),
             }
         }
 
-        let unused: Vec<String> = all_guids
-            .iter()
-            .filter(|(guid, _path)| !referenced_guids.contains(*guid))
-            .map(|(_guid, path)| path.clone())
-            .collect();
+        let success_count = file_ops.len();
+        if !file_ops.is_empty() {
+            state.undo_manager.record_batch(
+                format!("Normalize line endings: {} files", success_count),
+                file_ops,
+            );
+        }
+        Ok(BatchRenameResult {
+            success_count,
+            error_count: errors.len(),
+            errors,
+        })
+    })
+}
+
+/// Rewrite one file for `fix_mixed_line_endings`: `Some(backup path)` once
+/// rewritten, `None` when it already used `ending` throughout.
+fn normalize_line_endings_in(
+    undo_manager: &undo::UndoManager,
+    path: &Path,
+    ending: analyzer::rules::line_endings::LineEnding,
+) -> Result<Option<String>, String> {
+    let bytes =
+        std::fs::read(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    let text = std::str::from_utf8(&bytes)
+        .map_err(|_| format!("Not UTF-8 text, left as is: {}", path.display()))?;
+    let normalized = analyzer::rules::line_endings::normalize(text, ending);
+    if normalized.as_bytes() == bytes.as_slice() {
+        return Ok(None);
+    }
+    let backup = undo_manager.backup_before_edit(path)?;
+    if let Err(e) = fs_atomic::write_atomic(path, normalized.as_bytes()) {
+        let _ = std::fs::remove_file(&backup);
+        return Err(format!("Failed to rewrite {}: {}", path.display(), e));
+    }
+    Ok(Some(backup))
+}
+
+/// Check the scanned inventory against a policy manifest (allowed
+/// extensions, forbidden paths, required folders, per-extension placement;
+/// see `analyzer::rules::manifest`). Violations come back as errors, for
+/// CI-style gating.
+// `(async)`: globs every asset path under the project lock.
+#[tauri::command(async)]
+fn validate_against_manifest(
+    project_id: String,
+    manifest_toml: String,
+) -> Result<AnalysisResult, String> {
+    let manifest = analyzer::rules::manifest::AssetManifest::from_toml(&manifest_toml)?;
+    project::with_ref(&project_id, |state| {
+        analyzer::rules::manifest::validate_against_manifest(
+            &state.require_scan()?.assets,
+            &state.root_path,
+            &manifest,
+        )
+    })
+}
+
+/// One SHA-256 over every asset's relative path and content hash, for
+/// comparing checkouts or detecting change since a snapshot (see
+/// `fingerprint`). Reuses and refreshes the duplicate pass's cached hashes.
+// `(async)`: hashes every file not already in the scan cache.
+#[tauri::command(async)]
+fn compute_project_fingerprint(
+    project_id: String,
+) -> Result<fingerprint::ProjectFingerprint, String> {
+    project::with_ref(&project_id, |state| {
+        let scan_result = state.require_scan()?;
+        let algorithm = load_rule_config(&state.root_path)?.duplicate.algorithm;
+        let mut scan_cache = state.scan_cache_key.as_deref().and_then(ScanCache::load);
+        let result = fingerprint::compute(
+            &scan_result.assets,
+            &state.root_path,
+            algorithm,
+            scan_cache.as_mut(),
+        )?;
+        save_hashes_if_idle(state, scan_cache);
+        Ok(result)
+    })
+}
+
+/// Every set of identical files in the scan with all its members and the
+/// bytes a single copy would save, for picking which copy to keep. Same
+/// hashing as the duplicate check (`[duplicate]` settings, cached hashes),
+/// over the whole scan rather than the `[ignore]`-filtered set.
+/// `cancel_analysis` stops it, as it does an analysis.
+// `(async)`: hashes every same-size file not already in the scan cache.
+#[tauri::command(async)]
+fn get_duplicate_groups(
+    project_id: String,
+) -> Result<Vec<analyzer::rules::duplicate::DuplicateGroup>, String> {
+    // Registered before taking the project lock, as in
+    // `analyze_with_tracker`, so a run still waiting on it can be stopped.
+    let run = project::begin_analysis(&project_id);
+    project::with_ref(&project_id, |state| {
+        let scan_result = state.require_scan()?;
+        let config = load_rule_config(&state.root_path)?.duplicate;
+        let mut scan_cache = state.scan_cache_key.as_deref().and_then(ScanCache::load);
+        let groups = analyzer::rules::duplicate::duplicate_groups(
+            &scan_result.assets,
+            &config,
+            Some(run.flag()),
+            scan_cache.as_mut(),
+        )
+        .map_err(|e| e.to_string())?;
+        save_hashes_if_idle(state, scan_cache);
+        Ok(groups)
+    })
+}
 
-        Ok(unused)
+/// Group the project's models into `_LOD<n>` chains and report gaps, a
+/// missing LOD0, and heavy models with no LODs. The vertex threshold comes
+/// from `[model]` in tidycraft.toml.
+#[tauri::command]
+fn analyze_lod_chains(
+    project_id: String,
+) -> Result<Vec<analyzer::rules::lod_chain::LodChain>, String> {
+    project::with_ref(&project_id, |state| {
+        let scan_result = state.require_scan()?;
+        let config = load_rule_config(&state.root_path)?;
+        Ok(analyzer::rules::lod_chain::find_lod_chains(
+            &scan_result.assets,
+            &config.model,
+        ))
     })
 }
 
@@ -1376,6 +2941,27 @@ fn godot_asset_references(
     })
 }
 
+/// `ext_resource`s in scenes / resources that Godot would fail to load:
+/// missing `res://` or relative paths, and `uid://` references resolved
+/// through `.godot/uid_cache.bin` (unresolvable when the cache is absent).
+// `(async)`: reads every `.tscn` / `.tres` under the lock — off the main
+// thread (same shape as get_godot_dependencies).
+#[tauri::command(async)]
+fn find_broken_godot_references(
+    project_id: String,
+) -> Result<Vec<godot::BrokenGodotReference>, String> {
+    project::with_ref(&project_id, |state| {
+        let scan_result = state.require_scan()?;
+        if !matches!(scan_result.project_type, Some(scanner::ProjectType::Godot)) {
+            return Err("Not a Godot project".to_string());
+        }
+        Ok(godot::find_broken_godot_references(
+            Path::new(&state.root_path),
+            &scan_result.assets,
+        ))
+    })
+}
+
 // ============ Engine Info Commands ============
 //
 // Path-only commands (no project_id): they re-read small marker/config files
@@ -1392,6 +2978,13 @@ fn get_unity_file_info(path: String) -> Option<unity::UnityFileInfo> {
     unity::parse_unity_file(Path::new(&path))
 }
 
+/// GameObject tree of a `.unity` / `.prefab` file for the scene audit view.
+// `(async)`: same full-file read + line scan as get_unity_file_info.
+#[tauri::command(async)]
+fn get_unity_scene_hierarchy(path: String) -> Option<Vec<unity::SceneNode>> {
+    unity::parse_scene_hierarchy(Path::new(&path))
+}
+
 /// Unity engine card: editor version from `ProjectSettings/ProjectVersion.txt`.
 #[tauri::command(async)]
 fn get_unity_project_info(root_path: String) -> Option<unity::UnityProjectInfo> {
@@ -1415,18 +3008,22 @@ fn get_unreal_project_info(root_path: String) -> Option<unreal::UnrealProjectInf
 
 // ============ Statistics Commands ============
 
-#[derive(Serialize)]
+#[derive(Serialize, serde::Deserialize)]
 pub struct ProjectStats {
     pub total_assets: usize,
     pub total_size: u64,
     pub type_distribution: HashMap<String, usize>,
+    /// Bytes per type, keyed like `type_distribution`. Empty in stats
+    /// exported before it was added.
+    #[serde(default)]
+    pub type_sizes: HashMap<String, u64>,
     pub size_distribution: HashMap<String, usize>,
     pub extension_distribution: HashMap<String, usize>,
     pub largest_files: Vec<FileInfo>,
     pub directory_sizes: HashMap<String, u64>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, serde::Deserialize)]
 pub struct FileInfo {
     pub name: String,
     pub path: String,
@@ -1434,110 +3031,675 @@ pub struct FileInfo {
     pub asset_type: String,
 }
 
+/// Engine version, product and company name the last scan read from the
+/// engine's settings (Unity only). `None` when there were none to read.
+#[tauri::command]
+fn get_project_info(project_id: String) -> Result<Option<scanner::ProjectMeta>, String> {
+    project::with_ref(&project_id, |state| {
+        Ok(state.require_scan()?.project_info.clone())
+    })
+}
+
+/// Engine and pinned version of the open project, read fresh from the
+/// engine's project file (Unity `ProjectVersion.txt`, Unreal `.uproject`,
+/// Godot `project.godot`). `None` for generic projects.
+#[tauri::command]
+fn get_engine_version(project_id: String) -> Result<Option<scanner::EngineVersion>, String> {
+    project::with_ref(&project_id, |state| {
+        let scan_result = state.require_scan()?;
+        Ok(scan_result
+            .project_type
+            .as_ref()
+            .and_then(|t| scanner::read_engine_version(Path::new(&state.root_path), t)))
+    })
+}
+
 #[tauri::command]
 fn get_project_stats(project_id: String) -> Result<ProjectStats, String> {
+    project::with_ref(&project_id, |state| {
+        Ok(project_stats(state.require_scan()?))
+    })
+}
+
+/// Totals now against `baseline_json`, a previously exported
+/// `ProjectStats` or `ScanResult`: asset count, size, per-type counts and
+/// sizes, and the size buckets that grew — absolute and as a percentage.
+#[tauri::command]
+fn compare_stats_to_baseline(
+    project_id: String,
+    baseline_json: String,
+) -> Result<stats_delta::StatsDelta, String> {
+    let baseline = match serde_json::from_str(&baseline_json)
+        .map_err(|_| "Baseline is neither an exported scan nor project stats".to_string())?
+    {
+        stats_delta::Baseline::Scan(scan) => project_stats(&scan),
+        stats_delta::Baseline::Stats(stats) => stats,
+    };
+    project::with_ref(&project_id, |state| {
+        Ok(stats_delta::compare(
+            &baseline,
+            &project_stats(state.require_scan()?),
+        ))
+    })
+}
+
+fn project_stats(scan_result: &ScanResult) -> ProjectStats {
+    let mut type_distribution: HashMap<String, usize> = HashMap::new();
+    let mut type_sizes: HashMap<String, u64> = HashMap::new();
+    let mut size_distribution: HashMap<String, usize> = HashMap::new();
+    let mut extension_distribution: HashMap<String, usize> = HashMap::new();
+    let mut directory_sizes: HashMap<String, u64> = HashMap::new();
+    let mut all_files: Vec<FileInfo> = Vec::new();
+
+    for asset in &scan_result.assets {
+        let type_str = format!("{:?}", asset.asset_type).to_lowercase();
+        *type_distribution.entry(type_str.clone()).or_insert(0) += 1;
+        *type_sizes.entry(type_str.clone()).or_insert(0) += asset.size;
+
+        *extension_distribution
+            .entry(asset.extension.clone())
+            .or_insert(0) += 1;
+
+        let size_bucket = if asset.size < 1024 {
+            "< 1 KB"
+        } else if asset.size < 10 * 1024 {
+            "1-10 KB"
+        } else if asset.size < 100 * 1024 {
+            "10-100 KB"
+        } else if asset.size < 1024 * 1024 {
+            "100 KB - 1 MB"
+        } else if asset.size < 10 * 1024 * 1024 {
+            "1-10 MB"
+        } else {
+            "> 10 MB"
+        };
+        *size_distribution
+            .entry(size_bucket.to_string())
+            .or_insert(0) += 1;
+
+        if let Some(parent) = Path::new(&asset.path).parent() {
+            let dir_str = parent.to_string_lossy().to_string();
+            *directory_sizes.entry(dir_str).or_insert(0) += asset.size;
+        }
+
+        all_files.push(FileInfo {
+            name: asset.name.clone(),
+            path: asset.path.clone(),
+            size: asset.size,
+            asset_type: type_str,
+        });
+    }
+
+    all_files.sort_by(|a, b| b.size.cmp(&a.size));
+    let largest_files: Vec<FileInfo> = all_files.into_iter().take(10).collect();
+
+    ProjectStats {
+        total_assets: scan_result.total_count,
+        total_size: scan_result.total_size,
+        type_distribution,
+        type_sizes,
+        size_distribution,
+        extension_distribution,
+        largest_files,
+        directory_sizes,
+    }
+}
+
+#[derive(Serialize)]
+pub struct DirectorySize {
+    pub path: String,
+    pub size: u64,
+    pub file_count: usize,
+}
+
+/// Flatten every directory below the scan root into `out`. The root itself is
+/// skipped — it always tops the list and says nothing the totals don't.
+fn collect_directory_sizes(node: &scanner::DirectoryNode, out: &mut Vec<DirectorySize>) {
+    for child in &node.children {
+        out.push(DirectorySize {
+            path: child.path.clone(),
+            size: child.total_size,
+            file_count: child.file_count,
+        });
+        collect_directory_sizes(child, out);
+    }
+}
+
+/// Top `limit` directories by rolled-up size. Unlike
+/// `ProjectStats::directory_sizes` (files counted only against their
+/// immediate parent), each entry here includes every subfolder beneath it,
+/// straight from the directory tree's recursive totals.
+#[tauri::command]
+fn get_largest_directories(project_id: String, limit: usize) -> Result<Vec<DirectorySize>, String> {
     project::with_ref(&project_id, |state| {
         let scan_result = state.require_scan()?;
+        let mut dirs = Vec::new();
+        collect_directory_sizes(&scan_result.directory_tree, &mut dirs);
+        // Ties broken by path so the order is stable across calls.
+        dirs.sort_by(|a, b| b.size.cmp(&a.size).then_with(|| a.path.cmp(&b.path)));
+        dirs.truncate(limit);
+        Ok(dirs)
+    })
+}
+
+/// One immediate child of the directory `get_directory_breakdown` was
+/// asked about.
+#[derive(Debug, Serialize)]
+pub struct DirectoryChildSize {
+    pub name: String,
+    pub path: String,
+    pub is_directory: bool,
+    /// Rolled-up size for a subdirectory.
+    pub size: u64,
+    /// 1 for a file.
+    pub file_count: usize,
+    /// Share of the parent directory's total, 0–100.
+    pub percent: f64,
+}
 
-        let mut type_distribution: HashMap<String, usize> = HashMap::new();
-        let mut size_distribution: HashMap<String, usize> = HashMap::new();
-        let mut extension_distribution: HashMap<String, usize> = HashMap::new();
-        let mut directory_sizes: HashMap<String, u64> = HashMap::new();
-        let mut all_files: Vec<FileInfo> = Vec::new();
+/// `dir_path`'s files and subdirectories, largest first, or `None` when the
+/// tree has no such directory.
+fn directory_breakdown(
+    tree: &scanner::DirectoryNode,
+    assets: &[scanner::AssetInfo],
+    dir_path: &str,
+) -> Option<Vec<DirectoryChildSize>> {
+    fn find<'a>(
+        node: &'a scanner::DirectoryNode,
+        path: &str,
+    ) -> Option<&'a scanner::DirectoryNode> {
+        if node.path == path {
+            return Some(node);
+        }
+        node.children.iter().find_map(|child| find(child, path))
+    }
+    let node = find(tree, dir_path.trim_end_matches('/'))?;
+    let percent = |size: u64| match node.total_size {
+        0 => 0.0,
+        total => size as f64 * 100.0 / total as f64,
+    };
 
-        for asset in &scan_result.assets {
-            let type_str = format!("{:?}", asset.asset_type).to_lowercase();
-            *type_distribution.entry(type_str.clone()).or_insert(0) += 1;
-
-            *extension_distribution.entry(asset.extension.clone()).or_insert(0) += 1;
-
-            let size_bucket = if asset.size < 1024 {
-                "< 1 KB"
-            } else if asset.size < 10 * 1024 {
-                "1-10 KB"
-            } else if asset.size < 100 * 1024 {
-                "10-100 KB"
-            } else if asset.size < 1024 * 1024 {
-                "100 KB - 1 MB"
-            } else if asset.size < 10 * 1024 * 1024 {
-                "1-10 MB"
-            } else {
-                "> 10 MB"
+    let subdirectories = node.children.iter().map(|child| DirectoryChildSize {
+        name: child.name.clone(),
+        path: child.path.clone(),
+        is_directory: true,
+        size: child.total_size,
+        file_count: child.file_count,
+        percent: percent(child.total_size),
+    });
+    let files = assets
+        .iter()
+        .filter(|a| a.path.rsplit_once('/').map(|(parent, _)| parent) == Some(node.path.as_str()))
+        .map(|a| DirectoryChildSize {
+            name: a.name.clone(),
+            path: a.path.clone(),
+            is_directory: false,
+            size: a.size,
+            file_count: 1,
+            percent: percent(a.size),
+        });
+    let mut entries: Vec<DirectoryChildSize> = subdirectories.chain(files).collect();
+    entries.sort_by(|a, b| b.size.cmp(&a.size).then_with(|| a.path.cmp(&b.path)));
+    Some(entries)
+}
+
+/// One level of the size drill-down: what makes `dir_path` as big as it is.
+/// Subdirectory sizes are the tree's rolled-up totals; files come from the
+/// scan.
+#[tauri::command]
+fn get_directory_breakdown(
+    project_id: String,
+    dir_path: String,
+) -> Result<Vec<DirectoryChildSize>, String> {
+    project::with_ref(&project_id, |state| {
+        let scan_result = state.require_scan()?;
+        directory_breakdown(&scan_result.directory_tree, &scan_result.assets, &dir_path)
+            .ok_or_else(|| format!("Directory not in the scan: {}", dir_path))
+    })
+}
+
+/// Folder path → asset type → count.
+type FolderComposition =
+    std::collections::BTreeMap<String, std::collections::BTreeMap<String, usize>>;
+
+/// Asset counts by type per folder `depth` levels below `root` (1 = the
+/// top-level folders), everything beneath each folder included. Files
+/// shallower than `depth` belong to no such folder and are left out.
+fn folder_composition(
+    assets: &[scanner::AssetInfo],
+    root: &str,
+    depth: usize,
+) -> FolderComposition {
+    let root = root.trim_end_matches('/');
+    // With the separator, so `/p` doesn't claim the files of `/p2`.
+    let prefix = format!("{}/", root);
+    let mut folders = FolderComposition::new();
+    for asset in assets {
+        let Some(rel) = asset.path.strip_prefix(&prefix) else {
+            continue;
+        };
+        // Directory components only: the last one is the file name.
+        let components: Vec<&str> = rel.split('/').filter(|c| !c.is_empty()).collect();
+        if components.len() <= depth {
+            continue;
+        }
+        let folder = std::iter::once(root)
+            .chain(components[..depth].iter().copied())
+            .collect::<Vec<_>>()
+            .join("/");
+        let asset_type = format!("{:?}", asset.asset_type).to_lowercase();
+        *folders
+            .entry(folder)
+            .or_default()
+            .entry(asset_type)
+            .or_insert(0) += 1;
+    }
+    folders
+}
+
+/// What each folder at `depth` holds, as asset counts by type — "is
+/// `Assets/Art` really all textures and models?". Folder paths are
+/// absolute, like the directory tree's.
+#[tauri::command]
+fn get_folder_composition(project_id: String, depth: usize) -> Result<FolderComposition, String> {
+    project::with_ref(&project_id, |state| {
+        let scan_result = state.require_scan()?;
+        Ok(folder_composition(
+            &scan_result.assets,
+            &scan_result.root_path,
+            depth,
+        ))
+    })
+}
+
+/// One asset's path shape, as listed by `get_deepest_paths`.
+#[derive(Debug, Serialize)]
+pub struct PathDepth {
+    pub path: String,
+    /// Folders between the project root and the file.
+    pub depth: usize,
+    /// Characters in the absolute path — what Windows' 260-character
+    /// `MAX_PATH` limit counts.
+    pub length: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DeepestPaths {
+    /// Most deeply nested first.
+    pub deepest: Vec<PathDepth>,
+    /// Longest absolute path first.
+    pub longest: Vec<PathDepth>,
+}
+
+/// The `limit` most nested and the `limit` longest asset paths under
+/// `root`; ties broken by path.
+fn deepest_paths(assets: &[scanner::AssetInfo], root: &str, limit: usize) -> DeepestPaths {
+    // With the separator, so `/p` isn't taken as the root of `/p2/...`.
+    let prefix = format!("{}/", root.trim_end_matches('/'));
+    let shape = |asset: &scanner::AssetInfo| {
+        let rel = asset.path.strip_prefix(&prefix).unwrap_or(&asset.path);
+        PathDepth {
+            path: asset.path.clone(),
+            depth: rel.trim_start_matches('/').matches('/').count(),
+            length: asset.path.chars().count(),
+        }
+    };
+    let top = |key: fn(&PathDepth) -> usize| -> Vec<PathDepth> {
+        let mut shapes: Vec<PathDepth> = assets.iter().map(shape).collect();
+        shapes.sort_by(|a, b| key(b).cmp(&key(a)).then_with(|| a.path.cmp(&b.path)));
+        shapes.truncate(limit);
+        shapes
+    };
+    DeepestPaths {
+        deepest: top(|p| p.depth),
+        longest: top(|p| p.length),
+    }
+}
+
+/// The most deeply nested and the longest asset paths, `limit` (default
+/// 20) of each — structure to flatten before it breaks a Windows checkout.
+#[tauri::command]
+fn get_deepest_paths(project_id: String, limit: Option<usize>) -> Result<DeepestPaths, String> {
+    project::with_ref(&project_id, |state| {
+        let scan_result = state.require_scan()?;
+        Ok(deepest_paths(
+            &scan_result.assets,
+            &scan_result.root_path,
+            limit.unwrap_or(20),
+        ))
+    })
+}
+
+/// Coverage audit: scanned assets that no enabled type-specific rule in
+/// the project's `tidycraft.toml` applies to, grouped by extension —
+/// formats like `.shader` or custom data that analysis never looks at.
+#[tauri::command]
+fn get_unanalyzed_assets(project_id: String) -> Result<Vec<analyzer::UnanalyzedGroup>, String> {
+    project::with_ref(&project_id, |state| {
+        let scan_result = state.require_scan()?;
+        let config = load_rule_config(&state.root_path)?;
+        Ok(Analyzer::with_config(&config, &scan_result.root_path).unanalyzed(&scan_result.assets))
+    })
+}
+
+/// Per-asset rule pass / fail counts for the project's `tidycraft.toml`:
+/// per rule id an enabled rule can emit (`texture.pot`), how many assets
+/// its rule applies to, how many fail it and the pass rate. Ignored and
+/// vendored assets are left out, as in analysis.
+// `(async)`: runs every enabled rule over every asset.
+#[tauri::command(async)]
+fn get_rule_coverage(
+    project_id: String,
+) -> Result<HashMap<String, analyzer::RuleCoverage>, String> {
+    project::with_ref(&project_id, |state| {
+        let scan_result = state.require_scan()?;
+        let config = load_rule_config(&state.root_path)?;
+        let ignore_set = build_ignore_set(&config)?;
+        let vendor = config
+            .vendor
+            .matcher(&state.root_path, scan_result.project_type.as_ref())?;
+        let root = Path::new(&state.root_path);
+        let analyzer = Analyzer::with_config(&config, &scan_result.root_path);
+        Ok(analyzer.coverage(&scan_result.assets, |path| {
+            let rel = Path::new(path)
+                .strip_prefix(root)
+                .unwrap_or(Path::new(path));
+            ignore_set.as_ref().is_some_and(|set| set.is_match(rel)) || vendor.is_vendored(path)
+        }))
+    })
+}
+
+/// The latest analysis' issues counted per directory, each directory
+/// including everything beneath it — "which folders have the most
+/// problems". Errors until an analysis has completed in this session.
+#[tauri::command]
+fn get_issues_by_directory(project_id: String) -> Result<issue_rollup::DirectoryIssues, String> {
+    project::with_ref(&project_id, |state| {
+        let scan_result = state.require_scan()?;
+        let analysis = state
+            .last_analysis
+            .as_ref()
+            .ok_or_else(|| "No analysis result available. Run analysis first.".to_string())?;
+        Ok(issue_rollup::issues_by_directory(
+            &scan_result.directory_tree,
+            &analysis.issues,
+        ))
+    })
+}
+
+/// Approximate shipped size per asset type, using `[build_estimate]` from
+/// the project's `tidycraft.toml`. Compare with `ProjectStats::total_size`,
+/// which counts uncompressed sources.
+#[tauri::command]
+fn estimate_build_size(project_id: String) -> Result<build_estimate::BuildSizeEstimate, String> {
+    project::with_ref(&project_id, |state| {
+        let scan_result = state.require_scan()?;
+        let config = load_rule_config(&state.root_path)?;
+        Ok(build_estimate::estimate(
+            &scan_result.assets,
+            &config.build_estimate,
+        ))
+    })
+}
+
+/// Sample rates across the project's audio: count per rate and, when one
+/// rate covers most of it, the files at any other rate. Complements the
+/// per-file `allowed_sample_rates` check.
+#[tauri::command]
+fn check_sample_rate_consistency(
+    project_id: String,
+) -> Result<audio_consistency::SampleRateConsistency, String> {
+    project::with_ref(&project_id, |state| {
+        Ok(audio_consistency::check(&state.require_scan()?.assets))
+    })
+}
+
+/// Assets whose mtime is newer than `since_unix`, newest first — the
+/// "what changed since the last build" view. Reads the mtimes the scan
+/// recorded; re-scan first for an up-to-the-second answer.
+#[tauri::command]
+fn find_recently_modified(
+    project_id: String,
+    since_unix: u64,
+) -> Result<Vec<scanner::AssetInfo>, String> {
+    project::with_ref(&project_id, |state| {
+        let scan_result = state.require_scan()?;
+        Ok(modified_since(&scan_result.assets, since_unix))
+    })
+}
+
+fn modified_since(assets: &[scanner::AssetInfo], since_unix: u64) -> Vec<scanner::AssetInfo> {
+    let mut recent: Vec<scanner::AssetInfo> = assets
+        .iter()
+        .filter_map(|asset| {
+            // 0 = the scan couldn't read the mtime; ask the filesystem again
+            // rather than silently dropping the file.
+            let modified = match asset.modified {
+                0 => cache::get_modified_time(Path::new(&asset.path))?,
+                m => m,
             };
-            *size_distribution.entry(size_bucket.to_string()).or_insert(0) += 1;
+            (modified > since_unix).then(|| scanner::AssetInfo {
+                modified,
+                ..asset.clone()
+            })
+        })
+        .collect();
+    recent.sort_by(|a, b| {
+        b.modified
+            .cmp(&a.modified)
+            .then_with(|| a.path.cmp(&b.path))
+    });
+    recent
+}
 
-            if let Some(parent) = Path::new(&asset.path).parent() {
-                let dir_str = parent.to_string_lossy().to_string();
-                *directory_sizes.entry(dir_str).or_insert(0) += asset.size;
-            }
+// ============ Export Commands ============
 
-            all_files.push(FileInfo {
-                name: asset.name.clone(),
-                path: asset.path.clone(),
-                size: asset.size,
-                asset_type: type_str,
-            });
+#[tauri::command]
+fn export_to_json(project_id: String) -> Result<String, String> {
+    project::with_ref(&project_id, |state| {
+        let scan_result = state.require_scan()?;
+        serde_json::to_string_pretty(scan_result).map_err(|e| e.to_string())
+    })
+}
+
+/// The scanned folder structure as a `tree`-style text block for docs and
+/// bug reports. `max_depth` limits the levels below the root; folders
+/// smaller than `min_size` bytes are folded into one line per parent.
+#[tauri::command]
+fn export_tree_as_text(
+    project_id: String,
+    max_depth: Option<usize>,
+    show_sizes: bool,
+    min_size: Option<u64>,
+) -> Result<String, String> {
+    project::with_ref(&project_id, |state| {
+        let options = tree_text::TreeTextOptions {
+            max_depth,
+            show_sizes,
+            min_size: min_size.unwrap_or(0),
+        };
+        Ok(tree_text::render(
+            &state.require_scan()?.directory_tree,
+            &options,
+        ))
+    })
+}
+
+/// Gzip-compressed `export_to_json`, written straight to `path` (from the
+/// native save dialog) so tens of MB of JSON never cross the IPC bridge.
+/// Returns the compressed size in bytes.
+// `(async)`: serializing + compressing a 200k-asset scan takes seconds.
+#[tauri::command(async)]
+fn export_to_json_gzip(project_id: String, path: String) -> Result<u64, String> {
+    project::with_ref(&project_id, |state| {
+        write_json_gzip(Path::new(&path), state.require_scan()?)
+    })
+}
+
+/// Pretty-print `value` through a gzip encoder into `path`. The decompressed
+/// file is byte-identical to the matching uncompressed export.
+fn write_json_gzip<T: Serialize>(path: &Path, value: &T) -> Result<u64, String> {
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use std::io::{BufWriter, Write};
+
+    if path.as_os_str().is_empty() {
+        return Err("Empty destination path".to_string());
+    }
+    let file = std::fs::File::create(path).map_err(|e| e.to_string())?;
+    let mut encoder = GzEncoder::new(BufWriter::new(file), Compression::default());
+    serde_json::to_writer_pretty(&mut encoder, value).map_err(|e| e.to_string())?;
+    let mut writer = encoder.finish().map_err(|e| e.to_string())?;
+    writer.flush().map_err(|e| e.to_string())?;
+    std::fs::metadata(path)
+        .map(|m| m.len())
+        .map_err(|e| e.to_string())
+}
+
+const CSV_HEADER: &str = "Name,Path,Type,Extension,Size,Width,Height\n";
+
+/// One CSV line for `asset`, matching `CSV_HEADER`. Text fields are quoted
+/// with embedded quotes doubled.
+fn csv_row(asset: &scanner::AssetInfo) -> String {
+    let width = asset
+        .metadata
+        .as_ref()
+        .and_then(|m| m.width)
+        .map(|w| w.to_string())
+        .unwrap_or_default();
+    let height = asset
+        .metadata
+        .as_ref()
+        .and_then(|m| m.height)
+        .map(|h| h.to_string())
+        .unwrap_or_default();
+
+    format!(
+        "\"{}\",\"{}\",{:?},\"{}\",{},{},{}\n",
+        asset.name.replace('"', "\"\""),
+        asset.path.replace('"', "\"\""),
+        asset.asset_type,
+        asset.extension.replace('"', "\"\""),
+        asset.size,
+        width,
+        height
+    )
+}
+
+#[tauri::command]
+fn export_to_csv(project_id: String) -> Result<String, String> {
+    project::with_ref(&project_id, |state| {
+        let scan_result = state.require_scan()?;
+
+        let mut csv = String::from(CSV_HEADER);
+        for asset in &scan_result.assets {
+            csv.push_str(&csv_row(asset));
+        }
+
+        Ok(csv)
+    })
+}
+
+/// `export_to_csv` streamed straight to `path` (from the native save
+/// dialog): the CSV is never held whole in memory or sent over IPC.
+/// Returns the number of asset rows written.
+// `(async)`: formats and writes a row per asset — 200k on large projects.
+#[tauri::command(async)]
+fn export_to_csv_file(project_id: String, path: String) -> Result<u64, String> {
+    project::with_ref(&project_id, |state| {
+        write_csv(Path::new(&path), &state.require_scan()?.assets)
+    })
+}
+
+/// Rows formatted per batch in parallel, then written in order, so memory
+/// stays at one batch of text regardless of project size.
+fn write_csv(path: &Path, assets: &[scanner::AssetInfo]) -> Result<u64, String> {
+    use rayon::prelude::*;
+    use std::io::{BufWriter, Write};
+    const BATCH: usize = 4096;
+
+    if path.as_os_str().is_empty() {
+        return Err("Empty destination path".to_string());
+    }
+    let file = std::fs::File::create(path).map_err(|e| e.to_string())?;
+    let mut writer = BufWriter::new(file);
+    writer
+        .write_all(CSV_HEADER.as_bytes())
+        .map_err(|e| e.to_string())?;
+    for batch in assets.chunks(BATCH) {
+        let rows: Vec<String> = batch.par_iter().map(csv_row).collect();
+        for row in rows {
+            writer
+                .write_all(row.as_bytes())
+                .map_err(|e| e.to_string())?;
         }
+    }
+    writer.flush().map_err(|e| e.to_string())?;
+    Ok(assets.len() as u64)
+}
 
-        all_files.sort_by(|a, b| b.size.cmp(&a.size));
-        let largest_files: Vec<FileInfo> = all_files.into_iter().take(10).collect();
-
-        Ok(ProjectStats {
-            total_assets: scan_result.total_count,
-            total_size: scan_result.total_size,
-            type_distribution,
-            size_distribution,
-            extension_distribution,
-            largest_files,
-            directory_sizes,
-        })
+/// The scan's assets as JSON Lines at `path` (from the native save
+/// dialog): one compact `AssetInfo` object per line, for `jq` and
+/// line-oriented data tools. Returns the number of lines written.
+// `(async)`: serializes and writes a line per asset — 200k on large projects.
+#[tauri::command(async)]
+fn export_to_jsonl_file(project_id: String, path: String) -> Result<u64, String> {
+    project::with_ref(&project_id, |state| {
+        write_jsonl(Path::new(&path), &state.require_scan()?.assets)
     })
 }
 
-// ============ Export Commands ============
+/// Each asset serialized straight into the buffered file, so memory stays
+/// flat regardless of project size.
+fn write_jsonl(path: &Path, assets: &[scanner::AssetInfo]) -> Result<u64, String> {
+    use std::io::{BufWriter, Write};
 
-#[tauri::command]
-fn export_to_json(project_id: String) -> Result<String, String> {
-    project::with_ref(&project_id, |state| {
-        let scan_result = state.require_scan()?;
-        serde_json::to_string_pretty(scan_result).map_err(|e| e.to_string())
-    })
+    if path.as_os_str().is_empty() {
+        return Err("Empty destination path".to_string());
+    }
+    let file = std::fs::File::create(path).map_err(|e| e.to_string())?;
+    let mut writer = BufWriter::new(file);
+    for asset in assets {
+        serde_json::to_writer(&mut writer, asset).map_err(|e| e.to_string())?;
+        writer.write_all(b"\n").map_err(|e| e.to_string())?;
+    }
+    writer.flush().map_err(|e| e.to_string())?;
+    Ok(assets.len() as u64)
+}
+
+#[derive(Serialize)]
+pub struct FilteredCsvExport {
+    pub csv: String,
+    /// Requested paths that aren't in the current scan (deleted or moved
+    /// since the frontend's list was built).
+    pub skipped: usize,
 }
 
+/// CSV export of just `paths` (the frontend's current filtered view), in the
+/// order given. Same columns and escaping as `export_to_csv`.
 #[tauri::command]
-fn export_to_csv(project_id: String) -> Result<String, String> {
+fn export_filtered_to_csv(
+    project_id: String,
+    paths: Vec<String>,
+) -> Result<FilteredCsvExport, String> {
     project::with_ref(&project_id, |state| {
         let scan_result = state.require_scan()?;
+        let by_path: HashMap<&str, &scanner::AssetInfo> = scan_result
+            .assets
+            .iter()
+            .map(|a| (a.path.as_str(), a))
+            .collect();
 
-        let mut csv = String::from("Name,Path,Type,Extension,Size,Width,Height\n");
-
-        for asset in &scan_result.assets {
-            let width = asset
-                .metadata
-                .as_ref()
-                .and_then(|m| m.width)
-                .map(|w| w.to_string())
-                .unwrap_or_default();
-            let height = asset
-                .metadata
-                .as_ref()
-                .and_then(|m| m.height)
-                .map(|h| h.to_string())
-                .unwrap_or_default();
-
-            csv.push_str(&format!(
-                "\"{}\",\"{}\",{:?},\"{}\",{},{},{}\n",
-                asset.name.replace('"', "\"\""),
-                asset.path.replace('"', "\"\""),
-                asset.asset_type,
-                asset.extension.replace('"', "\"\""),
-                asset.size,
-                width,
-                height
-            ));
+        let mut csv = String::from(CSV_HEADER);
+        let mut skipped = 0;
+        for path in &paths {
+            match by_path.get(path.as_str()) {
+                Some(asset) => csv.push_str(&csv_row(asset)),
+                None => skipped += 1,
+            }
         }
 
-        Ok(csv)
+        Ok(FilteredCsvExport { csv, skipped })
     })
 }
 
@@ -1547,27 +3709,65 @@ fn export_issues_to_json(project_id: String) -> Result<String, String> {
     // Fetched before the lock below — see package_index_for.
     let package_index = package_index_for(&project_id);
     project::with_ref(&project_id, |state| {
-        let scan_result = state.require_scan()?;
+        let result = analyze_for_export(state, &package_index)?;
+        serde_json::to_string_pretty(&result).map_err(|e| e.to_string())
+    })
+}
 
-        // Mirror the UI's Run Analysis: honor the project's tidycraft.toml
-        // (rule thresholds + [ignore].patterns) and run every phase,
-        // including the PBR-set and DCC-source cross-asset checks. Without
-        // this the exported report would silently diverge from the Issues
-        // view under any custom config.
-        let config = load_rule_config(&state.root_path)?;
-        let ignore_set = build_ignore_set(&config)?;
-        let result = run_full_analysis(
-            scan_result,
-            &state.root_path,
-            &config,
-            ignore_set.as_ref(),
-            &package_index,
-        );
+/// Gzip-compressed `export_issues_to_json`, written to `path`. Returns the
+/// compressed size in bytes.
+// `(async)`: runs a full analysis (incl. duplicate re-hashing) under the lock.
+#[tauri::command(async)]
+fn export_issues_to_json_gzip(project_id: String, path: String) -> Result<u64, String> {
+    // Fetched before the lock below — see package_index_for.
+    let package_index = package_index_for(&project_id);
+    project::with_ref(&project_id, |state| {
+        let result = analyze_for_export(state, &package_index)?;
+        write_json_gzip(Path::new(&path), &result)
+    })
+}
 
-        serde_json::to_string_pretty(&result).map_err(|e| e.to_string())
+/// `export_issues_to_json` as a SARIF 2.1.0 log (see `sarif`), for
+/// code-scanning UIs to annotate pull requests. Paths are relative to the
+/// project root.
+// `(async)`: runs a full analysis (incl. duplicate re-hashing) under the lock.
+#[tauri::command(async)]
+fn export_issues_to_sarif(project_id: String) -> Result<String, String> {
+    // Fetched before the lock below — see package_index_for.
+    let package_index = package_index_for(&project_id);
+    project::with_ref(&project_id, |state| {
+        let result = analyze_for_export(state, &package_index)?;
+        let log = sarif::build(&result, &analyzer::rule_catalog(), &state.root_path);
+        serde_json::to_string_pretty(&log).map_err(|e| e.to_string())
     })
 }
 
+/// Mirror the UI's Run Analysis: honor the project's tidycraft.toml
+/// (rule thresholds + [ignore].patterns) and run every phase, including
+/// the PBR-set and DCC-source cross-asset checks. Without this the exported
+/// report would silently diverge from the Issues view under any custom
+/// config.
+fn analyze_for_export(
+    state: &project::ProjectState,
+    package_index: &unity::PackageGuidIndex,
+) -> Result<AnalysisResult, String> {
+    let scan_result = state.require_scan()?;
+    let config = load_rule_config(&state.root_path)?;
+    let ignore_set = build_ignore_set(&config)?;
+    let vendor = config
+        .vendor
+        .matcher(&state.root_path, scan_result.project_type.as_ref())?;
+    run_full_analysis(
+        state,
+        &config,
+        ignore_set.as_ref(),
+        &vendor,
+        package_index,
+        None,
+        None,
+    )
+}
+
 /// `issue_limit` / `asset_limit` cap the report's table rows (Settings →
 /// Export). `None` keeps the historical defaults (100 / 500); `Some(0)`
 /// means unlimited — a 100k-file project then produces a very large file,
@@ -1599,13 +3799,18 @@ fn export_to_html(
         // [ignore].patterns scope analysis, not the project's file census.
         let config = load_rule_config(&state.root_path)?;
         let ignore_set = build_ignore_set(&config)?;
+        let vendor = config
+            .vendor
+            .matcher(&state.root_path, scan_result.project_type.as_ref())?;
         let analysis_result = run_full_analysis(
-            scan_result,
-            &state.root_path,
+            state,
             &config,
             ignore_set.as_ref(),
+            &vendor,
             &package_index,
-        );
+            None,
+            None,
+        )?;
 
         let mut type_counts: HashMap<String, usize> = HashMap::new();
         let mut size_by_type: HashMap<String, u64> = HashMap::new();
@@ -1616,18 +3821,6 @@ fn export_to_html(
             *size_by_type.entry(type_str).or_insert(0) += asset.size;
         }
 
-        fn format_size(bytes: u64) -> String {
-            if bytes < 1024 {
-                format!("{} B", bytes)
-            } else if bytes < 1024 * 1024 {
-                format!("{:.1} KB", bytes as f64 / 1024.0)
-            } else if bytes < 1024 * 1024 * 1024 {
-                format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
-            } else {
-                format!("{:.2} GB", bytes as f64 / (1024.0 * 1024.0 * 1024.0))
-            }
-        }
-
         // "Passed" = assets with zero issues. `issue_count` counts ISSUES, not
         // assets, and one asset can raise several — so `total - issue_count`
         // under-counts and saturates to 0 on issue-heavy projects. Count the
@@ -2328,7 +4521,7 @@ fn open_in_editor(app: tauri::AppHandle, path: String, editor: String) -> Result
 
 const TEXTURE_EXTS: &[&str] = &[
     "png", "jpg", "jpeg", "tga", "bmp", "gif",
-    "dds", "hdr", "exr", "tif", "tiff", "webp", "psd",
+    "dds", "hdr", "exr", "tif", "tiff", "webp", "avif", "psd",
 ];
 
 /// Subdirs to scan below the model's own directory.
@@ -2421,6 +4614,38 @@ pub struct DeleteResult {
     pub errors: Vec<DeleteError>,
 }
 
+/// An asset `safe_delete_assets` left in place because something still
+/// references it.
+#[derive(Debug, Serialize)]
+pub struct BlockedDelete {
+    pub path: String,
+    /// Referrers that stay on disk, sorted. Referrers deleted in the same
+    /// request aren't listed — they don't block.
+    pub referenced_by: Vec<String>,
+}
+
+#[derive(Serialize)]
+pub struct SafeDeleteResult {
+    pub success_paths: Vec<String>,
+    pub blocked: Vec<BlockedDelete>,
+    pub errors: Vec<DeleteError>,
+}
+
+/// What deleting a set of assets together would do: `analyze_deletion_impact`.
+#[derive(Debug, Serialize)]
+pub struct DeletionImpact {
+    /// Requested assets nothing outside the set references, in request
+    /// order.
+    pub safe: Vec<String>,
+    /// Requested assets still referenced from outside the set, with those
+    /// referrers. `BlockedDelete` here means "would break", not "kept".
+    pub breaking: Vec<BlockedDelete>,
+    /// Every remaining asset that would lose a reference, sorted.
+    pub broken_referrers: Vec<String>,
+    /// Referrer → deleted-target pairs that would dangle.
+    pub broken_reference_count: usize,
+}
+
 // ============ Move / Copy / Duplicate ============
 
 #[derive(Serialize)]
@@ -2448,75 +4673,20 @@ fn unix_timestamp() -> u64 {
         .unwrap_or(0)
 }
 
-/// Move each path into `target_dir`. Per-file rename; target must not already
-/// exist at the destination. Successful moves are batched into the project's
-/// undo manager so the user can revert.
+/// Move each path (and its Unity `.meta`) into `target_dir`, creating the
+/// folder if needed. All or nothing: every destination is checked first
+/// and any collision — an existing file or sidecar there, or two sources
+/// with the same name — is reported with nothing moved; a move failing
+/// midway moves the earlier ones back. What moved is recorded as one undo
+/// batch.
 #[tauri::command]
-fn move_assets(
-    project_id: String,
-    paths: Vec<String>,
-    target_dir: String,
-) -> FileOpResult {
-    let mut successes: Vec<FileOpSuccess> = Vec::new();
-    let mut errors: Vec<FileOpError> = Vec::new();
-
-    let target = Path::new(&target_dir);
-    if !target.is_dir() {
-        errors.push(FileOpError {
-            path: target_dir.clone(),
-            message: "Target is not a directory".to_string(),
-        });
-        return FileOpResult { successes, errors };
-    }
-
-    for path in paths {
-        let src = Path::new(&path);
-        let name = match src.file_name() {
-            Some(n) => n.to_os_string(),
-            None => {
-                errors.push(FileOpError {
-                    path: path.clone(),
-                    message: "Invalid source path".to_string(),
-                });
-                continue;
-            }
-        };
-        let dst = target.join(&name);
-
-        if src == dst {
-            // No-op: source already in target directory. Skip silently.
-            continue;
-        }
-        if dst.exists() {
-            errors.push(FileOpError {
-                path: path.clone(),
-                message: format!("Target already exists: {}", scanner::path_to_string(&dst)),
-            });
-            continue;
-        }
-
-        match std::fs::rename(src, &dst) {
-            Ok(_) => {
-                // Carry the Unity .meta sidecar so moved assets keep their
-                // GUID. Best-effort: no-op without a sidecar, logs on failure.
-                if let Err(e) = meta_sidecar::carry_on_rename(src, &dst) {
-                    eprintln!("[move_assets] .meta sidecar not carried for {}: {}", path, e);
-                }
-                successes.push(FileOpSuccess {
-                    original_path: path,
-                    new_path: scanner::path_to_string(&dst),
-                })
-            }
-            Err(e) => errors.push(FileOpError {
-                path,
-                message: e.to_string(),
-            }),
-        }
-    }
+fn move_assets(project_id: String, paths: Vec<String>, target_dir: String) -> FileOpResult {
+    let result = move_batch(&paths, Path::new(&target_dir));
 
-    if !successes.is_empty() {
+    if !result.successes.is_empty() {
         let ts = unix_timestamp();
-        let ops: Vec<undo::FileOperation> = successes
+        let ops: Vec<undo::FileOperation> = result
+            .successes
             .iter()
             .map(|s| undo::FileOperation {
                 operation_type: undo::OperationType::Move,
@@ -2536,7 +4706,7 @@ fn move_assets(
             // are swallowed — the move itself already succeeded.
             if state.tags_data.is_some() {
                 let tags = state.ensure_tags();
-                for s in &successes {
+                for s in &result.successes {
                     tags.rename_path(&s.original_path, &s.new_path);
                 }
                 let _ = state.save_tags();
@@ -2545,6 +4715,124 @@ fn move_assets(
         });
     }
 
+    result
+}
+
+/// The file half of `move_assets`.
+fn move_batch(paths: &[String], target: &Path) -> FileOpResult {
+    let mut successes: Vec<FileOpSuccess> = Vec::new();
+    let mut errors: Vec<FileOpError> = Vec::new();
+    let target_dir = scanner::path_to_string(target);
+
+    if target.exists() && !target.is_dir() {
+        errors.push(FileOpError {
+            path: target_dir,
+            message: "Target is not a directory".to_string(),
+        });
+        return FileOpResult { successes, errors };
+    }
+
+    // Pre-flight: every (source, destination) pair, or why it can't move.
+    let mut plan: Vec<(&Path, std::path::PathBuf)> = Vec::new();
+    let mut claimed: std::collections::HashSet<std::path::PathBuf> =
+        std::collections::HashSet::new();
+    for path in paths {
+        let src = Path::new(path);
+        let Some(name) = src.file_name() else {
+            errors.push(FileOpError {
+                path: path.clone(),
+                message: "Invalid source path".to_string(),
+            });
+            continue;
+        };
+        let dst = target.join(name);
+        if src == dst {
+            // No-op: source already in target directory. Skip silently.
+            continue;
+        }
+        let src_meta = meta_sidecar::sidecar_path(src);
+        let dst_meta = meta_sidecar::sidecar_path(&dst);
+        let collision = if !src.exists() {
+            Some("Source does not exist".to_string())
+        } else if dst.exists() {
+            Some(format!(
+                "Target already exists: {}",
+                scanner::path_to_string(&dst)
+            ))
+        } else if src_meta.exists() && dst_meta.exists() {
+            Some(format!(
+                "Target already exists: {}",
+                scanner::path_to_string(&dst_meta)
+            ))
+        } else if !claimed.insert(dst.clone()) {
+            Some("Another file in this move has the same name".to_string())
+        } else {
+            None
+        };
+        match collision {
+            Some(message) => errors.push(FileOpError {
+                path: path.clone(),
+                message,
+            }),
+            None => plan.push((src, dst)),
+        }
+    }
+    if !errors.is_empty() {
+        return FileOpResult { successes, errors };
+    }
+
+    // Folders this call creates, deepest first, so a rollback can remove
+    // them again. Only created once the plan is accepted.
+    let created: Vec<&Path> = target.ancestors().take_while(|dir| !dir.exists()).collect();
+    if let Err(e) = std::fs::create_dir_all(target) {
+        errors.push(FileOpError {
+            path: target_dir,
+            message: format!("Cannot create target directory: {}", e),
+        });
+        return FileOpResult { successes, errors };
+    }
+
+    for (index, (src, dst)) in plan.iter().enumerate() {
+        let Err(e) = meta_sidecar::rename_with_sidecar(src, dst) else {
+            continue;
+        };
+        let failed = scanner::path_to_string(src);
+        // Roll back what already moved, newest first. A file that can't go
+        // back stays moved and is reported as a success, so undo covers it.
+        for (done_src, done_dst) in plan[..index].iter().rev() {
+            let original_path = scanner::path_to_string(done_src);
+            match meta_sidecar::rename_with_sidecar(done_dst, done_src) {
+                Ok(_) => errors.push(FileOpError {
+                    path: original_path,
+                    message: format!("Rolled back: {} could not be moved", failed),
+                }),
+                Err(back) => {
+                    eprintln!(
+                        "[move_assets] rollback failed for {}: {}",
+                        original_path, back
+                    );
+                    successes.push(FileOpSuccess {
+                        original_path,
+                        new_path: scanner::path_to_string(done_dst),
+                    });
+                }
+            }
+        }
+        errors.push(FileOpError {
+            path: failed,
+            message: e,
+        });
+        // Fails, as it should, for a folder a failed rollback left a file in.
+        for dir in &created {
+            let _ = std::fs::remove_dir(dir);
+        }
+        return FileOpResult { successes, errors };
+    }
+
+    successes.extend(plan.iter().map(|(src, dst)| FileOpSuccess {
+        original_path: scanner::path_to_string(src),
+        new_path: scanner::path_to_string(dst),
+    }));
     FileOpResult { successes, errors }
 }
 
@@ -2681,38 +4969,222 @@ fn duplicate_assets(paths: Vec<String>) -> FileOpResult {
 // can submit thousands of paths at once (Kenney-scale groups), which would
 // freeze the window if run on the main thread.
 #[tauri::command(async)]
-fn delete_assets(paths: Vec<String>) -> DeleteResult {
-    let mut success_paths = Vec::new();
-    let mut errors = Vec::new();
+fn delete_assets(paths: Vec<String>) -> DeleteResult {
+    let mut success_paths = Vec::new();
+    let mut errors = Vec::new();
+
+    for path in paths {
+        match trash::delete(&path) {
+            Ok(_) => {
+                // Also trash the Unity .meta sidecar so deleting an asset
+                // doesn't strand its sidecar. Best-effort: no-op without a
+                // sidecar, logs on failure.
+                if let Err(e) = meta_sidecar::carry_on_delete(Path::new(&path)) {
+                    eprintln!("[delete_assets] .meta sidecar not carried for {}: {}", path, e);
+                }
+                success_paths.push(path);
+            }
+            Err(e) => errors.push(DeleteError {
+                path,
+                message: e.to_string(),
+            }),
+        }
+    }
+
+    DeleteResult {
+        success_paths,
+        errors,
+    }
+}
+
+/// Reference-checked `delete_assets`. In a Unity project every requested
+/// asset that is still referenced is left in place and reported with its
+/// referrers; the rest go to the trash and are recorded as one undo batch.
+/// A referrer that is itself in the request doesn't block, so a prefab and
+/// the material only it uses can be deleted together. Other project types
+/// have no reference data, so every path is trashed.
+// `(async)`: the reference check refreshes the Unity reference index, and
+// the trash calls can number in the thousands — same reasons as delete_assets.
+#[tauri::command(async)]
+fn safe_delete_assets(project_id: String, paths: Vec<String>) -> Result<SafeDeleteResult, String> {
+    let reference_index = unity_reference_index_for(&project_id)?;
+    let (deletable, blocked) = project::with_ref(&project_id, |state| {
+        let scan_result = state.require_scan()?;
+        if !matches!(scan_result.project_type, Some(scanner::ProjectType::Unity)) {
+            return Ok((paths.clone(), Vec::new()));
+        }
+        let referrers = unity_referrers_of(&scan_result.assets, &reference_index, &paths);
+        Ok(partition_safe_deletes(&paths, &referrers))
+    })?;
+
+    let DeleteResult {
+        success_paths,
+        errors,
+    } = delete_assets(deletable);
+
+    if !success_paths.is_empty() {
+        let ts = unix_timestamp();
+        let ops: Vec<undo::FileOperation> = success_paths
+            .iter()
+            .map(|path| undo::FileOperation {
+                operation_type: undo::OperationType::Delete,
+                original_path: path.clone(),
+                new_path: None,
+                timestamp: ts,
+            })
+            .collect();
+        let _ = project::with_mut(&project_id, |state| {
+            state
+                .undo_manager
+                .record_batch(format!("Delete {} file(s)", ops.len()), ops);
+            Ok(())
+        });
+    }
+
+    Ok(SafeDeleteResult {
+        success_paths,
+        blocked,
+        errors,
+    })
+}
+
+/// For each of `paths` that has a GUID, the indexed Unity files referencing
+/// it (self-references excluded). Unreferenced paths are absent.
+fn unity_referrers_of(
+    assets: &[scanner::AssetInfo],
+    index: &unity_index::ReferenceIndex,
+    paths: &[String],
+) -> HashMap<String, std::collections::BTreeSet<String>> {
+    let wanted: std::collections::HashSet<&str> = paths.iter().map(String::as_str).collect();
+    let inbound = index.inbound_paths();
+    assets
+        .iter()
+        .filter(|asset| wanted.contains(asset.path.as_str()))
+        .filter_map(|asset| {
+            let from = inbound.get(asset.unity_guid.as_deref()?)?;
+            let others: std::collections::BTreeSet<String> = from
+                .iter()
+                .filter(|path| **path != asset.path)
+                .map(|path| path.to_string())
+                .collect();
+            (!others.is_empty()).then(|| (asset.path.clone(), others))
+        })
+        .collect()
+}
+
+/// Split `paths` into those safe to delete and those blocked by a referrer
+/// that stays. Blocking cascades: a blocked referrer stays on disk, so what
+/// it references must stay too. Request order is kept; repeats collapse.
+fn partition_safe_deletes(
+    paths: &[String],
+    referrers: &HashMap<String, std::collections::BTreeSet<String>>,
+) -> (Vec<String>, Vec<BlockedDelete>) {
+    let mut deletable: std::collections::HashSet<&str> = paths.iter().map(String::as_str).collect();
+    loop {
+        let newly_blocked: Vec<&str> = deletable
+            .iter()
+            .copied()
+            .filter(|path| {
+                referrers
+                    .get(*path)
+                    .is_some_and(|refs| refs.iter().any(|r| !deletable.contains(r.as_str())))
+            })
+            .collect();
+        if newly_blocked.is_empty() {
+            break;
+        }
+        for path in newly_blocked {
+            deletable.remove(path);
+        }
+    }
+
+    let mut seen = std::collections::HashSet::new();
+    let mut safe = Vec::new();
+    let mut blocked = Vec::new();
+    for path in paths {
+        if !seen.insert(path.as_str()) {
+            continue;
+        }
+        if deletable.contains(path.as_str()) {
+            safe.push(path.clone());
+        } else {
+            let referenced_by = referrers[path]
+                .iter()
+                .filter(|r| !deletable.contains(r.as_str()))
+                .cloned()
+                .collect();
+            blocked.push(BlockedDelete {
+                path: path.clone(),
+                referenced_by,
+            });
+        }
+    }
+    (safe, blocked)
+}
+
+/// Pre-flight for a batch delete: which of `paths` go cleanly and which
+/// leave references dangling in assets that stay. Unlike `safe_delete_assets`
+/// nothing is kept back, so nothing cascades — a referrer counts as
+/// breaking only when it's outside the set. Unity only; uses the same
+/// reference index.
+// `(async)`: refreshes the Unity reference index.
+#[tauri::command(async)]
+fn analyze_deletion_impact(
+    project_id: String,
+    paths: Vec<String>,
+) -> Result<DeletionImpact, String> {
+    let reference_index = unity_reference_index_for(&project_id)?;
+    project::with_ref(&project_id, |state| {
+        let scan_result = state.require_scan()?;
+        if !matches!(scan_result.project_type, Some(scanner::ProjectType::Unity)) {
+            return Err("Not a Unity project".to_string());
+        }
+        let referrers = unity_referrers_of(&scan_result.assets, &reference_index, &paths);
+        Ok(deletion_impact(&paths, &referrers))
+    })
+}
 
+fn deletion_impact(
+    paths: &[String],
+    referrers: &HashMap<String, std::collections::BTreeSet<String>>,
+) -> DeletionImpact {
+    let deleted: std::collections::HashSet<&str> = paths.iter().map(String::as_str).collect();
+    let mut seen = std::collections::HashSet::new();
+    let mut impact = DeletionImpact {
+        safe: Vec::new(),
+        breaking: Vec::new(),
+        broken_referrers: Vec::new(),
+        broken_reference_count: 0,
+    };
+    let mut broken_referrers = std::collections::BTreeSet::new();
     for path in paths {
-        match trash::delete(&path) {
-            Ok(_) => {
-                // Also trash the Unity .meta sidecar so deleting an asset
-                // doesn't strand its sidecar. Best-effort: no-op without a
-                // sidecar, logs on failure.
-                if let Err(e) = meta_sidecar::carry_on_delete(Path::new(&path)) {
-                    eprintln!("[delete_assets] .meta sidecar not carried for {}: {}", path, e);
-                }
-                success_paths.push(path);
-            }
-            Err(e) => errors.push(DeleteError {
-                path,
-                message: e.to_string(),
-            }),
+        if !seen.insert(path.as_str()) {
+            continue;
         }
+        let outside: Vec<String> = referrers
+            .get(path)
+            .into_iter()
+            .flatten()
+            .filter(|r| !deleted.contains(r.as_str()))
+            .cloned()
+            .collect();
+        if outside.is_empty() {
+            impact.safe.push(path.clone());
+            continue;
+        }
+        impact.broken_reference_count += outside.len();
+        broken_referrers.extend(outside.iter().cloned());
+        impact.breaking.push(BlockedDelete {
+            path: path.clone(),
+            referenced_by: outside,
+        });
     }
-
-    DeleteResult {
-        success_paths,
-        errors,
-    }
+    impact.broken_referrers = broken_referrers.into_iter().collect();
+    impact
 }
 
 #[tauri::command]
 fn rename_file(project_id: String, old_path: String, new_name: String) -> Result<String, String> {
-    use std::time::{SystemTime, UNIX_EPOCH};
-
     validate_new_name(&new_name)?;
 
     let old_path_ref = Path::new(&old_path);
@@ -2751,35 +5223,89 @@ fn rename_file(project_id: String, old_path: String, new_name: String) -> Result
         eprintln!("[rename_file] .meta sidecar not carried for {}: {}", old_path, e);
     }
 
-    let _ = project::with_mut(&project_id, |state| {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_secs();
+    record_single_rename(
+        &project_id,
+        &old_path,
+        &new_path_str,
+        format!("Rename {} to {}", old_name, new_name),
+    );
+
+    Ok(new_path_str)
+}
 
+/// Bookkeeping after a successful single-file rename: one undo batch (undo
+/// carries the `.meta` back itself) and the tag binding follows the file.
+/// `new_path` must already be normalized (`scanner::path_to_string`).
+fn record_single_rename(project_id: &str, old_path: &str, new_path: &str, description: String) {
+    let _ = project::with_mut(project_id, |state| {
         let operation = undo::FileOperation {
             operation_type: undo::OperationType::Rename,
-            original_path: old_path.clone(),
-            new_path: Some(new_path_str.clone()),
-            timestamp,
+            original_path: old_path.to_string(),
+            new_path: Some(new_path.to_string()),
+            timestamp: unix_timestamp(),
         };
 
         state
             .undo_manager
-            .record_batch(format!("Rename {} to {}", old_name, new_name), vec![operation]);
+            .record_batch(description, vec![operation]);
 
         // Carry tags from the old path to the new one. Best-effort —
         // tag bookkeeping must never block a successful rename, so we
         // ignore save errors (the file is already renamed on disk).
         if state.tags_data.is_some() {
-            // new_path_str is already normalized (scanner::path_to_string above).
-            state.ensure_tags().rename_path(&old_path, &new_path_str);
+            state.ensure_tags().rename_path(old_path, new_path);
             let _ = state.save_tags();
         }
         Ok(())
     });
+}
 
-    Ok(new_path_str)
+#[derive(Serialize)]
+pub struct UnityRenameResult {
+    /// Normalized destination path.
+    pub new_path: String,
+    /// False when the asset had no `.meta` yet (never imported by Unity).
+    pub meta_moved: bool,
+}
+
+/// Rename or move a Unity asset together with its `.meta` sidecar, so it
+/// keeps its GUID and every reference to it. Unlike `rename_file`, which
+/// carries the sidecar best-effort after the fact, this refuses when the
+/// destination or its `.meta` already exists and never leaves the pair
+/// split. Recorded as one undo batch.
+#[tauri::command]
+fn rename_unity_asset(
+    project_id: String,
+    old_path: String,
+    new_path: String,
+) -> Result<UnityRenameResult, String> {
+    if old_path.to_lowercase().ends_with(".meta") {
+        return Err("Rename the asset itself; its .meta follows".to_string());
+    }
+    let new_path_ref = Path::new(&new_path);
+    let new_name = new_path_ref
+        .file_name()
+        .and_then(|n| n.to_str())
+        .ok_or("Invalid destination path")?;
+    validate_new_name(new_name)?;
+
+    let meta_moved = meta_sidecar::rename_with_sidecar(Path::new(&old_path), new_path_ref)?;
+    let new_path_str = scanner::path_to_string(new_path_ref);
+    let old_name = Path::new(&old_path)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("");
+    record_single_rename(
+        &project_id,
+        &old_path,
+        &new_path_str,
+        format!("Rename {} to {}", old_name, new_name),
+    );
+
+    Ok(UnityRenameResult {
+        new_path: new_path_str,
+        meta_moved,
+    })
 }
 
 // ============ Undo Commands ============
@@ -2920,6 +5446,105 @@ fn add_tag_to_assets(
     })
 }
 
+/// Tag every scanned asset `filter` matches ("all textures over 4 MB").
+/// Returns how many assets gained the tag; ones that already had it don't
+/// count. Tags are saved once, and only if something changed.
+#[tauri::command]
+fn tag_assets_by_filter(
+    project_id: String,
+    filter: asset_filter::AssetFilter,
+    tag_id: String,
+) -> Result<usize, String> {
+    project::with_mut(&project_id, |state| {
+        let paths = filtered_paths(state, &filter)?;
+        let tags = state.ensure_tags();
+        if !tags.tags.iter().any(|t| t.id == tag_id) {
+            return Err("Tag not found".to_string());
+        }
+        let tagged = paths
+            .iter()
+            .filter(|path| tags.add_tag_to_asset(path, &tag_id))
+            .count();
+        if tagged > 0 {
+            state.save_tags()?;
+        }
+        Ok(tagged)
+    })
+}
+
+/// Counterpart of `tag_assets_by_filter`: returns how many matching assets
+/// lost the tag.
+#[tauri::command]
+fn untag_assets_by_filter(
+    project_id: String,
+    filter: asset_filter::AssetFilter,
+    tag_id: String,
+) -> Result<usize, String> {
+    project::with_mut(&project_id, |state| {
+        let paths = filtered_paths(state, &filter)?;
+        let tags = state.ensure_tags();
+        let untagged = paths
+            .iter()
+            .filter(|path| tags.remove_tag_from_asset(path, &tag_id))
+            .count();
+        if untagged > 0 {
+            state.save_tags()?;
+        }
+        Ok(untagged)
+    })
+}
+
+#[tauri::command]
+fn get_auto_tag_rules(project_id: String) -> Result<Vec<tags::AutoTagRule>, String> {
+    project::with_mut(&project_id, |state| {
+        Ok(state.ensure_tags().auto_tag_rules.clone())
+    })
+}
+
+/// Replace the project's auto-tag rules (stored in the tags file). Doesn't
+/// apply them — call `apply_auto_tags` for that.
+#[tauri::command]
+fn set_auto_tag_rules(project_id: String, rules: Vec<tags::AutoTagRule>) -> Result<(), String> {
+    project::with_mut(&project_id, |state| {
+        state.ensure_tags().auto_tag_rules = rules;
+        state.save_tags()
+    })
+}
+
+/// Run the auto-tag rules over the current scan, creating tags that don't
+/// exist yet. The frontend calls this after every scan; with no rules it's
+/// a no-op. Saved once, and only when something changed.
+#[tauri::command]
+fn apply_auto_tags(project_id: String) -> Result<Vec<tags::AutoTagOutcome>, String> {
+    project::with_mut(&project_id, |state| {
+        state.ensure_tags();
+        // Taken out so the rules can read the scan while they write tags.
+        let mut tags = state.tags_data.take().unwrap_or_default();
+        let outcomes = state
+            .require_scan()
+            .map(|scan_result| tags.apply_auto_tags(&scan_result.assets, &state.root_path));
+        state.tags_data = Some(tags);
+        let outcomes = outcomes?;
+        if outcomes.iter().any(|o| o.tagged > 0 || o.created_tag) {
+            state.save_tags()?;
+        }
+        Ok(outcomes)
+    })
+}
+
+fn filtered_paths(
+    state: &project::ProjectState,
+    filter: &asset_filter::AssetFilter,
+) -> Result<Vec<String>, String> {
+    Ok(state
+        .require_scan()?
+        .assets
+        .iter()
+        .filter(|asset| filter.matches(asset))
+        .map(|asset| asset.path.clone())
+        .collect())
+}
+
 #[tauri::command]
 fn get_all_asset_tags(project_id: String) -> Result<HashMap<String, Vec<tags::Tag>>, String> {
     project::with_mut(&project_id, |state| {
@@ -2965,31 +5590,97 @@ pub fn run() {
             unregister_project,
             // Scan
             scan_project_incremental,
+            scan_project_multi,
             cancel_scan,
             clear_scan_cache,
+            get_changes_since_cache,
+            find_symlinks,
+            clear_scan_cache_subtree,
             start_watching,
             stop_watching,
             get_thumbnail,
             get_thumbnail_cache_size,
             clear_thumbnail_cache,
+            verify_thumbnail_cache,
             // Analysis
             analyze_assets,
+            analyze_assets_async,
+            cancel_analysis,
+            find_extension_mismatches,
+            find_missing_meta,
+            find_case_collisions,
+            find_name_collisions,
+            find_text_duplicates,
+            find_mixed_line_endings,
+            fix_mixed_line_endings,
+            validate_against_manifest,
+            compute_project_fingerprint,
+            get_duplicate_groups,
+            analyze_lod_chains,
             read_project_config,
             ensure_project_config,
+            fill_project_config,
+            get_rule_catalog,
+            diff_config,
+            resolve_config,
             suggest_tags,
             // Git
             get_git_info,
             get_git_statuses,
+            find_conflicted_assets,
+            find_untracked_large_assets,
+            get_assets_by_git_status,
+            // Asset detail
+            get_asset_detail,
+            analyze_single_asset,
             // Unity
             get_unity_dependencies,
+            build_unity_reference_index,
+            get_reference_counts,
+            get_asset_criticality,
+            get_scene_asset_closure,
             find_unused_assets,
+            find_unused_assets_async,
+            cancel_unused_scan,
+            get_unused_asset_report,
+            scan_script_string_references,
+            find_unused_scripts,
+            get_shader_usage,
+            find_duplicate_materials,
+            find_materials_with_missing_textures,
+            find_missing_scripts,
+            reconcile_asset_types,
+            suggest_atlas_candidates,
+            suggest_channel_packing,
+            analyze_animation_bindings,
             get_godot_dependencies,
             godot_asset_references,
+            find_broken_godot_references,
             // Stats / export
+            get_project_info,
+            get_engine_version,
             get_project_stats,
+            compare_stats_to_baseline,
+            get_largest_directories,
+            get_directory_breakdown,
+            get_folder_composition,
+            get_deepest_paths,
+            get_unanalyzed_assets,
+            get_rule_coverage,
+            get_issues_by_directory,
+            estimate_build_size,
+            check_sample_rate_consistency,
+            find_recently_modified,
             export_to_json,
+            export_tree_as_text,
+            export_to_json_gzip,
             export_to_csv,
+            export_to_csv_file,
+            export_to_jsonl_file,
+            export_filtered_to_csv,
             export_issues_to_json,
+            export_issues_to_json_gzip,
+            export_issues_to_sarif,
             export_to_html,
             save_text_file,
             // Batch ops
@@ -3000,6 +5691,7 @@ pub fn run() {
             apply_naming_fixes,
             // Engine info
             get_unity_file_info,
+            get_unity_scene_hierarchy,
             get_unity_project_info,
             get_godot_project_info,
             get_unreal_project_info,
@@ -3013,7 +5705,10 @@ pub fn run() {
             open_with_default_app,
             open_in_editor,
             rename_file,
+            rename_unity_asset,
             delete_assets,
+            safe_delete_assets,
+            analyze_deletion_impact,
             move_assets,
             copy_assets,
             duplicate_assets,
@@ -3026,6 +5721,11 @@ pub fn run() {
             add_tag_to_asset,
             remove_tag_from_asset,
             add_tag_to_assets,
+            tag_assets_by_filter,
+            untag_assets_by_filter,
+            get_auto_tag_rules,
+            set_auto_tag_rules,
+            apply_auto_tags,
             get_all_asset_tags,
             // LLM tagging
             llm_estimate_cost,
@@ -3171,6 +5871,464 @@ mod tests {
         assert_eq!(rel, vec!["x.png"]);
     }
 
+    #[test]
+    fn reference_counts_tally_both_directions() {
+        let with_guid = |path: &str, guid: &str| scanner::AssetInfo {
+            unity_guid: Some(guid.to_string()),
+            ..asset_modified_at(path, 0)
+        };
+        let assets = vec![
+            with_guid("/p/a.prefab", "a"),
+            with_guid("/p/b.prefab", "b"),
+            with_guid("/p/tex.png", "t"),
+            asset_modified_at("/p/loose.png", 0),
+        ];
+        let set = |guids: &[&str]| -> std::collections::HashSet<String> {
+            guids.iter().map(|g| g.to_string()).collect()
+        };
+        let mut outbound = HashMap::new();
+        outbound.insert("a", set(&["t", "b", "pkg"]));
+        outbound.insert("b", set(&["t"]));
+
+        let counts = tally_reference_counts(&assets, &outbound);
+        let rows: Vec<(&str, usize, usize)> = counts
+            .iter()
+            .map(|c| (c.path.as_str(), c.inbound_count, c.outbound_count))
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                ("/p/tex.png", 2, 0),
+                ("/p/b.prefab", 1, 1),
+                ("/p/a.prefab", 0, 3),
+            ]
+        );
+    }
+
+    fn asset_modified_at(path: &str, modified: u64) -> scanner::AssetInfo {
+        scanner::AssetInfo {
+            path: path.to_string(),
+            name: path.rsplit('/').next().unwrap_or(path).to_string(),
+            extension: "png".to_string(),
+            asset_type: scanner::AssetType::Texture,
+            size: 1,
+            modified,
+            metadata: None,
+            unity_guid: None,
+        }
+    }
+
+    fn asset(path: &str, asset_type: scanner::AssetType, size: u64) -> scanner::AssetInfo {
+        scanner::AssetInfo {
+            path: path.to_string(),
+            name: path.rsplit('/').next().unwrap_or(path).to_string(),
+            extension: path.rsplit('.').next().unwrap_or("").to_string(),
+            asset_type,
+            size,
+            modified: 0,
+            metadata: None,
+            unity_guid: None,
+        }
+    }
+
+    #[test]
+    fn modified_since_filters_and_sorts_newest_first() {
+        let assets = vec![
+            asset_modified_at("/p/old.png", 100),
+            asset_modified_at("/p/b.png", 300),
+            asset_modified_at("/p/edge.png", 200),
+            asset_modified_at("/p/a.png", 300),
+        ];
+        let paths: Vec<String> = modified_since(&assets, 200)
+            .into_iter()
+            .map(|a| a.path)
+            .collect();
+        // Strictly newer than `since`; equal mtimes ordered by path.
+        assert_eq!(paths, vec!["/p/a.png", "/p/b.png"]);
+    }
+
+    fn strings(items: &[&str]) -> Vec<String> {
+        items.iter().map(|s| s.to_string()).collect()
+    }
+
+    /// `(target, referrer)` pairs → the map `unity_referrers_of` builds.
+    fn referrer_map(pairs: &[(&str, &str)]) -> HashMap<String, std::collections::BTreeSet<String>> {
+        let mut map: HashMap<String, std::collections::BTreeSet<String>> = HashMap::new();
+        for (target, referrer) in pairs {
+            map.entry(target.to_string())
+                .or_default()
+                .insert(referrer.to_string());
+        }
+        map
+    }
+
+    #[test]
+    fn safe_delete_blocks_on_referrers_that_stay() {
+        // Mat is used by Prefab (deleted too) and by Scene (stays).
+        let referrers = referrer_map(&[
+            ("/p/Mat.mat", "/p/Prefab.prefab"),
+            ("/p/Mat.mat", "/p/Scene.unity"),
+            ("/p/Tex.png", "/p/Mat.mat"),
+        ]);
+        let (safe, blocked) = partition_safe_deletes(
+            &strings(&["/p/Prefab.prefab", "/p/Mat.mat", "/p/Tex.png", "/p/Mat.mat"]),
+            &referrers,
+        );
+        assert_eq!(safe, strings(&["/p/Prefab.prefab"]));
+        // Tex is blocked through Mat, which is itself blocked; only the
+        // referrers that stay are reported, and the repeat collapses.
+        let blocked: Vec<(String, Vec<String>)> = blocked
+            .into_iter()
+            .map(|b| (b.path, b.referenced_by))
+            .collect();
+        assert_eq!(
+            blocked,
+            vec![
+                ("/p/Mat.mat".to_string(), strings(&["/p/Scene.unity"])),
+                ("/p/Tex.png".to_string(), strings(&["/p/Mat.mat"])),
+            ]
+        );
+    }
+
+    #[test]
+    fn safe_delete_ignores_referrers_in_the_same_request() {
+        let referrers = referrer_map(&[("/p/Tex.png", "/p/Mat.mat")]);
+        let (safe, blocked) =
+            partition_safe_deletes(&strings(&["/p/Tex.png", "/p/Mat.mat"]), &referrers);
+        assert_eq!(safe, strings(&["/p/Tex.png", "/p/Mat.mat"]));
+        assert!(blocked.is_empty());
+    }
+
+    /// Writes `rel` under `dir`, its relative path as content, and returns
+    /// the written path.
+    fn write_file(dir: &Path, rel: &str) -> String {
+        let path = dir.join(rel);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, rel).unwrap();
+        scanner::path_to_string(&path)
+    }
+
+    #[test]
+    fn move_batch_is_all_or_nothing_and_carries_meta() {
+        let dir = tempfile::tempdir().unwrap();
+        let rock = write_file(dir.path(), "Rock.png");
+        write_file(dir.path(), "Rock.png.meta");
+        let sky = write_file(dir.path(), "Sky.png");
+        let taken = write_file(dir.path(), "Art/Sky.png");
+        let target = dir.path().join("Art");
+
+        // One collision: nothing moves.
+        let result = move_batch(&[rock.clone(), sky.clone()], &target);
+        assert!(result.successes.is_empty());
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].path, sky);
+        assert!(Path::new(&rock).exists());
+
+        // Same-named sources from two folders collide with each other.
+        let other_rock = write_file(dir.path(), "Old/Rock.png");
+        let result = move_batch(&[rock.clone(), other_rock.clone()], &target);
+        assert!(result.successes.is_empty());
+        assert_eq!(result.errors[0].path, other_rock);
+
+        // A rejected batch leaves no new folder behind.
+        let nested = dir.path().join("New/Nested");
+        let result = move_batch(&[rock.clone(), other_rock.clone()], &nested);
+        assert!(result.successes.is_empty());
+        assert!(!dir.path().join("New").exists());
+
+        // A folder that doesn't exist yet is created; the sidecar follows.
+        std::fs::remove_file(&taken).unwrap();
+        let target = dir.path().join("Art/Textures");
+        let result = move_batch(&[rock.clone(), sky.clone()], &target);
+        assert!(result.errors.is_empty());
+        assert_eq!(result.successes.len(), 2);
+        assert!(target.join("Rock.png").exists());
+        assert!(target.join("Rock.png.meta").exists());
+        assert!(!Path::new(&rock).exists());
+    }
+
+    #[test]
+    fn deletion_impact_counts_only_referrers_left_behind() {
+        let referrers = referrer_map(&[
+            ("/p/Mat.mat", "/p/Prefab.prefab"),
+            ("/p/Mat.mat", "/p/Scene.unity"),
+            ("/p/Tex.png", "/p/Mat.mat"),
+            ("/p/Tex.png", "/p/Other.mat"),
+            ("/p/Icon.png", "/p/Prefab.prefab"),
+        ]);
+        let impact = deletion_impact(
+            &strings(&[
+                "/p/Prefab.prefab",
+                "/p/Mat.mat",
+                "/p/Tex.png",
+                "/p/Icon.png",
+            ]),
+            &referrers,
+        );
+        // Icon's only referrer goes too; Prefab has none. No cascade: Mat
+        // breaking doesn't keep Tex's Mat reference alive.
+        assert_eq!(impact.safe, strings(&["/p/Prefab.prefab", "/p/Icon.png"]));
+        let breaking: Vec<(String, Vec<String>)> = impact
+            .breaking
+            .into_iter()
+            .map(|b| (b.path, b.referenced_by))
+            .collect();
+        assert_eq!(
+            breaking,
+            vec![
+                ("/p/Mat.mat".to_string(), strings(&["/p/Scene.unity"])),
+                ("/p/Tex.png".to_string(), strings(&["/p/Other.mat"])),
+            ]
+        );
+        assert_eq!(
+            impact.broken_referrers,
+            strings(&["/p/Other.mat", "/p/Scene.unity"])
+        );
+        assert_eq!(impact.broken_reference_count, 2);
+    }
+
+    #[test]
+    fn git_status_groups_only_list_scanned_assets() {
+        let assets = vec![
+            asset_modified_at("/p/b.png", 0),
+            asset_modified_at("/p/a.png", 0),
+            asset_modified_at("/p/new.png", 0),
+            asset_modified_at("/p/clean.png", 0),
+        ];
+        let statuses: HashMap<String, git::GitFileStatus> = [
+            ("/p/a.png", git::GitFileStatus::Modified),
+            ("/p/b.png", git::GitFileStatus::Modified),
+            ("/p/new.png", git::GitFileStatus::Untracked),
+            // Deleted from disk, so not in the scan.
+            ("/p/gone.png", git::GitFileStatus::Deleted),
+            // Untracked but never scanned (e.g. an excluded extension).
+            ("/p/notes.txt", git::GitFileStatus::Untracked),
+        ]
+        .into_iter()
+        .map(|(p, s)| (p.to_string(), s))
+        .collect();
+
+        let grouped = group_assets_by_git_status(&assets, &statuses);
+        assert_eq!(
+            grouped.groups[&git::GitFileStatus::Modified],
+            vec!["/p/a.png", "/p/b.png"]
+        );
+        assert_eq!(
+            grouped.groups[&git::GitFileStatus::Untracked],
+            vec!["/p/new.png"]
+        );
+        assert!(!grouped.groups.contains_key(&git::GitFileStatus::Deleted));
+        assert_eq!(grouped.counts[&git::GitFileStatus::Modified], 2);
+        assert_eq!(grouped.counts.len(), 2);
+    }
+
+    fn dir(
+        path: &str,
+        size: u64,
+        file_count: usize,
+        children: Vec<scanner::DirectoryNode>,
+    ) -> scanner::DirectoryNode {
+        scanner::DirectoryNode {
+            name: path.rsplit('/').next().unwrap_or(path).to_string(),
+            path: path.to_string(),
+            children,
+            file_count,
+            total_size: size,
+        }
+    }
+
+    #[test]
+    fn collect_directory_sizes_uses_rolled_up_totals() {
+        // `Art` holds no files directly — the flat per-parent map would never
+        // list it — but it contains both heavy subfolders and must rank first.
+        let tree = dir(
+            "/p",
+            1000,
+            6,
+            vec![
+                dir(
+                    "/p/Art",
+                    900,
+                    5,
+                    vec![
+                        dir("/p/Art/Tex", 600, 3, vec![]),
+                        dir("/p/Art/Mesh", 300, 2, vec![]),
+                    ],
+                ),
+                dir("/p/Audio", 100, 1, vec![]),
+            ],
+        );
+        let mut dirs = Vec::new();
+        collect_directory_sizes(&tree, &mut dirs);
+        let paths: Vec<&str> = dirs.iter().map(|d| d.path.as_str()).collect();
+        assert_eq!(paths, ["/p/Art", "/p/Art/Tex", "/p/Art/Mesh", "/p/Audio"]);
+        assert_eq!((dirs[0].size, dirs[0].file_count), (900, 5));
+    }
+
+    #[test]
+    fn directory_breakdown_mixes_files_and_subdirectories() {
+        let tree = dir(
+            "/p",
+            1000,
+            3,
+            vec![dir(
+                "/p/Art",
+                1000,
+                3,
+                vec![dir("/p/Art/Tex", 600, 2, vec![])],
+            )],
+        );
+        let assets = vec![
+            asset("/p/Art/big.psd", scanner::AssetType::Texture, 400),
+            asset("/p/Art/Tex/a.png", scanner::AssetType::Texture, 300),
+            asset("/p/Art/Tex/b.png", scanner::AssetType::Texture, 300),
+        ];
+
+        let entries = directory_breakdown(&tree, &assets, "/p/Art/").unwrap();
+        let summary: Vec<(&str, bool, u64, f64)> = entries
+            .iter()
+            .map(|e| (e.name.as_str(), e.is_directory, e.size, e.percent))
+            .collect();
+        assert_eq!(
+            summary,
+            [("Tex", true, 600, 60.0), ("big.psd", false, 400, 40.0)]
+        );
+        assert!(directory_breakdown(&tree, &assets, "/p/Gone").is_none());
+    }
+
+    #[test]
+    fn analyze_file_runs_per_asset_rules_on_one_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let empty = dir.path().join("blank.png");
+        std::fs::write(&empty, b"").unwrap();
+
+        let issues = analyze_file(&empty, &RuleConfig::default()).unwrap();
+        assert!(issues.iter().any(|i| i.rule_id == "empty_asset"));
+        assert!(analyze_file(&dir.path().join("gone.png"), &RuleConfig::default()).is_err());
+    }
+
+    #[test]
+    fn deepest_paths_ranks_by_depth_and_length() {
+        let assets = vec![
+            asset_modified_at("/p/top.png", 0),
+            asset_modified_at("/p/A/B/C/deep.png", 0),
+            asset_modified_at("/p/Art/a_really_long_texture_name_for_the_hero.png", 0),
+            asset_modified_at("/p/A/B/mid.png", 0),
+        ];
+
+        let paths = deepest_paths(&assets, "/p/", 2);
+        let deepest: Vec<(&str, usize)> = paths
+            .deepest
+            .iter()
+            .map(|p| (p.path.as_str(), p.depth))
+            .collect();
+        assert_eq!(deepest, [("/p/A/B/C/deep.png", 3), ("/p/A/B/mid.png", 2)]);
+        assert_eq!(
+            paths.longest[0].path,
+            "/p/Art/a_really_long_texture_name_for_the_hero.png"
+        );
+        assert_eq!(paths.longest[0].length, 50);
+        assert_eq!(paths.longest.len(), 2);
+    }
+
+    #[test]
+    fn folder_composition_groups_by_truncated_parent() {
+        let assets = vec![
+            asset("/p/Assets/Art/Tex/a.png", scanner::AssetType::Texture, 1),
+            asset("/p/Assets/Art/b.fbx", scanner::AssetType::Model, 1),
+            asset("/p/Assets/Art/Player.cs", scanner::AssetType::Script, 1),
+            asset("/p/Assets/Audio/c.wav", scanner::AssetType::Audio, 1),
+            // Above depth 2: in no folder.
+            asset("/p/Assets/readme.txt", scanner::AssetType::Other, 1),
+            // A sibling project whose path merely starts with `/p`.
+            asset("/p2/Assets/Art/d.png", scanner::AssetType::Texture, 1),
+        ];
+
+        let composition = folder_composition(&assets, "/p/", 2);
+        let counts = |folder: &str| -> Vec<(&str, usize)> {
+            composition[folder]
+                .iter()
+                .map(|(t, n)| (t.as_str(), *n))
+                .collect()
+        };
+        assert_eq!(composition.len(), 2);
+        assert_eq!(
+            counts("/p/Assets/Art"),
+            [("model", 1), ("script", 1), ("texture", 1)]
+        );
+        assert_eq!(counts("/p/Assets/Audio"), [("audio", 1)]);
+    }
+
+    #[test]
+    fn csv_row_quotes_and_escapes_text_fields() {
+        let asset = scanner::AssetInfo {
+            metadata: Some(scanner::AssetMetadata {
+                width: Some(16),
+                height: Some(8),
+                ..Default::default()
+            }),
+            ..asset("/p/say \"hi\".png", scanner::AssetType::Texture, 42)
+        };
+        assert_eq!(
+            csv_row(&asset),
+            "\"say \"\"hi\"\".png\",\"/p/say \"\"hi\"\".png\",Texture,\"png\",42,16,8\n"
+        );
+    }
+
+    #[test]
+    fn streamed_csv_matches_the_in_memory_export() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("assets.csv");
+        // More than one batch, so batch boundaries keep row order.
+        let assets: Vec<scanner::AssetInfo> = (0..5000)
+            .map(|i| asset(&format!("/p/{}.png", i), scanner::AssetType::Texture, i))
+            .collect();
+
+        assert_eq!(write_csv(&path, &assets).unwrap(), 5000);
+        let expected: String = std::iter::once(CSV_HEADER.to_string())
+            .chain(assets.iter().map(csv_row))
+            .collect();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), expected);
+    }
+
+    #[test]
+    fn jsonl_writes_one_parseable_asset_per_line() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("assets.jsonl");
+        let assets: Vec<scanner::AssetInfo> = ["a\nb.png", "c.png"]
+            .iter()
+            .map(|name| asset(&format!("/p/{}", name), scanner::AssetType::Texture, 3))
+            .collect();
+
+        assert_eq!(write_jsonl(&path, &assets).unwrap(), 2);
+        let text = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 2);
+        // A newline inside a name stays escaped within its line.
+        let first: scanner::AssetInfo = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(first.name, "a\nb.png");
+        assert!(write_jsonl(Path::new(""), &assets).is_err());
+    }
+
+    #[test]
+    fn json_gzip_round_trips_to_the_pretty_export() {
+        use std::io::Read;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("assets.json.gz");
+        let value = serde_json::json!({ "assets": [{ "name": "a.png", "size": 3 }] });
+
+        let written = write_json_gzip(&path, &value).unwrap();
+        assert_eq!(written, std::fs::metadata(&path).unwrap().len());
+
+        let mut decoded = String::new();
+        flate2::read::GzDecoder::new(std::fs::File::open(&path).unwrap())
+            .read_to_string(&mut decoded)
+            .unwrap();
+        assert_eq!(decoded, serde_json::to_string_pretty(&value).unwrap());
+        assert!(write_json_gzip(Path::new(""), &value).is_err());
+    }
+
     #[test]
     fn html_escape_neutralizes_markup() {
         // An asset named to inject script must not produce live HTML.
@@ -3182,4 +6340,87 @@ mod tests {
             "&lt;img src=x onerror=&quot;alert(1)&quot;&gt;.png"
         );
     }
+
+    #[test]
+    fn references_into_vendored_packages_still_resolve() {
+        // `Packages/**` is vendored by default for Unity: its assets aren't
+        // analyzed, but a project prefab pointing into an embedded package
+        // is not a missing reference.
+        const PACKAGED: &str = "aaaa0000aaaa0000aaaa0000aaaa0000";
+        const DELETED: &str = "dddd0000dddd0000dddd0000dddd0000";
+        let dir = tempfile::tempdir().unwrap();
+        let write = |rel: &str, content: &str, guid: &str| {
+            let path = dir.path().join(rel);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(&path, content).unwrap();
+            std::fs::write(
+                format!("{}.meta", path.display()),
+                format!("fileFormatVersion: 2\nguid: {}\n", guid),
+            )
+            .unwrap();
+        };
+        std::fs::create_dir_all(dir.path().join("ProjectSettings")).unwrap();
+        write("Packages/com.studio.art/Rock.png", "png", PACKAGED);
+        write(
+            "Assets/Rock.prefab",
+            &format!(
+                "--- !u!114 &1\nMonoBehaviour:\n  a: {{fileID: 2800000, guid: {}, type: 3}}\n  b: {{fileID: 2800000, guid: {}, type: 3}}\n",
+                PACKAGED, DELETED
+            ),
+            "bbbb0000bbbb0000bbbb0000bbbb0000",
+        );
+
+        let root = dir.path().to_string_lossy().to_string();
+        let scan = scanner::scan_directory_with_state(&root, None, false, false).unwrap();
+        let config = RuleConfig::default();
+        let vendor = config
+            .vendor
+            .matcher(&root, scan.project_type.as_ref())
+            .unwrap();
+        let mut state = project::ProjectState::new("test".to_string(), root);
+        state.cached_scan = Some(scan);
+
+        let result = run_full_analysis(
+            &state,
+            &config,
+            None,
+            &vendor,
+            &unity::PackageGuidIndex::default(),
+            None,
+            None,
+        )
+        .unwrap();
+        let missing: Vec<&analyzer::Issue> = result
+            .issues
+            .iter()
+            .filter(|i| i.rule_id == "missing_reference")
+            .collect();
+        assert_eq!(missing.len(), 1);
+        assert!(missing[0].message.contains(DELETED));
+    }
+
+    #[test]
+    fn analysis_refuses_an_inventory_only_scan() {
+        let dir = tempfile::tempdir().unwrap();
+        write_file(dir.path(), "T_Rock.png");
+        let root = dir.path().to_string_lossy().to_string();
+        let mut scan = scanner::scan_directory_with_state(&root, None, false, false).unwrap();
+        scan.metadata_skipped = true;
+        let config = RuleConfig::default();
+        let vendor = config.vendor.matcher(&root, None).unwrap();
+        let mut state = project::ProjectState::new("test".to_string(), root);
+        state.cached_scan = Some(scan);
+
+        let err = run_full_analysis(
+            &state,
+            &config,
+            None,
+            &vendor,
+            &unity::PackageGuidIndex::default(),
+            None,
+            None,
+        )
+        .unwrap_err();
+        assert!(err.contains("full scan"));
+    }
 }
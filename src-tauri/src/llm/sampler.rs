@@ -209,6 +209,9 @@ mod tests {
             total_size: 0,
             type_counts: HashMap::new(),
             project_type: None,
+            timings: None,
+            metadata_skipped: false,
+            project_info: None,
             assets,
         }
     }
@@ -12,6 +12,11 @@
 //! without rolling back the already-succeeded primary op (rollback can itself
 //! fail and leave a more confusing half-state).
 //!
+//! `rename_with_sidecar` is the strict counterpart behind
+//! `rename_unity_asset` and `move_assets`: it checks both destinations
+//! before touching anything and undoes the asset rename if the sidecar
+//! can't follow, so the pair is never split.
+//!
 //! Copy / duplicate deliberately do NOT carry the sidecar: a duplicated asset
 //! must receive a fresh GUID, so copying the .meta (and its GUID) would create
 //! a collision. Those paths are left untouched on purpose.
@@ -49,6 +54,44 @@ pub fn carry_on_rename(from: &Path, to: &Path) -> Result<(), String> {
         .map_err(|e| format!("failed to move sidecar {}: {}", src.display(), e))
 }
 
+/// Rename `from` to `to` together with its `.meta` sidecar, as a pair.
+/// Refuses up front when `to` or its sidecar slot is taken (by anything other
+/// than the source itself — a case-only rename is fine), and moves the asset
+/// back if the sidecar move fails. Returns whether a sidecar was moved; an
+/// asset without one (not imported yet) is renamed alone.
+pub fn rename_with_sidecar(from: &Path, to: &Path) -> Result<bool, String> {
+    if !from.exists() {
+        return Err(format!("{} does not exist", from.display()));
+    }
+    let from_meta = sidecar_path(from);
+    let to_meta = sidecar_path(to);
+    let has_meta = from_meta.exists();
+    if to.exists() && !crate::undo::paths_are_same_file(from, to) {
+        return Err(format!("{} already exists", to.display()));
+    }
+    if to_meta.exists() && !(has_meta && crate::undo::paths_are_same_file(&from_meta, &to_meta)) {
+        return Err(format!("{} already exists", to_meta.display()));
+    }
+
+    std::fs::rename(from, to).map_err(|e| format!("failed to rename {}: {}", from.display(), e))?;
+    if has_meta {
+        if let Err(e) = std::fs::rename(&from_meta, &to_meta) {
+            let restored = std::fs::rename(to, from);
+            return Err(match restored {
+                Ok(()) => format!("failed to move sidecar {}: {}", from_meta.display(), e),
+                Err(back) => format!(
+                    "failed to move sidecar {}: {}; the asset was left at {} ({})",
+                    from_meta.display(),
+                    e,
+                    to.display(),
+                    back
+                ),
+            });
+        }
+    }
+    Ok(has_meta)
+}
+
 /// Best-effort: when `path` has a `.meta` sidecar, send it to the OS trash too,
 /// so deleting an asset doesn't strand its sidecar. `Ok(())` when trashed or
 /// when there's no sidecar; `Err` only when a sidecar exists but couldn't be
@@ -117,6 +160,34 @@ mod tests {
         assert!(sidecar_path(&from).exists()); // source sidecar untouched
     }
 
+    #[test]
+    fn rename_with_sidecar_moves_the_pair_or_nothing() {
+        let dir = tempdir().unwrap();
+        let from = dir.path().join("a.png");
+        let to = dir.path().join("b.png");
+        fs::write(&from, "x").unwrap();
+        fs::write(sidecar_path(&from), "guid: 123").unwrap();
+
+        // A stray sidecar at the destination: refused before anything moves.
+        fs::write(sidecar_path(&to), "stray").unwrap();
+        assert!(rename_with_sidecar(&from, &to).is_err());
+        assert!(from.exists() && sidecar_path(&from).exists());
+        assert!(!to.exists());
+
+        fs::remove_file(sidecar_path(&to)).unwrap();
+        assert_eq!(rename_with_sidecar(&from, &to), Ok(true));
+        assert!(to.exists() && sidecar_path(&to).exists());
+        assert!(!from.exists() && !sidecar_path(&from).exists());
+
+        // No sidecar (not imported yet): the asset moves alone.
+        let lone = dir.path().join("c.png");
+        fs::write(&lone, "x").unwrap();
+        assert_eq!(
+            rename_with_sidecar(&lone, &dir.path().join("d.png")),
+            Ok(false)
+        );
+    }
+
     #[test]
     fn carry_on_delete_is_noop_without_sidecar() {
         let dir = tempdir().unwrap();
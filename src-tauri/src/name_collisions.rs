@@ -0,0 +1,131 @@
+//! Assets sharing a file name across different folders.
+//!
+//! `Resources.Load("Icon")`, Godot's `load()` by bare name and most
+//! "find by name" editor tooling pick whichever match comes first, so two
+//! `Icon.png` in separate folders are a silent ambiguity. This is neither a
+//! case collision (the paths differ for real) nor a duplicate (the contents
+//! usually don't match) — just one pass grouping by name.
+//!
+//! By default only the exact file name counts, extension included; with
+//! `same_extension` off the stem is compared, since `Resources.Load` drops
+//! the extension and `Icon.png` / `Icon.psd` collide there too. Names are
+//! compared case-insensitively either way;
+//! a group only counts when it spans more than one folder.
+
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use crate::scanner::AssetInfo;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct NameCollision {
+    /// The shared name as the first path spells it — the stem when
+    /// extensions are ignored.
+    pub name: String,
+    /// Two or more assets with that name, sorted.
+    pub paths: Vec<String>,
+}
+
+/// The part of `name` compared: all of it, or the stem.
+fn compared_name(name: &str, same_extension: bool) -> &str {
+    if same_extension {
+        return name;
+    }
+    Path::new(name)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or(name)
+}
+
+/// Names owned by more than one asset in `assets`, ordered by name.
+pub fn find_name_collisions(
+    assets: &[AssetInfo],
+    same_extension: bool,
+    is_excluded: impl Fn(&str) -> bool,
+) -> Vec<NameCollision> {
+    let mut by_name: HashMap<String, Vec<&AssetInfo>> = HashMap::new();
+    for asset in assets.iter().filter(|a| !is_excluded(&a.path)) {
+        by_name
+            .entry(compared_name(&asset.name, same_extension).to_lowercase())
+            .or_default()
+            .push(asset);
+    }
+
+    let mut collisions: Vec<NameCollision> = by_name
+        .into_values()
+        .filter(|group| {
+            // Same folder, differing by case: the case-collision check's job.
+            group
+                .iter()
+                .map(|a| {
+                    Path::new(&a.path)
+                        .parent()
+                        .map(|p| p.to_string_lossy().to_lowercase())
+                })
+                .collect::<HashSet<_>>()
+                .len()
+                > 1
+        })
+        .map(|mut group| {
+            group.sort_by(|a, b| a.path.cmp(&b.path));
+            NameCollision {
+                name: compared_name(&group[0].name, same_extension).to_string(),
+                paths: group.into_iter().map(|a| a.path.clone()).collect(),
+            }
+        })
+        .collect();
+    collisions.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+    collisions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::AssetType;
+
+    fn asset(rel: &str) -> AssetInfo {
+        let name = rel.rsplit('/').next().unwrap();
+        AssetInfo {
+            path: format!("/p/{}", rel),
+            name: name.to_string(),
+            extension: name.rsplit('.').next().unwrap().to_string(),
+            asset_type: AssetType::Texture,
+            size: 1,
+            modified: 0,
+            metadata: None,
+            unity_guid: None,
+        }
+    }
+
+    #[test]
+    fn groups_names_across_folders() {
+        let assets = vec![
+            asset("UI/Icon.png"),
+            asset("Items/icon.png"),
+            asset("Source/Icon.psd"),
+            asset("UI/Button.png"),
+            // Same folder: a case collision, not a name collision.
+            asset("UI/button.PNG"),
+            asset("Vendor/Button.png"),
+        ];
+        let vendored = |path: &str| path.contains("/Vendor/");
+
+        let exact = find_name_collisions(&assets, true, vendored);
+        assert_eq!(
+            exact,
+            vec![NameCollision {
+                name: "icon.png".to_string(),
+                paths: vec![
+                    "/p/Items/icon.png".to_string(),
+                    "/p/UI/Icon.png".to_string(),
+                ],
+            }]
+        );
+
+        let by_stem = find_name_collisions(&assets, false, vendored);
+        assert_eq!(by_stem.len(), 1);
+        assert_eq!(by_stem[0].name, "icon");
+        assert_eq!(by_stem[0].paths.len(), 3);
+    }
+}
@@ -7,6 +7,7 @@
 use parking_lot::Mutex;
 use std::collections::HashMap;
 use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, OnceLock};
 
 use crate::git::GitManager;
@@ -33,6 +34,10 @@ pub struct ProjectState {
     /// scan-excluded files on FS events. Defaults to true (matches the
     /// frontend default) until the first scan overwrites it.
     pub respect_gitignore: bool,
+    /// Whether the most recent scan walked dot-prefixed files and
+    /// directories; recorded for the watcher like `respect_gitignore`.
+    /// Defaults to false (the scan commands' default).
+    pub include_hidden: bool,
     /// Live filesystem watcher. Dropping this stops the background watch.
     pub watcher: Option<ProjectWatcher>,
     /// Rules from the most recent AI-learning run, staged in memory until the
@@ -48,6 +53,23 @@ pub struct ProjectState {
     /// are immutable, so the listing changing is the only staleness signal).
     /// Built lazily by `lib.rs::package_index_for`; `None` until first use.
     pub package_index: Option<(Vec<String>, Arc<crate::unity::PackageGuidIndex>)>,
+    /// Git status pass started alongside the latest scan, taken by the
+    /// first `get_git_info` after it. Cleared when the scan fails, so a
+    /// pass from long ago is never mistaken for fresh data.
+    pub git_prewarm: Option<crate::git::StatusPrewarm>,
+    /// `ScanCache` key of the latest successful scan (the root path, or the
+    /// multi-root key). Analysis reads and extends the cached content
+    /// hashes under it; `None` until a scan has finished.
+    pub scan_cache_key: Option<String>,
+    /// Unity reference index, kept in memory between reference queries and
+    /// refreshed against the current scan by `lib.rs::unity_reference_index_for`.
+    /// `None` until first use; the persisted copy lives next to the scan cache.
+    pub unity_index: Option<Arc<crate::unity_index::ReferenceIndex>>,
+    /// Result of the latest completed analysis (Run Analysis or the
+    /// watcher's re-analysis), for queries over "the current issues" such
+    /// as `get_issues_by_directory`. Not invalidated by rescans — the
+    /// frontend already flags the analysis stale when files change.
+    pub last_analysis: Option<crate::analyzer::AnalysisResult>,
 }
 
 impl ProjectState {
@@ -63,9 +85,14 @@ impl ProjectState {
             undo_manager,
             tags_data: None,
             respect_gitignore: true,
+            include_hidden: false,
             watcher: None,
             pending_ai_rules: None,
             package_index: None,
+            git_prewarm: None,
+            scan_cache_key: None,
+            unity_index: None,
+            last_analysis: None,
         }
     }
 
@@ -146,3 +173,91 @@ where
     let state = proj.lock();
     f(&state)
 }
+
+type FlagMap = Mutex<HashMap<String, Arc<AtomicBool>>>;
+
+/// Cancellation flags of in-flight analyses, keyed by project id. Kept out
+/// of `ProjectState` on purpose: analysis runs under the project lock, so a
+/// flag behind that lock couldn't be reached until the very run it should
+/// stop had finished.
+static ANALYSIS_FLAGS: OnceLock<FlagMap> = OnceLock::new();
+/// Same for `find_unused_assets_async`, kept apart so cancelling one kind
+/// of run never stops the other.
+static UNUSED_SCAN_FLAGS: OnceLock<FlagMap> = OnceLock::new();
+
+fn analysis_flags() -> &'static FlagMap {
+    ANALYSIS_FLAGS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn unused_scan_flags() -> &'static FlagMap {
+    UNUSED_SCAN_FLAGS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Registration of one in-flight cancellable run; dropping it unregisters.
+pub struct AnalysisGuard {
+    registry: &'static FlagMap,
+    project_id: String,
+    flag: Arc<AtomicBool>,
+}
+
+impl AnalysisGuard {
+    pub fn flag(&self) -> &AtomicBool {
+        &self.flag
+    }
+}
+
+impl Drop for AnalysisGuard {
+    fn drop(&mut self) {
+        let mut flags = self.registry.lock();
+        // A newer analysis of the same project may have replaced our entry;
+        // leave its flag alone.
+        if flags
+            .get(&self.project_id)
+            .is_some_and(|f| Arc::ptr_eq(f, &self.flag))
+        {
+            flags.remove(&self.project_id);
+        }
+    }
+}
+
+/// Register an analysis of `project_id` as cancellable. The newest
+/// registration wins — `cancel_analysis` targets the latest run.
+pub fn begin_analysis(project_id: &str) -> AnalysisGuard {
+    register(analysis_flags(), project_id)
+}
+
+/// Signal the project's in-flight analysis to stop. Returns whether one was
+/// running.
+pub fn cancel_analysis(project_id: &str) -> bool {
+    cancel(analysis_flags(), project_id)
+}
+
+/// `begin_analysis` for `find_unused_assets_async`.
+pub fn begin_unused_scan(project_id: &str) -> AnalysisGuard {
+    register(unused_scan_flags(), project_id)
+}
+
+/// `cancel_analysis` for `find_unused_assets_async`.
+pub fn cancel_unused_scan(project_id: &str) -> bool {
+    cancel(unused_scan_flags(), project_id)
+}
+
+fn register(registry: &'static FlagMap, project_id: &str) -> AnalysisGuard {
+    let flag = Arc::new(AtomicBool::new(false));
+    registry.lock().insert(project_id.to_string(), flag.clone());
+    AnalysisGuard {
+        registry,
+        project_id: project_id.to_string(),
+        flag,
+    }
+}
+
+fn cancel(registry: &FlagMap, project_id: &str) -> bool {
+    match registry.lock().get(project_id) {
+        Some(flag) => {
+            flag.store(true, Ordering::Relaxed);
+            true
+        }
+        None => false,
+    }
+}
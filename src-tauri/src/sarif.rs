@@ -0,0 +1,259 @@
+//! Analysis results as SARIF 2.1.0, for code-scanning UIs.
+//!
+//! GitHub and GitLab annotate pull requests from a SARIF log: one run with
+//! the tool's rules and one result per finding. Rules come from the rule
+//! catalog (only those with findings are listed); ids the catalog doesn't
+//! know — on-demand checks — get a rule built from the issue itself.
+//! Locations are root-relative URIs so the consumer resolves them against
+//! its own checkout.
+
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::analyzer::rules::RuleDescription;
+use crate::analyzer::{AnalysisResult, Severity};
+use crate::scanner;
+
+const SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+#[derive(Debug, Serialize)]
+pub struct SarifLog {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: Vec<Run>,
+}
+
+#[derive(Debug, Serialize)]
+struct Run {
+    tool: Tool,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Debug, Serialize)]
+struct Tool {
+    driver: Driver,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Driver {
+    name: &'static str,
+    version: &'static str,
+    information_uri: &'static str,
+    rules: Vec<ReportingDescriptor>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ReportingDescriptor {
+    id: String,
+    name: String,
+    short_description: Message,
+    default_configuration: Configuration,
+}
+
+#[derive(Debug, Serialize)]
+struct Configuration {
+    level: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+struct Message {
+    text: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    rule_id: String,
+    rule_index: usize,
+    level: &'static str,
+    message: Message,
+    locations: Vec<Location>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Location {
+    physical_location: PhysicalLocation,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PhysicalLocation {
+    artifact_location: ArtifactLocation,
+}
+
+#[derive(Debug, Serialize)]
+struct ArtifactLocation {
+    uri: String,
+}
+
+fn level(severity: &Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info => "note",
+    }
+}
+
+/// `path` relative to `root`, percent-encoded into a URI reference.
+fn relative_uri(path: &str, root: &Path) -> String {
+    let p = Path::new(path);
+    let rel = scanner::path_to_string(p.strip_prefix(root).unwrap_or(p));
+    let mut uri = String::with_capacity(rel.len());
+    for byte in rel.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => {
+                uri.push(byte as char)
+            }
+            _ => uri.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    uri
+}
+
+pub fn build(result: &AnalysisResult, catalog: &[RuleDescription], root: &str) -> SarifLog {
+    let root = Path::new(root);
+    let described: HashMap<&str, &RuleDescription> =
+        catalog.iter().map(|d| (d.rule_id, d)).collect();
+
+    let mut rules: Vec<ReportingDescriptor> = Vec::new();
+    let mut rule_index: HashMap<&str, usize> = HashMap::new();
+    let mut results = Vec::with_capacity(result.issues.len());
+    for issue in &result.issues {
+        let index = *rule_index.entry(issue.rule_id.as_str()).or_insert_with(|| {
+            rules.push(match described.get(issue.rule_id.as_str()) {
+                Some(d) => ReportingDescriptor {
+                    id: d.rule_id.to_string(),
+                    name: d.name.to_string(),
+                    short_description: Message {
+                        text: d.description.to_string(),
+                    },
+                    default_configuration: Configuration {
+                        level: level(&d.default_severity),
+                    },
+                },
+                None => ReportingDescriptor {
+                    id: issue.rule_id.clone(),
+                    name: issue.rule_name.clone(),
+                    short_description: Message {
+                        text: issue.rule_name.clone(),
+                    },
+                    default_configuration: Configuration {
+                        level: level(&issue.severity),
+                    },
+                },
+            });
+            rules.len() - 1
+        });
+
+        let text = match &issue.suggestion {
+            Some(suggestion) => format!("{}\n\nSuggestion: {}", issue.message, suggestion),
+            None => issue.message.clone(),
+        };
+        results.push(SarifResult {
+            rule_id: issue.rule_id.clone(),
+            rule_index: index,
+            level: level(&issue.severity),
+            message: Message { text },
+            locations: vec![Location {
+                physical_location: PhysicalLocation {
+                    artifact_location: ArtifactLocation {
+                        uri: relative_uri(&issue.asset_path, root),
+                    },
+                },
+            }],
+        });
+    }
+
+    SarifLog {
+        schema: SCHEMA,
+        version: "2.1.0",
+        runs: vec![Run {
+            tool: Tool {
+                driver: Driver {
+                    name: "Tidycraft",
+                    version: env!("CARGO_PKG_VERSION"),
+                    information_uri: "https://github.com/Lynthar/Tidycraft",
+                    rules,
+                },
+            },
+            results,
+        }],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::Issue;
+
+    fn issue(rule_id: &str, severity: Severity, path: &str, suggestion: Option<&str>) -> Issue {
+        Issue {
+            rule_id: rule_id.to_string(),
+            rule_name: format!("{} name", rule_id),
+            severity,
+            message: "msg".to_string(),
+            asset_path: path.to_string(),
+            suggestion: suggestion.map(str::to_string),
+            auto_fixable: false,
+            related_paths: None,
+        }
+    }
+
+    #[test]
+    fn maps_rules_levels_and_relative_locations() {
+        let mut analysis = AnalysisResult::new();
+        analysis.add_issue(issue(
+            "texture.pot",
+            Severity::Warning,
+            "/p/Art/a b.png",
+            None,
+        ));
+        analysis.add_issue(issue("custom", Severity::Info, "/p/c.json", Some("fix it")));
+        analysis.add_issue(issue("texture.pot", Severity::Error, "/p/d.png", None));
+        let catalog = vec![RuleDescription::new(
+            "texture.pot",
+            "Power of Two",
+            Severity::Warning,
+            false,
+            "Texture sides should be powers of two.",
+        )];
+
+        let log = serde_json::to_value(build(&analysis, &catalog, "/p")).unwrap();
+        assert_eq!(log["version"], "2.1.0");
+        let run = &log["runs"][0];
+        let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[0]["name"], "Power of Two");
+        assert_eq!(rules[1]["id"], "custom");
+        assert_eq!(rules[1]["defaultConfiguration"]["level"], "note");
+
+        let results = run["results"].as_array().unwrap();
+        let summary: Vec<(&str, u64, &str, &str)> = results
+            .iter()
+            .map(|r| {
+                (
+                    r["ruleId"].as_str().unwrap(),
+                    r["ruleIndex"].as_u64().unwrap(),
+                    r["level"].as_str().unwrap(),
+                    r["locations"][0]["physicalLocation"]["artifactLocation"]["uri"]
+                        .as_str()
+                        .unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("texture.pot", 0, "warning", "Art/a%20b.png"),
+                ("custom", 1, "note", "c.json"),
+                ("texture.pot", 0, "error", "d.png"),
+            ]
+        );
+        assert_eq!(results[1]["message"]["text"], "msg\n\nSuggestion: fix it");
+    }
+}
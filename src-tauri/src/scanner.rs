@@ -3,14 +3,17 @@ use image::ImageDecoder;
 use parking_lot::RwLock;
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs::{self, File};
 use std::path::{Path, PathBuf};
-use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
 use thiserror::Error;
 
+use crate::analyzer::rules::RuleConfig;
 use crate::cache::{get_modified_time, ScanCache};
+use crate::tags;
 
 #[derive(Error, Debug)]
 pub enum ScanError {
@@ -68,6 +71,10 @@ pub struct AssetMetadata {
     pub height: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub has_alpha: Option<bool>,
+    // Bits per color channel from the decoder's color type: 8, 16, or 32
+    // for float images. Only filled for formats the `image` crate opens.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bits_per_channel: Option<u8>,
     // Model metadata
     #[serde(skip_serializing_if = "Option::is_none")]
     pub vertex_count: Option<u32>,
@@ -75,6 +82,15 @@ pub struct AssetMetadata {
     pub face_count: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub material_count: Option<u32>,
+    // Object count for scene-style model files that store several
+    // (`.blend`: Object datablocks; FBX: Model nodes; glTF: nodes — meshes
+    // or not).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub object_count: Option<u32>,
+    // Animation clips stored in the model file (glTF animations, FBX
+    // AnimationStacks). Animation-only exports have no vertices.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub animation_count: Option<u32>,
     // Audio / video metadata (duration is shared)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub duration_secs: Option<f64>,
@@ -96,6 +112,11 @@ pub struct AssetMetadata {
     // Mipmap level count (DDS). 1 = base only, no mipmaps.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mipmap_count: Option<u32>,
+    // GPU texture format as a DXGI name ("BC7_UNORM", "BC3_UNORM", ...),
+    // read from DDS headers; legacy FourCC codes map to their DXGI
+    // equivalent. Absent for formats that don't carry one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub texture_format: Option<String>,
     // DCC tool identifier when the file is an authoring/source format
     // (`.blend` / `.ma` / `.psd` / `.spp` / etc). Values are the stable
     // strings returned by `dcc_source_kind_for` — see that function for
@@ -107,6 +128,25 @@ pub struct AssetMetadata {
     // extensions from its own config, NOT on this field.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub dcc_source_kind: Option<String>,
+    // Version of the DCC tool that last saved the file, where its header
+    // records one (`.blend`: "2.93", "4.2").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dcc_version: Option<String>,
+    // Unity only: the TextureImporter's `maxTextureSize` from the sidecar
+    // `.meta` — the largest dimension the editor imports at. Compared with
+    // `width` / `height` by the `texture.import_size` rule.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub import_max_size: Option<u32>,
+    // Unity only: the TextureImporter's `enableMipMap` from the sidecar
+    // `.meta`. Checked against the texture's role by `texture.mipmaps`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub import_mipmaps: Option<bool>,
+    // Embedded print resolution as (x, y) dots per inch, rounded to 0.1:
+    // PNG `pHYs` in metres, JPEG JFIF density or EXIF X/YResolution.
+    // Absent when the file only stores an aspect ratio or nothing at all —
+    // the usual case for game textures.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dpi: Option<(f32, f32)>,
 }
 
 impl Default for AssetMetadata {
@@ -115,9 +155,12 @@ impl Default for AssetMetadata {
             width: None,
             height: None,
             has_alpha: None,
+            bits_per_channel: None,
             vertex_count: None,
             face_count: None,
             material_count: None,
+            object_count: None,
+            animation_count: None,
             duration_secs: None,
             sample_rate: None,
             channels: None,
@@ -126,7 +169,12 @@ impl Default for AssetMetadata {
             video_codec: None,
             color_space: None,
             mipmap_count: None,
+            texture_format: None,
             dcc_source_kind: None,
+            dcc_version: None,
+            import_max_size: None,
+            import_mipmaps: None,
+            dpi: None,
         }
     }
 }
@@ -149,9 +197,84 @@ pub struct ScanResult {
     pub total_size: u64,
     pub type_counts: HashMap<String, usize>,
     pub project_type: Option<ProjectType>,
+    /// Where the scan spent its time; only filled when the scan was asked
+    /// to collect timings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timings: Option<ScanTimings>,
+    /// Inventory-only scan: every asset's `metadata` is `None` because no
+    /// file was decoded, not because none could be.
+    #[serde(default)]
+    pub metadata_skipped: bool,
+    /// Engine version / product / company; see `ProjectMeta`.
+    #[serde(default)]
+    pub project_info: Option<ProjectMeta>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Engine-side identity of the scanned project, read from the engine's own
+/// settings files. Only Unity fills it during the scan; Unreal and Godot
+/// have their `.uproject` / `project.godot` cards (`get_*_project_info`).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectMeta {
+    /// e.g. "2022.3.10f1".
+    pub engine_version: Option<String>,
+    pub product_name: Option<String>,
+    pub company_name: Option<String>,
+}
+
+/// `ProjectMeta` for a Unity root, from `ProjectVersion.txt` and
+/// `ProjectSettings.asset`. `None` for other engines, or when neither file
+/// could be read.
+fn read_project_meta(root: &Path, project_type: &Option<ProjectType>) -> Option<ProjectMeta> {
+    if !matches!(project_type, Some(ProjectType::Unity)) {
+        return None;
+    }
+    let version = crate::unity::parse_project_version(root);
+    let player = crate::unity::parse_player_settings(root);
+    if version.is_none() && player.is_none() {
+        return None;
+    }
+    let player = player.unwrap_or_default();
+    Some(ProjectMeta {
+        engine_version: version.map(|v| v.editor_version),
+        product_name: player.product_name,
+        company_name: player.company_name,
+    })
+}
+
+/// The engine a project is built with and the version it pins, whichever
+/// engine that is.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct EngineVersion {
+    pub engine: ProjectType,
+    /// As the engine writes it: Unity "2022.3.10f1", Unreal "5.3" (or a
+    /// source build's `{GUID}`), Godot "4.2" (or "4.x" when only the config
+    /// format gives it away).
+    pub version: String,
+}
+
+/// Read the version from the engine's own project file —
+/// `ProjectVersion.txt`, the `.uproject`'s `EngineAssociation`, or
+/// `project.godot`. `None` for generic projects, or when the file is
+/// missing or names no version.
+pub fn read_engine_version(root: &Path, project_type: &ProjectType) -> Option<EngineVersion> {
+    let version = match project_type {
+        ProjectType::Unity => crate::unity::parse_project_version(root)?.editor_version,
+        ProjectType::Unreal => {
+            let uproject = crate::unreal::find_uproject_file(root)?;
+            crate::unreal::parse_uproject(&uproject)?.engine_association?
+        }
+        ProjectType::Godot => {
+            crate::godot::parse_project_godot(&root.join("project.godot"))?.godot_version?
+        }
+        ProjectType::Generic => return None,
+    };
+    (!version.is_empty()).then(|| EngineVersion {
+        engine: project_type.clone(),
+        version,
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum ProjectType {
     Unity,
@@ -166,6 +289,10 @@ pub struct ScanProgress {
     pub current: usize,
     pub total: Option<usize>,
     pub current_file: String,
+    /// Overall completion across all phases, 0–100. Monotonic over a scan
+    /// (see `ScanPhase::progress_span`), unlike `current / total`, which
+    /// restarts per phase.
+    pub percent: f32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -178,6 +305,20 @@ pub enum ScanPhase {
     Cancelled,
 }
 
+impl ScanPhase {
+    /// Slice of the overall progress bar (start, end percent) this phase
+    /// fills. Discovery only stats each file; parsing is where a cold scan
+    /// spends its time; building is a single pass over the in-memory list.
+    fn progress_span(&self) -> (f32, f32) {
+        match self {
+            ScanPhase::Discovering => (0.0, 10.0),
+            ScanPhase::Parsing => (10.0, 95.0),
+            ScanPhase::Building => (95.0, 100.0),
+            ScanPhase::Completed | ScanPhase::Cancelled => (100.0, 100.0),
+        }
+    }
+}
+
 /// Shared scan state for cancellation
 pub struct ScanState {
     pub cancelled: AtomicBool,
@@ -206,11 +347,37 @@ impl ScanState {
         self.cancelled.load(Ordering::SeqCst)
     }
 
+    /// Start `phase` with its counters at `0 / total`. The counters are
+    /// reset before the phase flips, so a progress read never pairs the new
+    /// phase with the previous one's numbers.
+    pub fn set_phase(&self, phase: ScanPhase, total: usize) {
+        self.current.store(0, Ordering::SeqCst);
+        self.total.store(total, Ordering::SeqCst);
+        *self.phase.write() = phase;
+    }
+
     pub fn get_progress(&self) -> ScanProgress {
+        let phase = self.phase.read().clone();
+        let current = self.current.load(Ordering::SeqCst);
+        let total = self.total.load(Ordering::SeqCst);
+
+        // During discovery `total` is only an estimate (the previous scan's
+        // file count, 0 on a cold cache), so the fraction is clamped. A parse
+        // phase with nothing to parse (everything cached) is already done.
+        let fraction = if total > 0 {
+            (current as f32 / total as f32).min(1.0)
+        } else if matches!(phase, ScanPhase::Parsing) {
+            1.0
+        } else {
+            0.0
+        };
+        let (start, end) = phase.progress_span();
+
         ScanProgress {
-            phase: self.phase.read().clone(),
-            current: self.current.load(Ordering::SeqCst),
-            total: Some(self.total.load(Ordering::SeqCst)),
+            percent: start + (end - start) * fraction,
+            phase,
+            current,
+            total: Some(total),
             current_file: self.current_file.read().clone(),
         }
     }
@@ -222,6 +389,100 @@ impl Default for ScanState {
     }
 }
 
+/// Time spent on one extension or parser, summed across rayon threads.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ParseTiming {
+    pub files: u64,
+    pub total_ns: u64,
+}
+
+/// Scan timing breakdown for "why is scanning slow" diagnostics. Phase
+/// times are wall clock; the per-extension and per-parser totals are CPU
+/// time across threads, so they can add up to more than `parse_ms`. Only
+/// files parsed in this scan count — cache hits cost nothing.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ScanTimings {
+    pub discover_ms: u64,
+    pub parse_ms: u64,
+    pub build_ms: u64,
+    /// Keyed by lowercase extension: the whole per-file cost (stat, type
+    /// lookup, metadata parsing, `.meta` sidecar).
+    pub by_extension: BTreeMap<String, ParseTiming>,
+    /// Keyed by parser function name, e.g. `parse_image_metadata`.
+    pub by_parser: BTreeMap<String, ParseTiming>,
+}
+
+#[derive(Default)]
+struct TimingCounter {
+    files: AtomicU64,
+    nanos: AtomicU64,
+}
+
+/// Named atomic counters. The common case — a key seen before — only takes
+/// the read lock, so parser threads don't serialize on it.
+#[derive(Default)]
+struct TimingCounters(RwLock<HashMap<String, TimingCounter>>);
+
+impl TimingCounters {
+    fn add(&self, key: &str, elapsed: Duration) {
+        let nanos = elapsed.as_nanos().min(u64::MAX as u128) as u64;
+        let record = |c: &TimingCounter| {
+            c.files.fetch_add(1, Ordering::Relaxed);
+            c.nanos.fetch_add(nanos, Ordering::Relaxed);
+        };
+        if let Some(counter) = self.0.read().get(key) {
+            record(counter);
+            return;
+        }
+        record(self.0.write().entry(key.to_string()).or_default());
+    }
+
+    fn snapshot(&self) -> BTreeMap<String, ParseTiming> {
+        self.0
+            .read()
+            .iter()
+            .map(|(key, c)| {
+                let timing = ParseTiming {
+                    files: c.files.load(Ordering::Relaxed),
+                    total_ns: c.nanos.load(Ordering::Relaxed),
+                };
+                (key.clone(), timing)
+            })
+            .collect()
+    }
+}
+
+/// Shared by the parse workers of one scan; turned into `ScanTimings` once
+/// the scan finishes.
+#[derive(Default)]
+pub struct TimingCollector {
+    extensions: TimingCounters,
+    parsers: TimingCounters,
+}
+
+impl TimingCollector {
+    fn finish(&self, discover: Duration, parse: Duration, build: Duration) -> ScanTimings {
+        ScanTimings {
+            discover_ms: discover.as_millis() as u64,
+            parse_ms: parse.as_millis() as u64,
+            build_ms: build.as_millis() as u64,
+            by_extension: self.extensions.snapshot(),
+            by_parser: self.parsers.snapshot(),
+        }
+    }
+}
+
+/// Run `f`, charging its wall time to `parser` when timings are collected.
+fn timed<T>(timings: Option<&TimingCollector>, parser: &str, f: impl FnOnce() -> T) -> T {
+    let Some(timings) = timings else {
+        return f();
+    };
+    let start = Instant::now();
+    let out = f();
+    timings.parsers.add(parser, start.elapsed());
+    out
+}
+
 /// Convert a `Path` to a string using forward slashes as the separator.
 ///
 /// All paths we send to the frontend go through this — the frontend expects
@@ -237,6 +498,31 @@ pub(crate) fn path_to_string(path: &Path) -> String {
     }
 }
 
+/// Extension → type overrides from `[scan].extension_overrides`, keyed by
+/// lowercase extension without the dot (see `ScanConfig::normalized_overrides`).
+pub type ExtensionOverrides = HashMap<String, AssetType>;
+
+/// Read the overrides from `<root>/tidycraft.toml`. Lenient on purpose: a
+/// missing or malformed file scans with the built-in table, and the parse
+/// error surfaces from `analyze_assets` instead of failing the scan.
+pub fn load_extension_overrides(root: &Path) -> ExtensionOverrides {
+    fs::read_to_string(root.join("tidycraft.toml"))
+        .ok()
+        .and_then(|content| RuleConfig::from_toml(&content).ok())
+        .map(|config| config.scan.normalized_overrides())
+        .unwrap_or_default()
+}
+
+/// Asset type for an extension, with project overrides taking precedence
+/// over the built-in table.
+fn get_asset_type_with(extension: &str, overrides: &ExtensionOverrides) -> AssetType {
+    let ext = extension.to_lowercase();
+    match overrides.get(&ext) {
+        Some(asset_type) => asset_type.clone(),
+        None => get_asset_type(&ext),
+    }
+}
+
 /// Get asset type from file extension
 fn get_asset_type(extension: &str) -> AssetType {
     match extension.to_lowercase().as_str() {
@@ -245,8 +531,8 @@ fn get_asset_type(extension: &str) -> AssetType {
         // file (1→N, paired against generated PBR textures); .sbs is
         // Substance Designer's source graph (typically produces .sbsar
         // or PNG output).
-        "png" | "jpg" | "jpeg" | "tga" | "psd" | "psb" | "tiff" | "tif" | "exr" | "hdr" | "webp"
-        | "dds" | "bmp" | "gif" | "svg" | "spp" | "sbs" => AssetType::Texture,
+        "png" | "jpg" | "jpeg" | "tga" | "psd" | "psb" | "tiff" | "tif" | "exr" | "hdr"
+        | "webp" | "avif" | "dds" | "bmp" | "gif" | "svg" | "spp" | "sbs" => AssetType::Texture,
         // Models + 3D-source DCC formats. ZBrush (ztl/zpr), Maya
         // (ma/mb), 3ds Max (max), Modo (lxo), Houdini (hip/hipnc/hiplc),
         // Cinema 4D (c4d), Marvelous Designer (zprj — garment, exports
@@ -312,58 +598,164 @@ pub fn dcc_source_kind_for(extension: &str) -> Option<&'static str> {
     }
 }
 
-/// Dispatch metadata parsing for a single asset based on its type + extension.
-/// Used by both the full scan and the incremental per-file reparse so the set
-/// of supported formats lives in one place.
+/// Reads one format's header into `AssetMetadata`; `None` when the file
+/// doesn't parse.
+type MetadataParser = fn(&Path) -> Option<AssetMetadata>;
+
+struct RegisteredParser {
+    /// Parsers only run on assets of their extension's default type, so an
+    /// `[scan.extension_overrides]` entry moving the extension elsewhere
+    /// (say, a `.tga` that isn't an image) turns the parser off.
+    asset_type: AssetType,
+    /// Label in `ScanTimings::by_parser`.
+    name: &'static str,
+    parse: MetadataParser,
+    /// Extra chunk scans run on a successful parse, timed under their own
+    /// labels.
+    chunks: Option<ChunkReader>,
+}
+
+/// Fills `AssetMetadata` fields a format keeps outside its header.
+type ChunkReader = fn(&Path, &mut AssetMetadata, Option<&TimingCollector>);
+
+/// The built-in parsers as `(extensions, timing label, parser)`.
+const BUILTIN_PARSERS: &[(&[&str], &str, MetadataParser)] = &[
+    (
+        &["png", "jpg", "jpeg"],
+        "parse_image_metadata",
+        parse_image_metadata,
+    ),
+    // Other formats the `image` crate fully decodes (enabled via Cargo features).
+    (
+        &["bmp", "gif", "tif", "tiff", "webp", "hdr", "exr"],
+        "parse_image_metadata",
+        parse_image_metadata,
+    ),
+    // TGA has no magic bytes, so `image` can only go by extension and
+    // rejects some exporter variants outright. The fixed 18-byte header is
+    // enough for the texture rules; `image` is only the fallback.
+    (&["tga"], "parse_tga_metadata", parse_tga_or_image_metadata),
+    // DDS has too many compressed sub-formats for `image` to decode
+    // reliably; we parse the header ourselves.
+    (&["dds"], "parse_dds_metadata", parse_dds_metadata),
+    // SVG is vector XML; we just pull width/height from the root tag.
+    (&["svg"], "parse_svg_metadata", parse_svg_metadata),
+    (&["gltf", "glb"], "parse_gltf_metadata", parse_gltf_metadata),
+    (&["obj"], "parse_obj_metadata", parse_obj_metadata),
+    (&["fbx"], "parse_fbx_metadata", parse_fbx_metadata),
+    (&["blend"], "parse_blend_metadata", parse_blend_metadata),
+    (
+        &["mp3", "ogg", "wav"],
+        "parse_audio_metadata",
+        parse_audio_metadata,
+    ),
+    (
+        &["mp4", "mov", "m4v"],
+        "parse_mp4_metadata",
+        parse_mp4_metadata,
+    ),
+    // AVI: no pure-Rust parser we ship with yet.
+    (
+        &["webm", "mkv"],
+        "parse_matroska_metadata",
+        parse_matroska_metadata,
+    ),
+];
+
+/// The built-in chunk scans on top of the image header: PNG's color-space
+/// and resolution chunks, JPEG's resolution.
+const BUILTIN_CHUNK_READERS: &[(&[&str], ChunkReader)] = &[
+    (&["png"], read_png_chunks),
+    (&["jpg", "jpeg"], read_jpeg_chunks),
+];
+
+type ParserRegistry = HashMap<String, RegisteredParser>;
+
+static METADATA_PARSERS: OnceLock<ParserRegistry> = OnceLock::new();
+
+fn metadata_parsers() -> &'static ParserRegistry {
+    METADATA_PARSERS.get_or_init(|| {
+        let mut parsers = ParserRegistry::new();
+        for &(extensions, name, parse) in BUILTIN_PARSERS {
+            for ext in extensions {
+                parsers.insert(
+                    ext.to_string(),
+                    RegisteredParser {
+                        asset_type: get_asset_type(ext),
+                        name,
+                        parse,
+                        chunks: BUILTIN_CHUNK_READERS
+                            .iter()
+                            .find(|(exts, _)| exts.contains(ext))
+                            .map(|&(_, read)| read),
+                    },
+                );
+            }
+        }
+        // Only decodable with the `avif` crate feature; otherwise the open
+        // would just fail on every file.
+        if cfg!(feature = "avif") {
+            parsers.insert(
+                "avif".to_string(),
+                RegisteredParser {
+                    asset_type: AssetType::Texture,
+                    name: "parse_image_metadata",
+                    parse: parse_image_metadata,
+                    chunks: None,
+                },
+            );
+        }
+        parsers
+    })
+}
+
+fn read_png_chunks(path: &Path, m: &mut AssetMetadata, timings: Option<&TimingCollector>) {
+    m.color_space = timed(timings, "parse_png_color_space", || {
+        parse_png_color_space(path)
+    });
+    m.dpi = timed(timings, "parse_png_dpi", || parse_png_dpi(path));
+}
+
+fn read_jpeg_chunks(path: &Path, m: &mut AssetMetadata, timings: Option<&TimingCollector>) {
+    m.dpi = timed(timings, "parse_jpeg_dpi", || parse_jpeg_dpi(path));
+}
+
+fn parse_tga_or_image_metadata(path: &Path) -> Option<AssetMetadata> {
+    parse_tga_metadata(path).or_else(|| parse_image_metadata(path))
+}
+
+/// Dispatch metadata parsing for a single asset through the parser
+/// registry, by extension. Used by both the full scan and the incremental
+/// per-file reparse so the set of supported formats lives in one place.
 ///
 /// After per-format parsing, files identified as a DCC source by
 /// `dcc_source_kind_for` get their `dcc_source_kind` field tagged —
 /// this happens even when format-specific parsing returned None (e.g.
-/// `.blend` has no metadata extractor, but we still want the kind
-/// label so the dcc_source analyzer can find it). For files that are
-/// both DCC sources AND parseable (`.psd` parsed via `image` would
-/// be such a case if we enabled the feature), the parsed metadata is
-/// preserved and the kind field is overlaid.
-fn parse_metadata_for(path: &Path, extension: &str, asset_type: &AssetType) -> Option<AssetMetadata> {
+/// `.ma` has no metadata extractor and a compressed `.blend` can't be
+/// read, but we still want the kind label so the dcc_source analyzer can
+/// find it). For files that are both DCC sources AND parseable (`.blend`),
+/// the parsed metadata is preserved and the kind field is overlaid.
+fn parse_metadata_for(
+    path: &Path,
+    extension: &str,
+    asset_type: &AssetType,
+    timings: Option<&TimingCollector>,
+) -> Option<AssetMetadata> {
     let ext = extension.to_lowercase();
-    let parsed: Option<AssetMetadata> = match asset_type {
-        AssetType::Texture => match ext.as_str() {
-            // PNG gets the color-space chunk scan on top of the image::open pass.
-            "png" => parse_image_metadata(path).map(|mut m| {
-                m.color_space = parse_png_color_space(path);
-                m
-            }),
-            // Other formats the `image` crate fully decodes (enabled via Cargo features).
-            "jpg" | "jpeg" | "bmp" | "gif" | "tga"
-            | "tif" | "tiff" | "webp" | "hdr" | "exr" => parse_image_metadata(path),
-            // DDS has too many compressed sub-formats for `image` to decode
-            // reliably; we parse the header ourselves.
-            "dds" => parse_dds_metadata(path),
-            // SVG is vector XML; we just pull width/height from the root tag.
-            "svg" => parse_svg_metadata(path),
-            _ => None,
-        },
-        AssetType::Model => match ext.as_str() {
-            "gltf" | "glb" => parse_gltf_metadata(path),
-            "obj" => parse_obj_metadata(path),
-            "fbx" => parse_fbx_metadata(path),
-            _ => None,
-        },
-        AssetType::Audio => match ext.as_str() {
-            "mp3" | "ogg" | "wav" => parse_audio_metadata(path),
-            _ => None,
-        },
-        AssetType::Video => match ext.as_str() {
-            "mp4" | "mov" | "m4v" => parse_mp4_metadata(path),
-            "webm" | "mkv" => parse_matroska_metadata(path),
-            _ => None, // AVI: no pure-Rust parser we ship with yet
-        },
-        _ => None,
-    };
+    let parsed = metadata_parsers()
+        .get(&ext)
+        .filter(|p| p.asset_type == *asset_type)
+        .and_then(|p| {
+            let mut m = timed(timings, p.name, || (p.parse)(path))?;
+            if let Some(read) = p.chunks {
+                read(path, &mut m, timings);
+            }
+            Some(m)
+        });
 
     // Tag DCC source kind. Even when format-specific parsing failed
-    // (most authoring formats — .blend, .ma, .psd — have no Rust
-    // parser), we still produce a metadata entry carrying the kind
+    // (most authoring formats — .ma, .psd — have no Rust parser, and
+    // compressed .blend files aren't read), we still produce a metadata entry carrying the kind
     // so the dcc_source analyzer can reason about source/export pairs.
     if let Some(kind) = dcc_source_kind_for(&ext) {
         let mut m = parsed.unwrap_or_default();
@@ -388,11 +780,13 @@ fn parse_image_metadata(path: &Path) -> Option<AssetMetadata> {
         .ok()?;
     let decoder = reader.into_decoder().ok()?;
     let (width, height) = decoder.dimensions();
-    let has_alpha = decoder.color_type().has_alpha();
+    let color_type = decoder.color_type();
+    let bits_per_channel = color_type.bytes_per_pixel() / color_type.channel_count() * 8;
     Some(AssetMetadata {
         width: Some(width),
         height: Some(height),
-        has_alpha: Some(has_alpha),
+        has_alpha: Some(color_type.has_alpha()),
+        bits_per_channel: Some(bits_per_channel),
         ..Default::default()
     })
 }
@@ -495,6 +889,100 @@ fn parse_svg_metadata(path: &Path) -> Option<AssetMetadata> {
     None
 }
 
+/// Parse a TGA header for width/height/alpha.
+///
+/// Layout (18 bytes, little-endian):
+///   0      : ID length
+///   1      : color map type (0 = none, 1 = present)
+///   2      : image type (1/2/3 uncompressed, 9/10/11 RLE)
+///   3..8   : color map spec
+///   8..12  : x/y origin
+///   12..14 : width
+///   14..16 : height
+///   16     : pixel depth (bits)
+///   17     : image descriptor (bits 0..4 = alpha bits per pixel)
+///
+/// With no magic number to check, the field ranges are validated instead so
+/// a mislabeled file yields None rather than garbage dimensions.
+fn parse_tga_metadata(path: &Path) -> Option<AssetMetadata> {
+    let mut file = File::open(path).ok()?;
+    let mut buf = [0u8; 18];
+    std::io::Read::read_exact(&mut file, &mut buf).ok()?;
+
+    let color_map_type = buf[1];
+    let image_type = buf[2];
+    let width = u16::from_le_bytes([buf[12], buf[13]]) as u32;
+    let height = u16::from_le_bytes([buf[14], buf[15]]) as u32;
+    let depth = buf[16];
+    let alpha_bits = buf[17] & 0x0f;
+
+    if color_map_type > 1
+        || !matches!(image_type, 1 | 2 | 3 | 9 | 10 | 11)
+        || !matches!(depth, 8 | 15 | 16 | 24 | 32)
+        || width == 0
+        || height == 0
+    {
+        return None;
+    }
+
+    // Many exporters write 32-bit TGAs with the descriptor's alpha bits
+    // left at 0; a fourth 8-bit channel is alpha either way.
+    let has_alpha = alpha_bits > 0 || depth == 32;
+
+    Some(AssetMetadata {
+        width: Some(width),
+        height: Some(height),
+        has_alpha: Some(has_alpha),
+        ..Default::default()
+    })
+}
+
+/// DXGI name for the DDS formats game pipelines actually ship. Unlisted
+/// values yield None rather than a bare number.
+fn dxgi_format_name(format: u32) -> Option<&'static str> {
+    Some(match format {
+        2 => "R32G32B32A32_FLOAT",
+        10 => "R16G16B16A16_FLOAT",
+        24 => "R10G10B10A2_UNORM",
+        28 => "R8G8B8A8_UNORM",
+        29 => "R8G8B8A8_UNORM_SRGB",
+        49 => "R8G8_UNORM",
+        61 => "R8_UNORM",
+        71 => "BC1_UNORM",
+        72 => "BC1_UNORM_SRGB",
+        74 => "BC2_UNORM",
+        75 => "BC2_UNORM_SRGB",
+        77 => "BC3_UNORM",
+        78 => "BC3_UNORM_SRGB",
+        80 => "BC4_UNORM",
+        81 => "BC4_SNORM",
+        83 => "BC5_UNORM",
+        84 => "BC5_SNORM",
+        87 => "B8G8R8A8_UNORM",
+        91 => "B8G8R8A8_UNORM_SRGB",
+        95 => "BC6H_UF16",
+        96 => "BC6H_SF16",
+        98 => "BC7_UNORM",
+        99 => "BC7_UNORM_SRGB",
+        _ => return None,
+    })
+}
+
+/// DXGI equivalent of a pre-DX10 FourCC code, so legacy and DX10 headers
+/// report the same `texture_format` for the same block format.
+fn legacy_fourcc_format(fourcc: &[u8]) -> Option<&'static str> {
+    Some(match fourcc {
+        b"DXT1" => "BC1_UNORM",
+        b"DXT2" | b"DXT3" => "BC2_UNORM",
+        b"DXT4" | b"DXT5" => "BC3_UNORM",
+        b"ATI1" | b"BC4U" => "BC4_UNORM",
+        b"BC4S" => "BC4_SNORM",
+        b"ATI2" | b"BC5U" => "BC5_UNORM",
+        b"BC5S" => "BC5_SNORM",
+        _ => return None,
+    })
+}
+
 /// Parse DDS (DirectDraw Surface) header for width/height/alpha/mipmap count.
 ///
 /// DDS files are very common for game textures (BC1/BC3/BC7 compressed) but
@@ -517,6 +1005,8 @@ fn parse_svg_metadata(path: &Path) -> Option<AssetMetadata> {
 ///       84..88: ddspf.dwFourCC (compressed format tag, e.g. "DXT5"/"DX10")
 ///   128..148: DDS_HEADER_DXT10 extension, only when FourCC == "DX10"
 ///       128..132: dxgiFormat
+///
+/// The block format is reported as `texture_format` (DXGI naming).
 fn parse_dds_metadata(path: &Path) -> Option<AssetMetadata> {
     const DDPF_ALPHAPIXELS: u32 = 0x1;
     const DDPF_FOURCC: u32 = 0x4;
@@ -541,7 +1031,9 @@ fn parse_dds_metadata(path: &Path) -> Option<AssetMetadata> {
     // For FourCC (compressed) formats, alpha is a property of the block
     // format itself — the ALPHAPIXELS bit only describes uncompressed
     // layouts and is typically 0 on compressed files.
+    let mut texture_format: Option<&str> = None;
     let has_alpha = if (ddspf_flags & DDPF_FOURCC) != 0 {
+        texture_format = legacy_fourcc_format(&buf[84..88]);
         match &buf[84..88] {
             b"DXT2" | b"DXT3" | b"DXT4" | b"DXT5" => Some(true),
             // BC1's optional 1-bit alpha isn't recorded in the header;
@@ -554,8 +1046,10 @@ fn parse_dds_metadata(path: &Path) -> Option<AssetMetadata> {
                 std::io::Read::read_exact(&mut file, &mut dxgi)
                     .ok()
                     .map(|_| {
+                        let format = u32::from_le_bytes(dxgi);
+                        texture_format = dxgi_format_name(format);
                         matches!(
-                            u32::from_le_bytes(dxgi),
+                            format,
                             // block-compressed with alpha: BC2 / BC3 / BC7
                             73..=78 | 97..=99
                             // common uncompressed alpha layouts:
@@ -580,6 +1074,7 @@ fn parse_dds_metadata(path: &Path) -> Option<AssetMetadata> {
         height: Some(height),
         has_alpha,
         mipmap_count,
+        texture_format: texture_format.map(str::to_string),
         ..Default::default()
     })
 }
@@ -654,6 +1149,166 @@ fn parse_png_color_space(path: &Path) -> Option<String> {
     iccp.flatten()
 }
 
+/// Resolution from a PNG `pHYs` chunk. Only unit 1 (per metre) is a real
+/// resolution; unit 0 is just a pixel aspect ratio and reports `None`.
+fn parse_png_dpi(path: &Path) -> Option<(f32, f32)> {
+    use std::io::{Read, Seek, SeekFrom};
+
+    let mut file = File::open(path).ok()?;
+    let mut magic = [0u8; 8];
+    file.read_exact(&mut magic).ok()?;
+    if &magic != b"\x89PNG\r\n\x1a\n" {
+        return None;
+    }
+
+    // pHYs must precede IDAT, so the walk stays in the header area.
+    for _ in 0..64 {
+        let mut head = [0u8; 8];
+        file.read_exact(&mut head).ok()?;
+        let len = u32::from_be_bytes(head[0..4].try_into().ok()?);
+        let kind = &head[4..8];
+
+        if kind == b"IDAT" || kind == b"IEND" {
+            return None;
+        }
+        if kind == b"pHYs" && len == 9 {
+            let mut data = [0u8; 9];
+            file.read_exact(&mut data).ok()?;
+            if data[8] != 1 {
+                return None;
+            }
+            let x = u32::from_be_bytes(data[0..4].try_into().ok()?);
+            let y = u32::from_be_bytes(data[4..8].try_into().ok()?);
+            return dpi_pair(x as f32 * 0.0254, y as f32 * 0.0254);
+        }
+        file.seek(SeekFrom::Current(len as i64 + 4)).ok()?;
+    }
+    None
+}
+
+/// Resolution from a JPEG's JFIF `APP0` density, falling back to the EXIF
+/// `APP1` X/YResolution when JFIF is absent or only gives an aspect ratio.
+fn parse_jpeg_dpi(path: &Path) -> Option<(f32, f32)> {
+    use std::io::{Read, Seek, SeekFrom};
+
+    let mut file = File::open(path).ok()?;
+    let mut soi = [0u8; 2];
+    file.read_exact(&mut soi).ok()?;
+    if soi != [0xFF, 0xD8] {
+        return None;
+    }
+
+    let mut exif: Option<(f32, f32)> = None;
+    // Metadata segments come before the first scan; stop there.
+    for _ in 0..32 {
+        let mut head = [0u8; 4];
+        if file.read_exact(&mut head).is_err()
+            || head[0] != 0xFF
+            || head[1] == 0xDA
+            || head[1] == 0xD9
+        {
+            break;
+        }
+        let Some(len) = u16::from_be_bytes([head[2], head[3]]).checked_sub(2) else {
+            break;
+        };
+        let len = len as usize;
+        match head[1] {
+            0xE0 | 0xE1 => {
+                let mut data = vec![0u8; len];
+                if file.read_exact(&mut data).is_err() {
+                    break;
+                }
+                if head[1] == 0xE0 && data.starts_with(b"JFIF\0") {
+                    if let Some(dpi) = jfif_dpi(&data[5..]) {
+                        return Some(dpi);
+                    }
+                } else if head[1] == 0xE1 && exif.is_none() && data.starts_with(b"Exif\0\0") {
+                    exif = exif_dpi(&data[6..]);
+                }
+            }
+            _ => {
+                if file.seek(SeekFrom::Current(len as i64)).is_err() {
+                    break;
+                }
+            }
+        }
+    }
+    exif
+}
+
+/// JFIF body after the identifier: version (2), units (1), Xdensity (2),
+/// Ydensity (2). Units 1 = dots per inch, 2 = dots per cm, 0 = aspect only.
+fn jfif_dpi(body: &[u8]) -> Option<(f32, f32)> {
+    let unit = *body.get(2)?;
+    let x = u16::from_be_bytes(body.get(3..5)?.try_into().ok()?) as f32;
+    let y = u16::from_be_bytes(body.get(5..7)?.try_into().ok()?) as f32;
+    match unit {
+        1 => dpi_pair(x, y),
+        2 => dpi_pair(x * 2.54, y * 2.54),
+        _ => None,
+    }
+}
+
+/// X/YResolution (RATIONAL) and ResolutionUnit from IFD0 of an EXIF TIFF
+/// block. ResolutionUnit defaults to inches; 3 = centimetres, 1 = none.
+fn exif_dpi(tiff: &[u8]) -> Option<(f32, f32)> {
+    let big_endian = match tiff.get(0..2)? {
+        b"MM" => true,
+        b"II" => false,
+        _ => return None,
+    };
+    let u16_at = |at: usize| -> Option<u16> {
+        let b: [u8; 2] = tiff.get(at..at + 2)?.try_into().ok()?;
+        Some(if big_endian {
+            u16::from_be_bytes(b)
+        } else {
+            u16::from_le_bytes(b)
+        })
+    };
+    let u32_at = |at: usize| -> Option<u32> {
+        let b: [u8; 4] = tiff.get(at..at + 4)?.try_into().ok()?;
+        Some(if big_endian {
+            u32::from_be_bytes(b)
+        } else {
+            u32::from_le_bytes(b)
+        })
+    };
+    let rational_at = |at: usize| -> Option<f32> {
+        let (num, den) = (u32_at(at)?, u32_at(at + 4)?);
+        (den != 0).then(|| num as f32 / den as f32)
+    };
+
+    let ifd = u32_at(4)? as usize;
+    let count = u16_at(ifd)? as usize;
+    let (mut x, mut y, mut unit) = (None, None, 2u16);
+    for i in 0..count.min(256) {
+        let entry = ifd + 2 + i * 12;
+        match u16_at(entry)? {
+            0x011A => x = rational_at(u32_at(entry + 8)? as usize),
+            0x011B => y = rational_at(u32_at(entry + 8)? as usize),
+            0x0128 => unit = u16_at(entry + 8)?,
+            _ => {}
+        }
+    }
+    let (x, y) = (x?, y?);
+    match unit {
+        2 => dpi_pair(x, y),
+        3 => dpi_pair(x * 2.54, y * 2.54),
+        _ => None,
+    }
+}
+
+/// Round to 0.1 DPI (so a PNG's 2835 px/m reads as 72.0, not 72.009) and
+/// reject zero densities, which some exporters write for "unset".
+fn dpi_pair(x: f32, y: f32) -> Option<(f32, f32)> {
+    if !(x > 0.0 && y > 0.0) {
+        return None;
+    }
+    let round = |v: f32| (v * 10.0).round() / 10.0;
+    Some((round(x), round(y)))
+}
+
 /// Decode a PNG `iCCP` chunk payload — `[profile name][NUL][compression
 /// method byte][zlib stream]` — and classify the embedded ICC profile.
 /// `None` = unreadable/unknown, so the colorspace rule stays silent rather
@@ -842,6 +1497,8 @@ fn parse_gltf_metadata(path: &Path) -> Option<AssetMetadata> {
                 vertex_count: Some(vertex_count),
                 face_count: Some(face_count),
                 material_count: Some(gltf.materials().count() as u32),
+                object_count: Some(gltf.nodes().count() as u32),
+                animation_count: Some(gltf.animations().count() as u32),
                 ..Default::default()
             })
         }
@@ -849,7 +1506,8 @@ fn parse_gltf_metadata(path: &Path) -> Option<AssetMetadata> {
     }
 }
 
-/// Parse FBX model metadata (vertex/face/material count).
+/// Parse FBX model metadata (vertex / face / material / object / animation
+/// counts).
 ///
 /// FBX is Autodesk's proprietary interchange format — both binary (most common
 /// today) and ASCII variants exist. `fbxcel-dom` handles both and gives us a
@@ -877,6 +1535,8 @@ fn parse_fbx_metadata(path: &Path) -> Option<AssetMetadata> {
     let mut vertex_count: u64 = 0;
     let mut face_count: u64 = 0;
     let mut material_count: u32 = 0;
+    let mut object_count: u32 = 0;
+    let mut animation_count: u32 = 0;
 
     for obj in doc.objects() {
         match obj.get_typed() {
@@ -902,11 +1562,22 @@ fn parse_fbx_metadata(path: &Path) -> Option<AssetMetadata> {
             TypedObjectHandle::Material(_) => {
                 material_count = material_count.saturating_add(1);
             }
-            _ => {}
+            // Bones, cameras and mesh transforms are all `Model` nodes; a
+            // take is an `AnimationStack`.
+            _ => match obj.node().name() {
+                "Model" => object_count = object_count.saturating_add(1),
+                "AnimationStack" => animation_count = animation_count.saturating_add(1),
+                _ => {}
+            },
         }
     }
 
-    if vertex_count == 0 && face_count == 0 && material_count == 0 {
+    if vertex_count == 0
+        && face_count == 0
+        && material_count == 0
+        && object_count == 0
+        && animation_count == 0
+    {
         return None;
     }
 
@@ -914,6 +1585,8 @@ fn parse_fbx_metadata(path: &Path) -> Option<AssetMetadata> {
         vertex_count: Some(vertex_count.min(u32::MAX as u64) as u32),
         face_count: Some(face_count.min(u32::MAX as u64) as u32),
         material_count: Some(material_count),
+        object_count: Some(object_count),
+        animation_count: Some(animation_count),
         ..Default::default()
     })
 }
@@ -946,6 +1619,258 @@ fn parse_obj_metadata(path: &Path) -> Option<AssetMetadata> {
     }
 }
 
+/// `.blend` file header: how the rest of the file is laid out.
+struct BlendHeader {
+    pointer_size: usize,
+    little_endian: bool,
+    /// Blender 5.0+ files: 64-bit block lengths, fields reordered.
+    large_blocks: bool,
+    version: String,
+}
+
+/// One file-block header. `code` names the datablock kind (`ME\0\0` for a
+/// Mesh, `OB\0\0` Object, `DNA1` the struct catalog, `ENDB` the end).
+struct BlendBlock {
+    code: [u8; 4],
+    len: u64,
+}
+
+impl BlendHeader {
+    /// `BLENDER_v293` (pointer size `_` = 4 / `-` = 8, `v` / `V` = little /
+    /// big endian, version 2.93), or from 5.0 on `BLENDER17-01v0500`.
+    fn read(reader: &mut impl std::io::Read) -> Option<Self> {
+        let mut head = [0u8; 12];
+        reader.read_exact(&mut head).ok()?;
+        if &head[..7] != b"BLENDER" {
+            return None;
+        }
+        if head[7].is_ascii_digit() {
+            // `17` is the header length; 5 more bytes after these 12.
+            let mut rest = [0u8; 5];
+            reader.read_exact(&mut rest).ok()?;
+            if &head[7..10] != b"17-" || rest[0] != b'v' {
+                return None;
+            }
+            let digits = std::str::from_utf8(&rest[1..]).ok()?;
+            let (major, minor) = (
+                digits[..2].parse::<u32>().ok()?,
+                digits[2..].parse::<u32>().ok()?,
+            );
+            return Some(Self {
+                pointer_size: 8,
+                little_endian: true,
+                large_blocks: true,
+                version: format!("{}.{}", major, minor),
+            });
+        }
+        let pointer_size = match head[7] {
+            b'_' => 4,
+            b'-' => 8,
+            _ => return None,
+        };
+        let little_endian = match head[8] {
+            b'v' => true,
+            b'V' => false,
+            _ => return None,
+        };
+        let digits = std::str::from_utf8(&head[9..12]).ok()?;
+        let (major, minor) = (
+            digits[..1].parse::<u32>().ok()?,
+            digits[1..].parse::<u32>().ok()?,
+        );
+        Some(Self {
+            pointer_size,
+            little_endian,
+            large_blocks: false,
+            version: format!("{}.{}", major, minor),
+        })
+    }
+
+    fn u32_at(&self, bytes: &[u8], at: usize) -> Option<u32> {
+        let b: [u8; 4] = bytes.get(at..at + 4)?.try_into().ok()?;
+        Some(if self.little_endian {
+            u32::from_le_bytes(b)
+        } else {
+            u32::from_be_bytes(b)
+        })
+    }
+
+    fn u16_at(&self, bytes: &[u8], at: usize) -> Option<u16> {
+        let b: [u8; 2] = bytes.get(at..at + 2)?.try_into().ok()?;
+        Some(if self.little_endian {
+            u16::from_le_bytes(b)
+        } else {
+            u16::from_be_bytes(b)
+        })
+    }
+
+    /// The next block header, leaving `reader` at the block's data.
+    fn read_block(&self, reader: &mut impl std::io::Read) -> Option<BlendBlock> {
+        // Legacy: code, i32 len, pointer, i32 SDNA index, i32 count.
+        // Large: code, i32 SDNA index, u64 pointer, i64 len, i64 count.
+        let size = if self.large_blocks {
+            32
+        } else {
+            16 + self.pointer_size
+        };
+        let mut head = [0u8; 32];
+        reader.read_exact(&mut head[..size]).ok()?;
+        let code: [u8; 4] = head[..4].try_into().ok()?;
+        let len = if self.large_blocks {
+            let b: [u8; 8] = head[16..24].try_into().ok()?;
+            u64::from_le_bytes(b)
+        } else {
+            self.u32_at(&head, 4)? as u64
+        };
+        Some(BlendBlock { code, len })
+    }
+
+    /// Byte offset of the first of `fields` present in struct `name`, per
+    /// the file's SDNA (`DNA1` block). DNA structs carry explicit padding,
+    /// so an offset is the plain sum of the preceding field sizes.
+    fn field_offset(&self, dna: &[u8], name: &str, fields: &[&str]) -> Option<usize> {
+        /// Skip a 4-byte section tag, reporting whether it was `tag`.
+        fn expect(dna: &[u8], tag: &[u8], at: &mut usize) -> bool {
+            let ok = dna.get(*at..*at + 4) == Some(tag);
+            *at += 4;
+            ok
+        }
+        /// `count` NUL-terminated names, then padding to 4 bytes.
+        fn strings<'a>(dna: &'a [u8], at: &mut usize, count: usize) -> Option<Vec<&'a str>> {
+            let mut out = Vec::with_capacity(count);
+            for _ in 0..count {
+                let len = dna.get(*at..)?.iter().position(|&b| b == 0)?;
+                out.push(std::str::from_utf8(&dna[*at..*at + len]).ok()?);
+                *at += len + 1;
+            }
+            *at = (*at + 3) & !3;
+            Some(out)
+        }
+
+        let mut at = 0;
+        if !expect(dna, b"SDNA", &mut at) || !expect(dna, b"NAME", &mut at) {
+            return None;
+        }
+        let count = self.u32_at(dna, at)? as usize;
+        at += 4;
+        let names = strings(dna, &mut at, count)?;
+        if !expect(dna, b"TYPE", &mut at) {
+            return None;
+        }
+        let count = self.u32_at(dna, at)? as usize;
+        at += 4;
+        let types = strings(dna, &mut at, count)?;
+        if !expect(dna, b"TLEN", &mut at) {
+            return None;
+        }
+        let lengths: Vec<usize> = (0..types.len())
+            .map(|i| self.u16_at(dna, at + i * 2).map(usize::from))
+            .collect::<Option<_>>()?;
+        at = (at + types.len() * 2 + 3) & !3;
+        if !expect(dna, b"STRC", &mut at) {
+            return None;
+        }
+        let count = self.u32_at(dna, at)? as usize;
+        at += 4;
+
+        for _ in 0..count {
+            let struct_type = self.u16_at(dna, at)? as usize;
+            let field_count = self.u16_at(dna, at + 2)? as usize;
+            at += 4;
+            let is_target = types.get(struct_type) == Some(&name);
+            let mut offset = 0;
+            for i in 0..field_count {
+                let field_type = self.u16_at(dna, at + i * 4)? as usize;
+                let field_name = *names.get(self.u16_at(dna, at + i * 4 + 2)? as usize)?;
+                let is_pointer = field_name.starts_with('*') || field_name.starts_with('(');
+                let base = field_name
+                    .trim_start_matches(['*', '('])
+                    .split(['[', ')'])
+                    .next()
+                    .unwrap_or_default();
+                if is_target && !is_pointer && fields.contains(&base) {
+                    return Some(offset);
+                }
+                let elements: usize = field_name
+                    .split('[')
+                    .skip(1)
+                    .filter_map(|dim| dim.split(']').next()?.parse::<usize>().ok())
+                    .product();
+                let size = if is_pointer {
+                    self.pointer_size
+                } else {
+                    *lengths.get(field_type)?
+                };
+                offset += size * elements;
+            }
+            if is_target {
+                return None;
+            }
+            at += field_count * 4;
+        }
+        None
+    }
+}
+
+/// Parse Blender `.blend` metadata by walking the file-block list: the
+/// saving version from the header, Object and Material datablock counts,
+/// and vertex / face totals summed over Mesh blocks. The Mesh fields
+/// (`totvert` / `totpoly`, `verts_num` / `faces_num` in newer files) are
+/// located through the file's own SDNA, so no per-version layout is
+/// hard-coded. Compressed files (gzip, or zstd since 3.0) return `None`;
+/// the scan still tags them as Blender sources.
+fn parse_blend_metadata(path: &Path) -> Option<AssetMetadata> {
+    use std::io::{BufReader, Read, Seek, SeekFrom};
+
+    // The struct catalog is a few hundred KB; anything far larger is not one.
+    const DNA_CAP: u64 = 16 << 20;
+
+    let mut reader = BufReader::new(File::open(path).ok()?);
+    let header = BlendHeader::read(&mut reader)?;
+    let mut mesh_blocks: Vec<u64> = Vec::new();
+    let (mut objects, mut materials) = (0u32, 0u32);
+    let mut dna: Option<Vec<u8>> = None;
+    while let Some(block) = header.read_block(&mut reader) {
+        match &block.code {
+            b"ENDB" => break,
+            b"DNA1" if block.len <= DNA_CAP => {
+                let mut buf = vec![0u8; block.len as usize];
+                reader.read_exact(&mut buf).ok()?;
+                dna = Some(buf);
+                continue;
+            }
+            b"ME\0\0" => mesh_blocks.push(reader.stream_position().ok()?),
+            b"OB\0\0" => objects += 1,
+            b"MA\0\0" => materials += 1,
+            _ => {}
+        }
+        reader.seek_relative(i64::try_from(block.len).ok()?).ok()?;
+    }
+
+    let mesh_total = |reader: &mut BufReader<File>, fields: &[&str]| -> Option<u32> {
+        let offset = header.field_offset(dna.as_deref()?, "Mesh", fields)? as u64;
+        let mut total: u64 = 0;
+        for &start in &mesh_blocks {
+            let mut value = [0u8; 4];
+            reader.seek(SeekFrom::Start(start + offset)).ok()?;
+            reader.read_exact(&mut value).ok()?;
+            total += header.u32_at(&value, 0)? as u64;
+        }
+        Some(total.min(u32::MAX as u64) as u32)
+    };
+    let vertex_count = mesh_total(&mut reader, &["totvert", "verts_num"]);
+    let face_count = mesh_total(&mut reader, &["totpoly", "faces_num"]);
+
+    Some(AssetMetadata {
+        vertex_count,
+        face_count,
+        material_count: Some(materials),
+        object_count: Some(objects),
+        dcc_version: Some(header.version),
+        ..Default::default()
+    })
+}
+
 /// Parse MP4 / MOV / M4V container metadata: duration, resolution, framerate,
 /// and the first video track's codec. Uses the pure-Rust `mp4` crate.
 fn parse_mp4_metadata(path: &Path) -> Option<AssetMetadata> {
@@ -1082,39 +2007,89 @@ fn meta_modified_time(path: &Path) -> Option<u64> {
     get_modified_time(Path::new(&p))
 }
 
-/// Parse Unity .meta file to get GUID
-fn parse_unity_meta(path: &Path) -> Option<String> {
+/// What the scan keeps from a Unity `.meta` sidecar.
+struct UnityMeta {
+    guid: Option<String>,
+    /// TextureImporter `maxTextureSize`; absent for non-texture importers.
+    max_texture_size: Option<u32>,
+    /// TextureImporter `enableMipMap`; absent for non-texture importers.
+    mipmaps: Option<bool>,
+}
+
+/// The existing `.meta` sidecar of `path`, if any. Shared by the scan and
+/// `find_missing_meta`, so both agree on which assets have one.
+pub(crate) fn unity_meta_path(path: &Path) -> Option<PathBuf> {
     let meta_path = path.with_extension(format!(
         "{}.meta",
         path.extension().unwrap_or_default().to_str().unwrap_or("")
     ));
 
     // Try the standard .meta path
-    let meta_file_path = if meta_path.exists() {
-        meta_path
+    if meta_path.exists() {
+        Some(meta_path)
     } else {
         // Try appending .meta to full path
         let mut p = path.as_os_str().to_owned();
         p.push(".meta");
-        let p = Path::new(&p);
-        if p.exists() {
-            p.to_path_buf()
-        } else {
-            return None;
-        }
-    };
+        let p = PathBuf::from(p);
+        p.exists().then_some(p)
+    }
+}
 
-    let content = fs::read_to_string(meta_file_path).ok()?;
+/// Parse Unity .meta file to get the GUID and texture import settings
+fn parse_unity_meta(path: &Path) -> Option<UnityMeta> {
+    let content = fs::read_to_string(unity_meta_path(path)?).ok()?;
+    Some(parse_unity_meta_content(&content))
+}
 
-    // Parse GUID from meta file (simple regex-like approach)
-    for line in content.lines() {
-        let line = line.trim();
-        if line.starts_with("guid:") {
-            return Some(line[5..].trim().to_string());
-        }
+/// Fold an asset's `.meta` sidecar into its scan entry: returns the GUID,
+/// and records a texture's import cap and mipmap flag next to its parsed
+/// dimensions.
+fn apply_unity_meta(
+    path: &Path,
+    asset_type: &AssetType,
+    metadata: &mut Option<AssetMetadata>,
+) -> Option<String> {
+    let meta = parse_unity_meta(path)?;
+    if matches!(asset_type, AssetType::Texture)
+        && (meta.max_texture_size.is_some() || meta.mipmaps.is_some())
+    {
+        let metadata = metadata.get_or_insert_with(AssetMetadata::default);
+        metadata.import_max_size = meta.max_texture_size;
+        metadata.import_mipmaps = meta.mipmaps;
     }
+    meta.guid
+}
 
-    None
+fn parse_unity_meta_content(content: &str) -> UnityMeta {
+    // Parse GUID from meta file (simple regex-like approach)
+    let guid = content
+        .lines()
+        .map(str::trim)
+        .find_map(|line| line.strip_prefix("guid:"))
+        .map(|v| v.trim().to_string());
+
+    // The importer-wide `maxTextureSize` sits directly under
+    // `TextureImporter:` and precedes the per-platform overrides in
+    // `platformSettings`, so the first occurrence is the default cap.
+    let max_texture_size = content
+        .lines()
+        .skip_while(|line| line.trim_end() != "TextureImporter:")
+        .find_map(|line| line.trim().strip_prefix("maxTextureSize:"))
+        .and_then(|v| v.trim().parse().ok());
+    // `enableMipMap` lives in the importer's `mipmaps:` block; only the
+    // TextureImporter has one.
+    let mipmaps = content
+        .lines()
+        .skip_while(|line| line.trim_end() != "TextureImporter:")
+        .find_map(|line| line.trim().strip_prefix("enableMipMap:"))
+        .map(|v| v.trim() != "0");
+
+    UnityMeta {
+        guid,
+        max_texture_size,
+        mipmaps,
+    }
 }
 
 /// Detect project type based on marker files
@@ -1191,13 +2166,17 @@ fn precompute_dir_stats(assets: &[AssetInfo]) -> HashMap<String, DirStats> {
 /// looks at — got fully re-walked on every scan AND every watcher batch,
 /// and showed up in the sidebar tree even though none of its files exist
 /// in the scan result.
+///
+/// `include_hidden` matches the scan's: dot-directories are pruned unless
+/// it's set (`.git/` always is, see `skips_entry_name`).
 pub(crate) fn build_directory_tree(
     root: &Path,
     assets: &[AssetInfo],
     ignore: Option<&IgnoreMatcher>,
+    include_hidden: bool,
 ) -> DirectoryNode {
     let stats = precompute_dir_stats(assets);
-    build_dir_node(root, root, &stats, ignore)
+    build_dir_node(root, root, &stats, ignore, include_hidden)
 }
 
 fn build_dir_node(
@@ -1205,6 +2184,7 @@ fn build_dir_node(
     root: &Path,
     stats: &HashMap<String, DirStats>,
     ignore: Option<&IgnoreMatcher>,
+    include_hidden: bool,
 ) -> DirectoryNode {
     let name = path
         .file_name()
@@ -1221,7 +2201,7 @@ fn build_dir_node(
             let entry_path = entry.path();
             if entry_path.is_dir() {
                 let dir_name = entry_path.file_name().unwrap_or_default().to_string_lossy();
-                if dir_name.starts_with('.') {
+                if skips_entry_name(&dir_name, include_hidden) {
                     continue;
                 }
                 if let (Some(matcher), Ok(rel)) = (ignore, entry_path.strip_prefix(root)) {
@@ -1229,7 +2209,13 @@ fn build_dir_node(
                         continue;
                     }
                 }
-                children.push(build_dir_node(&entry_path, root, stats, ignore));
+                children.push(build_dir_node(
+                    &entry_path,
+                    root,
+                    stats,
+                    ignore,
+                    include_hidden,
+                ));
             }
         }
     }
@@ -1252,17 +2238,145 @@ fn build_dir_node(
     }
 }
 
+impl DirectoryNode {
+    /// Patch a tree from `build_directory_tree` for files added and removed
+    /// since, touching only their ancestor chains: missing directories are
+    /// created, and a directory left empty is pruned once it's gone from
+    /// disk (an empty one still there stays, as in a full build). Removals
+    /// take the assets as they were counted — the tree keeps no per-file
+    /// sizes — so a modified file is its old version removed plus its new
+    /// one added.
+    pub fn apply_delta(&mut self, added: &[AssetInfo], removed: &[AssetInfo]) {
+        for asset in removed {
+            if let Some(dirs) = self.dirs_to(&asset.path) {
+                let dirs: Vec<&str> = dirs.iter().map(String::as_str).collect();
+                self.remove_file(&dirs, asset.size);
+            }
+        }
+        for asset in added {
+            if let Some(dirs) = self.dirs_to(&asset.path) {
+                let dirs: Vec<&str> = dirs.iter().map(String::as_str).collect();
+                self.add_file(&dirs, asset.size);
+            }
+        }
+    }
+
+    /// Directory names from this node down to `file`'s parent; `None` when
+    /// the file isn't under this node.
+    fn dirs_to(&self, file: &str) -> Option<Vec<String>> {
+        let parent = Path::new(file).parent()?;
+        let rel = parent.strip_prefix(&self.path).ok()?;
+        Some(
+            rel.components()
+                .map(|c| c.as_os_str().to_string_lossy().to_string())
+                .collect(),
+        )
+    }
+
+    fn add_file(&mut self, dirs: &[&str], size: u64) {
+        self.file_count += 1;
+        self.total_size += size;
+        let Some((first, rest)) = dirs.split_first() else {
+            return;
+        };
+        let index = match self.children.iter().position(|c| c.name == *first) {
+            Some(index) => index,
+            None => {
+                let key = first.to_lowercase();
+                let index = self
+                    .children
+                    .partition_point(|c| c.name.to_lowercase() < key);
+                self.children.insert(
+                    index,
+                    DirectoryNode {
+                        name: first.to_string(),
+                        path: path_to_string(&Path::new(&self.path).join(first)),
+                        children: Vec::new(),
+                        file_count: 0,
+                        total_size: 0,
+                    },
+                );
+                index
+            }
+        };
+        self.children[index].add_file(rest, size);
+    }
+
+    /// Whether the file's directory was in the tree; counts only change
+    /// when it was.
+    fn remove_file(&mut self, dirs: &[&str], size: u64) -> bool {
+        if let Some((first, rest)) = dirs.split_first() {
+            let Some(index) = self.children.iter().position(|c| c.name == *first) else {
+                return false;
+            };
+            if !self.children[index].remove_file(rest, size) {
+                return false;
+            }
+            let child = &self.children[index];
+            if child.file_count == 0
+                && child.children.is_empty()
+                && !Path::new(&child.path).is_dir()
+            {
+                self.children.remove(index);
+            }
+        }
+        self.file_count = self.file_count.saturating_sub(1);
+        self.total_size = self.total_size.saturating_sub(size);
+        true
+    }
+}
+
+/// Files and directories Tidycraft itself writes into a project. A feature
+/// that adds one registers its name here so scans, the watcher and the
+/// fingerprint never report the tool's own metadata as assets. Undo history
+/// and the scan cache live in the user's data / cache dirs, and deletes go
+/// to the OS trash, so only the tags file is in the tree today.
+pub(crate) const SIDECAR_NAMES: &[&str] = &[tags::TAGS_FILE];
+
+/// Whether `name` is a registered sidecar, or one of its `fs_atomic` temp
+/// (`<name>.tmp.*`) / corrupt-backup (`<name>.corrupt`) siblings.
+pub(crate) fn is_sidecar_name(name: &str) -> bool {
+    SIDECAR_NAMES.iter().any(|sidecar| {
+        name.strip_prefix(sidecar)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+    })
+}
+
+/// Whether a file or directory named `name` stays out of the scan:
+/// Tidycraft's own sidecars always, then any dot-prefixed name by default,
+/// and only `.git` with `include_hidden` — the repository's object store is
+/// never assets, and walking it would cost more than the project itself.
+pub(crate) fn skips_entry_name(name: &str, include_hidden: bool) -> bool {
+    if is_sidecar_name(name) {
+        true
+    } else if include_hidden {
+        name == ".git"
+    } else {
+        name.starts_with('.')
+    }
+}
+
 /// Build the directory walker. When `respect_gitignore` is true the
 /// walker honors `.gitignore` (incl. parent dirs and `.git/info/exclude`)
 /// and `.ignore` files; `require_git(false)` makes the gitignore rules
 /// apply even outside a git repo. Hidden files and directories
-/// (`.git/`, `.vscode/`, `.idea/`, etc.) are always skipped — matches
-/// the user-visible behavior of the previous walkdir filter (which
-/// only checked `starts_with('.')` at the file-name level after
-/// recursing wastefully into dot dirs).
-fn build_walker(root: &Path, respect_gitignore: bool) -> ignore::Walk {
+/// (`.git/`, `.vscode/`, `.idea/`, etc.) are skipped unless
+/// `include_hidden`, and Tidycraft's sidecars (`SIDECAR_NAMES`) always
+/// are — matches the user-visible behavior of the previous
+/// walkdir filter (which only checked `starts_with('.')` at the file-name
+/// level after recursing wastefully into dot dirs).
+pub(crate) fn build_walker(
+    root: &Path,
+    respect_gitignore: bool,
+    include_hidden: bool,
+) -> ignore::Walk {
     let mut builder = WalkBuilder::new(root);
-    builder.follow_links(false).hidden(true);
+    builder
+        .follow_links(false)
+        .hidden(!include_hidden)
+        .filter_entry(move |entry| {
+            !skips_entry_name(&entry.file_name().to_string_lossy(), include_hidden)
+        });
     if respect_gitignore {
         builder
             .git_ignore(true)
@@ -1346,6 +2460,7 @@ pub fn build_gitignore_matcher(root: &Path, respect_gitignore: bool) -> Option<I
 /// Scan a directory with optional state for progress tracking and
 /// cancellation. `respect_gitignore=true` honors the user's
 /// `.gitignore` / `.ignore` files; `false` re-enables "scan everything".
+/// `include_hidden=true` walks dot-prefixed files and directories too.
 ///
 /// The shipped scan path is `scan_directory_incremental`; since the legacy
 /// non-incremental commands were removed this full-scan variant survives as
@@ -1356,6 +2471,7 @@ pub fn scan_directory_with_state(
     path: &str,
     state: Option<Arc<ScanState>>,
     respect_gitignore: bool,
+    include_hidden: bool,
 ) -> Result<ScanResult, ScanError> {
     let root_path = Path::new(path);
 
@@ -1372,15 +2488,16 @@ pub fn scan_directory_with_state(
 
     // Detect project type
     let project_type = detect_project_type(root_path);
+    let overrides = load_extension_overrides(root_path);
 
     // Phase 1: Discover all files
     if let Some(ref s) = state {
-        *s.phase.write() = ScanPhase::Discovering;
+        s.set_phase(ScanPhase::Discovering, 0);
     }
 
     let mut file_paths: Vec<PathBuf> = Vec::new();
 
-    for result in build_walker(root_path, respect_gitignore) {
+    for result in build_walker(root_path, respect_gitignore, include_hidden) {
         let entry = match result {
             Ok(e) => e,
             // Walk errors (permission denied on a sibling, transient IO
@@ -1396,8 +2513,7 @@ pub fn scan_directory_with_state(
         }
 
         // Hidden files and dot-directories are filtered upstream by
-        // `build_walker(hidden=true)`, so no `starts_with('.')` check
-        // is needed here.
+        // `build_walker`, so no `starts_with('.')` check is needed here.
         if entry.file_type().map_or(false, |ft| ft.is_dir()) {
             continue;
         }
@@ -1426,13 +2542,10 @@ pub fn scan_directory_with_state(
     }
 
     let total_files = file_paths.len();
-    if let Some(ref s) = state {
-        s.total.store(total_files, Ordering::SeqCst);
-    }
 
     // Phase 2: Parse all files in parallel
     if let Some(ref s) = state {
-        *s.phase.write() = ScanPhase::Parsing;
+        s.set_phase(ScanPhase::Parsing, total_files);
     }
 
     // Parse files in parallel using rayon
@@ -1481,13 +2594,13 @@ pub fn scan_directory_with_state(
                 .unwrap_or(0);
 
             // Determine asset type
-            let asset_type = get_asset_type(&extension);
+            let asset_type = get_asset_type_with(&extension, &overrides);
 
-            let asset_metadata = parse_metadata_for(entry_path, &extension, &asset_type);
+            let mut asset_metadata = parse_metadata_for(entry_path, &extension, &asset_type, None);
 
-            // Try to get Unity GUID if it's a Unity project
+            // Try to get Unity GUID (and texture import cap) if it's a Unity project
             let unity_guid = if matches!(project_type_clone, Some(ProjectType::Unity)) {
-                parse_unity_meta(entry_path)
+                apply_unity_meta(entry_path, &asset_type, &mut asset_metadata)
             } else {
                 None
             };
@@ -1544,11 +2657,12 @@ pub fn scan_directory_with_state(
 
     // Phase 3: Build directory tree
     if let Some(ref s) = state {
-        *s.phase.write() = ScanPhase::Building;
+        s.set_phase(ScanPhase::Building, assets.len());
     }
 
     let tree_ignore = build_gitignore_matcher(root_path, respect_gitignore);
-    let directory_tree = build_directory_tree(root_path, &assets, tree_ignore.as_ref());
+    let directory_tree =
+        build_directory_tree(root_path, &assets, tree_ignore.as_ref(), include_hidden);
 
     let total_count = assets.len();
     let total_size = assets.iter().map(|a| a.size).sum();
@@ -1564,14 +2678,23 @@ pub fn scan_directory_with_state(
         total_count,
         total_size,
         type_counts,
+        project_info: read_project_meta(root_path, &project_type),
         project_type,
+        timings: None,
+        metadata_skipped: false,
     })
 }
 
-/// Parse a single asset file and return AssetInfo
+/// Parse a single asset file and return AssetInfo. With `timings`, the
+/// per-file cost is charged to its extension and each parser to its name.
+/// Without `parse_metadata` no parser runs and `metadata` is `None`; the
+/// Unity GUID is still read.
 pub fn parse_asset_file(
     path: &Path,
     project_type: &Option<ProjectType>,
+    overrides: &ExtensionOverrides,
+    timings: Option<&TimingCollector>,
+    parse_metadata: bool,
 ) -> Option<AssetInfo> {
     let file_name = path
         .file_name()
@@ -1586,6 +2709,7 @@ pub fn parse_asset_file(
     if extension.is_empty() {
         return None;
     }
+    let start = timings.map(|_| Instant::now());
 
     // Get file metadata
     let metadata = path.metadata().ok()?;
@@ -1597,18 +2721,31 @@ pub fn parse_asset_file(
         .map(|d| d.as_secs())
         .unwrap_or(0);
 
-    // Determine asset type
-    let asset_type = get_asset_type(&extension);
+    // Determine asset type. Metadata dispatch follows the overridden type,
+    // so an extension mapped away from Texture is never decoded as an image.
+    let asset_type = get_asset_type_with(&extension, overrides);
 
-    let asset_metadata = parse_metadata_for(path, &extension, &asset_type);
+    let mut asset_metadata = if parse_metadata {
+        parse_metadata_for(path, &extension, &asset_type, timings)
+    } else {
+        None
+    };
 
-    // Try to get Unity GUID if it's a Unity project
+    // Try to get Unity GUID (and texture import cap) if it's a Unity project
     let unity_guid = if matches!(project_type, Some(ProjectType::Unity)) {
-        parse_unity_meta(path)
+        timed(timings, "apply_unity_meta", || {
+            apply_unity_meta(path, &asset_type, &mut asset_metadata)
+        })
     } else {
         None
     };
 
+    if let (Some(timings), Some(start)) = (timings, start) {
+        timings
+            .extensions
+            .add(&extension.to_lowercase(), start.elapsed());
+    }
+
     Some(AssetInfo {
         path: path_to_string(path),
         name: file_name,
@@ -1616,7 +2753,8 @@ pub fn parse_asset_file(
         asset_type,
         size,
         modified,
-        metadata: asset_metadata,
+        // The `.meta` may have added a texture's import cap.
+        metadata: asset_metadata.filter(|_| parse_metadata),
         unity_guid,
     })
 }
@@ -1627,13 +2765,207 @@ pub fn parse_asset_file(
 /// everything" run will cause newly-ignored files to look "deleted"
 /// and get pruned from the cache on the next run — desired but worth
 /// noting for users who flip the setting.
+///
+/// `collect_timings` fills `ScanResult::timings`; off by default because
+/// the per-parser clock reads aren't free on hundred-thousand-file scans.
+///
+/// `parse_metadata = false` is the inventory-only fast path: no file is
+/// decoded, every asset comes back with `metadata: None` (cached ones
+/// included) and the tree, counts and sizes fill in as usual. The cache is
+/// left untouched, so the next full scan parses what it needs.
 pub fn scan_directory_incremental(
     path: &str,
     state: Option<Arc<ScanState>>,
     respect_gitignore: bool,
+    include_hidden: bool,
+    collect_timings: bool,
+    parse_metadata: bool,
 ) -> Result<(ScanResult, IncrementalStats), ScanError> {
     let root_path = Path::new(path);
+    check_scan_root(root_path, path)?;
+    let (result, stats, _) = scan_roots_incremental(
+        &[root_path],
+        path,
+        &load_extension_overrides(root_path),
+        state,
+        respect_gitignore,
+        include_hidden,
+        collect_timings,
+        parse_metadata,
+    )?;
+    Ok((result, stats))
+}
+
+/// Whether a walker entry is a file the scan picks up: not a directory, not
+/// a Unity `.meta`, and with an extension. Hidden files and dirs are
+/// filtered upstream by `build_walker`; `.meta` is Unity per-asset
+/// metadata, surfaced via `unity_guid`.
+fn is_scannable_entry(entry: &ignore::DirEntry) -> bool {
+    if entry.file_type().map_or(false, |ft| ft.is_dir()) {
+        return false;
+    }
+    let path = entry.path();
+    let is_meta = path
+        .file_name()
+        .is_some_and(|name| name.to_string_lossy().ends_with(".meta"));
+    !is_meta && path.extension().is_some_and(|e| !e.is_empty())
+}
+
+/// What an incremental scan of a root would find changed against its
+/// cache, from file stats alone.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CacheChanges {
+    /// False when the root has no (current) cache; every file is then
+    /// `added`.
+    pub has_cache: bool,
+    /// Files on disk the cache doesn't know, sorted.
+    pub added: Vec<String>,
+    /// Cached files whose mtime, size or `.meta` mtime moved, sorted.
+    pub modified: Vec<String>,
+    /// Cached files no longer on disk (or newly ignored), sorted.
+    pub deleted: Vec<String>,
+    pub added_count: usize,
+    pub modified_count: usize,
+    pub deleted_count: usize,
+}
+
+/// The "what's dirty" half of `scan_directory_incremental`: walk `path`
+/// the same way and compare each file's stats with the cache using the
+/// same `needs_rescan` / prune rules, without parsing anything or writing
+/// the cache. A cache classified under other extension overrides would be
+/// re-parsed whole, so all of it counts as modified.
+pub fn changes_since_cache(
+    path: &str,
+    respect_gitignore: bool,
+    include_hidden: bool,
+) -> Result<CacheChanges, ScanError> {
+    let root_path = Path::new(path);
+    check_scan_root(root_path, path)?;
+    let Some(cache) = ScanCache::load(path) else {
+        let mut added: Vec<String> = build_walker(root_path, respect_gitignore, include_hidden)
+            .flatten()
+            .filter(is_scannable_entry)
+            .map(|entry| path_to_string(entry.path()))
+            .collect();
+        added.sort();
+        return Ok(CacheChanges {
+            added_count: added.len(),
+            added,
+            ..Default::default()
+        });
+    };
+    let overrides_changed = cache.extension_overrides != load_extension_overrides(root_path);
+    let is_unity = matches!(detect_project_type(root_path), Some(ProjectType::Unity));
 
+    let mut changes = CacheChanges {
+        has_cache: true,
+        ..Default::default()
+    };
+    let mut on_disk: HashSet<String> = HashSet::new();
+    for entry in build_walker(root_path, respect_gitignore, include_hidden).flatten() {
+        if !is_scannable_entry(&entry) {
+            continue;
+        }
+        let file = entry.path();
+        let path_str = path_to_string(file);
+        if !cache.entries.contains_key(&path_str) {
+            changes.added.push(path_str.clone());
+        } else {
+            let modified = get_modified_time(file).unwrap_or(0);
+            let size = file.metadata().map(|m| m.len()).unwrap_or(0);
+            let meta_modified = if is_unity {
+                meta_modified_time(file)
+            } else {
+                None
+            };
+            if overrides_changed || cache.needs_rescan(&path_str, modified, size, meta_modified) {
+                changes.modified.push(path_str.clone());
+            }
+        }
+        on_disk.insert(path_str);
+    }
+    changes.deleted = cache
+        .entries
+        .keys()
+        .filter(|path| !on_disk.contains(*path))
+        .cloned()
+        .collect();
+
+    changes.added.sort();
+    changes.modified.sort();
+    changes.deleted.sort();
+    changes.added_count = changes.added.len();
+    changes.modified_count = changes.modified.len();
+    changes.deleted_count = changes.deleted.len();
+    Ok(changes)
+}
+
+/// Per-root project type reported by `scan_directories_incremental`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RootProjectType {
+    pub root: String,
+    pub project_type: Option<ProjectType>,
+}
+
+/// Incremental scan of several sibling roots (e.g. `ArtSource/` and
+/// `GameProject/Assets/`) as one logical project. Assets are merged and
+/// de-duplicated by absolute path, so nested or repeated roots are fine.
+/// The tree gets a synthetic top-level node at the roots' common ancestor
+/// with one child per root, and `root_path` is that ancestor so
+/// root-relative paths in analysis and exports stay unambiguous.
+///
+/// `ScanResult::project_type` is the first root's non-generic type (Unity
+/// GUID parsing still follows each root's own detection); the per-root
+/// list is returned alongside. The cache is keyed on the sorted root set.
+pub fn scan_directories_incremental(
+    paths: &[String],
+    state: Option<Arc<ScanState>>,
+    respect_gitignore: bool,
+    include_hidden: bool,
+    collect_timings: bool,
+    parse_metadata: bool,
+) -> Result<(ScanResult, IncrementalStats, Vec<RootProjectType>), ScanError> {
+    let sorted = sorted_roots(paths);
+    if sorted.is_empty() {
+        return Err(ScanError::InvalidPath("No directories to scan".to_string()));
+    }
+
+    let roots: Vec<&Path> = sorted.iter().map(Path::new).collect();
+    for (root, path) in roots.iter().zip(&sorted) {
+        check_scan_root(root, path)?;
+    }
+
+    // The project's tidycraft.toml lives at the reported root (the common
+    // ancestor), same as `load_rule_config` reads it for analysis.
+    let overrides = load_extension_overrides(&common_ancestor(&roots));
+    scan_roots_incremental(
+        &roots,
+        &multi_root_cache_key(&sorted),
+        &overrides,
+        state,
+        respect_gitignore,
+        include_hidden,
+        collect_timings,
+        parse_metadata,
+    )
+}
+
+/// Normalized, sorted and deduplicated roots, the order a multi-root scan
+/// walks them in.
+pub fn sorted_roots(paths: &[String]) -> Vec<String> {
+    let mut sorted: Vec<String> = paths.iter().map(|p| path_to_string(Path::new(p))).collect();
+    sorted.sort();
+    sorted.dedup();
+    sorted
+}
+
+/// Cache key for a multi-root scan: the sorted root set, one per line.
+/// A single root keys on its own path, so it shares the normal cache.
+pub fn multi_root_cache_key(sorted_roots: &[String]) -> String {
+    sorted_roots.join("\n")
+}
+
+pub(crate) fn check_scan_root(root_path: &Path, path: &str) -> Result<(), ScanError> {
     if !root_path.exists() {
         return Err(ScanError::PathNotFound(path.to_string()));
     }
@@ -1645,59 +2977,107 @@ pub fn scan_directory_incremental(
         )));
     }
 
-    // Load existing cache
-    let mut cache = ScanCache::load(path).unwrap_or_else(|| ScanCache::new(path));
+    Ok(())
+}
 
-    // Detect project type
-    let project_type = detect_project_type(root_path);
+/// The `root_path` a multi-root scan of `paths` reports — their common
+/// ancestor, normalized like every other path we hand the frontend.
+pub fn multi_root_path(paths: &[String]) -> String {
+    let roots: Vec<&Path> = paths.iter().map(Path::new).collect();
+    if roots.is_empty() {
+        return String::new();
+    }
+    path_to_string(&common_ancestor(&roots))
+}
 
-    // Phase 1: Discover all files
-    if let Some(ref s) = state {
-        *s.phase.write() = ScanPhase::Discovering;
+/// Deepest directory containing every root. Empty when the roots share
+/// nothing (different drives on Windows).
+fn common_ancestor(roots: &[&Path]) -> PathBuf {
+    let mut ancestor = roots[0].to_path_buf();
+    for root in &roots[1..] {
+        while !root.starts_with(&ancestor) {
+            if !ancestor.pop() {
+                return PathBuf::new();
+            }
+        }
     }
+    ancestor
+}
 
-    let mut file_entries: Vec<(PathBuf, u64)> = Vec::new();
+fn scan_roots_incremental(
+    roots: &[&Path],
+    cache_key: &str,
+    overrides: &ExtensionOverrides,
+    state: Option<Arc<ScanState>>,
+    respect_gitignore: bool,
+    include_hidden: bool,
+    collect_timings: bool,
+    parse_metadata: bool,
+) -> Result<(ScanResult, IncrementalStats, Vec<RootProjectType>), ScanError> {
+    let timings = collect_timings.then(TimingCollector::default);
+    let discover_start = Instant::now();
 
-    for result in build_walker(root_path, respect_gitignore) {
-        let entry = match result {
-            Ok(e) => e,
-            Err(_) => continue,
-        };
+    // Load existing cache
+    let mut cache = ScanCache::load(cache_key).unwrap_or_else(|| ScanCache::new(cache_key));
 
-        if let Some(ref s) = state {
-            if s.is_cancelled() {
-                *s.phase.write() = ScanPhase::Cancelled;
-                return Err(ScanError::Cancelled);
-            }
-        }
+    // Cached entries carry the type they were classified as. Editing the
+    // overrides doesn't touch any asset's mtime, so re-parse everything
+    // rather than serve types from the old mapping.
+    if cache.extension_overrides != *overrides {
+        cache.entries.clear();
+        cache.extension_overrides = overrides.clone();
+    }
 
-        if entry.file_type().map_or(false, |ft| ft.is_dir()) {
-            continue;
-        }
+    // Detect project type per root. Files are parsed with their own root's
+    // type; the combined result reports the first non-generic one.
+    let root_types: Vec<Option<ProjectType>> =
+        roots.iter().map(|root| detect_project_type(root)).collect();
+    let project_type = root_types
+        .iter()
+        .find(|t| !matches!(t, Some(ProjectType::Generic) | None))
+        .or(root_types.first())
+        .cloned()
+        .flatten();
 
-        let entry_path = entry.path();
-        let file_name = entry_path
-            .file_name()
-            .map(|n| n.to_string_lossy().to_string())
-            .unwrap_or_default();
+    // Phase 1: Discover all files. The previous scan's file count stands in
+    // as the expected total so discovery advances the progress bar too.
+    if let Some(ref s) = state {
+        s.set_phase(ScanPhase::Discovering, cache.entries.len());
+    }
 
-        // Hidden files / dirs filtered upstream by build_walker(hidden=true);
-        // .meta is Unity per-asset metadata (surfaced via unity_guid).
-        if file_name.ends_with(".meta") {
-            continue;
-        }
+    // (path, mtime, index into `roots`). `seen` de-dupes files reachable
+    // from more than one root.
+    let mut file_entries: Vec<(PathBuf, u64, usize)> = Vec::new();
+    let mut seen: HashSet<PathBuf> = HashSet::new();
 
-        let extension = entry_path
-            .extension()
-            .map(|e| e.to_string_lossy().to_string())
-            .unwrap_or_default();
+    for (root_index, root_path) in roots.iter().enumerate() {
+        for result in build_walker(root_path, respect_gitignore, include_hidden) {
+            let entry = match result {
+                Ok(e) => e,
+                Err(_) => continue,
+            };
+
+            if let Some(ref s) = state {
+                if s.is_cancelled() {
+                    *s.phase.write() = ScanPhase::Cancelled;
+                    return Err(ScanError::Cancelled);
+                }
+            }
+
+            if !is_scannable_entry(&entry) {
+                continue;
+            }
+            let entry_path = entry.path();
+            if !seen.insert(entry_path.to_path_buf()) {
+                continue;
+            }
 
-        if extension.is_empty() {
-            continue;
+            let modified = get_modified_time(entry_path).unwrap_or(0);
+            file_entries.push((entry_path.to_path_buf(), modified, root_index));
+            if let Some(ref s) = state {
+                s.current.store(file_entries.len(), Ordering::Relaxed);
+            }
         }
-
-        let modified = get_modified_time(entry_path).unwrap_or(0);
-        file_entries.push((entry_path.to_path_buf(), modified));
     }
 
     // Collect all current file paths for pruning. Use normalized
@@ -1705,7 +3085,7 @@ pub fn scan_directory_incremental(
     // AssetInfo.path — the cache keys off the exact same string.
     let current_paths: Vec<String> = file_entries
         .iter()
-        .map(|(p, _)| path_to_string(p))
+        .map(|(p, _, _)| path_to_string(p))
         .collect();
 
     // Prune deleted files from cache. Files that just fell out of
@@ -1714,15 +3094,19 @@ pub fn scan_directory_incremental(
     cache.prune(&current_paths);
 
     // Determine which files need scanning. Sidecar mtimes only matter for
-    // Unity projects (the only place `.meta` is parsed) — everyone else
+    // Unity roots (the only place `.meta` is parsed) — everyone else
     // skips the extra stat per file.
-    let is_unity = matches!(project_type, Some(ProjectType::Unity));
-    let files_to_scan: Vec<&(PathBuf, u64)> = file_entries
+    let is_unity = |root_index: usize| matches!(root_types[root_index], Some(ProjectType::Unity));
+    let files_to_scan: Vec<&(PathBuf, u64, usize)> = file_entries
         .iter()
-        .filter(|(p, modified)| {
+        .filter(|(p, modified, root_index)| {
             let path_str = path_to_string(p);
             let size = p.metadata().map(|m| m.len()).unwrap_or(0);
-            let meta_modified = if is_unity { meta_modified_time(p) } else { None };
+            let meta_modified = if is_unity(*root_index) {
+                meta_modified_time(p)
+            } else {
+                None
+            };
             cache.needs_rescan(&path_str, *modified, size, meta_modified)
         })
         .collect();
@@ -1730,25 +3114,22 @@ pub fn scan_directory_incremental(
     let total_files = file_entries.len();
     let files_to_parse = files_to_scan.len();
     let cached_count = total_files - files_to_parse;
+    let discover_time = discover_start.elapsed();
+    let parse_start = Instant::now();
 
+    // Phase 2: Parse only changed files in parallel.
     if let Some(ref s) = state {
-        s.total.store(files_to_parse, Ordering::SeqCst);
-    }
-
-    // Phase 2: Parse only changed files in parallel
-    if let Some(ref s) = state {
-        *s.phase.write() = ScanPhase::Parsing;
+        s.set_phase(ScanPhase::Parsing, files_to_parse);
     }
 
     let state_clone = state.clone();
-    let project_type_clone = project_type.clone();
     let counter = Arc::new(AtomicUsize::new(0));
     let counter_clone = counter.clone();
 
     // Parse files in parallel and collect results
-    let parsed_assets: Vec<(AssetInfo, u64)> = files_to_scan
+    let parsed_assets: Vec<(AssetInfo, u64, usize)> = files_to_scan
         .par_iter()
-        .filter_map(|(p, modified)| {
+        .filter_map(|(p, modified, root_index)| {
             // Check for cancellation periodically
             if let Some(ref s) = state_clone {
                 if s.is_cancelled() {
@@ -1765,8 +3146,14 @@ pub fn scan_directory_incremental(
                 }
             }
 
-            parse_asset_file(p, &project_type_clone)
-                .map(|asset| (asset, *modified))
+            parse_asset_file(
+                p,
+                &root_types[*root_index],
+                overrides,
+                timings.as_ref(),
+                parse_metadata,
+            )
+            .map(|asset| (asset, *modified, *root_index))
         })
         .collect();
 
@@ -1782,17 +3169,24 @@ pub fn scan_directory_incremental(
     // rather than carried from the filter pass — if the .meta changed in
     // between, storing the later value just means one more (correct)
     // re-parse next scan.
-    for (asset, modified) in parsed_assets {
-        let meta_modified = if is_unity {
+    for (asset, modified, root_index) in parsed_assets {
+        let meta_modified = if is_unity(root_index) {
             meta_modified_time(Path::new(&asset.path))
         } else {
             None
         };
         cache.update_entry(asset, modified, meta_modified);
     }
+    let parse_time = parse_start.elapsed();
+    let build_start = Instant::now();
 
     // Get all assets from cache
     let mut assets = cache.get_assets();
+    if !parse_metadata {
+        for asset in &mut assets {
+            asset.metadata = None;
+        }
+    }
 
     // Sort assets by path using parallel sort for large collections
     if assets.len() > 1000 {
@@ -1822,30 +3216,69 @@ pub fn scan_directory_incremental(
 
     // Phase 3: Build directory tree
     if let Some(ref s) = state {
-        *s.phase.write() = ScanPhase::Building;
+        s.set_phase(ScanPhase::Building, assets.len());
     }
 
-    let tree_ignore = build_gitignore_matcher(root_path, respect_gitignore);
-    let directory_tree = build_directory_tree(root_path, &assets, tree_ignore.as_ref());
+    let (root_path, directory_tree) = if let [root] = roots {
+        let tree_ignore = build_gitignore_matcher(root, respect_gitignore);
+        (
+            root.to_path_buf(),
+            build_directory_tree(root, &assets, tree_ignore.as_ref(), include_hidden),
+        )
+    } else {
+        let ancestor = common_ancestor(roots);
+        let children: Vec<DirectoryNode> = roots
+            .iter()
+            .map(|root| {
+                let tree_ignore = build_gitignore_matcher(root, respect_gitignore);
+                build_directory_tree(root, &assets, tree_ignore.as_ref(), include_hidden)
+            })
+            .collect();
+        let tree = DirectoryNode {
+            name: ancestor
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_else(|| path_to_string(&ancestor)),
+            path: path_to_string(&ancestor),
+            children,
+            // From the de-duplicated assets — summing the children would
+            // count files under nested roots twice.
+            file_count: assets.len(),
+            total_size: assets.iter().map(|a| a.size).sum(),
+        };
+        (ancestor, tree)
+    };
 
     let total_count = assets.len();
     let total_size = assets.iter().map(|a| a.size).sum();
+    let timings = timings.map(|t| t.finish(discover_time, parse_time, build_start.elapsed()));
 
-    // Save updated cache
-    let _ = cache.save();
+    // Save updated cache. An inventory-only scan must not: its entries
+    // would pass for parsed ones and serve `None` metadata from then on.
+    if parse_metadata {
+        let _ = cache.save();
+    }
 
     if let Some(ref s) = state {
         *s.phase.write() = ScanPhase::Completed;
     }
 
+    // Read from the first Unity root — the one that made this a Unity scan.
+    let project_info = root_types
+        .iter()
+        .position(|t| matches!(t, Some(ProjectType::Unity)))
+        .and_then(|i| read_project_meta(roots[i], &project_type));
     let result = ScanResult {
-        root_path: path_to_string(Path::new(path)),
+        root_path: path_to_string(&root_path),
         directory_tree,
         assets,
         total_count,
         total_size,
         type_counts,
         project_type,
+        timings,
+        metadata_skipped: !parse_metadata,
+        project_info,
     };
 
     let stats = IncrementalStats {
@@ -1854,7 +3287,16 @@ pub fn scan_directory_incremental(
         rescanned_files: files_to_parse,
     };
 
-    Ok((result, stats))
+    let root_project_types = roots
+        .iter()
+        .zip(root_types)
+        .map(|(root, project_type)| RootProjectType {
+            root: path_to_string(root),
+            project_type,
+        })
+        .collect();
+
+    Ok((result, stats, root_project_types))
 }
 
 /// Statistics about incremental scan
@@ -1871,6 +3313,141 @@ mod tests {
     use std::fs;
     use tempfile::tempdir;
 
+    #[test]
+    fn scan_progress_percent_is_monotonic_across_phases() {
+        let state = ScanState::new();
+        let mut last = -1.0;
+        let mut step = |phase: ScanPhase, current: usize, total: usize| {
+            state.set_phase(phase, total);
+            state.current.store(current, Ordering::SeqCst);
+            let p = state.get_progress().percent;
+            assert!(p >= last, "progress went backwards: {last} -> {p}");
+            last = p;
+        };
+        // Discovery overshooting a stale estimate stays clamped to its slice.
+        step(ScanPhase::Discovering, 0, 100);
+        step(ScanPhase::Discovering, 150, 100);
+        // Incremental rescan with only a handful of changed files.
+        step(ScanPhase::Parsing, 0, 3);
+        step(ScanPhase::Parsing, 3, 3);
+        step(ScanPhase::Building, 0, 3);
+        assert_eq!(state.get_progress().percent, 95.0);
+        step(ScanPhase::Completed, 0, 3);
+        assert_eq!(last, 100.0);
+
+        // Entering a phase doesn't carry the previous phase's counts.
+        state.set_phase(ScanPhase::Parsing, 3);
+        state.current.store(3, Ordering::SeqCst);
+        state.set_phase(ScanPhase::Building, 3);
+        let building = state.get_progress();
+        assert_eq!((building.current, building.total), (0, Some(3)));
+        assert_eq!(building.percent, 95.0);
+    }
+
+    /// Writes `size` zero bytes to `root/rel` as a texture asset.
+    fn texture_file(root: &Path, rel: &str, size: usize) -> AssetInfo {
+        let path = root.join(rel);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, vec![0u8; size]).unwrap();
+        AssetInfo {
+            path: path_to_string(&path),
+            name: path.file_name().unwrap().to_string_lossy().to_string(),
+            extension: "png".to_string(),
+            asset_type: AssetType::Texture,
+            size: size as u64,
+            modified: 0,
+            metadata: None,
+            unity_guid: None,
+        }
+    }
+
+    #[test]
+    fn directory_tree_delta_updates_only_the_ancestor_chain() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        let assets = vec![
+            texture_file(root, "Art/Props/Rocks/rock.png", 10),
+            texture_file(root, "Art/tree.png", 20),
+            texture_file(root, "Audio/hit.png", 30),
+        ];
+        let mut tree = build_directory_tree(root, &assets, None, false);
+        let counts = |node: &DirectoryNode| (node.file_count, node.total_size);
+        let child = |node: &DirectoryNode, name: &str| -> DirectoryNode {
+            node.children
+                .iter()
+                .find(|c| c.name == name)
+                .unwrap()
+                .clone()
+        };
+        let audio_before = child(&tree, "Audio");
+
+        let deep = texture_file(root, "Art/Props/Rocks/Mossy/Large/boulder.png", 5);
+        tree.apply_delta(std::slice::from_ref(&deep), &[]);
+        let art = child(&tree, "Art");
+        let rocks = child(&child(&art, "Props"), "Rocks");
+        assert_eq!(counts(&tree), (4, 65));
+        assert_eq!(counts(&art), (3, 35));
+        assert_eq!(counts(&rocks), (2, 15));
+        assert_eq!(counts(&child(&child(&rocks, "Mossy"), "Large")), (1, 5));
+        // Full rebuild agrees.
+        let mut all = assets.clone();
+        all.push(deep.clone());
+        let rebuilt = build_directory_tree(root, &all, None, false);
+        assert_eq!(counts(&child(&rebuilt, "Art")), counts(&art));
+
+        fs::remove_dir_all(root.join("Art/Props/Rocks/Mossy")).unwrap();
+        tree.apply_delta(&[], std::slice::from_ref(&deep));
+        let art = child(&tree, "Art");
+        let rocks = child(&child(&art, "Props"), "Rocks");
+        assert_eq!(counts(&tree), (3, 60));
+        assert_eq!(counts(&art), (2, 30));
+        // Emptied and gone from disk: pruned.
+        assert!(rocks.children.is_empty());
+        assert_eq!(counts(&rocks), (1, 10));
+
+        let audio_after = child(&tree, "Audio");
+        assert_eq!(counts(&audio_after), counts(&audio_before));
+        assert_eq!(audio_after.children.len(), audio_before.children.len());
+    }
+
+    #[test]
+    fn metadata_parsers_are_gated_on_their_asset_type() {
+        let dir = tempdir().unwrap();
+        let png = dir.path().join("icon.png");
+        image::RgbaImage::new(8, 4).save(&png).unwrap();
+
+        let m = parse_metadata_for(&png, "png", &AssetType::Texture, None).unwrap();
+        assert_eq!((m.width, m.height), (Some(8), Some(4)));
+        assert!(parse_metadata_for(&png, "png", &AssetType::Model, None).is_none());
+    }
+
+    #[test]
+    fn image_metadata_reports_bits_per_channel() {
+        let dir = tempdir().unwrap();
+        let deep = dir.path().join("deep.png");
+        image::ImageBuffer::<image::Luma<u16>, _>::new(4, 4)
+            .save(&deep)
+            .unwrap();
+        let plain = dir.path().join("plain.png");
+        image::RgbaImage::new(4, 4).save(&plain).unwrap();
+
+        assert_eq!(
+            parse_image_metadata(&deep).unwrap().bits_per_channel,
+            Some(16)
+        );
+        assert_eq!(
+            parse_image_metadata(&plain).unwrap().bits_per_channel,
+            Some(8)
+        );
+    }
+
+    #[test]
+    fn scan_progress_fully_cached_parse_counts_as_done() {
+        let state = ScanState::new();
+        *state.phase.write() = ScanPhase::Parsing;
+        assert_eq!(state.get_progress().percent, 95.0);
+    }
+
     #[test]
     fn test_get_asset_type_textures() {
         assert!(matches!(get_asset_type("png"), AssetType::Texture));
@@ -1883,6 +3460,7 @@ mod tests {
         assert!(matches!(get_asset_type("svg"), AssetType::Texture));
         assert!(matches!(get_asset_type("dds"), AssetType::Texture));
         assert!(matches!(get_asset_type("webp"), AssetType::Texture));
+        assert!(matches!(get_asset_type("avif"), AssetType::Texture));
     }
 
     #[test]
@@ -1954,18 +3532,97 @@ mod tests {
 
     #[test]
     fn test_parse_metadata_tags_dcc_kind_when_no_parser() {
-        // .blend has no Rust metadata parser, but parse_metadata_for
-        // should still return Some(metadata) with dcc_source_kind set
-        // — the analyzer relies on this.
+        // Not a readable .blend (the parser rejects the header), but
+        // parse_metadata_for should still return Some(metadata) with
+        // dcc_source_kind set — the analyzer relies on this.
         let dir = tempdir().unwrap();
         let path = dir.path().join("character.blend");
         fs::write(&path, b"FAKE BLEND HEADER").unwrap();
-        let m = parse_metadata_for(&path, "blend", &AssetType::Model).unwrap();
+        let m = parse_metadata_for(&path, "blend", &AssetType::Model, None).unwrap();
         assert_eq!(m.dcc_source_kind.as_deref(), Some("blender"));
-        // Format-specific fields stay None — we have no parser.
+        // Format-specific fields stay None — the parse failed.
         assert!(m.vertex_count.is_none());
     }
 
+    /// A minimal uncompressed 64-bit little-endian `.blend`: an Object, a
+    /// Material, two Mesh blocks, then the SDNA describing `Mesh` as
+    /// `{ *next, name[4], totvert, totpoly }`.
+    fn synthetic_blend() -> Vec<u8> {
+        fn block(out: &mut Vec<u8>, code: &[u8; 4], data: &[u8]) {
+            out.extend_from_slice(code);
+            out.extend_from_slice(&(data.len() as u32).to_le_bytes());
+            out.extend_from_slice(&[0; 8]); // old pointer
+            out.extend_from_slice(&0u32.to_le_bytes()); // SDNA index
+            out.extend_from_slice(&1u32.to_le_bytes()); // count
+            out.extend_from_slice(data);
+        }
+        fn names(out: &mut Vec<u8>, tag: &[u8], list: &[&str]) {
+            out.extend_from_slice(tag);
+            out.extend_from_slice(&(list.len() as u32).to_le_bytes());
+            for name in list {
+                out.extend_from_slice(name.as_bytes());
+                out.push(0);
+            }
+            while out.len() % 4 != 0 {
+                out.push(0);
+            }
+        }
+        let mesh = |verts: u32, faces: u32| {
+            let mut data = vec![0u8; 12];
+            data.extend_from_slice(&verts.to_le_bytes());
+            data.extend_from_slice(&faces.to_le_bytes());
+            data
+        };
+
+        let mut dna = b"SDNA".to_vec();
+        names(
+            &mut dna,
+            b"NAME",
+            &["*next", "name[4]", "totvert", "totpoly"],
+        );
+        names(&mut dna, b"TYPE", &["char", "int", "Mesh"]);
+        dna.extend_from_slice(b"TLEN");
+        for len in [1u16, 4, 20] {
+            dna.extend_from_slice(&len.to_le_bytes());
+        }
+        dna.extend_from_slice(&[0, 0]); // pad 3 shorts to 4 bytes
+        dna.extend_from_slice(b"STRC");
+        dna.extend_from_slice(&1u32.to_le_bytes());
+        // Mesh (type 2), 4 fields: (type, name) index pairs.
+        for v in [2u16, 4, 2, 0, 0, 1, 1, 2, 1, 3] {
+            dna.extend_from_slice(&v.to_le_bytes());
+        }
+
+        let mut file = b"BLENDER-v402".to_vec();
+        block(&mut file, b"OB\0\0", &[0; 16]);
+        block(&mut file, b"MA\0\0", &[0; 16]);
+        block(&mut file, b"ME\0\0", &mesh(8, 6));
+        block(&mut file, b"ME\0\0", &mesh(4, 1));
+        block(&mut file, b"DNA1", &dna);
+        block(&mut file, b"ENDB", &[]);
+        file
+    }
+
+    #[test]
+    fn parse_blend_metadata_reads_header_and_mesh_totals() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("scene.blend");
+        fs::write(&path, synthetic_blend()).unwrap();
+
+        let m = parse_metadata_for(&path, "blend", &AssetType::Model, None).unwrap();
+        assert_eq!(m.dcc_version.as_deref(), Some("4.2"));
+        assert_eq!(m.object_count, Some(1));
+        assert_eq!(m.material_count, Some(1));
+        assert_eq!(m.vertex_count, Some(12));
+        assert_eq!(m.face_count, Some(7));
+        assert_eq!(m.dcc_source_kind.as_deref(), Some("blender"));
+
+        // gzip-compressed: not read, only tagged.
+        fs::write(&path, [0x1f, 0x8b, 0x08, 0x00]).unwrap();
+        let m = parse_metadata_for(&path, "blend", &AssetType::Model, None).unwrap();
+        assert!(m.dcc_version.is_none());
+    }
+
     #[test]
     fn test_parse_metadata_no_kind_for_runtime_export() {
         // Sanity check: parsing a runtime format (here, missing
@@ -1974,7 +3631,7 @@ mod tests {
         let dir = tempdir().unwrap();
         let path = dir.path().join("ghost.fbx");
         // Don't actually write — just confirm a None parse stays None.
-        let m = parse_metadata_for(&path, "fbx", &AssetType::Model);
+        let m = parse_metadata_for(&path, "fbx", &AssetType::Model, None);
         assert!(m.is_none());
     }
 
@@ -2017,6 +3674,23 @@ mod tests {
         assert!(matches!(get_asset_type(""), AssetType::Other));
     }
 
+    #[test]
+    fn extension_overrides_take_precedence() {
+        let overrides: ExtensionOverrides = [
+            ("bytes".to_string(), AssetType::Data),
+            ("svg".to_string(), AssetType::Data),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(get_asset_type_with("BYTES", &overrides), AssetType::Data);
+        assert_eq!(get_asset_type_with("svg", &overrides), AssetType::Data);
+        assert_eq!(get_asset_type_with("png", &overrides), AssetType::Texture);
+        assert_eq!(
+            get_asset_type_with("bytes", &ExtensionOverrides::new()),
+            AssetType::Other
+        );
+    }
+
     fn make_dds_bytes(width: u32, height: u32, alpha: bool) -> Vec<u8> {
         let mut buf = vec![0u8; 128];
         buf[0..4].copy_from_slice(b"DDS ");
@@ -2077,7 +3751,7 @@ mod tests {
         let path = dir.path().join("tex.dds");
         fs::write(&path, make_dds_bytes(128, 64, true)).unwrap();
 
-        let meta = parse_metadata_for(&path, "dds", &AssetType::Texture);
+        let meta = parse_metadata_for(&path, "dds", &AssetType::Texture, None);
         assert_eq!(meta.and_then(|m| m.width), Some(128));
     }
 
@@ -2154,6 +3828,60 @@ mod tests {
         let meta = parse_dds_metadata(&path).expect("valid DDS should parse");
         // Two-channel normal-map format — no alpha despite being compressed.
         assert_eq!(meta.has_alpha, Some(false));
+        assert_eq!(meta.texture_format.as_deref(), Some("BC5_UNORM"));
+    }
+
+    #[test]
+    fn test_parse_dds_legacy_fourcc_maps_to_dxgi_name() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("c.dds");
+        fs::write(&path, make_dds_fourcc_bytes(b"DXT5")).unwrap();
+        // DXT5 and DX10/BC3 are the same block format; rules comparing
+        // formats shouldn't have to know both spellings.
+        let meta = parse_dds_metadata(&path).expect("valid DDS should parse");
+        assert_eq!(meta.texture_format.as_deref(), Some("BC3_UNORM"));
+    }
+
+    fn make_tga_bytes(width: u16, height: u16, depth: u8, descriptor: u8) -> Vec<u8> {
+        let mut buf = vec![0u8; 18];
+        buf[2] = 2; // uncompressed true-color
+        buf[12..14].copy_from_slice(&width.to_le_bytes());
+        buf[14..16].copy_from_slice(&height.to_le_bytes());
+        buf[16] = depth;
+        buf[17] = descriptor;
+        buf
+    }
+
+    #[test]
+    fn test_parse_tga_header_dimensions_and_alpha() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("t.tga");
+        // Header only — no pixel data. The texture rules need nothing more,
+        // and a full decode would reject this file.
+        fs::write(&path, make_tga_bytes(2048, 1024, 32, 8)).unwrap();
+        let meta = parse_metadata_for(&path, "tga", &AssetType::Texture, None).expect("TGA header");
+        assert_eq!((meta.width, meta.height), (Some(2048), Some(1024)));
+        assert_eq!(meta.has_alpha, Some(true));
+
+        fs::write(&path, make_tga_bytes(512, 512, 24, 0)).unwrap();
+        assert_eq!(parse_tga_metadata(&path).unwrap().has_alpha, Some(false));
+
+        // 32-bit with the descriptor's alpha bits left at 0 is still RGBA.
+        fs::write(&path, make_tga_bytes(64, 64, 32, 0)).unwrap();
+        assert_eq!(parse_tga_metadata(&path).unwrap().has_alpha, Some(true));
+    }
+
+    #[test]
+    fn test_parse_tga_rejects_implausible_header() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("t.tga");
+        let mut buf = make_tga_bytes(64, 64, 24, 0);
+        buf[2] = 7; // not a TGA image type
+        fs::write(&path, &buf).unwrap();
+        assert!(parse_tga_metadata(&path).is_none());
+
+        fs::write(&path, make_tga_bytes(0, 64, 24, 0)).unwrap();
+        assert!(parse_tga_metadata(&path).is_none());
     }
 
     #[test]
@@ -2282,8 +4010,13 @@ mod tests {
 
         let state = Arc::new(ScanState::new());
         state.cancel();
-        let err = scan_directory_with_state(dir.path().to_str().unwrap(), Some(state.clone()), true)
-            .expect_err("pre-cancelled scan must not complete");
+        let err = scan_directory_with_state(
+            dir.path().to_str().unwrap(),
+            Some(state.clone()),
+            true,
+            false,
+        )
+        .expect_err("pre-cancelled scan must not complete");
         assert!(matches!(err, ScanError::Cancelled));
         // The progress reporter treats Cancelled as terminal and stops
         // emitting; the scan must actually record it instead of bailing with
@@ -2395,7 +4128,7 @@ mod tests {
 
     #[test]
     fn test_scan_nonexistent_path() {
-        let result = scan_directory_with_state("/nonexistent/path/123456", None, false);
+        let result = scan_directory_with_state("/nonexistent/path/123456", None, false, false);
         assert!(result.is_err());
         assert!(matches!(result.unwrap_err(), ScanError::PathNotFound(_)));
     }
@@ -2403,7 +4136,7 @@ mod tests {
     #[test]
     fn test_scan_empty_directory() {
         let dir = tempdir().unwrap();
-        let result = scan_directory_with_state(dir.path().to_str().unwrap(), None, false);
+        let result = scan_directory_with_state(dir.path().to_str().unwrap(), None, false, false);
 
         assert!(result.is_ok());
         let scan_result = result.unwrap();
@@ -2420,7 +4153,7 @@ mod tests {
         fs::write(dir.path().join("test.mp3"), "fake mp3 data").unwrap();
         fs::write(dir.path().join("test.txt"), "some text").unwrap();
 
-        let result = scan_directory_with_state(dir.path().to_str().unwrap(), None, false);
+        let result = scan_directory_with_state(dir.path().to_str().unwrap(), None, false, false);
 
         assert!(result.is_ok());
         let scan_result = result.unwrap();
@@ -2441,13 +4174,49 @@ mod tests {
         fs::write(dir.path().join(".hidden"), "hidden content").unwrap();
         fs::write(dir.path().join("visible.png"), "visible content").unwrap();
 
-        let result = scan_directory_with_state(dir.path().to_str().unwrap(), None, false);
+        let result = scan_directory_with_state(dir.path().to_str().unwrap(), None, false, false);
 
         assert!(result.is_ok());
         let scan_result = result.unwrap();
         assert_eq!(scan_result.total_count, 1);
     }
 
+    #[test]
+    fn include_hidden_walks_dot_files_and_dirs_but_not_git() {
+        let dir = tempdir().unwrap();
+        fs::create_dir_all(dir.path().join(".config")).unwrap();
+        fs::create_dir_all(dir.path().join(".git").join("objects")).unwrap();
+        fs::write(dir.path().join(".config").join("settings.json"), "{}").unwrap();
+        fs::write(
+            dir.path().join(".git").join("objects").join("pack.png"),
+            "x",
+        )
+        .unwrap();
+        fs::write(dir.path().join(".splash.png"), "x").unwrap();
+        fs::write(dir.path().join("visible.png"), "x").unwrap();
+        let root = dir.path().to_str().unwrap();
+
+        // Tidycraft's own files stay out even with hidden files included.
+        fs::write(dir.path().join(tags::TAGS_FILE), "{}").unwrap();
+        fs::write(dir.path().join(format!("{}.corrupt", tags::TAGS_FILE)), "{").unwrap();
+
+        let result = scan_directory_with_state(root, None, false, true).unwrap();
+        let mut names: Vec<&str> = result.assets.iter().map(|a| a.name.as_str()).collect();
+        names.sort();
+        assert_eq!(names, [".splash.png", "settings.json", "visible.png"]);
+        let dirs: Vec<&str> = result
+            .directory_tree
+            .children
+            .iter()
+            .map(|c| c.name.as_str())
+            .collect();
+        assert_eq!(dirs, [".config"]);
+
+        let hidden_off = scan_directory_with_state(root, None, false, false).unwrap();
+        assert_eq!(hidden_off.total_count, 1);
+        assert!(hidden_off.directory_tree.children.is_empty());
+    }
+
     #[test]
     fn test_scan_skips_meta_files() {
         let dir = tempdir().unwrap();
@@ -2456,7 +4225,7 @@ mod tests {
         fs::write(dir.path().join("texture.png"), "texture data").unwrap();
         fs::write(dir.path().join("texture.png.meta"), "meta data").unwrap();
 
-        let result = scan_directory_with_state(dir.path().to_str().unwrap(), None, false);
+        let result = scan_directory_with_state(dir.path().to_str().unwrap(), None, false, false);
 
         assert!(result.is_ok());
         let scan_result = result.unwrap();
@@ -2473,7 +4242,7 @@ mod tests {
         fs::write(dir.path().join("textures/bg.png"), "texture").unwrap();
         fs::write(dir.path().join("models/char.fbx"), "model").unwrap();
 
-        let result = scan_directory_with_state(dir.path().to_str().unwrap(), None, false);
+        let result = scan_directory_with_state(dir.path().to_str().unwrap(), None, false, false);
 
         assert!(result.is_ok());
         let scan_result = result.unwrap();
@@ -2673,6 +4442,104 @@ mod tests {
         assert_eq!(parse_png_color_space(&path).as_deref(), Some("sRGB"));
     }
 
+    fn phys_payload(x: u32, y: u32, unit: u8) -> Vec<u8> {
+        let mut data = x.to_be_bytes().to_vec();
+        data.extend_from_slice(&y.to_be_bytes());
+        data.push(unit);
+        data
+    }
+
+    #[test]
+    fn png_phys_chunk_reports_dpi() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("ui.png");
+
+        // 2835 px/m is how 72 DPI round-trips through PNG.
+        let png = png_with_chunks(&[(b"pHYs", phys_payload(2835, 3780, 1)), (b"IEND", vec![])]);
+        fs::write(&path, png).unwrap();
+        assert_eq!(parse_png_dpi(&path), Some((72.0, 96.0)));
+
+        // Unit 0 is an aspect ratio, not a resolution.
+        let png = png_with_chunks(&[(b"pHYs", phys_payload(1, 1, 0)), (b"IEND", vec![])]);
+        fs::write(&path, png).unwrap();
+        assert_eq!(parse_png_dpi(&path), None);
+
+        // pHYs after IDAT is out of spec and never reached.
+        let png = png_with_chunks(&[(b"IDAT", vec![0]), (b"pHYs", phys_payload(2835, 2835, 1))]);
+        fs::write(&path, png).unwrap();
+        assert_eq!(parse_png_dpi(&path), None);
+    }
+
+    /// SOI + the given `(marker, payload)` segments + SOS.
+    fn jpeg_with_segments(segments: &[(u8, Vec<u8>)]) -> Vec<u8> {
+        let mut out = vec![0xFF, 0xD8];
+        for (marker, data) in segments {
+            out.extend_from_slice(&[0xFF, *marker]);
+            out.extend_from_slice(&(data.len() as u16 + 2).to_be_bytes());
+            out.extend_from_slice(data);
+        }
+        out.extend_from_slice(&[0xFF, 0xDA, 0x00, 0x02]);
+        out
+    }
+
+    fn jfif_payload(unit: u8, x: u16, y: u16) -> Vec<u8> {
+        let mut data = b"JFIF\0\x01\x02".to_vec();
+        data.push(unit);
+        data.extend_from_slice(&x.to_be_bytes());
+        data.extend_from_slice(&y.to_be_bytes());
+        data.extend_from_slice(&[0, 0]); // no thumbnail
+        data
+    }
+
+    /// Little-endian EXIF block with X/YResolution = `res`/1 and the
+    /// given ResolutionUnit.
+    fn exif_payload(res: u32, unit: u16) -> Vec<u8> {
+        let mut tiff = b"II*\0".to_vec();
+        tiff.extend_from_slice(&8u32.to_le_bytes());
+        tiff.extend_from_slice(&3u16.to_le_bytes());
+        // Rationals live right after the 3-entry IFD and its next-IFD link.
+        let rational_at = 8 + 2 + 3 * 12 + 4;
+        for (tag, offset) in [(0x011Au16, rational_at), (0x011B, rational_at + 8)] {
+            tiff.extend_from_slice(&tag.to_le_bytes());
+            tiff.extend_from_slice(&5u16.to_le_bytes());
+            tiff.extend_from_slice(&1u32.to_le_bytes());
+            tiff.extend_from_slice(&(offset as u32).to_le_bytes());
+        }
+        tiff.extend_from_slice(&0x0128u16.to_le_bytes());
+        tiff.extend_from_slice(&3u16.to_le_bytes());
+        tiff.extend_from_slice(&1u32.to_le_bytes());
+        tiff.extend_from_slice(&(unit as u32).to_le_bytes());
+        tiff.extend_from_slice(&0u32.to_le_bytes());
+        for _ in 0..2 {
+            tiff.extend_from_slice(&res.to_le_bytes());
+            tiff.extend_from_slice(&1u32.to_le_bytes());
+        }
+        let mut data = b"Exif\0\0".to_vec();
+        data.extend_from_slice(&tiff);
+        data
+    }
+
+    #[test]
+    fn jpeg_density_prefers_jfif_then_exif() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("photo.jpg");
+
+        let jpeg = jpeg_with_segments(&[(0xE0, jfif_payload(1, 300, 300))]);
+        fs::write(&path, jpeg).unwrap();
+        assert_eq!(parse_jpeg_dpi(&path), Some((300.0, 300.0)));
+
+        // JFIF aspect-only (unit 0) defers to EXIF; unit 3 is per cm.
+        let jpeg =
+            jpeg_with_segments(&[(0xE0, jfif_payload(0, 1, 1)), (0xE1, exif_payload(100, 3))]);
+        fs::write(&path, jpeg).unwrap();
+        assert_eq!(parse_jpeg_dpi(&path), Some((254.0, 254.0)));
+
+        // Neither segment carries a resolution: the common game-texture case.
+        let jpeg = jpeg_with_segments(&[(0xE0, jfif_payload(0, 1, 1))]);
+        fs::write(&path, jpeg).unwrap();
+        assert_eq!(parse_jpeg_dpi(&path), None);
+    }
+
     /// Set a file's mtime a fixed number of seconds into the future so a
     /// rewrite within the same wall-clock second still registers as a
     /// change (cache mtimes have whole-second granularity).
@@ -2682,6 +4549,37 @@ mod tests {
         file.set_times(fs::FileTimes::new().set_modified(t)).unwrap();
     }
 
+    #[test]
+    fn unity_meta_yields_guid_and_default_texture_cap() {
+        let meta = parse_unity_meta_content(
+            "fileFormatVersion: 2
+guid: aaaa1111aaaa1111aaaa1111aaaa1111
+TextureImporter:
+  mipmaps:
+    mipMapMode: 0
+    enableMipMap: 0
+  maxTextureSize: 512
+  platformSettings:
+  - serializedVersion: 3
+    buildTarget: Android
+    maxTextureSize: 256
+",
+        );
+        assert_eq!(
+            meta.guid.as_deref(),
+            Some("aaaa1111aaaa1111aaaa1111aaaa1111")
+        );
+        // The importer-wide cap, not the Android override.
+        assert_eq!(meta.max_texture_size, Some(512));
+        assert_eq!(meta.mipmaps, Some(false));
+
+        let audio = parse_unity_meta_content(
+            "fileFormatVersion: 2\nguid: bbbb2222bbbb2222bbbb2222bbbb2222\nAudioImporter:\n",
+        );
+        assert_eq!(audio.max_texture_size, None);
+        assert_eq!(audio.mipmaps, None);
+    }
+
     #[test]
     fn incremental_rescan_picks_up_meta_only_changes() {
         let dir = tempdir().unwrap();
@@ -2695,7 +4593,7 @@ mod tests {
         )
         .unwrap();
 
-        let (r1, _) = scan_directory_incremental(root, None, false).unwrap();
+        let (r1, _) = scan_directory_incremental(root, None, false, false, false, true).unwrap();
         assert_eq!(
             r1.assets[0].unity_guid.as_deref(),
             Some("aaaa1111aaaa1111aaaa1111aaaa1111")
@@ -2710,7 +4608,7 @@ mod tests {
         .unwrap();
         bump_mtime(&dir.path().join("tex.png.meta"), 5);
 
-        let (r2, _) = scan_directory_incremental(root, None, false).unwrap();
+        let (r2, _) = scan_directory_incremental(root, None, false, false, false, true).unwrap();
         // Clean up the on-disk cache this test created in the user cache dir.
         let _ = crate::cache::ScanCache::clear(root);
         assert_eq!(
@@ -2727,7 +4625,7 @@ mod tests {
         fs::write(dir.path().join("tex.png"), "png data").unwrap();
 
         // First scan: no sidecar yet.
-        let (r1, _) = scan_directory_incremental(root, None, false).unwrap();
+        let (r1, _) = scan_directory_incremental(root, None, false, false, false, true).unwrap();
         assert_eq!(r1.assets[0].unity_guid, None);
 
         // Unity generates the sidecar afterwards ("copy asset in, let the
@@ -2737,7 +4635,7 @@ mod tests {
             "fileFormatVersion: 2\nguid: cccc3333cccc3333cccc3333cccc3333\n",
         )
         .unwrap();
-        let (r2, _) = scan_directory_incremental(root, None, false).unwrap();
+        let (r2, _) = scan_directory_incremental(root, None, false, false, false, true).unwrap();
         assert_eq!(
             r2.assets[0].unity_guid.as_deref(),
             Some("cccc3333cccc3333cccc3333cccc3333")
@@ -2745,11 +4643,278 @@ mod tests {
 
         // Sidecar removed again → guid must clear.
         fs::remove_file(dir.path().join("tex.png.meta")).unwrap();
-        let (r3, _) = scan_directory_incremental(root, None, false).unwrap();
+        let (r3, _) = scan_directory_incremental(root, None, false, false, false, true).unwrap();
         let _ = crate::cache::ScanCache::clear(root);
         assert_eq!(r3.assets[0].unity_guid, None);
     }
 
+    #[test]
+    fn timings_cover_parsed_files_only_when_requested() {
+        let dir = tempdir().unwrap();
+        let root = dir.path().to_str().unwrap();
+        fs::write(dir.path().join("a.png"), "not really a png").unwrap();
+        fs::write(dir.path().join("b.PNG"), "not really a png").unwrap();
+        fs::write(dir.path().join("mesh.obj"), "v 0 0 0\nv 1 0 0\n").unwrap();
+
+        let (first, _) = scan_directory_incremental(root, None, false, false, true, true).unwrap();
+        let timings = first.timings.expect("timings were requested");
+        assert_eq!(timings.by_extension["png"].files, 2);
+        assert_eq!(timings.by_extension["obj"].files, 1);
+        // Even a failed decode is charged to the parser that tried it.
+        assert_eq!(timings.by_parser["parse_image_metadata"].files, 2);
+        assert_eq!(timings.by_parser["parse_obj_metadata"].files, 1);
+
+        // Everything is cached now: nothing parsed, nothing timed.
+        let (second, _) = scan_directory_incremental(root, None, false, false, true, true).unwrap();
+        assert!(second.timings.unwrap().by_extension.is_empty());
+
+        let (off, _) = scan_directory_incremental(root, None, false, false, false, true).unwrap();
+        let _ = crate::cache::ScanCache::clear(root);
+        assert!(off.timings.is_none());
+    }
+
+    #[test]
+    fn timings_charge_png_and_jpeg_chunk_scans_separately() {
+        let dir = tempdir().unwrap();
+        let root = dir.path().to_str().unwrap();
+        image::RgbaImage::new(4, 4)
+            .save(dir.path().join("a.png"))
+            .unwrap();
+        image::RgbImage::new(4, 4)
+            .save(dir.path().join("b.jpg"))
+            .unwrap();
+
+        let (result, _) = scan_directory_incremental(root, None, false, false, true, true).unwrap();
+        let _ = crate::cache::ScanCache::clear(root);
+        let timings = result.timings.unwrap();
+        assert_eq!(timings.by_parser["parse_image_metadata"].files, 2);
+        assert_eq!(timings.by_parser["parse_png_color_space"].files, 1);
+        assert_eq!(timings.by_parser["parse_png_dpi"].files, 1);
+        assert_eq!(timings.by_parser["parse_jpeg_dpi"].files, 1);
+    }
+
+    #[test]
+    fn inventory_scan_skips_metadata_and_leaves_the_cache_alone() {
+        let dir = tempdir().unwrap();
+        let root = dir.path().to_str().unwrap();
+        fs::write(dir.path().join("mesh.obj"), "v 0 0 0\nv 1 0 0\n").unwrap();
+
+        let (inventory, stats) =
+            scan_directory_incremental(root, None, false, false, false, false).unwrap();
+        assert!(inventory.metadata_skipped);
+        assert_eq!(inventory.total_count, 1);
+        assert_eq!(inventory.total_size, 16);
+        assert_eq!(inventory.type_counts["model"], 1);
+        assert!(inventory.assets[0].metadata.is_none());
+        assert_eq!(stats.rescanned_files, 1);
+
+        // Nothing was cached: the full scan parses the file for real.
+        let (full, stats) =
+            scan_directory_incremental(root, None, false, false, false, true).unwrap();
+        assert!(!full.metadata_skipped);
+        assert_eq!(stats.rescanned_files, 1);
+        assert!(full.assets[0].metadata.is_some());
+
+        // And a cached full result is still stripped for an inventory scan.
+        let (again, _) =
+            scan_directory_incremental(root, None, false, false, false, false).unwrap();
+        let _ = crate::cache::ScanCache::clear(root);
+        assert!(again.assets[0].metadata.is_none());
+    }
+
+    #[test]
+    fn unity_scan_reads_project_settings() {
+        let dir = tempdir().unwrap();
+        let root = dir.path().to_str().unwrap();
+        let settings = dir.path().join("ProjectSettings");
+        fs::create_dir(&settings).unwrap();
+        fs::write(
+            settings.join("ProjectVersion.txt"),
+            "m_EditorVersion: 2022.3.10f1\n",
+        )
+        .unwrap();
+
+        // ProjectSettings.asset missing (or locked): the version still counts.
+        let (result, _) =
+            scan_directory_incremental(root, None, false, false, false, true).unwrap();
+        let _ = crate::cache::ScanCache::clear(root);
+        assert_eq!(
+            result.project_info,
+            Some(ProjectMeta {
+                engine_version: Some("2022.3.10f1".to_string()),
+                ..Default::default()
+            })
+        );
+
+        fs::remove_dir_all(&settings).unwrap();
+        fs::write(dir.path().join("project.godot"), "config_version=5\n").unwrap();
+        let (godot, _) = scan_directory_incremental(root, None, false, false, false, true).unwrap();
+        let _ = crate::cache::ScanCache::clear(root);
+        assert!(godot.project_info.is_none());
+    }
+
+    #[test]
+    fn changes_since_cache_compares_stats_without_scanning() {
+        let dir = tempdir().unwrap();
+        let root = dir.path().to_str().unwrap();
+        let file = |name: &str| path_to_string(&dir.path().join(name));
+        fs::write(dir.path().join("keep.json"), "{}").unwrap();
+        fs::write(dir.path().join("edit.json"), "{}").unwrap();
+        fs::write(dir.path().join("gone.json"), "{}").unwrap();
+
+        let before = changes_since_cache(root, true, false).unwrap();
+        assert!(!before.has_cache);
+        assert_eq!(before.added_count, 3);
+
+        scan_directory_incremental(root, None, true, false, false, true).unwrap();
+        fs::write(dir.path().join("edit.json"), "{\"a\": 1}").unwrap();
+        fs::remove_file(dir.path().join("gone.json")).unwrap();
+        fs::write(dir.path().join("new.json"), "{}").unwrap();
+        fs::write(dir.path().join("new.json.meta"), "guid: x").unwrap();
+
+        let changes = changes_since_cache(root, true, false).unwrap();
+        let _ = crate::cache::ScanCache::clear(root);
+        assert!(changes.has_cache);
+        assert_eq!(changes.added, [file("new.json")]);
+        assert_eq!(changes.modified, [file("edit.json")]);
+        assert_eq!(changes.deleted, [file("gone.json")]);
+        assert_eq!(
+            (
+                changes.added_count,
+                changes.modified_count,
+                changes.deleted_count
+            ),
+            (1, 1, 1)
+        );
+    }
+
+    #[test]
+    fn engine_version_dispatches_on_project_type() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        let version = |project_type| read_engine_version(root, &project_type).map(|v| v.version);
+
+        fs::create_dir(root.join("ProjectSettings")).unwrap();
+        fs::write(
+            root.join("ProjectSettings/ProjectVersion.txt"),
+            "m_EditorVersion: 2022.3.10f1\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("Game.uproject"),
+            r#"{"FileVersion": 3, "EngineAssociation": "5.3"}"#,
+        )
+        .unwrap();
+        fs::write(
+            root.join("project.godot"),
+            "[application]\nconfig/features=PackedStringArray(\"4.2\", \"Forward Plus\")\n",
+        )
+        .unwrap();
+
+        assert_eq!(version(ProjectType::Unity).as_deref(), Some("2022.3.10f1"));
+        assert_eq!(version(ProjectType::Unreal).as_deref(), Some("5.3"));
+        assert_eq!(version(ProjectType::Godot).as_deref(), Some("4.2"));
+        assert_eq!(version(ProjectType::Generic), None);
+        assert_eq!(
+            read_engine_version(root, &ProjectType::Unity)
+                .unwrap()
+                .engine,
+            ProjectType::Unity
+        );
+    }
+
+    #[test]
+    fn incremental_rescan_reclassifies_when_overrides_change() {
+        let dir = tempdir().unwrap();
+        let root = dir.path().to_str().unwrap();
+        fs::write(
+            dir.path().join("icon.svg"),
+            r#"<svg width="32" height="16"></svg>"#,
+        )
+        .unwrap();
+
+        let (r1, _) = scan_directory_incremental(root, None, false, false, false, true).unwrap();
+        let svg = r1.assets.iter().find(|a| a.extension == "svg").unwrap();
+        assert_eq!(svg.asset_type, AssetType::Texture);
+        assert!(svg.metadata.is_some());
+
+        // Only the config changes — the svg's mtime doesn't, so a stale
+        // cache would keep serving it as a texture.
+        fs::write(
+            dir.path().join("tidycraft.toml"),
+            "[scan.extension_overrides]\n\".SVG\" = \"data\"\n",
+        )
+        .unwrap();
+        let (r2, _) = scan_directory_incremental(root, None, false, false, false, true).unwrap();
+        let _ = crate::cache::ScanCache::clear(root);
+        let svg = r2.assets.iter().find(|a| a.extension == "svg").unwrap();
+        assert_eq!(svg.asset_type, AssetType::Data);
+        assert!(svg.metadata.is_none());
+    }
+
+    #[test]
+    fn multi_root_scan_merges_roots_under_synthetic_node() {
+        let dir = tempdir().unwrap();
+        let art = dir.path().join("ArtSource");
+        let game = dir.path().join("Game");
+        fs::create_dir_all(art.join("Props")).unwrap();
+        fs::create_dir_all(game.join("ProjectSettings")).unwrap();
+        fs::write(art.join("Props").join("crate.png"), "png").unwrap();
+        fs::write(game.join("hit.wav"), "wav").unwrap();
+
+        let game_path = path_to_string(&game);
+        // The nested root re-discovers crate.png — it must appear once.
+        let paths = vec![
+            game_path.clone(),
+            path_to_string(&art),
+            path_to_string(&art.join("Props")),
+            game_path.clone(),
+        ];
+        let (result, stats, types) =
+            scan_directories_incremental(&paths, None, false, false, false, true).unwrap();
+        let mut key_roots: Vec<String> = paths.clone();
+        key_roots.sort();
+        key_roots.dedup();
+        let _ = crate::cache::ScanCache::clear(&multi_root_cache_key(&key_roots));
+
+        assert_eq!(result.total_count, 2);
+        assert_eq!(stats.total_files, 2);
+        assert_eq!(result.root_path, path_to_string(dir.path()));
+        assert_eq!(result.directory_tree.children.len(), 3);
+        // Nested roots overlap; the synthetic node counts each file once.
+        assert_eq!(result.directory_tree.file_count, 2);
+        assert_eq!(result.type_counts.get("texture"), Some(&1));
+        assert_eq!(result.type_counts.get("audio"), Some(&1));
+
+        assert_eq!(types.len(), 3);
+        let game_type = types.iter().find(|t| t.root == game_path).unwrap();
+        assert!(matches!(game_type.project_type, Some(ProjectType::Unity)));
+        assert!(matches!(result.project_type, Some(ProjectType::Unity)));
+    }
+
+    #[test]
+    fn multi_root_scan_rejects_missing_root() {
+        let dir = tempdir().unwrap();
+        let missing = path_to_string(&dir.path().join("nope"));
+        let paths = vec![path_to_string(dir.path()), missing];
+        assert!(matches!(
+            scan_directories_incremental(&paths, None, false, false, false, true),
+            Err(ScanError::PathNotFound(_))
+        ));
+        assert!(scan_directories_incremental(&[], None, false, false, false, true).is_err());
+    }
+
+    #[test]
+    fn common_ancestor_of_sibling_roots() {
+        let a = Path::new("/work/studio/ArtSource");
+        let b = Path::new("/work/studio/Game/Assets");
+        assert_eq!(common_ancestor(&[a, b]), PathBuf::from("/work/studio"));
+        assert_eq!(
+            common_ancestor(&[a]),
+            PathBuf::from("/work/studio/ArtSource")
+        );
+    }
+
     #[test]
     fn directory_tree_prunes_gitignored_dirs() {
         let dir = tempdir().unwrap();
@@ -2761,7 +4926,7 @@ mod tests {
 
         // gitignore respected → Library/ neither walked nor shown.
         let result =
-            scan_directory_with_state(dir.path().to_str().unwrap(), None, true).unwrap();
+            scan_directory_with_state(dir.path().to_str().unwrap(), None, true, false).unwrap();
         let names: Vec<&str> = result
             .directory_tree
             .children
@@ -2777,7 +4942,7 @@ mod tests {
 
         // gitignore off → the dir still appears (scan-everything mode).
         let result_all =
-            scan_directory_with_state(dir.path().to_str().unwrap(), None, false).unwrap();
+            scan_directory_with_state(dir.path().to_str().unwrap(), None, false, false).unwrap();
         assert!(result_all
             .directory_tree
             .children
@@ -0,0 +1,175 @@
+//! Dashboard numbers compared against an earlier snapshot.
+//!
+//! Per-file diffs answer "what changed"; a milestone review wants "how
+//! much": the project grew by 1.2 GB, most of it textures, and the
+//! `> 10 MB` bucket doubled. The baseline is a previously exported
+//! `ProjectStats` or whole `ScanResult` (which is reduced to its stats
+//! first), so any saved export can serve as the reference point.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+
+use crate::scanner::ScanResult;
+use crate::ProjectStats;
+
+/// What `compare_stats_to_baseline` accepts. A scan export is tried
+/// first: its fields don't overlap `ProjectStats`'.
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub enum Baseline {
+    Scan(Box<ScanResult>),
+    Stats(ProjectStats),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Delta {
+    pub baseline: u64,
+    pub current: u64,
+    pub change: i64,
+    /// `change` relative to `baseline`; `None` when the baseline is zero.
+    pub percent: Option<f64>,
+}
+
+impl Delta {
+    fn new(baseline: u64, current: u64) -> Self {
+        let change = current as i64 - baseline as i64;
+        Self {
+            baseline,
+            current,
+            change,
+            percent: (baseline > 0).then(|| change as f64 * 100.0 / baseline as f64),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct KeyedDelta {
+    /// Asset type or size bucket, as `ProjectStats` names them.
+    pub key: String,
+    pub count: Delta,
+    /// `None` for size buckets, and for types when the baseline predates
+    /// per-type sizes.
+    pub size: Option<Delta>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StatsDelta {
+    pub total_assets: Delta,
+    pub total_size: Delta,
+    /// Every type present on either side, largest size growth first.
+    pub by_type: Vec<KeyedDelta>,
+    /// Size buckets that gained files, most growth first.
+    pub grown_size_buckets: Vec<KeyedDelta>,
+}
+
+pub fn compare(baseline: &ProjectStats, current: &ProjectStats) -> StatsDelta {
+    let count = |map: &HashMap<String, usize>, key: &str| map.get(key).copied().unwrap_or(0) as u64;
+    let size = |map: &HashMap<String, u64>, key: &str| map.get(key).copied().unwrap_or(0);
+
+    // Stats exported before `type_sizes` existed carry no sizes to compare.
+    let has_sizes = !baseline.type_sizes.is_empty() || baseline.type_distribution.is_empty();
+    let types: BTreeSet<&String> = baseline
+        .type_distribution
+        .keys()
+        .chain(current.type_distribution.keys())
+        .collect();
+    let mut by_type: Vec<KeyedDelta> = types
+        .into_iter()
+        .map(|key| KeyedDelta {
+            key: key.clone(),
+            count: Delta::new(
+                count(&baseline.type_distribution, key),
+                count(&current.type_distribution, key),
+            ),
+            size: has_sizes.then(|| {
+                Delta::new(
+                    size(&baseline.type_sizes, key),
+                    size(&current.type_sizes, key),
+                )
+            }),
+        })
+        .collect();
+    by_type.sort_by_key(|d| {
+        std::cmp::Reverse((d.size.as_ref().map_or(0, |s| s.change), d.count.change))
+    });
+
+    let buckets: BTreeSet<&String> = baseline
+        .size_distribution
+        .keys()
+        .chain(current.size_distribution.keys())
+        .collect();
+    let mut grown_size_buckets: Vec<KeyedDelta> = buckets
+        .into_iter()
+        .map(|key| KeyedDelta {
+            key: key.clone(),
+            count: Delta::new(
+                count(&baseline.size_distribution, key),
+                count(&current.size_distribution, key),
+            ),
+            size: None,
+        })
+        .filter(|d| d.count.change > 0)
+        .collect();
+    grown_size_buckets.sort_by_key(|d| std::cmp::Reverse(d.count.change));
+
+    StatsDelta {
+        total_assets: Delta::new(baseline.total_assets as u64, current.total_assets as u64),
+        total_size: Delta::new(baseline.total_size, current.total_size),
+        by_type,
+        grown_size_buckets,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(types: &[(&str, usize, u64)], buckets: &[(&str, usize)]) -> ProjectStats {
+        ProjectStats {
+            total_assets: types.iter().map(|t| t.1).sum(),
+            total_size: types.iter().map(|t| t.2).sum(),
+            type_distribution: types.iter().map(|t| (t.0.to_string(), t.1)).collect(),
+            type_sizes: types.iter().map(|t| (t.0.to_string(), t.2)).collect(),
+            size_distribution: buckets.iter().map(|b| (b.0.to_string(), b.1)).collect(),
+            extension_distribution: HashMap::new(),
+            largest_files: Vec::new(),
+            directory_sizes: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn reports_absolute_and_percent_growth() {
+        let before = stats(
+            &[("texture", 10, 1000), ("audio", 4, 400)],
+            &[("1-10 KB", 14)],
+        );
+        let after = stats(
+            &[("texture", 15, 3000), ("audio", 4, 400), ("model", 1, 50)],
+            &[("1-10 KB", 14), ("> 10 MB", 6)],
+        );
+
+        let delta = compare(&before, &after);
+        assert_eq!(delta.total_assets, Delta::new(14, 20));
+        assert_eq!(delta.total_size.change, 2050);
+        let texture = &delta.by_type[0];
+        assert_eq!(texture.key, "texture");
+        assert_eq!(texture.size.as_ref().unwrap().change, 2000);
+        assert_eq!(texture.size.as_ref().unwrap().percent, Some(200.0));
+        let model = delta.by_type.iter().find(|d| d.key == "model").unwrap();
+        assert_eq!(model.count.percent, None);
+        assert_eq!(delta.grown_size_buckets.len(), 1);
+        assert_eq!(delta.grown_size_buckets[0].key, "> 10 MB");
+
+        // A stats export from before per-type sizes: counts only.
+        let old: ProjectStats = serde_json::from_str(
+            r#"{"total_assets":14,"total_size":1400,"type_distribution":{"texture":10,"audio":4},
+                "size_distribution":{},"extension_distribution":{},"largest_files":[],
+                "directory_sizes":{}}"#,
+        )
+        .unwrap();
+        assert!(compare(&old, &after)
+            .by_type
+            .iter()
+            .all(|d| d.size.is_none()));
+    }
+}
@@ -0,0 +1,137 @@
+//! Symbolic links (and Windows junctions) inside a project folder.
+//!
+//! The scan walks with `follow_links(false)`, so a link is invisible: the
+//! files behind it never show up, and nothing says the link exists. Links
+//! still matter — one pointing outside the project works on its author's
+//! machine and breaks the build everywhere else, and one pointing back
+//! inside makes tools that do follow links see the same files twice. This
+//! lists every link with where it points. `fs::symlink_metadata` reports
+//! junctions as links too, and `fs::read_link` reads their target.
+
+use serde::Serialize;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use crate::scanner::{self, ScanError};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SymlinkInfo {
+    pub path: String,
+    /// The target as stored in the link; relative targets are relative to
+    /// the link's folder.
+    pub target: String,
+    /// The target resolves outside the project root: it won't exist on a
+    /// machine with a different layout.
+    pub outside_root: bool,
+    /// Nothing exists at the target.
+    pub broken: bool,
+}
+
+/// Every link under `path`, sorted. Walks like the scan does, except that
+/// hidden entries are included (`.git/` aside): a link is worth knowing
+/// about wherever it hides.
+pub fn find_symlinks(path: &str, respect_gitignore: bool) -> Result<Vec<SymlinkInfo>, ScanError> {
+    let root = Path::new(path);
+    scanner::check_scan_root(root, path)?;
+    let canonical_root = fs::canonicalize(root).unwrap_or_else(|_| root.to_path_buf());
+
+    let mut links: Vec<SymlinkInfo> = scanner::build_walker(root, respect_gitignore, true)
+        .flatten()
+        .filter(|entry| {
+            fs::symlink_metadata(entry.path()).is_ok_and(|meta| meta.file_type().is_symlink())
+        })
+        .filter_map(|entry| {
+            let link = entry.path();
+            let target = fs::read_link(link).ok()?;
+            let parent = link.parent().unwrap_or(root);
+            // `canonicalize` follows chained links; a broken one can only be
+            // resolved lexically, against the canonical folder it lives in.
+            let (resolved, broken) = match fs::canonicalize(link) {
+                Ok(resolved) => (resolved, false),
+                Err(_) => {
+                    let parent = fs::canonicalize(parent).unwrap_or_else(|_| parent.to_path_buf());
+                    (lexical_join(&parent, &target), true)
+                }
+            };
+            Some(SymlinkInfo {
+                path: scanner::path_to_string(link),
+                target: scanner::path_to_string(&target),
+                outside_root: !resolved.starts_with(&canonical_root),
+                broken,
+            })
+        })
+        .collect();
+    links.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(links)
+}
+
+/// `base` joined with `rel`, with `.` and `..` folded away without touching
+/// the filesystem. An absolute `rel` replaces `base`, as in `Path::join`.
+fn lexical_join(base: &Path, rel: &Path) -> PathBuf {
+    let mut out = PathBuf::new();
+    for component in base.join(rel).components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                out.pop();
+            }
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    // Creating symlinks on Windows needs developer mode or elevation.
+    #[cfg(unix)]
+    #[test]
+    fn lists_links_flagging_outside_and_broken_targets() {
+        use std::os::unix::fs::symlink;
+
+        let outside = tempdir().unwrap();
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("Assets/Art")).unwrap();
+        fs::write(root.join("Assets/Art/rock.png"), "x").unwrap();
+        symlink("Art", root.join("Assets/ArtLink")).unwrap();
+        symlink(outside.path(), root.join("Shared")).unwrap();
+        symlink("../missing.png", root.join("Assets/gone.png")).unwrap();
+
+        let links = find_symlinks(root.to_str().unwrap(), true).unwrap();
+        let summary: Vec<(&str, bool, bool)> = links
+            .iter()
+            .map(|l| {
+                (
+                    l.path.strip_prefix(&scanner::path_to_string(root)).unwrap(),
+                    l.outside_root,
+                    l.broken,
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("/Assets/ArtLink", false, false),
+                ("/Assets/gone.png", false, true),
+                ("/Shared", true, false),
+            ]
+        );
+        assert_eq!(links[0].target, "Art");
+    }
+
+    #[test]
+    fn lexical_join_folds_parent_components() {
+        assert_eq!(
+            lexical_join(Path::new("/p/Assets"), Path::new("../Shared/./a.png")),
+            PathBuf::from("/p/Shared/a.png")
+        );
+        assert_eq!(
+            lexical_join(Path::new("/p/Assets"), Path::new("/abs/b.png")),
+            PathBuf::from("/abs/b.png")
+        );
+    }
+}
@@ -3,6 +3,9 @@ use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
 
+use crate::asset_filter::AssetFilter;
+use crate::scanner::AssetInfo;
+
 /// A tag that can be assigned to assets.
 ///
 /// `description` is optional context the user can fill in via TagManager.
@@ -22,6 +25,32 @@ pub struct Tag {
     pub description: Option<String>,
 }
 
+/// Auto-tagging rule: every asset `when` matches gets the tag `then`
+/// names. `then` is a tag id or, failing that, a tag name (case-insensitive);
+/// a name no tag has yet creates the tag on the first apply. `when.directory`
+/// is project-relative (see `AssetFilter::rooted_at`).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AutoTagRule {
+    pub when: AssetFilter,
+    pub then: String,
+}
+
+/// What one auto-tag rule did on an apply run.
+#[derive(Debug, Clone, Serialize)]
+pub struct AutoTagOutcome {
+    pub tag_id: String,
+    pub tag_name: String,
+    /// Scanned assets the rule matched.
+    pub matched: usize,
+    /// Of those, how many gained the tag on this run.
+    pub tagged: usize,
+    /// The tag didn't exist and was created for this rule.
+    pub created_tag: bool,
+}
+
+/// Color of tags created by auto-tag rules (the tag manager's gray preset).
+const AUTO_TAG_COLOR: &str = "#6b7280";
+
 /// Tags storage - persisted to a JSON file in the project root
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct TagsData {
@@ -29,9 +58,13 @@ pub struct TagsData {
     pub tags: Vec<Tag>,
     /// Mapping from asset path to list of tag IDs
     pub asset_tags: HashMap<String, Vec<String>>,
+    /// Applied by `apply_auto_tags` after every scan. Skipped when empty so
+    /// files without rules stay byte-clean.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub auto_tag_rules: Vec<AutoTagRule>,
 }
 
-const TAGS_FILE: &str = ".tidycraft-tags.json";
+pub const TAGS_FILE: &str = ".tidycraft-tags.json";
 
 impl TagsData {
     /// Load tags from the project directory.
@@ -136,26 +169,78 @@ impl TagsData {
         None
     }
 
-    /// Add a tag to an asset
-    pub fn add_tag_to_asset(&mut self, asset_path: &str, tag_id: &str) {
+    /// Add a tag to an asset. Returns whether the asset gained it (false
+    /// for an unknown tag or one it already had).
+    pub fn add_tag_to_asset(&mut self, asset_path: &str, tag_id: &str) -> bool {
         // Verify tag exists
         if !self.tags.iter().any(|t| t.id == tag_id) {
-            return;
+            return false;
         }
 
         let tags = self.asset_tags.entry(asset_path.to_string()).or_default();
-        if !tags.contains(&tag_id.to_string()) {
-            tags.push(tag_id.to_string());
+        if tags.contains(&tag_id.to_string()) {
+            return false;
         }
+        tags.push(tag_id.to_string());
+        true
     }
 
-    /// Remove a tag from an asset
-    pub fn remove_tag_from_asset(&mut self, asset_path: &str, tag_id: &str) {
-        if let Some(tags) = self.asset_tags.get_mut(asset_path) {
-            tags.retain(|id| id != tag_id);
+    /// Remove a tag from an asset. Returns whether the asset had it.
+    pub fn remove_tag_from_asset(&mut self, asset_path: &str, tag_id: &str) -> bool {
+        match self.asset_tags.get_mut(asset_path) {
+            Some(tags) => {
+                let before = tags.len();
+                tags.retain(|id| id != tag_id);
+                tags.len() != before
+            }
+            None => false,
         }
     }
 
+    /// Run every auto-tag rule over `assets` (scanned under `root`), in
+    /// order, creating missing tags. Tagging only adds — assets that stop
+    /// matching keep the tag. One outcome per rule.
+    pub fn apply_auto_tags(&mut self, assets: &[AssetInfo], root: &str) -> Vec<AutoTagOutcome> {
+        let rules = self.auto_tag_rules.clone();
+        rules
+            .iter()
+            .map(|rule| {
+                let (tag, created_tag) = match self.find_tag(&rule.then) {
+                    Some(tag) => (tag.clone(), false),
+                    None => (
+                        self.create_tag(rule.then.clone(), AUTO_TAG_COLOR.to_string()),
+                        true,
+                    ),
+                };
+                let filter = rule.when.rooted_at(root);
+                let mut matched = 0;
+                let mut tagged = 0;
+                for asset in assets.iter().filter(|a| filter.matches(a)) {
+                    matched += 1;
+                    if self.add_tag_to_asset(&asset.path, &tag.id) {
+                        tagged += 1;
+                    }
+                }
+                AutoTagOutcome {
+                    tag_id: tag.id,
+                    tag_name: tag.name,
+                    matched,
+                    tagged,
+                    created_tag,
+                }
+            })
+            .collect()
+    }
+
+    /// Tag by id, else by case-insensitive name.
+    fn find_tag(&self, id_or_name: &str) -> Option<&Tag> {
+        self.tags.iter().find(|t| t.id == id_or_name).or_else(|| {
+            self.tags
+                .iter()
+                .find(|t| t.name.eq_ignore_ascii_case(id_or_name))
+        })
+    }
+
     /// Move every tag binding from `old_path` to `new_path`. If `new_path`
     /// already had bindings they're merged (union of tag IDs). No-op when
     /// `old_path` had no bindings. Used when a file is renamed or moved
@@ -218,6 +303,7 @@ impl TagsData {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::scanner::AssetType;
 
     #[test]
     fn test_create_tag() {
@@ -234,11 +320,15 @@ mod tests {
         let mut data = TagsData::default();
         let tag = data.create_tag("Test".to_string(), "#00ff00".to_string());
 
-        data.add_tag_to_asset("/path/to/asset.png", &tag.id);
+        assert!(data.add_tag_to_asset("/path/to/asset.png", &tag.id));
         assert_eq!(data.get_asset_tags("/path/to/asset.png").len(), 1);
+        // Already tagged / unknown tag: nothing changes.
+        assert!(!data.add_tag_to_asset("/path/to/asset.png", &tag.id));
+        assert!(!data.add_tag_to_asset("/path/to/asset.png", "no-such-tag"));
 
-        data.remove_tag_from_asset("/path/to/asset.png", &tag.id);
+        assert!(data.remove_tag_from_asset("/path/to/asset.png", &tag.id));
         assert_eq!(data.get_asset_tags("/path/to/asset.png").len(), 0);
+        assert!(!data.remove_tag_from_asset("/path/to/asset.png", &tag.id));
     }
 
     #[test]
@@ -298,6 +388,61 @@ mod tests {
         );
     }
 
+    fn asset(path: &str, size: u64) -> AssetInfo {
+        AssetInfo {
+            path: path.to_string(),
+            name: path.rsplit('/').next().unwrap().to_string(),
+            extension: "png".to_string(),
+            asset_type: AssetType::Texture,
+            size,
+            modified: 0,
+            metadata: None,
+            unity_guid: None,
+        }
+    }
+
+    #[test]
+    fn auto_tag_rules_tag_matches_and_create_missing_tags() {
+        let assets = vec![
+            asset("/p/Deprecated/old.png", 10),
+            asset("/p/Art/huge.png", 8 << 20),
+            asset("/p/Art/small.png", 10),
+        ];
+
+        let mut data = TagsData::default();
+        let existing = data.create_tag("Oversized".to_string(), "#ff0000".to_string());
+        data.auto_tag_rules = vec![
+            AutoTagRule {
+                when: AssetFilter {
+                    directory: Some("Deprecated".to_string()),
+                    ..Default::default()
+                },
+                then: "deprecated".to_string(),
+            },
+            AutoTagRule {
+                when: AssetFilter {
+                    min_size: Some(4 << 20),
+                    ..Default::default()
+                },
+                then: "oversized".to_string(),
+            },
+        ];
+
+        let outcomes = data.apply_auto_tags(&assets, "/p");
+        assert!(outcomes[0].created_tag);
+        assert_eq!((outcomes[0].matched, outcomes[0].tagged), (1, 1));
+        // Matched by name, case-insensitively — no duplicate tag.
+        assert_eq!(outcomes[1].tag_id, existing.id);
+        assert!(!outcomes[1].created_tag);
+        assert_eq!(data.tags.len(), 2);
+        assert_eq!(data.get_asset_tags("/p/Art/huge.png")[0].name, "Oversized");
+        assert!(data.get_asset_tags("/p/Art/small.png").is_empty());
+
+        // A second run finds everything already tagged.
+        let again = data.apply_auto_tags(&assets, "/p");
+        assert!(again.iter().all(|o| o.tagged == 0 && !o.created_tag));
+    }
+
     #[test]
     fn save_then_load_roundtrips_and_leaves_no_temp_file() {
         let dir = tempfile::tempdir().unwrap();
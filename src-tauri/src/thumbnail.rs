@@ -1,10 +1,12 @@
 use base64::{engine::general_purpose::STANDARD, Engine};
-use image::{imageops::FilterType, GenericImageView, ImageFormat};
+use image::codecs::gif::GifDecoder;
+use image::{imageops::FilterType, AnimationDecoder, DynamicImage, GenericImageView, ImageFormat};
+use serde::Serialize;
 use sha2::{Digest, Sha256};
 use std::fs::{self, File};
-use std::io::{Cursor, Read};
+use std::io::{BufReader, Cursor, Read};
 use std::path::{Path, PathBuf};
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -24,8 +26,9 @@ fn get_cache_dir() -> Option<PathBuf> {
     dirs::cache_dir().map(|p| p.join("tidycraft").join("thumbnails"))
 }
 
-/// Generate a cache key from file path and modification time
-fn get_cache_key(path: &Path, max_size: u32) -> Option<String> {
+/// Generate a cache key from file path, modification time and the
+/// requested rendering (size + sprite-grid hint)
+fn get_cache_key(path: &Path, max_size: u32, sprite_grid: Option<(u32, u32)>) -> Option<String> {
     let metadata = path.metadata().ok()?;
     let modified = metadata.modified().ok()?;
     let duration = modified.duration_since(SystemTime::UNIX_EPOCH).ok()?;
@@ -34,6 +37,10 @@ fn get_cache_key(path: &Path, max_size: u32) -> Option<String> {
     hasher.update(path.to_string_lossy().as_bytes());
     hasher.update(duration.as_secs().to_le_bytes());
     hasher.update(max_size.to_le_bytes());
+    if let Some((cols, rows)) = sprite_grid {
+        hasher.update(cols.to_le_bytes());
+        hasher.update(rows.to_le_bytes());
+    }
 
     let hash = hasher.finalize();
     Some(format!("{:x}", hash))
@@ -72,7 +79,14 @@ fn save_to_cache(cache_key: &str, data: &[u8]) -> Result<(), ThumbnailError> {
 
 /// Generate a thumbnail and return as base64 encoded PNG
 /// Uses disk cache to avoid regenerating thumbnails
-pub fn get_thumbnail_base64(path: &str, max_size: u32) -> Result<String, ThumbnailError> {
+///
+/// `sprite_grid` is a `(columns, rows)` hint for sprite sheets: the preview
+/// shows the top-left cell instead of the whole sheet shrunk to a smear.
+pub fn get_thumbnail_base64(
+    path: &str,
+    max_size: u32,
+    sprite_grid: Option<(u32, u32)>,
+) -> Result<String, ThumbnailError> {
     let path = Path::new(path);
 
     // Check if file exists and is an image
@@ -87,21 +101,23 @@ pub fn get_thumbnail_base64(path: &str, max_size: u32) -> Result<String, Thumbna
     // supported by `image` at all, and DDS uses our own header-only
     // parser elsewhere (no full decode path). HDR/EXR will lose dynamic
     // range when written out as 8-bit PNG, but a slightly compressed
-    // preview is more useful than no preview.
+    // preview is more useful than no preview. AVIF needs the `avif` crate
+    // feature (dav1d decoder).
     match extension.as_str() {
         "png" | "jpg" | "jpeg" | "gif" | "bmp" | "tga"
         | "tiff" | "tif" | "webp" | "hdr" | "exr" => {}
+        "avif" if cfg!(feature = "avif") => {}
         _ => return Err(ThumbnailError::UnsupportedFormat),
     }
 
     // Try to get from cache first
-    if let Some(cache_key) = get_cache_key(path, max_size) {
+    if let Some(cache_key) = get_cache_key(path, max_size, sprite_grid) {
         if let Some(cached) = get_from_cache(&cache_key) {
             return Ok(cached);
         }
 
         // Generate thumbnail
-        let thumbnail_data = generate_thumbnail(path, max_size)?;
+        let thumbnail_data = generate_thumbnail(path, max_size, sprite_grid)?;
 
         // Save to cache (ignore errors)
         let _ = save_to_cache(&cache_key, &thumbnail_data);
@@ -110,15 +126,39 @@ pub fn get_thumbnail_base64(path: &str, max_size: u32) -> Result<String, Thumbna
         Ok(STANDARD.encode(&thumbnail_data))
     } else {
         // No cache key available, just generate
-        let thumbnail_data = generate_thumbnail(path, max_size)?;
+        let thumbnail_data = generate_thumbnail(path, max_size, sprite_grid)?;
         Ok(STANDARD.encode(&thumbnail_data))
     }
 }
 
 /// Generate thumbnail bytes (PNG format)
-fn generate_thumbnail(path: &Path, max_size: u32) -> Result<Vec<u8>, ThumbnailError> {
-    // Open and decode image
-    let img = image::open(path).map_err(|e| ThumbnailError::ImageOpen(e.to_string()))?;
+fn generate_thumbnail(
+    path: &Path,
+    max_size: u32,
+    sprite_grid: Option<(u32, u32)>,
+) -> Result<Vec<u8>, ThumbnailError> {
+    let is_gif = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| e.eq_ignore_ascii_case("gif"));
+
+    // A grid hint wins over the GIF montage: the caller knows the file is a
+    // sheet. Otherwise an animated GIF gets a 2x2 montage, and everything
+    // else (including single-frame GIFs) decodes as a plain image.
+    let montage = match sprite_grid {
+        None if is_gif => gif_montage(path, max_size)?,
+        _ => None,
+    };
+    let img = match montage {
+        Some(montage) => montage,
+        None => {
+            let img = image::open(path).map_err(|e| ThumbnailError::ImageOpen(e.to_string()))?;
+            match sprite_grid {
+                Some(grid) => crop_sprite_cell(img, grid),
+                None => img,
+            }
+        }
+    };
 
     // Calculate thumbnail size maintaining aspect ratio
     let (width, height) = img.dimensions();
@@ -159,6 +199,72 @@ fn generate_thumbnail(path: &Path, max_size: u32) -> Result<Vec<u8>, ThumbnailEr
     Ok(buffer.into_inner())
 }
 
+/// Top-left cell of a `(columns, rows)` sprite sheet. The hint comes from
+/// the user, not the file — a zero count or a grid finer than the image
+/// leaves the sheet whole rather than failing the preview.
+fn crop_sprite_cell(img: DynamicImage, (cols, rows): (u32, u32)) -> DynamicImage {
+    let (width, height) = img.dimensions();
+    if cols == 0 || rows == 0 || cols > width || rows > height {
+        return img;
+    }
+    img.crop_imm(0, 0, width / cols, height / rows)
+}
+
+/// Frames shown in an animated GIF's montage, laid out 2x2.
+const MONTAGE_FRAMES: usize = 4;
+/// Frame budget for sampling. Longer GIFs are sampled from their first
+/// `MAX_GIF_FRAMES` frames — enough to be representative, and it bounds
+/// the decode work for screen recordings saved as GIF.
+const MAX_GIF_FRAMES: usize = 512;
+
+/// 2x2 montage of evenly spaced frames, fitting in `max_size`. `None` for a
+/// GIF with fewer than two frames — the caller renders it as a still.
+///
+/// Two passes over the file: the first only counts frames, so we never hold
+/// more than the four sampled canvases in memory.
+fn gif_montage(path: &Path, max_size: u32) -> Result<Option<DynamicImage>, ThumbnailError> {
+    let decode = || -> Result<_, ThumbnailError> {
+        let reader = BufReader::new(File::open(path)?);
+        let decoder =
+            GifDecoder::new(reader).map_err(|e| ThumbnailError::ImageOpen(e.to_string()))?;
+        Ok(decoder
+            .into_frames()
+            .take(MAX_GIF_FRAMES)
+            .map_while(Result::ok))
+    };
+
+    let frame_count = decode()?.count();
+    if frame_count < 2 {
+        return Ok(None);
+    }
+    let picks: Vec<usize> = (0..MONTAGE_FRAMES)
+        .map(|i| i * frame_count / MONTAGE_FRAMES)
+        .collect();
+
+    let cell = (max_size / 2).max(1);
+    let mut canvas = image::RgbaImage::new(cell * 2, cell * 2);
+    // `into_frames` yields each frame composited onto the full logical
+    // screen, so every pick is a complete picture. With fewer frames than
+    // cells an index repeats; `slot` keeps each cell distinct.
+    let mut slot = 0;
+    for (index, frame) in decode()?.enumerate() {
+        while slot < MONTAGE_FRAMES && picks[slot] == index {
+            let mut tile = DynamicImage::ImageRgba8(frame.buffer().clone());
+            if tile.width() > cell || tile.height() > cell {
+                tile = tile.resize(cell, cell, FilterType::Triangle);
+            }
+            let x = (slot as u32 % 2) * cell + (cell - tile.width()) / 2;
+            let y = (slot as u32 / 2) * cell + (cell - tile.height()) / 2;
+            image::imageops::overlay(&mut canvas, &tile.to_rgba8(), x as i64, y as i64);
+            slot += 1;
+        }
+        if slot == MONTAGE_FRAMES {
+            break;
+        }
+    }
+    Ok(Some(DynamicImage::ImageRgba8(canvas)))
+}
+
 /// Clear the thumbnail cache
 #[allow(dead_code)]
 pub fn clear_cache() -> Result<(), ThumbnailError> {
@@ -170,6 +276,59 @@ pub fn clear_cache() -> Result<(), ThumbnailError> {
     Ok(())
 }
 
+/// What `verify_cache` deleted.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct CacheRepair {
+    pub removed: usize,
+    pub bytes_reclaimed: u64,
+}
+
+/// A write temp file this old was orphaned by a crash, not mid-rename.
+const STALE_TEMP_AGE: Duration = Duration::from_secs(60 * 60);
+
+/// Decode every cached thumbnail and delete the ones that don't decode —
+/// files cached before writes were atomic can be truncated, and were
+/// served as broken images until their source changed. Write temp files
+/// left behind by a crash are reaped too.
+pub fn verify_cache() -> Result<CacheRepair, ThumbnailError> {
+    match get_cache_dir() {
+        Some(cache_dir) if cache_dir.exists() => verify_cache_dir(&cache_dir),
+        _ => Ok(CacheRepair::default()),
+    }
+}
+
+fn verify_cache_dir(cache_dir: &Path) -> Result<CacheRepair, ThumbnailError> {
+    let mut repair = CacheRepair::default();
+    for entry in fs::read_dir(cache_dir)?.flatten() {
+        let path = entry.path();
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        if !metadata.is_file() {
+            continue;
+        }
+        let name = entry.file_name().to_string_lossy().to_string();
+        let corrupt = if name.ends_with(".png") {
+            fs::read(&path).map_or(true, |bytes| {
+                image::load_from_memory_with_format(&bytes, ImageFormat::Png).is_err()
+            })
+        } else if name.contains(".png.tmp.") {
+            metadata
+                .modified()
+                .ok()
+                .and_then(|m| m.elapsed().ok())
+                .is_some_and(|age| age > STALE_TEMP_AGE)
+        } else {
+            false
+        };
+        if corrupt && fs::remove_file(&path).is_ok() {
+            repair.removed += 1;
+            repair.bytes_reclaimed += metadata.len();
+        }
+    }
+    Ok(repair)
+}
+
 /// Get cache size in bytes
 #[allow(dead_code)]
 pub fn get_cache_size() -> u64 {
@@ -211,9 +370,124 @@ mod tests {
             .save_with_format(&path, ImageFormat::Hdr)
             .expect("write test .hdr");
 
-        let bytes =
-            generate_thumbnail(&path, 256).expect("HDR thumbnail must encode to PNG, not error");
+        let bytes = generate_thumbnail(&path, 256, None)
+            .expect("HDR thumbnail must encode to PNG, not error");
         // The output is a real PNG (8-byte signature), not an encoder failure.
         assert_eq!(&bytes[..8], b"\x89PNG\r\n\x1a\n");
     }
+
+    fn decode_png(bytes: &[u8]) -> image::RgbaImage {
+        image::load_from_memory_with_format(bytes, ImageFormat::Png)
+            .expect("thumbnail is a PNG")
+            .to_rgba8()
+    }
+
+    #[test]
+    fn animated_gif_renders_a_two_by_two_montage() {
+        use image::codecs::gif::GifEncoder;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("walk.gif");
+        let colors = [
+            [255, 0, 0, 255],
+            [0, 255, 0, 255],
+            [0, 0, 255, 255],
+            [255, 255, 255, 255],
+        ];
+        {
+            let mut encoder = GifEncoder::new(File::create(&path).unwrap());
+            encoder
+                .encode_frames(colors.iter().map(|c| {
+                    image::Frame::new(image::RgbaImage::from_pixel(16, 16, image::Rgba(*c)))
+                }))
+                .expect("write test .gif");
+        }
+
+        let thumb = decode_png(&generate_thumbnail(&path, 64, None).unwrap());
+        assert_eq!(thumb.dimensions(), (64, 64));
+        // Frames fill the cells in reading order.
+        assert_eq!(thumb.get_pixel(8, 8).0, colors[0]);
+        assert_eq!(thumb.get_pixel(40, 8).0, colors[1]);
+        assert_eq!(thumb.get_pixel(8, 40).0, colors[2]);
+        assert_eq!(thumb.get_pixel(40, 40).0, colors[3]);
+    }
+
+    #[test]
+    fn sprite_grid_hint_crops_the_first_cell() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("sheet.png");
+        // Four 8x8 cells in a row; only the first is red.
+        let sheet = image::RgbaImage::from_fn(32, 8, |x, _| {
+            if x < 8 {
+                image::Rgba([255, 0, 0, 255])
+            } else {
+                image::Rgba([0, 0, 255, 255])
+            }
+        });
+        sheet.save(&path).unwrap();
+
+        let thumb = decode_png(&generate_thumbnail(&path, 64, Some((4, 1))).unwrap());
+        assert_eq!(thumb.dimensions(), (8, 8));
+        assert!(thumb.pixels().all(|p| p.0 == [255, 0, 0, 255]));
+
+        // A nonsensical hint leaves the whole sheet.
+        let whole = decode_png(&generate_thumbnail(&path, 64, Some((0, 1))).unwrap());
+        assert_eq!(whole.dimensions(), (32, 8));
+    }
+
+    #[test]
+    fn verify_cache_dir_deletes_only_undecodable_thumbnails() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut good = Vec::new();
+        image::RgbaImage::new(16, 16)
+            .write_to(&mut Cursor::new(&mut good), ImageFormat::Png)
+            .unwrap();
+        fs::write(dir.path().join("good.png"), &good).unwrap();
+        // Killed mid-write: the header survives, the data doesn't.
+        fs::write(dir.path().join("torn.png"), &good[..good.len() / 2]).unwrap();
+        fs::write(dir.path().join("junk.png"), b"not a png").unwrap();
+        // A fresh temp file may belong to a write in progress.
+        fs::write(dir.path().join("good.png.tmp.1.0"), &good[..8]).unwrap();
+
+        let repair = verify_cache_dir(dir.path()).unwrap();
+        assert_eq!(
+            repair,
+            CacheRepair {
+                removed: 2,
+                bytes_reclaimed: (good.len() / 2 + 9) as u64,
+            }
+        );
+        let mut left: Vec<String> = fs::read_dir(dir.path())
+            .unwrap()
+            .map(|e| e.unwrap().file_name().to_string_lossy().to_string())
+            .collect();
+        left.sort();
+        assert_eq!(left, ["good.png", "good.png.tmp.1.0"]);
+    }
+
+    #[test]
+    fn webp_decodes_and_corrupt_input_is_an_open_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("leaf.webp");
+        image::RgbaImage::from_pixel(16, 8, image::Rgba([0, 128, 0, 255]))
+            .save_with_format(&path, ImageFormat::WebP)
+            .expect("write test .webp");
+        let thumb = decode_png(&generate_thumbnail(&path, 64, None).unwrap());
+        assert_eq!(thumb.dimensions(), (16, 8));
+
+        for name in ["broken.webp", "broken.avif"] {
+            let path = dir.path().join(name);
+            fs::write(&path, b"not an image").unwrap();
+            let result = get_thumbnail_base64(&path.to_string_lossy(), 64, None);
+            if name.ends_with(".avif") && !cfg!(feature = "avif") {
+                assert!(matches!(result, Err(ThumbnailError::UnsupportedFormat)));
+            } else {
+                assert!(
+                    matches!(result, Err(ThumbnailError::ImageOpen(_))),
+                    "{}",
+                    name
+                );
+            }
+        }
+    }
 }
@@ -0,0 +1,158 @@
+//! The scan's directory tree as indented text.
+//!
+//! For pasting a project's layout into docs, a wiki page or a bug report,
+//! where a screenshot of the sidebar can't be searched or diffed. Draws
+//! `├──` / `└──` connectors like `tree(1)`; plain text, so it drops into a
+//! fenced Markdown block as is. Only folders are listed — the files of a
+//! game project would drown the structure.
+
+use crate::format_size;
+use crate::scanner::DirectoryNode;
+
+#[derive(Debug, Clone, Default)]
+pub struct TreeTextOptions {
+    /// Levels below the root to list; `None` lists everything.
+    pub max_depth: Option<usize>,
+    /// Annotate each folder with its total size and file count.
+    pub show_sizes: bool,
+    /// Folders smaller than this are folded into one "smaller folders"
+    /// line per parent.
+    pub min_size: u64,
+}
+
+pub fn render(root: &DirectoryNode, options: &TreeTextOptions) -> String {
+    let mut out = label(root, options);
+    out.push('\n');
+    render_children(root, "", 1, options, &mut out);
+    out
+}
+
+fn render_children(
+    node: &DirectoryNode,
+    prefix: &str,
+    depth: usize,
+    options: &TreeTextOptions,
+    out: &mut String,
+) {
+    if options.max_depth.is_some_and(|max| depth > max) {
+        return;
+    }
+    let (shown, folded): (Vec<&DirectoryNode>, Vec<&DirectoryNode>) = node
+        .children
+        .iter()
+        .partition(|child| child.total_size >= options.min_size);
+
+    let mut lines: Vec<(String, Option<&DirectoryNode>)> = shown
+        .iter()
+        .map(|child| (label(child, options), Some(*child)))
+        .collect();
+    if !folded.is_empty() {
+        let size: u64 = folded.iter().map(|c| c.total_size).sum();
+        lines.push((
+            format!(
+                "… {} smaller folder{} ({})",
+                folded.len(),
+                if folded.len() == 1 { "" } else { "s" },
+                format_size(size)
+            ),
+            None,
+        ));
+    }
+
+    let last = lines.len().saturating_sub(1);
+    for (i, (text, child)) in lines.into_iter().enumerate() {
+        let (connector, indent) = if i == last {
+            ("└── ", "    ")
+        } else {
+            ("├── ", "│   ")
+        };
+        out.push_str(prefix);
+        out.push_str(connector);
+        out.push_str(&text);
+        out.push('\n');
+        if let Some(child) = child {
+            render_children(
+                child,
+                &format!("{}{}", prefix, indent),
+                depth + 1,
+                options,
+                out,
+            );
+        }
+    }
+}
+
+fn label(node: &DirectoryNode, options: &TreeTextOptions) -> String {
+    if options.show_sizes {
+        format!(
+            "{}/ ({}, {} file{})",
+            node.name,
+            format_size(node.total_size),
+            node.file_count,
+            if node.file_count == 1 { "" } else { "s" }
+        )
+    } else {
+        format!("{}/", node.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dir(name: &str, total_size: u64, children: Vec<DirectoryNode>) -> DirectoryNode {
+        DirectoryNode {
+            name: name.to_string(),
+            path: format!("/p/{}", name),
+            children,
+            file_count: 1,
+            total_size,
+        }
+    }
+
+    #[test]
+    fn draws_connectors_and_folds_small_and_deep_folders() {
+        let tree = dir(
+            "Game",
+            5000,
+            vec![
+                dir(
+                    "Assets",
+                    4000,
+                    vec![
+                        dir("Art", 3000, vec![dir("Rocks", 3000, vec![])]),
+                        dir("Docs", 10, vec![]),
+                        dir("Temp", 20, vec![]),
+                    ],
+                ),
+                dir("Settings", 1000, vec![]),
+            ],
+        );
+
+        let text = render(
+            &tree,
+            &TreeTextOptions {
+                max_depth: Some(2),
+                show_sizes: false,
+                min_size: 100,
+            },
+        );
+        assert_eq!(
+            text,
+            "Game/\n\
+             ├── Assets/\n\
+             │   ├── Art/\n\
+             │   └── … 2 smaller folders (30 B)\n\
+             └── Settings/\n"
+        );
+
+        let sized = render(
+            &dir("Game", 2048, vec![]),
+            &TreeTextOptions {
+                show_sizes: true,
+                ..Default::default()
+            },
+        );
+        assert_eq!(sized, "Game/ (2.0 KB, 1 file)\n");
+    }
+}
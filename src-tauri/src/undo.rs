@@ -32,8 +32,11 @@ pub enum OperationType {
     Rename,
     /// 移动操作（预留）
     Move,
-    /// 删除操作（预留，需要备份机制）
+    /// 删除操作（移入系统回收站；撤销 = 从回收站还原）
     Delete,
+    /// 内容改写(如统一换行符)。`new_path` 指向改写前内容的备份
+    /// (`backup_before_edit`);撤销 = 用备份覆盖回原文件,随后删掉备份。
+    Edit,
 }
 
 /// 批量操作记录
@@ -177,7 +180,7 @@ impl UndoManager {
 
         // 超过最大历史记录数时移除最旧的
         while self.history.len() > self.max_history {
-            self.history.remove(0);
+            discard_backups(&self.history.remove(0));
         }
 
         self.save_to_disk();
@@ -242,10 +245,29 @@ impl UndoManager {
 
     /// 清空历史记录
     pub fn clear_history(&mut self) {
-        self.history.clear();
+        for batch in self.history.drain(..) {
+            discard_backups(&batch);
+        }
         self.save_to_disk();
     }
 
+    /// 改写 `path` 之前把它的当前内容复制一份,返回备份路径——作为 `Edit`
+    /// 操作的 `new_path` 记录。备份放在撤销历史旁的 `backups/` 下(纯内存
+    /// 管理器用系统临时目录),不落在项目里,扫描永远看不到。
+    pub fn backup_before_edit(&self, path: &Path) -> Result<String, String> {
+        let dir = match &self.persist_path {
+            Some(p) => p.with_file_name("backups"),
+            None => std::env::temp_dir().join("tidycraft-undo-backups"),
+        };
+        fs::create_dir_all(&dir)
+            .map_err(|e| format!("Failed to create '{}': {}", dir.display(), e))?;
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        let backup = dir.join(format!("{}-{}", uuid::Uuid::new_v4().simple(), name));
+        fs::copy(path, &backup)
+            .map_err(|e| format!("Failed to back up '{}': {}", path.display(), e))?;
+        Ok(crate::scanner::path_to_string(&backup))
+    }
+
     /// 获取最近一次操作的描述
     #[allow(dead_code)]
     pub fn get_last_operation_description(&self) -> Option<String> {
@@ -296,8 +318,11 @@ fn execute_batch_undo(operations: &[FileOperation]) -> UndoResult {
         match execute_single_undo(op) {
             Ok(()) => {
                 reverted_count += 1;
-                if let Some(np) = &op.new_path {
-                    reverted_pairs.push((op.original_path.clone(), np.clone()));
+                // Edit 的 new_path 是备份,不是文件曾经的位置,没有标签要迁。
+                if op.operation_type != OperationType::Edit {
+                    if let Some(np) = &op.new_path {
+                        reverted_pairs.push((op.original_path.clone(), np.clone()));
+                    }
                 }
             }
             Err(e) => {
@@ -411,13 +436,98 @@ fn execute_single_undo(operation: &FileOperation) -> Result<(), String> {
             }
             Ok(())
         }
-        OperationType::Delete => {
-            // 删除操作的撤销需要备份机制，目前不支持
-            Err("Undo for delete operations is not yet supported".to_string())
+        OperationType::Delete => restore_from_trash(Path::new(&operation.original_path)),
+        OperationType::Edit => {
+            let backup = operation
+                .new_path
+                .as_ref()
+                .ok_or("Missing backup path for edit operation")?;
+            let content = fs::read(backup)
+                .map_err(|e| format!("Backup of '{}' is gone: {}", operation.original_path, e))?;
+            crate::fs_atomic::write_atomic(Path::new(&operation.original_path), &content)
+                .map_err(|e| format!("Failed to restore '{}': {}", operation.original_path, e))?;
+            let _ = fs::remove_file(backup);
+            Ok(())
+        }
+    }
+}
+
+/// 批次被挤出历史或清空时,删掉其 `Edit` 操作留下的备份——它们再也用不上了。
+/// 已撤销的备份早已删除,这里 best-effort。
+fn discard_backups(batch: &BatchOperation) {
+    for op in &batch.operations {
+        if let (OperationType::Edit, Some(backup)) = (&op.operation_type, &op.new_path) {
+            let _ = fs::remove_file(backup);
         }
     }
 }
 
+/// 从系统回收站还原 `path`,连同它的 Unity .meta(若也在回收站里)。同一路径被
+/// 删过多次时取最近一次。.meta 还原失败只记日志——Unity 会重新生成 sidecar,
+/// 资源本身回来了就算撤销成功。
+#[cfg(any(
+    target_os = "windows",
+    all(
+        unix,
+        not(target_os = "macos"),
+        not(target_os = "ios"),
+        not(target_os = "android")
+    )
+))]
+fn restore_from_trash(path: &Path) -> Result<(), String> {
+    use trash::os_limited;
+
+    if path.exists() {
+        return Err(format!(
+            "Cannot restore '{}': a file already exists there",
+            path.display()
+        ));
+    }
+
+    let items = os_limited::list().map_err(|e| format!("Failed to read the trash: {}", e))?;
+    let latest = |target: &Path| {
+        items
+            .iter()
+            .filter(|item| item.original_path() == target)
+            .max_by_key(|item| item.time_deleted)
+            .cloned()
+    };
+
+    let item =
+        latest(path).ok_or_else(|| format!("'{}' is no longer in the trash", path.display()))?;
+    let sidecar = latest(&crate::meta_sidecar::sidecar_path(path));
+
+    os_limited::restore_all([item])
+        .map_err(|e| format!("Failed to restore '{}': {}", path.display(), e))?;
+    if let Some(meta) = sidecar {
+        if let Err(e) = os_limited::restore_all([meta]) {
+            eprintln!(
+                "[undo] .meta sidecar not restored for {}: {}",
+                path.display(),
+                e
+            );
+        }
+    }
+    Ok(())
+}
+
+/// macOS 的 `trash` crate 没有列举 / 还原接口,只能请用户在 Finder 里「放回原处」。
+#[cfg(not(any(
+    target_os = "windows",
+    all(
+        unix,
+        not(target_os = "macos"),
+        not(target_os = "ios"),
+        not(target_os = "android")
+    )
+)))]
+fn restore_from_trash(path: &Path) -> Result<(), String> {
+    Err(format!(
+        "'{}' is in the Trash; use Put Back in Finder to restore it",
+        path.display()
+    ))
+}
+
 /// 生成唯一的操作 ID。用 uuid v4 —— 旧实现是 `秒级时间戳 ^ 栈地址`,而同一
 /// 调用点的栈地址通常不变,于是同一秒内记录的两批操作会生成相同 id,
 /// 按 id 查找的路径(如 undo 历史列表)命中第一个 → 关联到错误的批次。
@@ -558,6 +668,32 @@ mod tests {
         assert!(!new_path.exists());
     }
 
+    #[test]
+    fn test_undo_edit_restores_backup() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("data.json");
+        fs::write(&path, "a\r\nb\n").unwrap();
+
+        let mut manager = UndoManager::new(10);
+        let backup = manager.backup_before_edit(&path).unwrap();
+        fs::write(&path, "a\nb\n").unwrap();
+        manager.record_batch(
+            "Normalize line endings".to_string(),
+            vec![FileOperation {
+                operation_type: OperationType::Edit,
+                original_path: path.to_string_lossy().to_string(),
+                new_path: Some(backup.clone()),
+                timestamp: current_timestamp(),
+            }],
+        );
+
+        let result = manager.undo_last().unwrap();
+        assert!(result.success);
+        assert!(result.reverted_pairs.is_empty());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "a\r\nb\n");
+        assert!(!Path::new(&backup).exists());
+    }
+
     #[test]
     fn test_undo_rename_carries_meta_sidecar() {
         // Undoing a rename must move the Unity .meta sidecar back too —
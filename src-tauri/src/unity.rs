@@ -9,6 +9,25 @@ pub struct UnityReference {
     pub guid: String,
     pub file_id: Option<i64>,
     pub ref_type: Option<i32>,
+    pub reference_kind: ReferenceKind,
+}
+
+/// How a reference arises. Prefab instancing writes the source prefab's GUID
+/// into the file several times over (`m_SourcePrefab`, every override
+/// `target:`, every stripped object's `m_CorrespondingSourceObject`) — those
+/// are one structural link, not content the file uses directly.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ReferenceKind {
+    /// An ordinary PPtr field: a material's texture, a renderer's mesh, a
+    /// component's script.
+    Direct,
+    /// The base of a prefab variant — the `.prefab` file's root is itself a
+    /// `PrefabInstance` of this prefab.
+    PrefabBase,
+    /// A prefab instanced inside this file: a nested prefab in a prefab, or
+    /// any prefab placed in a scene.
+    Nested,
 }
 
 /// Parsed Unity file data
@@ -60,7 +79,7 @@ pub fn parse_unity_file(path: &Path) -> Option<UnityFileInfo> {
     let content = fs::read_to_string(path).ok()?;
 
     // Extract all GUID references
-    let references = extract_references(&content);
+    let references = extract_file_references(&content, &file_type);
 
     // Extract component types (for prefabs)
     let components = if matches!(file_type, UnityFileType::Prefab | UnityFileType::Scene) {
@@ -79,6 +98,254 @@ pub fn parse_unity_file(path: &Path) -> Option<UnityFileInfo> {
     })
 }
 
+/// One GameObject (or prefab instance) in a scene/prefab hierarchy.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SceneNode {
+    pub name: String,
+    /// Local fileID of the object's document within the file.
+    pub file_id: i64,
+    /// Attached component types in `m_Component` order (Transform first).
+    /// Empty for prefab instances.
+    pub components: Vec<String>,
+    pub children: Vec<SceneNode>,
+    /// Set on prefab instances: GUID of the source prefab. Their contents
+    /// live in that prefab, so they are reported as leaves.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefab_guid: Option<String>,
+}
+
+/// One `--- !u!<class> &<fileID>` document of a Unity YAML file.
+struct UnityDocument<'a> {
+    class_id: i32,
+    file_id: i64,
+    /// `stripped` documents are stand-ins for objects that live inside a
+    /// prefab instance; they only carry the link back to the instance.
+    stripped: bool,
+    lines: Vec<&'a str>,
+}
+
+impl UnityDocument<'_> {
+    /// Raw value of the first `key:` line in the document.
+    fn field(&self, key: &str) -> Option<&str> {
+        self.lines.iter().find_map(|line| {
+            line.trim_start()
+                .strip_prefix(key)
+                .and_then(|rest| rest.strip_prefix(':'))
+                .map(str::trim)
+        })
+    }
+
+    fn field_file_id(&self, key: &str) -> Option<i64> {
+        self.field(key).and_then(extract_file_id)
+    }
+
+    /// fileIDs from a block list (`key:` followed by `- ...{fileID: n}`
+    /// lines). An inline `key: []` yields nothing.
+    fn list_file_ids(&self, key: &str) -> Vec<i64> {
+        let header = format!("{}:", key);
+        let Some(start) = self.lines.iter().position(|l| l.trim() == header) else {
+            return Vec::new();
+        };
+        self.lines[start + 1..]
+            .iter()
+            .map(|l| l.trim_start())
+            .take_while(|l| l.starts_with('-'))
+            .filter_map(extract_file_id)
+            .collect()
+    }
+}
+
+fn split_unity_documents(content: &str) -> Vec<UnityDocument<'_>> {
+    let mut docs: Vec<UnityDocument> = Vec::new();
+    for line in content.lines() {
+        if line.starts_with("---") && line.contains("!u!") {
+            let Some(class_id) = extract_unity_class_id(line) else {
+                continue;
+            };
+            let file_id = line
+                .find('&')
+                .map(|i| {
+                    line[i + 1..]
+                        .chars()
+                        .take_while(|c| c.is_ascii_digit() || *c == '-')
+                        .collect::<String>()
+                })
+                .and_then(|n| n.parse().ok())
+                .unwrap_or(0);
+            docs.push(UnityDocument {
+                class_id,
+                file_id,
+                stripped: line.trim_end().ends_with("stripped"),
+                lines: Vec::new(),
+            });
+        } else if let Some(doc) = docs.last_mut() {
+            doc.lines.push(line);
+        }
+    }
+    docs
+}
+
+const CLASS_GAME_OBJECT: i32 = 1;
+const CLASS_TRANSFORM: i32 = 4;
+const CLASS_RECT_TRANSFORM: i32 = 224;
+const CLASS_PREFAB_INSTANCE: i32 = 1001;
+
+/// Reconstruct the GameObject hierarchy of a scene or prefab file. Returns
+/// the root nodes in file order, or `None` when `path` isn't a `.unity` /
+/// `.prefab` file or can't be read.
+pub fn parse_scene_hierarchy(path: &Path) -> Option<Vec<SceneNode>> {
+    let extension = path.extension()?.to_str()?;
+    if !matches!(
+        UnityFileType::from_extension(extension),
+        UnityFileType::Scene | UnityFileType::Prefab
+    ) {
+        return None;
+    }
+    let content = fs::read_to_string(path).ok()?;
+    Some(build_scene_hierarchy(&content))
+}
+
+/// Hierarchy links live on the Transforms: each names its GameObject
+/// (`m_GameObject`), parent (`m_Father`) and ordered `m_Children`. Prefab
+/// instances hang off a parent Transform via `m_TransformParent`, and the
+/// parent's `m_Children` lists the instance's `stripped` root Transform.
+fn build_scene_hierarchy(content: &str) -> Vec<SceneNode> {
+    let docs = split_unity_documents(content);
+    let by_id: HashMap<i64, &UnityDocument> = docs.iter().map(|d| (d.file_id, d)).collect();
+    let instances = instances_by_parent(&docs);
+    let mut visited: HashSet<i64> = HashSet::new();
+    let mut roots = Vec::new();
+
+    for doc in &docs {
+        let node = match doc.class_id {
+            CLASS_TRANSFORM | CLASS_RECT_TRANSFORM
+                if !doc.stripped && doc.field_file_id("m_Father").unwrap_or(0) == 0 =>
+            {
+                transform_node(doc.file_id, &by_id, &instances, &mut visited)
+            }
+            CLASS_PREFAB_INSTANCE if doc.field_file_id("m_TransformParent").unwrap_or(0) == 0 => {
+                prefab_instance_node(doc, &mut visited)
+            }
+            _ => None,
+        };
+        roots.extend(node);
+    }
+    roots
+}
+
+/// `PrefabInstance` documents keyed by the Transform they're parented
+/// under (`m_TransformParent`), in file order — built once per file so
+/// hierarchy walks don't rescan every document for each Transform.
+fn instances_by_parent<'d, 'c>(
+    docs: &'d [UnityDocument<'c>],
+) -> HashMap<i64, Vec<&'d UnityDocument<'c>>> {
+    let mut instances: HashMap<i64, Vec<&UnityDocument>> = HashMap::new();
+    for doc in docs.iter().filter(|d| d.class_id == CLASS_PREFAB_INSTANCE) {
+        if let Some(parent) = doc.field_file_id("m_TransformParent") {
+            instances.entry(parent).or_default().push(doc);
+        }
+    }
+    instances
+}
+
+fn transform_node(
+    transform_id: i64,
+    by_id: &HashMap<i64, &UnityDocument>,
+    instances: &HashMap<i64, Vec<&UnityDocument>>,
+    visited: &mut HashSet<i64>,
+) -> Option<SceneNode> {
+    let transform = by_id.get(&transform_id)?;
+    if transform.stripped {
+        let instance = by_id.get(&transform.field_file_id("m_PrefabInstance")?)?;
+        return prefab_instance_node(instance, visited);
+    }
+    // Guards against malformed files with parent cycles.
+    if !visited.insert(transform_id) {
+        return None;
+    }
+
+    let game_object = transform
+        .field_file_id("m_GameObject")
+        .and_then(|id| by_id.get(&id))
+        .filter(|d| d.class_id == CLASS_GAME_OBJECT);
+    let name = game_object
+        .and_then(|go| go.field("m_Name"))
+        .map(unquote_yaml)
+        .unwrap_or_default();
+    let components = game_object
+        .map(|go| {
+            go.list_file_ids("m_Component")
+                .into_iter()
+                .filter_map(|id| by_id.get(&id))
+                .map(|c| {
+                    unity_class_name(c.class_id)
+                        .map(str::to_string)
+                        .unwrap_or_else(|| format!("ClassID {}", c.class_id))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut children: Vec<SceneNode> = transform
+        .list_file_ids("m_Children")
+        .into_iter()
+        .filter_map(|id| transform_node(id, by_id, instances, visited))
+        .collect();
+    // Older serializations don't list instance roots in `m_Children`; pick
+    // up any instance parented here that wasn't reached above.
+    for instance in instances.get(&transform_id).into_iter().flatten() {
+        children.extend(prefab_instance_node(instance, visited));
+    }
+
+    Some(SceneNode {
+        name,
+        file_id: game_object.map(|go| go.file_id).unwrap_or(transform_id),
+        components,
+        children,
+        prefab_guid: None,
+    })
+}
+
+fn prefab_instance_node(instance: &UnityDocument, visited: &mut HashSet<i64>) -> Option<SceneNode> {
+    if !visited.insert(instance.file_id) {
+        return None;
+    }
+    // Renamed instances record the name as an `m_Name` property override.
+    let mut name = None;
+    let mut lines = instance.lines.iter().map(|l| l.trim());
+    while let Some(line) = lines.next() {
+        if line == "propertyPath: m_Name" {
+            name = lines
+                .next()
+                .and_then(|l| l.strip_prefix("value:"))
+                .map(unquote_yaml)
+                .filter(|v| !v.is_empty());
+            break;
+        }
+    }
+    let prefab_guid = prefab_source_guid(instance);
+
+    Some(SceneNode {
+        name: name.unwrap_or_else(|| "Prefab Instance".to_string()),
+        file_id: instance.file_id,
+        components: Vec::new(),
+        children: Vec::new(),
+        prefab_guid,
+    })
+}
+
+/// Strip the single or double quotes Unity adds around names with
+/// YAML-significant characters.
+pub(crate) fn unquote_yaml(raw: &str) -> String {
+    let v = raw.trim();
+    for q in ['\'', '"'] {
+        if v.len() >= 2 && v.starts_with(q) && v.ends_with(q) {
+            return v[1..v.len() - 1].to_string();
+        }
+    }
+    v.to_string()
+}
+
 /// Unity project info surfaced on the Stats dashboard's engine card. Parsed
 /// from `ProjectSettings/ProjectVersion.txt` — plain `key: value` YAML the
 /// editor rewrites on every version switch and that is committed to VCS by
@@ -119,6 +386,47 @@ pub fn parse_project_version(root_path: &Path) -> Option<UnityProjectInfo> {
     })
 }
 
+/// Player identity from `ProjectSettings/ProjectSettings.asset`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UnityPlayerSettings {
+    pub product_name: Option<String>,
+    pub company_name: Option<String>,
+}
+
+/// Read `productName` / `companyName` from `ProjectSettings.asset`. `None`
+/// when the file is missing or unreadable (locked by a running editor on
+/// Windows), or saved with binary serialization (no `%YAML` header).
+pub fn parse_player_settings(root_path: &Path) -> Option<UnityPlayerSettings> {
+    let path = root_path
+        .join("ProjectSettings")
+        .join("ProjectSettings.asset");
+    let content = fs::read_to_string(path).ok()?;
+    if !content.starts_with("%YAML") {
+        return None;
+    }
+
+    // Unity quotes a scalar only when it has to: `'It''s'` → `It's`.
+    let scalar = |v: &str| {
+        let v = v.trim();
+        let v = match v.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')) {
+            Some(inner) => inner.replace("''", "'"),
+            None => v.trim_matches('"').to_string(),
+        };
+        Some(v).filter(|v| !v.is_empty())
+    };
+    let mut settings = UnityPlayerSettings::default();
+    for line in content.lines() {
+        // Top-level PlayerSettings fields sit at two-space indent; deeper
+        // ones (per-platform blocks) don't carry these names.
+        if let Some(v) = line.strip_prefix("  productName:") {
+            settings.product_name = scalar(v);
+        } else if let Some(v) = line.strip_prefix("  companyName:") {
+            settings.company_name = scalar(v);
+        }
+    }
+    Some(settings)
+}
+
 /// One package asset resolved from `Library/PackageCache`.
 #[derive(Debug, Clone)]
 pub struct PackageAssetRef {
@@ -271,6 +579,372 @@ pub fn is_builtin_guid(guid: &str) -> bool {
         && bytes[17..].iter().all(|&b| b == b'0')
 }
 
+/// A material's `m_Shader` PPtr. Built-in shaders share the two built-in
+/// bundle GUIDs and differ only by `file_id`; a project or package shader is
+/// identified by its GUID alone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShaderPtr {
+    pub file_id: i64,
+    /// `None` for `{fileID: 0}` — no shader assigned.
+    pub guid: Option<String>,
+}
+
+/// The `m_Shader` reference in a `.mat` file's content, or `None` when the
+/// file has no such field (not a material).
+pub fn material_shader(content: &str) -> Option<ShaderPtr> {
+    let line = content
+        .lines()
+        .map(str::trim)
+        .find(|line| line.starts_with("m_Shader:"))?;
+    Some(ShaderPtr {
+        file_id: extract_file_id(line).unwrap_or(0),
+        guid: pptr_guid(line),
+    })
+}
+
+/// The GUID of a one-line PPtr (`{fileID: .., guid: .., type: ..}`), or
+/// `None` when it has none or it's the null GUID.
+fn pptr_guid(line: &str) -> Option<String> {
+    let start = line.find("guid:")?;
+    let guid: String = line[start + 5..]
+        .trim_start()
+        .chars()
+        .take_while(|c| c.is_ascii_hexdigit())
+        .collect();
+    (guid.len() == 32 && !is_null_guid(&guid)).then_some(guid)
+}
+
+/// One `m_TexEnvs` entry of a material.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextureSlot {
+    /// Shader property name, e.g. `_MainTex`.
+    pub name: String,
+    /// `None` when the slot is empty.
+    pub guid: Option<String>,
+}
+
+/// A `.mat` file's texture slots in file order, from both `m_TexEnvs`
+/// layouts: `- _MainTex:` and the pre-2018 `- first: {name: _MainTex}`
+/// with the texture under `second:`.
+pub fn material_texture_slots(content: &str) -> Vec<TextureSlot> {
+    let mut slots: Vec<TextureSlot> = Vec::new();
+    let mut in_tex_envs = false;
+    for line in content.lines() {
+        // `m_SavedProperties`' sections sit at four spaces, their list
+        // entries at `    - `.
+        if let Some(field) = line.strip_prefix("    ") {
+            if !field.starts_with(' ') && !field.starts_with('-') {
+                in_tex_envs = field.trim_end() == "m_TexEnvs:";
+                continue;
+            }
+        } else {
+            in_tex_envs = false;
+            continue;
+        }
+        if !in_tex_envs {
+            continue;
+        }
+        let trimmed = line.trim();
+        if let Some(item) = trimmed.strip_prefix("- ") {
+            // Legacy entries name the slot on a following `name:` line.
+            let name = item.trim_end_matches(':');
+            slots.push(TextureSlot {
+                name: if name == "first" { "" } else { name }.to_string(),
+                guid: None,
+            });
+        } else if let Some(slot) = slots.last_mut() {
+            if let Some(name) = trimmed.strip_prefix("name:") {
+                slot.name = name.trim().to_string();
+            } else if trimmed.starts_with("m_Texture:") {
+                slot.guid = pptr_guid(trimmed);
+            }
+        }
+    }
+    slots
+}
+
+/// `Material` fields that identify the file rather than how it renders.
+const MATERIAL_BOOKKEEPING: &[&str] = &[
+    "serializedVersion",
+    "m_ObjectHideFlags",
+    "m_CorrespondingSourceObject",
+    "m_PrefabInstance",
+    "m_PrefabAsset",
+    "m_Name",
+];
+
+/// A `.mat` reduced to what decides how it renders: shader, keywords,
+/// render queue and every saved texture / float / color property, each
+/// flattened to one line and sorted, so two materials saved separately —
+/// different names, GUIDs, property order — compare equal when they'd
+/// render the same.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MaterialSignature(Vec<String>);
+
+/// The `Material` document's signature, or `None` when the content has
+/// none. Works on both `m_SavedProperties` layouts (`- _Name: value` and
+/// the pre-2018 `- first: / second:` pairs): each list entry is compared
+/// as a whole.
+pub fn material_signature(content: &str) -> Option<MaterialSignature> {
+    let mut lines = content.lines().skip_while(|line| *line != "Material:");
+    lines.next()?;
+    let body: Vec<&str> = lines.take_while(|line| !line.starts_with("---")).collect();
+
+    let mut entries = Vec::new();
+    let mut i = 0;
+    while i < body.len() {
+        let line = body[i];
+        i += 1;
+        // Fields are two-space-indented; a field's lists and maps follow
+        // it deeper, or as `  - ` items.
+        let Some(field) = line.strip_prefix("  ") else {
+            continue;
+        };
+        let start = i;
+        while i < body.len() && (body[i].starts_with("   ") || body[i].starts_with("  - ")) {
+            i += 1;
+        }
+        let children = &body[start..i];
+        let key = field.split(':').next().unwrap_or_default();
+        if MATERIAL_BOOKKEEPING.contains(&key) {
+            continue;
+        }
+        if key == "m_SavedProperties" {
+            entries.extend(saved_property_entries(children));
+            continue;
+        }
+        let mut value = field.trim_end().to_string();
+        if key == "m_ShaderKeywords" {
+            let mut keywords: Vec<&str> = field[key.len() + 1..].split_whitespace().collect();
+            keywords.sort_unstable();
+            value = format!("m_ShaderKeywords: {}", keywords.join(" "));
+        }
+        let mut items: Vec<&str> = children.iter().map(|line| line.trim()).collect();
+        items.sort_unstable();
+        for item in items {
+            value.push(' ');
+            value.push_str(item);
+        }
+        entries.push(value);
+    }
+    entries.sort();
+    Some(MaterialSignature(entries))
+}
+
+/// `m_SavedProperties`' children as `section: entry` lines, one per list
+/// entry with its nested lines joined.
+fn saved_property_entries(lines: &[&str]) -> Vec<String> {
+    let mut entries: Vec<String> = Vec::new();
+    let mut section = "";
+    for line in lines {
+        if let Some(item) = line.strip_prefix("    - ") {
+            entries.push(format!("m_SavedProperties.{}: {}", section, item.trim()));
+        } else if line.starts_with("     ") {
+            if let Some(last) = entries.last_mut() {
+                last.push(' ');
+                last.push_str(line.trim());
+            }
+        } else {
+            let field = line.trim();
+            section = field.split(':').next().unwrap_or_default();
+            entries.push(format!("m_SavedProperties.{}", field));
+        }
+    }
+    entries
+}
+
+/// `AnimationClip` sections whose entries bind a curve to a Transform path
+/// (`path:`, relative to the Animator's GameObject). `m_ClipBindingConstant`
+/// also has `path:` lines, but those are CRC hashes, not names.
+const CLIP_CURVE_SECTIONS: &[&str] = &[
+    "m_RotationCurves",
+    "m_CompressedRotationCurves",
+    "m_EulerCurves",
+    "m_PositionCurves",
+    "m_ScaleCurves",
+    "m_FloatCurves",
+    "m_PPtrCurves",
+    "m_EditorCurves",
+    "m_EulerEditorCurves",
+];
+
+/// Distinct, sorted Transform paths an `.anim` file's curves bind to.
+/// Root bindings (empty path) — including every humanoid muscle curve —
+/// are left out; they always resolve.
+pub fn animation_binding_paths(content: &str) -> Vec<String> {
+    let mut paths = HashSet::new();
+    let mut in_curves = false;
+    for line in content.lines() {
+        // Sections are the two-space-indented keys of the clip body.
+        if let Some(key) = line.strip_prefix("  ") {
+            if !key.starts_with(' ') && !key.starts_with('-') {
+                let name = key.split(':').next().unwrap_or_default();
+                in_curves = CLIP_CURVE_SECTIONS.contains(&name);
+                continue;
+            }
+        } else {
+            in_curves = false;
+        }
+        if !in_curves {
+            continue;
+        }
+        if let Some(value) = line.trim_start().strip_prefix("path:") {
+            let path = unquote_yaml(value);
+            if !path.is_empty() {
+                paths.insert(path);
+            }
+        }
+    }
+    let mut paths: Vec<String> = paths.into_iter().collect();
+    paths.sort();
+    paths
+}
+
+const CLASS_ANIMATOR: i32 = 95;
+
+/// An `Animator` component in a prefab or scene, with what its clips'
+/// bindings resolve against.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnimatorTarget {
+    pub controller_guid: Option<String>,
+    /// The model whose skeleton the Animator drives (`m_Avatar`).
+    pub avatar_guid: Option<String>,
+    /// Transform paths below the Animator's GameObject, `Child/Grandchild`
+    /// style. `None` when part of that subtree lives in a nested prefab
+    /// instance, whose contents this file doesn't spell out.
+    pub paths: Option<HashSet<String>>,
+}
+
+/// Every Animator in a prefab or scene file's content.
+pub fn animator_targets(content: &str) -> Vec<AnimatorTarget> {
+    let docs = split_unity_documents(content);
+    let by_id: HashMap<i64, &UnityDocument> = docs.iter().map(|d| (d.file_id, d)).collect();
+    let instances = instances_by_parent(&docs);
+    let guid_of = |doc: &UnityDocument, key: &str| {
+        let line = doc.field(key)?;
+        let start = line.find("guid:")?;
+        let guid: String = line[start + 5..]
+            .trim_start()
+            .chars()
+            .take_while(|c| c.is_ascii_hexdigit())
+            .collect();
+        (guid.len() == 32 && !is_null_guid(&guid)).then_some(guid)
+    };
+
+    docs.iter()
+        .filter(|d| d.class_id == CLASS_ANIMATOR && !d.stripped)
+        .map(|animator| {
+            let game_object = animator.field_file_id("m_GameObject");
+            let transform = docs.iter().find(|d| {
+                matches!(d.class_id, CLASS_TRANSFORM | CLASS_RECT_TRANSFORM)
+                    && !d.stripped
+                    && d.field_file_id("m_GameObject") == game_object
+            });
+            let paths = transform.and_then(|t| {
+                let mut paths = HashSet::new();
+                let mut visited = HashSet::new();
+                let complete = child_paths(t, "", &by_id, &instances, &mut visited, &mut paths);
+                complete.then_some(paths)
+            });
+            AnimatorTarget {
+                controller_guid: guid_of(animator, "m_Controller"),
+                avatar_guid: guid_of(animator, "m_Avatar"),
+                paths,
+            }
+        })
+        .collect()
+}
+
+/// Collect the paths of `transform`'s descendants into `out`. Returns
+/// `false` if any of them is a prefab instance.
+fn child_paths(
+    transform: &UnityDocument,
+    prefix: &str,
+    by_id: &HashMap<i64, &UnityDocument>,
+    instances: &HashMap<i64, Vec<&UnityDocument>>,
+    visited: &mut HashSet<i64>,
+    out: &mut HashSet<String>,
+) -> bool {
+    if !visited.insert(transform.file_id) {
+        return true;
+    }
+    if instances.contains_key(&transform.file_id) {
+        return false;
+    }
+    for id in transform.list_file_ids("m_Children") {
+        let Some(child) = by_id.get(&id) else {
+            continue;
+        };
+        if child.stripped {
+            return false;
+        }
+        let name = child
+            .field_file_id("m_GameObject")
+            .and_then(|id| by_id.get(&id))
+            .and_then(|go| go.field("m_Name"))
+            .map(unquote_yaml)
+            .unwrap_or_default();
+        let path = if prefix.is_empty() {
+            name
+        } else {
+            format!("{}/{}", prefix, name)
+        };
+        if !child_paths(child, &path, by_id, instances, visited, out) {
+            return false;
+        }
+        out.insert(path);
+    }
+    true
+}
+
+/// Node names of an imported model, from its `.meta`: GameObject and
+/// Transform entries of `internalIDToNameTable` (2019.3+) or the older
+/// `fileIDToRecycleName`. Empty when the importer didn't record them.
+pub fn model_node_names(meta_content: &str) -> HashSet<String> {
+    let mut names = HashSet::new();
+    let mut section = "";
+    let mut class_id: Option<i64> = None;
+    for line in meta_content.lines() {
+        let trimmed = line.trim();
+        // Section keys sit at the importer's top level (two spaces).
+        if !line.starts_with("   ") && !trimmed.starts_with('-') {
+            section = trimmed.split(':').next().unwrap_or_default();
+            class_id = None;
+            continue;
+        }
+        let entry = match section {
+            "internalIDToNameTable" => {
+                if let Some(value) = trimmed.strip_prefix("second:") {
+                    class_id
+                        .take()
+                        .filter(|c| matches!(c, 1 | 4))
+                        .map(|_| value)
+                } else {
+                    // `first:` holds a single `<classID>: <id>` line.
+                    if let Some((class, _)) = trimmed.split_once(':') {
+                        if let Ok(class) = class.parse() {
+                            class_id = Some(class);
+                        }
+                    }
+                    None
+                }
+            }
+            "fileIDToRecycleName" => trimmed.split_once(':').and_then(|(id, value)| {
+                let id: i64 = id.parse().ok()?;
+                matches!(id / 100_000, 1 | 4).then_some(value)
+            }),
+            _ => None,
+        };
+        if let Some(name) = entry.map(unquote_yaml) {
+            // `//RootNode` stands for the model file itself.
+            if !name.is_empty() && !name.starts_with("//") {
+                names.insert(name);
+            }
+        }
+    }
+    names
+}
+
 /// Extract all GUID references from Unity YAML content
 fn extract_references(content: &str) -> Vec<UnityReference> {
     let mut refs = HashSet::new();
@@ -301,6 +975,7 @@ fn extract_references(content: &str) -> Vec<UnityReference> {
                     guid,
                     file_id,
                     ref_type,
+                    reference_kind: ReferenceKind::Direct,
                 });
             }
         }
@@ -309,6 +984,84 @@ fn extract_references(content: &str) -> Vec<UnityReference> {
     refs.into_iter().collect()
 }
 
+/// `extract_references` with prefab instancing recognized. Inside a
+/// `PrefabInstance` block, the `m_SourcePrefab` line and the override
+/// `target:` lines name the instanced prefab; so do the stripped stand-ins'
+/// `m_CorrespondingSourceObject` lines. All of them take the instance's kind.
+/// Override *values* (`objectReference:`) and added components stay
+/// `Direct` — those are content this file contributes.
+fn extract_file_references(content: &str, file_type: &UnityFileType) -> Vec<UnityReference> {
+    let docs = split_unity_documents(content);
+    if docs.is_empty() {
+        return extract_references(content);
+    }
+
+    // Keyed by source GUID: a stripped object only carries the GUID it
+    // stands in for, not the instance that created it.
+    let mut instance_kinds: HashMap<String, ReferenceKind> = HashMap::new();
+    for doc in docs
+        .iter()
+        .filter(|d| d.class_id == CLASS_PREFAB_INSTANCE && !d.stripped)
+    {
+        let Some(source) = prefab_source_guid(doc) else {
+            continue;
+        };
+        // A root-level instance in a prefab file is what makes it a variant;
+        // the same shape in a scene is just a prefab placed at the top level.
+        let kind = if *file_type == UnityFileType::Prefab
+            && doc.field_file_id("m_TransformParent").unwrap_or(0) == 0
+        {
+            ReferenceKind::PrefabBase
+        } else {
+            ReferenceKind::Nested
+        };
+        instance_kinds
+            .entry(source)
+            .and_modify(|k| {
+                if kind == ReferenceKind::PrefabBase {
+                    *k = kind;
+                }
+            })
+            .or_insert(kind);
+    }
+
+    let mut refs = HashSet::new();
+    for doc in &docs {
+        let in_instance = doc.class_id == CLASS_PREFAB_INSTANCE && !doc.stripped;
+        for line in &doc.lines {
+            let trimmed = line.trim_start();
+            let structural = if in_instance {
+                trimmed.starts_with("m_SourcePrefab:")
+                    || trimmed.starts_with("m_ParentPrefab:")
+                    || trimmed.starts_with("- target:")
+            } else {
+                trimmed.starts_with("m_CorrespondingSourceObject:")
+                    || trimmed.starts_with("m_PrefabParentObject:")
+            };
+            for mut r in extract_references(line) {
+                if structural {
+                    if let Some(kind) = instance_kinds.get(&r.guid) {
+                        r.reference_kind = *kind;
+                    }
+                }
+                refs.insert(r);
+            }
+        }
+    }
+
+    refs.into_iter().collect()
+}
+
+/// GUID of the prefab a `PrefabInstance` block instantiates. `m_SourcePrefab`
+/// since 2018.3; older files call it `m_ParentPrefab`.
+fn prefab_source_guid(instance: &UnityDocument) -> Option<String> {
+    instance
+        .field("m_SourcePrefab")
+        .or_else(|| instance.field("m_ParentPrefab"))
+        .and_then(|v| extract_references(v).into_iter().next())
+        .map(|r| r.guid)
+}
+
 /// Extract fileID from a line
 fn extract_file_id(line: &str) -> Option<i64> {
     if let Some(start) = line.find("fileID:") {
@@ -334,6 +1087,24 @@ fn extract_type(line: &str) -> Option<i32> {
     }
 }
 
+/// The PPtr after a component's `m_Script:` key.
+fn script_field(line: &str) -> Option<&str> {
+    line.trim_start().strip_prefix("m_Script:")
+}
+
+/// The distinct script GUIDs a prefab or scene's MonoBehaviours name in
+/// `m_Script:`, sorted. Null references (`{fileID: 0}`) have none.
+pub fn script_guids(content: &str) -> Vec<String> {
+    let guids: HashSet<String> = content
+        .lines()
+        .filter_map(script_field)
+        .filter_map(pptr_guid)
+        .collect();
+    let mut guids: Vec<String> = guids.into_iter().collect();
+    guids.sort();
+    guids
+}
+
 /// Extract component types from prefab/scene content
 fn extract_components(content: &str) -> Vec<String> {
     let mut components = HashSet::new();
@@ -344,10 +1115,8 @@ fn extract_components(content: &str) -> Vec<String> {
         let line = line.trim();
 
         // Look for MonoBehaviour components with script references
-        if line.starts_with("m_Script:") {
-            if let Some(_) = line.find("guid:") {
-                components.insert("MonoBehaviour".to_string());
-            }
+        if script_field(line).is_some_and(|value| value.contains("guid:")) {
+            components.insert("MonoBehaviour".to_string());
         }
 
         // Extract Unity built-in component types
@@ -445,6 +1214,129 @@ mod tests {
         assert_eq!(refs[0].guid, "abc123def456789012345678901234ab");
     }
 
+    /// A variant of `base…` that also nests `child…` under its root and
+    /// overrides a field with a texture reference.
+    const VARIANT_PREFAB: &str = "%YAML 1.1
+--- !u!1001 &100100000
+PrefabInstance:
+  m_Modification:
+    m_TransformParent: {fileID: 0}
+    m_Modifications:
+    - target: {fileID: 400000, guid: bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb, type: 3}
+      propertyPath: m_Name
+      value: Variant
+      objectReference: {fileID: 0}
+    - target: {fileID: 2300000, guid: bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb, type: 3}
+      propertyPath: m_Texture
+      value:
+      objectReference: {fileID: 2800000, guid: dddddddddddddddddddddddddddddddd, type: 3}
+  m_SourcePrefab: {fileID: 100100000, guid: bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb, type: 3}
+--- !u!4 &400000 stripped
+Transform:
+  m_CorrespondingSourceObject: {fileID: 400000, guid: bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb, type: 3}
+  m_PrefabInstance: {fileID: 100100000}
+--- !u!1001 &200200000
+PrefabInstance:
+  m_Modification:
+    m_TransformParent: {fileID: 400000}
+    m_Modifications:
+    - target: {fileID: 400000, guid: cccccccccccccccccccccccccccccccc, type: 3}
+      propertyPath: m_LocalPosition.x
+      value: 1
+      objectReference: {fileID: 0}
+  m_SourcePrefab: {fileID: 100100000, guid: cccccccccccccccccccccccccccccccc, type: 3}
+";
+
+    fn kinds_by_guid(refs: &[UnityReference]) -> HashMap<&str, HashSet<ReferenceKind>> {
+        let mut out: HashMap<&str, HashSet<ReferenceKind>> = HashMap::new();
+        for r in refs {
+            out.entry(r.guid.as_str())
+                .or_default()
+                .insert(r.reference_kind);
+        }
+        out
+    }
+
+    #[test]
+    fn prefab_variant_links_to_its_base() {
+        let refs = extract_file_references(VARIANT_PREFAB, &UnityFileType::Prefab);
+        let kinds = kinds_by_guid(&refs);
+        // Every mention of the base — source, override targets, stripped
+        // stand-in — is structural; none reads as a direct content reference.
+        assert_eq!(
+            kinds["bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb"],
+            HashSet::from([ReferenceKind::PrefabBase])
+        );
+        assert_eq!(
+            kinds["cccccccccccccccccccccccccccccccc"],
+            HashSet::from([ReferenceKind::Nested])
+        );
+        // An override *value* is content the variant contributes.
+        assert_eq!(
+            kinds["dddddddddddddddddddddddddddddddd"],
+            HashSet::from([ReferenceKind::Direct])
+        );
+    }
+
+    #[test]
+    fn root_prefab_instance_in_a_scene_is_nested_not_a_base() {
+        let refs = extract_file_references(VARIANT_PREFAB, &UnityFileType::Scene);
+        let kinds = kinds_by_guid(&refs);
+        assert_eq!(
+            kinds["bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb"],
+            HashSet::from([ReferenceKind::Nested])
+        );
+    }
+
+    #[test]
+    fn plain_files_keep_direct_references() {
+        let content = "--- !u!21 &2100000
+Material:
+  m_Name: Mat
+  m_Texture: {fileID: 2800000, guid: abc123def456789012345678901234ab, type: 3}
+";
+        let refs = extract_file_references(content, &UnityFileType::Material);
+        assert_eq!(refs.len(), 1);
+        assert_eq!(refs[0].reference_kind, ReferenceKind::Direct);
+    }
+
+    #[test]
+    fn scriptable_object_asset_references_are_extracted() {
+        // Data tables and configs point at prefabs / sprites from `.asset`
+        // files; assets referenced only there must not look unused.
+        let dir = tempfile::tempdir().unwrap();
+        let p = dir.path().join("EnemyTable.asset");
+        std::fs::write(
+            &p,
+            "%YAML 1.1
+%TAG !u! tag:unity3d.com,2011:
+--- !u!114 &11400000
+MonoBehaviour:
+  m_Script: {fileID: 11500000, guid: 0123456789abcdef0123456789abcdef, type: 3}
+  m_Name: EnemyTable
+  icon: {fileID: 21300000, guid: 11111111111111111111111111111111, type: 3}
+  prefabs:
+  - {fileID: 100100000, guid: 22222222222222222222222222222222, type: 3}
+  - {fileID: 100100000, guid: 33333333333333333333333333333333, type: 3}
+",
+        )
+        .unwrap();
+
+        let info = parse_unity_file(&p).expect(".asset should parse");
+        assert_eq!(info.file_type, UnityFileType::Asset);
+        let mut guids: Vec<&str> = info.references.iter().map(|r| r.guid.as_str()).collect();
+        guids.sort();
+        assert_eq!(
+            guids,
+            vec![
+                "0123456789abcdef0123456789abcdef",
+                "11111111111111111111111111111111",
+                "22222222222222222222222222222222",
+                "33333333333333333333333333333333",
+            ]
+        );
+    }
+
     #[test]
     fn test_file_type() {
         assert_eq!(UnityFileType::from_extension("prefab"), UnityFileType::Prefab);
@@ -562,6 +1454,93 @@ mod tests {
         assert_eq!(components, vec!["MeshRenderer", "MonoBehaviour", "Transform"]);
     }
 
+    const SCENE: &str = "\
+%YAML 1.1
+%TAG !u! tag:unity3d.com,2011:
+--- !u!1 &100
+GameObject:
+  m_Component:
+  - component: {fileID: 101}
+  - component: {fileID: 102}
+  m_Layer: 0
+  m_Name: Player
+--- !u!4 &101
+Transform:
+  m_GameObject: {fileID: 100}
+  m_Children:
+  - {fileID: 201}
+  - {fileID: 301}
+  m_Father: {fileID: 0}
+--- !u!114 &102
+MonoBehaviour:
+  m_GameObject: {fileID: 100}
+  m_Script: {fileID: 11500000, guid: abc123def456789012345678901234ab, type: 3}
+--- !u!1 &200
+GameObject:
+  m_Component:
+  - component: {fileID: 201}
+  m_Name: 'Weapon Socket'
+--- !u!4 &201
+Transform:
+  m_GameObject: {fileID: 200}
+  m_Children: []
+  m_Father: {fileID: 101}
+--- !u!1001 &300
+PrefabInstance:
+  m_Modification:
+    m_TransformParent: {fileID: 101}
+    m_Modifications:
+    - target: {fileID: 1, guid: 0123456789abcdef0123456789abcdef, type: 3}
+      propertyPath: m_Name
+      value: Companion
+      objectReference: {fileID: 0}
+  m_SourcePrefab: {fileID: 100100000, guid: 0123456789abcdef0123456789abcdef, type: 3}
+--- !u!4 &301 stripped
+Transform:
+  m_CorrespondingSourceObject: {fileID: 4, guid: 0123456789abcdef0123456789abcdef, type: 3}
+  m_PrefabInstance: {fileID: 300}
+--- !u!1001 &400
+PrefabInstance:
+  m_Modification:
+    m_TransformParent: {fileID: 0}
+    m_Modifications: []
+  m_SourcePrefab: {fileID: 100100000, guid: fedcba9876543210fedcba9876543210, type: 3}
+";
+
+    #[test]
+    fn scene_hierarchy_follows_transform_links() {
+        let roots = build_scene_hierarchy(SCENE);
+        assert_eq!(roots.len(), 2);
+
+        let player = &roots[0];
+        assert_eq!(player.name, "Player");
+        assert_eq!(player.components, vec!["Transform", "MonoBehaviour"]);
+        let names: Vec<&str> = player.children.iter().map(|c| c.name.as_str()).collect();
+        // Order comes from m_Children; the quoted name is unquoted.
+        assert_eq!(names, ["Weapon Socket", "Companion"]);
+    }
+
+    #[test]
+    fn scene_hierarchy_reports_prefab_instances_as_leaves() {
+        let roots = build_scene_hierarchy(SCENE);
+        // Reached through the stripped Transform in Player's m_Children —
+        // and only once, despite also matching by m_TransformParent.
+        let companion = &roots[0].children[1];
+        assert_eq!(
+            companion.prefab_guid.as_deref(),
+            Some("0123456789abcdef0123456789abcdef")
+        );
+        assert!(companion.children.is_empty());
+        assert_eq!(roots[0].children.len(), 2);
+
+        // A root-level instance with no name override gets a placeholder.
+        assert_eq!(roots[1].name, "Prefab Instance");
+        assert_eq!(
+            roots[1].prefab_guid.as_deref(),
+            Some("fedcba9876543210fedcba9876543210")
+        );
+    }
+
     #[test]
     fn parse_project_version_reads_both_fields() {
         let dir = tempfile::tempdir().unwrap();
@@ -606,4 +1585,71 @@ mod tests {
         fs::write(settings.join("ProjectVersion.txt"), "m_EditorVersion:\n").unwrap();
         assert!(parse_project_version(dir.path()).is_none());
     }
+
+    #[test]
+    fn parse_player_settings_reads_names_and_skips_binary() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(parse_player_settings(dir.path()).is_none());
+
+        let settings = dir.path().join("ProjectSettings");
+        fs::create_dir(&settings).unwrap();
+        let asset = settings.join("ProjectSettings.asset");
+        fs::write(
+            &asset,
+            "%YAML 1.1\n--- !u!129 &1\nPlayerSettings:\n  companyName: Acme Games\n  productName: 'Rock''n Roll'\n",
+        )
+        .unwrap();
+        assert_eq!(
+            parse_player_settings(dir.path()),
+            Some(UnityPlayerSettings {
+                product_name: Some("Rock'n Roll".to_string()),
+                company_name: Some("Acme Games".to_string()),
+            })
+        );
+
+        fs::write(&asset, b"\x00\x00\x01\x9cbinary").unwrap();
+        assert!(parse_player_settings(dir.path()).is_none());
+    }
+
+    #[test]
+    fn material_texture_slots_reads_both_layouts() {
+        let modern = "Material:\n  m_SavedProperties:\n    serializedVersion: 3\n    m_TexEnvs:\n    - _BumpMap:\n        m_Texture: {fileID: 0}\n        m_Scale: {x: 1, y: 1}\n    - _MainTex:\n        m_Texture: {fileID: 2800000, guid: abc123def456789012345678901234ab, type: 3}\n    m_Floats:\n    - _Glossiness: 0.5\n";
+        let legacy = "Material:\n  m_SavedProperties:\n    serializedVersion: 2\n    m_TexEnvs:\n    - first:\n        name: _MainTex\n      second:\n        m_Texture: {fileID: 2800000, guid: abc123def456789012345678901234ab, type: 3}\n        m_Scale: {x: 1, y: 1}\n    m_Colors:\n    - first:\n        name: _Color\n      second: {r: 1, g: 1, b: 1, a: 1}\n";
+        let main = TextureSlot {
+            name: "_MainTex".to_string(),
+            guid: Some("abc123def456789012345678901234ab".to_string()),
+        };
+
+        assert_eq!(
+            material_texture_slots(modern),
+            vec![
+                TextureSlot {
+                    name: "_BumpMap".to_string(),
+                    guid: None,
+                },
+                main.clone(),
+            ]
+        );
+        assert_eq!(material_texture_slots(legacy), vec![main]);
+    }
+
+    #[test]
+    fn material_signature_compares_whole_legacy_property_entries() {
+        let legacy = |name: &str, color: &str| {
+            format!(
+                "--- !u!21 &2100000\nMaterial:\n  m_Name: {}\n  m_Shader: {{fileID: 7, guid: 0000000000000000f000000000000000, type: 0}}\n  m_SavedProperties:\n    serializedVersion: 2\n    m_Colors:\n    - first:\n        name: _Color\n      second: {}\n--- !u!114 &1\nMonoBehaviour:\n  m_Name: {}\n",
+                name, color, name
+            )
+        };
+        let white = material_signature(&legacy("A", "{r: 1, g: 1, b: 1, a: 1}")).unwrap();
+        assert_eq!(
+            material_signature(&legacy("B", "{r: 1, g: 1, b: 1, a: 1}")),
+            Some(white.clone())
+        );
+        assert_ne!(
+            material_signature(&legacy("A", "{r: 1, g: 0, b: 0, a: 1}")),
+            Some(white)
+        );
+        assert!(material_signature("--- !u!1 &1\nGameObject:\n  m_Name: A\n").is_none());
+    }
 }
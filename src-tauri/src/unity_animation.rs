@@ -0,0 +1,316 @@
+//! Animation clips whose curves bind to Transforms that aren't there.
+//!
+//! A clip stores each curve against a Transform path relative to the
+//! Animator (`Hips/Spine/Head`). Rename or re-rig the model and the path
+//! quietly stops resolving: Unity shows the property as "(Missing!)" in
+//! the Animation window and the curve does nothing at runtime. The paths
+//! are checked against every rig a clip is known to play on — a prefab
+//! whose Animator uses a controller containing the clip (its own hierarchy
+//! below the Animator, and the node names of the model in `m_Avatar`).
+//! Paths that are malformed on their face (`Arm//Hand`, a leading or
+//! trailing `/`) are reported whether or not the clip is used anywhere.
+
+use rayon::prelude::*;
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+use crate::scanner::{self, AssetInfo};
+use crate::unity;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct UnresolvedBindings {
+    pub clip_path: String,
+    /// Prefab or model the paths were checked against; `None` for paths
+    /// that are malformed regardless of target.
+    pub target_path: Option<String>,
+    /// Binding paths that don't resolve, sorted.
+    pub unresolved: Vec<String>,
+}
+
+/// What a clip's paths are checked against.
+enum Rig {
+    /// Transform paths below an Animator in a prefab.
+    Hierarchy(HashSet<String>),
+    /// Node names of a model; every path segment must be one of them.
+    Nodes(HashSet<String>),
+}
+
+impl Rig {
+    fn resolves(&self, path: &str) -> bool {
+        match self {
+            Rig::Hierarchy(paths) => paths.contains(path),
+            Rig::Nodes(names) => path.split('/').all(|segment| names.contains(segment)),
+        }
+    }
+}
+
+fn is_malformed(path: &str) -> bool {
+    path.starts_with('/') || path.ends_with('/') || path.contains("//")
+}
+
+pub fn analyze_animation_bindings(assets: &[AssetInfo]) -> Vec<UnresolvedBindings> {
+    let by_guid: HashMap<&str, &AssetInfo> = assets
+        .iter()
+        .filter_map(|a| Some((a.unity_guid.as_deref()?, a)))
+        .collect();
+    let with_ext = |exts: &'static [&'static str]| {
+        assets
+            .par_iter()
+            .filter(move |a| exts.iter().any(|e| a.extension.eq_ignore_ascii_case(e)))
+    };
+
+    let clips: HashMap<&str, Vec<String>> = with_ext(&["anim"])
+        .filter_map(|a| {
+            let content = fs::read_to_string(&a.path).ok()?;
+            let paths = unity::animation_binding_paths(&content);
+            (!paths.is_empty()).then_some((a.path.as_str(), paths))
+        })
+        .collect();
+
+    // Controller GUID → the clips it plays (override controllers list
+    // their replacement clips the same way).
+    let controllers: HashMap<&str, Vec<&str>> = with_ext(&["controller", "overridecontroller"])
+        .filter_map(|a| {
+            let info = unity::parse_unity_file(Path::new(&a.path))?;
+            let played: BTreeSet<&str> = info
+                .references
+                .iter()
+                .filter_map(|r| by_guid.get(r.guid.as_str()))
+                .filter(|clip| clips.contains_key(clip.path.as_str()))
+                .map(|clip| clip.path.as_str())
+                .collect();
+            Some((a.unity_guid.as_deref()?, played.into_iter().collect()))
+        })
+        .collect();
+
+    // Every rig a controller is played on, from the prefabs' Animators.
+    let rigs: Vec<(&Vec<&str>, String, Rig)> = with_ext(&["prefab"])
+        .flat_map_iter(|prefab| {
+            let content = fs::read_to_string(&prefab.path).unwrap_or_default();
+            let mut rigs = Vec::new();
+            for animator in unity::animator_targets(&content) {
+                let Some(clips) = animator
+                    .controller_guid
+                    .as_deref()
+                    .and_then(|g| controllers.get(g))
+                else {
+                    continue;
+                };
+                if let Some(paths) = animator.paths {
+                    rigs.push((clips, prefab.path.clone(), Rig::Hierarchy(paths)));
+                }
+                if let Some(model) = animator.avatar_guid.as_deref().and_then(|g| by_guid.get(g)) {
+                    let names = scanner::unity_meta_path(Path::new(&model.path))
+                        .and_then(|meta| fs::read_to_string(meta).ok())
+                        .map(|meta| unity::model_node_names(&meta))
+                        .unwrap_or_default();
+                    if !names.is_empty() {
+                        rigs.push((clips, model.path.clone(), Rig::Nodes(names)));
+                    }
+                }
+            }
+            rigs
+        })
+        .collect();
+
+    let mut out = Vec::new();
+    // Many prefabs share one model: check each (clip, target) pair once.
+    let mut seen: HashSet<(&str, &str)> = HashSet::new();
+    for (controller_clips, target, rig) in &rigs {
+        for clip in controller_clips.iter() {
+            if !seen.insert((*clip, target.as_str())) {
+                continue;
+            }
+            let unresolved: Vec<String> = clips[clip]
+                .iter()
+                .filter(|path| !is_malformed(path) && !rig.resolves(path))
+                .cloned()
+                .collect();
+            if !unresolved.is_empty() {
+                out.push(UnresolvedBindings {
+                    clip_path: clip.to_string(),
+                    target_path: Some(target.clone()),
+                    unresolved,
+                });
+            }
+        }
+    }
+    for (clip, paths) in &clips {
+        let malformed: Vec<String> = paths.iter().filter(|p| is_malformed(p)).cloned().collect();
+        if !malformed.is_empty() {
+            out.push(UnresolvedBindings {
+                clip_path: clip.to_string(),
+                target_path: None,
+                unresolved: malformed,
+            });
+        }
+    }
+    out.sort_by(|a, b| {
+        a.clip_path
+            .cmp(&b.clip_path)
+            .then_with(|| a.target_path.cmp(&b.target_path))
+    });
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::AssetType;
+    use tempfile::tempdir;
+
+    const CLIP_GUID: &str = "11111111111111111111111111111111";
+    const CONTROLLER_GUID: &str = "22222222222222222222222222222222";
+    const MODEL_GUID: &str = "33333333333333333333333333333333";
+
+    const CLIP: &str = "%YAML 1.1
+--- !u!74 &7400000
+AnimationClip:
+  m_Name: Walk
+  m_RotationCurves:
+  - curve:
+      serializedVersion: 2
+      m_Curve: []
+    path: Hips
+  - curve:
+      serializedVersion: 2
+      m_Curve: []
+    path: Hips/Spine
+  m_PositionCurves:
+  - curve:
+      serializedVersion: 2
+      m_Curve: []
+    path: Hips/Tail
+  m_FloatCurves:
+  - curve:
+      serializedVersion: 2
+      m_Curve: []
+    attribute: RootT.x
+    path: 
+  - curve:
+      serializedVersion: 2
+      m_Curve: []
+    attribute: m_IsActive
+    path: Arm//Hand
+  m_ClipBindingConstant:
+    genericBindings:
+    - serializedVersion: 2
+      path: 2147483647
+";
+
+    const PREFAB: &str = "%YAML 1.1
+--- !u!1 &1
+GameObject:
+  m_Name: Hero
+--- !u!4 &2
+Transform:
+  m_GameObject: {fileID: 1}
+  m_Children:
+  - {fileID: 4}
+  m_Father: {fileID: 0}
+--- !u!95 &3
+Animator:
+  m_GameObject: {fileID: 1}
+  m_Avatar: {fileID: 9000000, guid: 33333333333333333333333333333333, type: 3}
+  m_Controller: {fileID: 9100000, guid: 22222222222222222222222222222222, type: 2}
+--- !u!4 &4
+Transform:
+  m_GameObject: {fileID: 5}
+  m_Children:
+  - {fileID: 6}
+  m_Father: {fileID: 2}
+--- !u!1 &5
+GameObject:
+  m_Name: Hips
+--- !u!4 &6
+Transform:
+  m_GameObject: {fileID: 7}
+  m_Children: []
+  m_Father: {fileID: 4}
+--- !u!1 &7
+GameObject:
+  m_Name: Spine
+";
+
+    const MODEL_META: &str = "fileFormatVersion: 2
+guid: 33333333333333333333333333333333
+ModelImporter:
+  serializedVersion: 19301
+  internalIDToNameTable:
+  - first:
+      1: 100000
+    second: //RootNode
+  - first:
+      4: 400002
+    second: Hips
+  - first:
+      4: 400004
+    second: Spine
+  - first:
+      4: 400006
+    second: Tail
+  externalObjects: {}
+";
+
+    fn asset(dir: &Path, name: &str, content: &str, guid: &str) -> AssetInfo {
+        let path = dir.join(name);
+        fs::write(&path, content).unwrap();
+        AssetInfo {
+            path: scanner::path_to_string(&path),
+            extension: name.rsplit('.').next().unwrap().to_string(),
+            name: name.to_string(),
+            asset_type: AssetType::Other,
+            size: 0,
+            modified: 0,
+            metadata: None,
+            unity_guid: Some(guid.to_string()),
+        }
+    }
+
+    #[test]
+    fn flags_paths_missing_from_the_rig_and_malformed_ones() {
+        let dir = tempdir().unwrap();
+        let dir = dir.path();
+        fs::write(dir.join("Hero.fbx.meta"), MODEL_META).unwrap();
+        let controller = format!(
+            "--- !u!1102 &1\nAnimatorState:\n  m_Motion: {{fileID: 7400000, guid: {}, type: 2}}\n",
+            CLIP_GUID
+        );
+        let assets = vec![
+            asset(dir, "Walk.anim", CLIP, CLIP_GUID),
+            asset(dir, "Hero.controller", &controller, CONTROLLER_GUID),
+            asset(
+                dir,
+                "Hero.prefab",
+                PREFAB,
+                "44444444444444444444444444444444",
+            ),
+            asset(dir, "Hero.fbx", "", MODEL_GUID),
+        ];
+
+        let found = analyze_animation_bindings(&assets);
+        let summary: Vec<(Option<&str>, Vec<&str>)> = found
+            .iter()
+            .map(|f| {
+                assert_eq!(f.clip_path, assets[0].path);
+                (
+                    f.target_path
+                        .as_deref()
+                        .map(|t| t.rsplit('/').next().unwrap()),
+                    f.unresolved.iter().map(String::as_str).collect(),
+                )
+            })
+            .collect();
+        // The model knows a Tail bone the prefab's hierarchy lacks; the
+        // root binding and the hashed genericBindings path are ignored.
+        assert_eq!(
+            summary,
+            vec![
+                (None, vec!["Arm//Hand"]),
+                (Some("Hero.prefab"), vec!["Hips/Tail"]),
+            ]
+        );
+    }
+}
@@ -0,0 +1,222 @@
+//! Small textures that could share an atlas.
+//!
+//! Textures drawn together — by the materials of one prefab or scene —
+//! cost a draw call per material; packed into one atlas they can share a
+//! material and batch. The heuristic here is deliberately simple: for each
+//! prefab and scene, the small textures (both sides at most
+//! `max_texture_size`) its directly referenced materials use. Sets found
+//! for several prefabs are reported once, with every source listed. Each
+//! set is shelf-packed to see whether it fits a 2048 or a 4096 atlas;
+//! packing ignores padding, so treat a tight fit as a maybe.
+
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use crate::scanner::{AssetInfo, AssetType};
+use crate::unity_index::ReferenceIndex;
+
+/// Textures larger than this on either side are left out by default.
+pub const DEFAULT_MAX_TEXTURE_SIZE: u32 = 512;
+
+/// Atlas sides tried, smallest first.
+const ATLAS_SIZES: [u32; 2] = [2048, 4096];
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AtlasCandidate {
+    /// Prefabs and scenes whose materials use exactly these textures,
+    /// sorted.
+    pub sources: Vec<String>,
+    /// Sorted.
+    pub textures: Vec<String>,
+    /// Sum of the textures' pixel areas.
+    pub total_pixels: u64,
+    /// Smallest atlas side the set packs into; `None` when not even 4096
+    /// holds it.
+    pub atlas_size: Option<u32>,
+}
+
+/// Whether `sizes` shelf-pack into a `side`×`side` square: tallest first,
+/// left to right, a new row when one is full.
+fn fits(sizes: &[(u32, u32)], side: u32) -> bool {
+    let mut sorted = sizes.to_vec();
+    sorted.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| b.0.cmp(&a.0)));
+    let (mut x, mut y, mut row_height) = (0u32, 0u32, 0u32);
+    for (width, height) in sorted {
+        if width > side {
+            return false;
+        }
+        if x + width > side {
+            y += row_height;
+            x = 0;
+            row_height = 0;
+        }
+        if y + height > side {
+            return false;
+        }
+        x += width;
+        row_height = row_height.max(height);
+    }
+    true
+}
+
+pub fn suggest_atlas_candidates(
+    assets: &[AssetInfo],
+    index: &ReferenceIndex,
+    max_texture_size: u32,
+) -> Vec<AtlasCandidate> {
+    let by_guid: HashMap<&str, &AssetInfo> = assets
+        .iter()
+        .filter_map(|a| Some((a.unity_guid.as_deref()?, a)))
+        .collect();
+    let targets = |path: &str| {
+        index
+            .outbound(path)
+            .iter()
+            .filter_map(|r| by_guid.get(r.guid.as_str()).copied())
+            .collect::<Vec<&AssetInfo>>()
+    };
+    let small = |asset: &AssetInfo| {
+        let metadata = asset.metadata.as_ref()?;
+        let (width, height) = (metadata.width?, metadata.height?);
+        (matches!(asset.asset_type, AssetType::Texture)
+            && width > 0
+            && height > 0
+            && width.max(height) <= max_texture_size)
+            .then_some((width, height))
+    };
+
+    // Texture set → the sources that use it.
+    let mut sets: BTreeMap<BTreeSet<&str>, Vec<&str>> = BTreeMap::new();
+    let mut dimensions: HashMap<&str, (u32, u32)> = HashMap::new();
+    for source in assets.iter().filter(|a| {
+        ["prefab", "unity"]
+            .iter()
+            .any(|e| a.extension.eq_ignore_ascii_case(e))
+    }) {
+        let mut textures = BTreeSet::new();
+        for material in targets(&source.path) {
+            if !material.extension.eq_ignore_ascii_case("mat") {
+                continue;
+            }
+            for texture in targets(&material.path) {
+                if let Some(size) = small(texture) {
+                    dimensions.insert(texture.path.as_str(), size);
+                    textures.insert(texture.path.as_str());
+                }
+            }
+        }
+        if textures.len() >= 2 {
+            sets.entry(textures).or_default().push(source.path.as_str());
+        }
+    }
+
+    let mut candidates: Vec<AtlasCandidate> = sets
+        .into_iter()
+        .map(|(textures, mut sources)| {
+            sources.sort();
+            let sizes: Vec<(u32, u32)> = textures.iter().map(|t| dimensions[t]).collect();
+            AtlasCandidate {
+                sources: sources.into_iter().map(str::to_string).collect(),
+                total_pixels: sizes.iter().map(|&(w, h)| w as u64 * h as u64).sum(),
+                atlas_size: ATLAS_SIZES.into_iter().find(|&side| fits(&sizes, side)),
+                textures: textures.into_iter().map(str::to_string).collect(),
+            }
+        })
+        .collect();
+    // Biggest sets first: the most draw calls to save.
+    candidates.sort_by(|a, b| {
+        b.textures
+            .len()
+            .cmp(&a.textures.len())
+            .then_with(|| a.sources.cmp(&b.sources))
+    });
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::{self, AssetMetadata};
+    use std::fs;
+    use std::path::Path;
+    use tempfile::tempdir;
+
+    fn guid(n: u8) -> String {
+        format!("{:032x}", n)
+    }
+
+    fn pptr(guid: &str) -> String {
+        format!("  - m_Ref: {{fileID: 2800000, guid: {}, type: 3}}\n", guid)
+    }
+
+    fn asset(dir: &Path, name: &str, n: u8, content: Option<String>) -> AssetInfo {
+        let path = dir.join(name);
+        if let Some(content) = &content {
+            fs::write(&path, content).unwrap();
+        }
+        AssetInfo {
+            path: scanner::path_to_string(&path),
+            name: name.to_string(),
+            extension: name.rsplit('.').next().unwrap().to_string(),
+            asset_type: AssetType::Other,
+            size: content.map_or(0, |c| c.len() as u64),
+            modified: 1,
+            metadata: None,
+            unity_guid: Some(guid(n)),
+        }
+    }
+
+    fn texture(dir: &Path, name: &str, n: u8, side: u32) -> AssetInfo {
+        AssetInfo {
+            asset_type: AssetType::Texture,
+            metadata: Some(AssetMetadata {
+                width: Some(side),
+                height: Some(side),
+                ..Default::default()
+            }),
+            ..asset(dir, name, n, None)
+        }
+    }
+
+    #[test]
+    fn groups_small_textures_behind_one_prefab() {
+        let dir = tempdir().unwrap();
+        let dir = dir.path();
+        let mut assets = vec![
+            texture(dir, "a.png", 1, 256),
+            texture(dir, "b.png", 2, 512),
+            texture(dir, "huge.png", 3, 2048),
+            asset(dir, "A.mat", 10, Some(pptr(&guid(1)) + &pptr(&guid(3)))),
+            asset(dir, "B.mat", 11, Some(pptr(&guid(2)))),
+        ];
+        let both = pptr(&guid(10)) + &pptr(&guid(11));
+        assets.push(asset(dir, "Hero.prefab", 20, Some(both.clone())));
+        assets.push(asset(dir, "Level.unity", 21, Some(both)));
+        // One small texture only: nothing to atlas.
+        assets.push(asset(dir, "Lone.prefab", 22, Some(pptr(&guid(10)))));
+
+        let mut index = ReferenceIndex::new("test");
+        index.refresh(&assets);
+        let found = suggest_atlas_candidates(&assets, &index, DEFAULT_MAX_TEXTURE_SIZE);
+
+        assert_eq!(found.len(), 1);
+        let names = |paths: &[String]| -> Vec<String> {
+            paths
+                .iter()
+                .map(|p| p.rsplit('/').next().unwrap().to_string())
+                .collect()
+        };
+        assert_eq!(names(&found[0].sources), ["Hero.prefab", "Level.unity"]);
+        assert_eq!(names(&found[0].textures), ["a.png", "b.png"]);
+        assert_eq!(found[0].total_pixels, 256 * 256 + 512 * 512);
+        assert_eq!(found[0].atlas_size, Some(2048));
+    }
+
+    #[test]
+    fn shelf_packing_respects_the_atlas_side() {
+        assert!(fits(&[(1024, 1024); 4], 2048));
+        assert!(!fits(&[(1024, 1024); 5], 2048));
+        assert!(fits(&[(1024, 1024); 5], 4096));
+        assert!(!fits(&[(4097, 1)], 4096));
+    }
+}
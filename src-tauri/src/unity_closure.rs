@@ -0,0 +1,134 @@
+//! Everything a Unity scene needs, transitively.
+//!
+//! The unused-asset check walks references from every scene at once; for
+//! "what does the tutorial level alone pull in" — packaging a demo,
+//! splitting DLC — the walk starts from one scene instead. A breadth-first
+//! search over the reference index follows GUIDs through prefabs,
+//! materials, controllers and so on; the visited set keeps prefab cycles
+//! from looping. GUIDs outside the scan (packages, deleted assets) end the
+//! path there.
+
+use serde::Serialize;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::scanner::AssetInfo;
+use crate::unity_index::ReferenceIndex;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AssetClosure {
+    /// Every asset reachable from the start, the start included, sorted.
+    pub paths: Vec<String>,
+    /// Combined size of `paths`.
+    pub total_size: u64,
+}
+
+pub fn asset_closure(assets: &[AssetInfo], index: &ReferenceIndex, start: &str) -> AssetClosure {
+    let by_guid: HashMap<&str, &AssetInfo> = assets
+        .iter()
+        .filter_map(|a| Some((a.unity_guid.as_deref()?, a)))
+        .collect();
+    let by_path: HashMap<&str, &AssetInfo> = assets.iter().map(|a| (a.path.as_str(), a)).collect();
+
+    let mut visited: HashSet<&str> = HashSet::new();
+    let mut queue: VecDeque<&str> = VecDeque::new();
+    let mut total_size = 0;
+    if let Some(asset) = by_path.get(start) {
+        visited.insert(asset.path.as_str());
+        queue.push_back(asset.path.as_str());
+        total_size += asset.size;
+    }
+    while let Some(path) = queue.pop_front() {
+        for reference in index.outbound(path) {
+            let Some(target) = by_guid.get(reference.guid.as_str()) else {
+                continue;
+            };
+            if visited.insert(target.path.as_str()) {
+                queue.push_back(target.path.as_str());
+                total_size += target.size;
+            }
+        }
+    }
+
+    let mut paths: Vec<String> = visited.into_iter().map(str::to_string).collect();
+    paths.sort();
+    AssetClosure { paths, total_size }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::{self, AssetType};
+    use std::fs;
+    use std::path::Path;
+    use tempfile::tempdir;
+
+    fn guid(n: u8) -> String {
+        format!("{:032x}", n)
+    }
+
+    /// Writes `name` referencing the assets with guids `refs`.
+    fn referencing(dir: &Path, name: &str, n: u8, refs: &[u8]) -> AssetInfo {
+        let path = dir.join(name);
+        let content: String = refs
+            .iter()
+            .map(|r| {
+                format!(
+                    "  m_Ref: {{fileID: 2100000, guid: {}, type: 2}}\n",
+                    guid(*r)
+                )
+            })
+            .collect();
+        fs::write(&path, &content).unwrap();
+        AssetInfo {
+            path: scanner::path_to_string(&path),
+            name: name.to_string(),
+            extension: name.rsplit('.').next().unwrap().to_string(),
+            asset_type: AssetType::Other,
+            size: 10,
+            modified: 1,
+            metadata: None,
+            unity_guid: Some(guid(n)),
+        }
+    }
+
+    #[test]
+    fn walks_references_transitively_through_cycles() {
+        let dir = tempdir().unwrap();
+        // name, own guid, guids referenced
+        let files: [(&str, u8, &[u8]); 7] = [
+            ("Tutorial.unity", 1, &[2, 9]),
+            ("Player.prefab", 2, &[3, 4]),
+            ("Player.mat", 3, &[5]),
+            // Player and Weapon reference each other.
+            ("Weapon.prefab", 4, &[2]),
+            ("Player.png", 5, &[]),
+            ("Boss.unity", 6, &[7]),
+            ("Boss.prefab", 7, &[]),
+        ];
+        let assets: Vec<AssetInfo> = files
+            .iter()
+            .map(|(name, n, refs)| referencing(dir.path(), name, *n, refs))
+            .collect();
+        let mut index = ReferenceIndex::new("test");
+        index.refresh(&assets);
+
+        let closure = asset_closure(&assets, &index, &assets[0].path);
+        let names: Vec<&str> = closure
+            .paths
+            .iter()
+            .map(|p| p.rsplit('/').next().unwrap())
+            .collect();
+        // Guid 9 isn't in the project: skipped.
+        assert_eq!(
+            names,
+            [
+                "Player.mat",
+                "Player.png",
+                "Player.prefab",
+                "Tutorial.unity",
+                "Weapon.prefab"
+            ]
+        );
+        assert_eq!(closure.total_size, 50);
+    }
+}
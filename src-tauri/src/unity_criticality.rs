@@ -0,0 +1,316 @@
+//! How much of the project depends on each asset, transitively.
+//!
+//! Inbound counts (`get_reference_counts`) say how many files use an asset
+//! directly. The blast radius of changing it is larger: a shader used by
+//! two materials, each used by fifty prefabs, touches every scene those
+//! prefabs sit in. Criticality is the size of that transitive
+//! reverse-dependency set over the Unity reference index.
+//!
+//! Prefab variants and nested prefabs can reference each other in cycles,
+//! so the walk first collapses strongly connected components (Tarjan); the
+//! dependent sets are then memoized per component as bitsets over
+//! components, each computed once from the components depending on it and
+//! dropped once nothing still needs it.
+
+use serde::Serialize;
+use std::collections::HashMap;
+
+use crate::scanner::AssetInfo;
+use crate::unity_index::ReferenceIndex;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AssetCriticality {
+    pub path: String,
+    /// Distinct project assets referencing this one directly.
+    pub direct_dependents: usize,
+    /// Distinct project assets that depend on it through any chain of
+    /// references — what a change could affect.
+    pub transitive_dependents: usize,
+}
+
+/// Tarjan's SCC over `dependents` (asset → assets referencing it).
+/// Components come out in reverse topological order: every component
+/// depending on one is emitted before it.
+struct Tarjan<'a> {
+    dependents: &'a [Vec<usize>],
+    index: Vec<Option<usize>>,
+    low: Vec<usize>,
+    on_stack: Vec<bool>,
+    stack: Vec<usize>,
+    next: usize,
+    components: Vec<Vec<usize>>,
+}
+
+impl Tarjan<'_> {
+    fn open(&mut self, node: usize) {
+        self.index[node] = Some(self.next);
+        self.low[node] = self.next;
+        self.next += 1;
+        self.stack.push(node);
+        self.on_stack[node] = true;
+    }
+
+    /// Iterative: a reference chain thousands of assets long must not
+    /// overflow the thread stack. `calls` holds each open node with the
+    /// position of the next dependent to look at.
+    fn visit(&mut self, root: usize) {
+        self.open(root);
+        let mut calls = vec![(root, 0)];
+        while let Some(frame) = calls.last_mut() {
+            let (node, edge) = *frame;
+            if let Some(&dependent) = self.dependents[node].get(edge) {
+                frame.1 += 1;
+                match self.index[dependent] {
+                    None => {
+                        self.open(dependent);
+                        calls.push((dependent, 0));
+                    }
+                    Some(index) if self.on_stack[dependent] => {
+                        self.low[node] = self.low[node].min(index);
+                    }
+                    Some(_) => {}
+                }
+                continue;
+            }
+
+            calls.pop();
+            if Some(self.low[node]) == self.index[node] {
+                let mut component = Vec::new();
+                while let Some(member) = self.stack.pop() {
+                    self.on_stack[member] = false;
+                    component.push(member);
+                    if member == node {
+                        break;
+                    }
+                }
+                self.components.push(component);
+            }
+            if let Some(&(parent, _)) = calls.last() {
+                self.low[parent] = self.low[parent].min(self.low[node]);
+            }
+        }
+    }
+}
+
+/// The strongly connected components of `dependents`, in Tarjan's order.
+fn strongly_connected(dependents: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    let mut tarjan = Tarjan {
+        dependents,
+        index: vec![None; dependents.len()],
+        low: vec![0; dependents.len()],
+        on_stack: vec![false; dependents.len()],
+        stack: Vec::new(),
+        next: 0,
+        components: Vec::new(),
+    };
+    for node in 0..dependents.len() {
+        if tarjan.index[node].is_none() {
+            tarjan.visit(node);
+        }
+    }
+    tarjan.components
+}
+
+/// Every scanned asset with a GUID, most critical first (then by path).
+pub fn asset_criticality(assets: &[AssetInfo], index: &ReferenceIndex) -> Vec<AssetCriticality> {
+    let nodes: Vec<&AssetInfo> = assets.iter().filter(|a| a.unity_guid.is_some()).collect();
+    let by_guid: HashMap<&str, usize> = nodes
+        .iter()
+        .enumerate()
+        .filter_map(|(i, a)| Some((a.unity_guid.as_deref()?, i)))
+        .collect();
+
+    let mut dependents: Vec<Vec<usize>> = vec![Vec::new(); nodes.len()];
+    for (referrer, asset) in nodes.iter().enumerate() {
+        for reference in index.outbound(&asset.path) {
+            if let Some(&target) = by_guid.get(reference.guid.as_str()) {
+                if target != referrer {
+                    dependents[target].push(referrer);
+                }
+            }
+        }
+    }
+    for list in &mut dependents {
+        list.sort_unstable();
+        list.dedup();
+    }
+
+    let components = strongly_connected(&dependents);
+
+    let mut component_of = vec![0; nodes.len()];
+    for (c, members) in components.iter().enumerate() {
+        for &member in members {
+            component_of[member] = c;
+        }
+    }
+    // The distinct components depending on each one. Tarjan emits those
+    // first, so every entry is below the component's own index.
+    let component_dependents: Vec<Vec<usize>> = components
+        .iter()
+        .enumerate()
+        .map(|(c, members)| {
+            let mut list: Vec<usize> = members
+                .iter()
+                .flat_map(|&member| &dependents[member])
+                .map(|&dependent| component_of[dependent])
+                .filter(|&d| d != c)
+                .collect();
+            list.sort_unstable();
+            list.dedup();
+            list
+        })
+        .collect();
+    // How many components have yet to read each reach set.
+    let mut readers = vec![0usize; components.len()];
+    for &d in component_dependents.iter().flatten() {
+        readers[d] += 1;
+    }
+
+    // Memo: component → bitset of the components depending on it, directly
+    // or not, and the assets those hold. Filled in emission order, so
+    // dependent components are always ready; a set only spans the
+    // components before it and is freed after its last reader.
+    let mut reach: Vec<Vec<u64>> = Vec::with_capacity(components.len());
+    let mut reach_len: Vec<usize> = Vec::with_capacity(components.len());
+    for (c, list) in component_dependents.iter().enumerate() {
+        let mut set = vec![0u64; c.div_ceil(64)];
+        for &d in list {
+            set[d / 64] |= 1 << (d % 64);
+            for (word, theirs) in set.iter_mut().zip(&reach[d]) {
+                *word |= theirs;
+            }
+            readers[d] -= 1;
+            if readers[d] == 0 {
+                reach[d] = Vec::new();
+            }
+        }
+        let mut len = 0;
+        for (w, &word) in set.iter().enumerate() {
+            let mut bits = word;
+            while bits != 0 {
+                len += components[w * 64 + bits.trailing_zeros() as usize].len();
+                bits &= bits - 1;
+            }
+        }
+        reach_len.push(len);
+        reach.push(if readers[c] == 0 { Vec::new() } else { set });
+    }
+
+    let mut result: Vec<AssetCriticality> = nodes
+        .iter()
+        .enumerate()
+        .map(|(i, asset)| {
+            let c = component_of[i];
+            AssetCriticality {
+                path: asset.path.clone(),
+                direct_dependents: dependents[i].len(),
+                // The rest of its own cycle depends on it too.
+                transitive_dependents: reach_len[c] + components[c].len() - 1,
+            }
+        })
+        .collect();
+    result.sort_by(|a, b| {
+        b.transitive_dependents
+            .cmp(&a.transitive_dependents)
+            .then_with(|| a.path.cmp(&b.path))
+    });
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::{self, AssetType};
+    use std::fs;
+    use std::path::Path;
+    use tempfile::tempdir;
+
+    fn guid(n: u8) -> String {
+        format!("{:032x}", n)
+    }
+
+    /// Writes `name` referencing the assets with guids `refs`.
+    fn referencing(dir: &Path, name: &str, n: u8, refs: &[u8]) -> AssetInfo {
+        let path = dir.join(name);
+        let content: String = refs
+            .iter()
+            .map(|r| {
+                format!(
+                    "  m_Ref: {{fileID: 2100000, guid: {}, type: 2}}\n",
+                    guid(*r)
+                )
+            })
+            .collect();
+        fs::write(&path, &content).unwrap();
+        AssetInfo {
+            path: scanner::path_to_string(&path),
+            name: name.to_string(),
+            extension: name.rsplit('.').next().unwrap().to_string(),
+            asset_type: AssetType::Other,
+            size: content.len() as u64,
+            modified: 1,
+            metadata: None,
+            unity_guid: Some(guid(n)),
+        }
+    }
+
+    #[test]
+    fn counts_transitive_dependents_through_cycles() {
+        let dir = tempdir().unwrap();
+        // name, own guid, guids referenced
+        let files: [(&str, u8, &[u8]); 6] = [
+            ("Rock.shader", 1, &[]),
+            ("Rock.mat", 2, &[1]),
+            ("A.prefab", 3, &[2, 4]),
+            // A and B reference each other.
+            ("B.prefab", 4, &[3]),
+            ("Level.unity", 5, &[3]),
+            ("Other.mat", 6, &[1]),
+        ];
+        let assets: Vec<AssetInfo> = files
+            .iter()
+            .map(|(name, n, refs)| referencing(dir.path(), name, *n, refs))
+            .collect();
+        let mut index = ReferenceIndex::new("test");
+        index.refresh(&assets);
+
+        let criticality = asset_criticality(&assets, &index);
+        let found: Vec<(&str, usize, usize)> = criticality
+            .iter()
+            .map(|c| {
+                let name = c.path.rsplit('/').next().unwrap();
+                (name, c.direct_dependents, c.transitive_dependents)
+            })
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("Rock.shader", 2, 5),
+                ("Rock.mat", 1, 3),
+                ("A.prefab", 2, 2),
+                ("B.prefab", 1, 2),
+                ("Level.unity", 0, 0),
+                ("Other.mat", 0, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn long_reference_chains_do_not_recurse() {
+        // A chain where each asset is referenced by the next, then the same
+        // chain closed into one cycle.
+        let n = 200_000;
+        let mut dependents: Vec<Vec<usize>> = (0..n).map(|i| vec![i + 1]).collect();
+        dependents[n - 1] = Vec::new();
+        let components = strongly_connected(&dependents);
+        assert_eq!(components.len(), n);
+        // Dependents first: the chain's far end is emitted before its root.
+        assert_eq!(components[0], [n - 1]);
+        assert_eq!(components[n - 1], [0]);
+
+        dependents[n - 1] = vec![0];
+        let components = strongly_connected(&dependents);
+        assert_eq!(components.len(), 1);
+        assert_eq!(components[0].len(), n);
+    }
+}
@@ -0,0 +1,189 @@
+//! Unity assets whose importer disagrees with their extension-based type.
+//!
+//! The scan classifies by extension, and some extensions are ambiguous:
+//! an `.asset` is a ScriptableObject as often as a lightmap texture, a mesh
+//! or a material saved as an asset; a `.txt` or `.bytes` Unity imports as a
+//! TextAsset is data, not "other". The `.meta` knows — its top-level
+//! `...Importer:` key names the importer, and for `NativeFormatImporter`
+//! the `mainObjectFileID` names the stored class (Unity's class id times
+//! 100000). Where that pins down a type and it differs from the scan's,
+//! the pair is reported; importers that accept anything (`DefaultImporter`,
+//! `ScriptedImporter`) tell nothing and are skipped.
+
+use rayon::prelude::*;
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+
+use crate::scanner::{self, AssetInfo, AssetType};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TypeMismatch {
+    pub path: String,
+    /// What the scan classified it as from its extension.
+    pub guessed: AssetType,
+    /// The `.meta`'s importer, e.g. `NativeFormatImporter`.
+    pub importer: String,
+    /// The type that importer implies.
+    pub importer_type: AssetType,
+}
+
+/// The top-level importer key of a `.meta` (`TextureImporter`, ...).
+fn importer_class(meta: &str) -> Option<&str> {
+    meta.lines()
+        .find(|line| !line.starts_with(' ') && line.trim_end().ends_with("Importer:"))
+        .map(|line| line.trim_end().trim_end_matches(':'))
+}
+
+/// What a `NativeFormatImporter` asset stores, from its `mainObjectFileID`.
+fn native_format_type(meta: &str) -> Option<AssetType> {
+    let file_id: i64 = meta
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("mainObjectFileID:"))?
+        .trim()
+        .parse()
+        .ok()?;
+    match file_id {
+        // Texture2D, Cubemap, RenderTexture, Texture2DArray, Texture3D
+        2800000 | 8900000 | 8400000 | 18700000 | 11700000 => Some(AssetType::Texture),
+        4300000 => Some(AssetType::Model),
+        2100000 => Some(AssetType::Material),
+        // AnimationClip, AnimatorController, AnimatorOverrideController
+        7400000 | 9100000 | 22100000 => Some(AssetType::Animation),
+        // MonoBehaviour: a ScriptableObject
+        11400000 => Some(AssetType::Data),
+        _ => None,
+    }
+}
+
+/// The importer named by a `.meta` and the type it implies, when it
+/// implies one.
+pub fn importer_type(meta: &str) -> Option<(String, AssetType)> {
+    let importer = importer_class(meta)?;
+    let asset_type = match importer {
+        "TextureImporter" | "IHVImageFormatImporter" => AssetType::Texture,
+        "ModelImporter" | "SpeedTreeImporter" => AssetType::Model,
+        "AudioImporter" => AssetType::Audio,
+        "VideoClipImporter" | "MovieImporter" => AssetType::Video,
+        "MonoImporter" => AssetType::Script,
+        "TextScriptImporter" => AssetType::Data,
+        "PrefabImporter" => AssetType::Prefab,
+        "NativeFormatImporter" => native_format_type(meta)?,
+        _ => return None,
+    };
+    Some((importer.to_string(), asset_type))
+}
+
+/// Assets in `assets` whose `.meta` importer implies a different type than
+/// the scan's, sorted by path.
+pub fn reconcile_asset_types(assets: &[AssetInfo]) -> Vec<TypeMismatch> {
+    let mut mismatches: Vec<TypeMismatch> = assets
+        .par_iter()
+        .filter(|a| a.unity_guid.is_some())
+        .filter_map(|asset| {
+            let meta_path = scanner::unity_meta_path(Path::new(&asset.path))?;
+            let (importer, importer_type) = importer_type(&fs::read_to_string(meta_path).ok()?)?;
+            (importer_type != asset.asset_type).then(|| TypeMismatch {
+                path: asset.path.clone(),
+                guessed: asset.asset_type.clone(),
+                importer,
+                importer_type,
+            })
+        })
+        .collect();
+    mismatches.sort_by(|a, b| a.path.cmp(&b.path));
+    mismatches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    /// Writes `name` and a `.meta` with `meta` as its importer section.
+    fn asset(dir: &Path, name: &str, asset_type: AssetType, meta: &str) -> AssetInfo {
+        let path = dir.join(name);
+        fs::write(&path, "x").unwrap();
+        fs::write(
+            dir.join(format!("{}.meta", name)),
+            format!(
+                "fileFormatVersion: 2\nguid: aaaa0000aaaa0000aaaa0000aaaa0000\n{}",
+                meta
+            ),
+        )
+        .unwrap();
+        AssetInfo {
+            path: scanner::path_to_string(&path),
+            name: name.to_string(),
+            extension: name.rsplit('.').next().unwrap().to_string(),
+            asset_type,
+            size: 1,
+            modified: 0,
+            metadata: None,
+            unity_guid: Some("aaaa0000aaaa0000aaaa0000aaaa0000".to_string()),
+        }
+    }
+
+    #[test]
+    fn reports_assets_the_importer_classifies_differently() {
+        let dir = tempdir().unwrap();
+        let dir = dir.path();
+        let native = |file_id: u32| {
+            format!(
+                "NativeFormatImporter:\n  externalObjects: {{}}\n  mainObjectFileID: {}\n",
+                file_id
+            )
+        };
+        let assets = vec![
+            asset(dir, "Lightmap-0.asset", AssetType::Data, &native(2800000)),
+            asset(dir, "Settings.asset", AssetType::Data, &native(11400000)),
+            asset(
+                dir,
+                "Dialogue.txt",
+                AssetType::Other,
+                "TextScriptImporter:\n  userData: \n",
+            ),
+            asset(
+                dir,
+                "Rock.png",
+                AssetType::Texture,
+                "TextureImporter:\n  mipmaps:\n",
+            ),
+            asset(
+                dir,
+                "Notes.md",
+                AssetType::Other,
+                "DefaultImporter:\n  userData: \n",
+            ),
+        ];
+
+        let summary: Vec<(String, AssetType, String, AssetType)> = reconcile_asset_types(&assets)
+            .into_iter()
+            .map(|m| {
+                (
+                    m.path.rsplit('/').next().unwrap().to_string(),
+                    m.guessed,
+                    m.importer,
+                    m.importer_type,
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (
+                    "Dialogue.txt".to_string(),
+                    AssetType::Other,
+                    "TextScriptImporter".to_string(),
+                    AssetType::Data
+                ),
+                (
+                    "Lightmap-0.asset".to_string(),
+                    AssetType::Data,
+                    "NativeFormatImporter".to_string(),
+                    AssetType::Texture
+                ),
+            ]
+        );
+    }
+}
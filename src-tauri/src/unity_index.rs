@@ -0,0 +1,376 @@
+//! Prebuilt Unity reference index: the outbound GUID edges of every file
+//! that can reference other assets, persisted next to the scan cache.
+//!
+//! The dependency graph, reference counts, unused-asset scan and safe
+//! delete all need the same edges, and each used to re-read and re-parse
+//! every prefab / scene / material on every call. The index parses a file
+//! once and keeps its edges until the file's size or mtime (as the scan —
+//! or the watcher — last saw it) changes, so a refresh after an
+//! incremental scan only re-parses what the scan re-parsed.
+//!
+//! Entries are keyed by path, not GUID: a file's own GUID lives in its
+//! `.meta` and can change without the file changing, so it's looked up in
+//! the current scan whenever GUIDs are needed.
+
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use crate::cache::cache_file_path;
+use crate::scanner::AssetInfo;
+use crate::unity::{self, ReferenceKind};
+
+/// Unity text files that carry GUID references to other assets. Every
+/// reference view (dependency graph, reference counts, unused assets, safe
+/// delete) reads this *same* set through the index so their results never
+/// diverge (deps once used prefab/unity/mat while unused added controller).
+/// Beyond prefab/scene/material/controller it covers:
+///   - `.asset` — ScriptableObjects + EditorBuildSettings (scene refs live
+///     here, so scenes were otherwise always flagged unused),
+///   - `.anim` — sprite-animation PPtr curves,
+///   - `.overridecontroller` — animator override controllers.
+/// `unity::parse_unity_file` recognizes each of these extensions.
+pub const REFERENCE_SOURCE_EXTS: &[&str] = &[
+    "prefab",
+    "unity",
+    "mat",
+    "controller",
+    "overridecontroller",
+    "asset",
+    "anim",
+];
+
+pub fn is_reference_source(asset: &AssetInfo) -> bool {
+    REFERENCE_SOURCE_EXTS.contains(&asset.extension.to_lowercase().as_str())
+}
+
+/// How far a `refresh_with_progress` got: files parsed out of those that
+/// needed parsing. Shared with a reporter thread.
+#[derive(Debug, Default)]
+pub struct RefreshProgress {
+    parsed: AtomicUsize,
+    total: AtomicUsize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct RefreshSnapshot {
+    pub current: usize,
+    pub total: usize,
+}
+
+impl RefreshProgress {
+    pub fn snapshot(&self) -> RefreshSnapshot {
+        RefreshSnapshot {
+            current: self.parsed.load(Ordering::Relaxed),
+            total: self.total.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// One outbound edge: a referenced GUID and how the file references it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexedReference {
+    pub guid: String,
+    pub kind: ReferenceKind,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct IndexEntry {
+    modified: u64,
+    size: u64,
+    /// Sorted by GUID, one per target.
+    references: Vec<IndexedReference>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReferenceIndex {
+    version: u32,
+    /// Scan cache key this index belongs to.
+    project_key: String,
+    /// Referencing file path → its outbound edges.
+    entries: HashMap<String, IndexEntry>,
+}
+
+impl ReferenceIndex {
+    /// Bump when the edge extraction changes, so persisted indexes built by
+    /// the old parser are discarded instead of served.
+    const VERSION: u32 = 1;
+
+    pub fn new(project_key: &str) -> Self {
+        Self {
+            version: Self::VERSION,
+            project_key: project_key.to_string(),
+            entries: HashMap::new(),
+        }
+    }
+
+    pub fn index_path(project_key: &str) -> Option<PathBuf> {
+        cache_file_path(project_key, "unity-refs.json")
+    }
+
+    pub fn project_key(&self) -> &str {
+        &self.project_key
+    }
+
+    /// The persisted index for `project_key`, or `None` when there is none
+    /// or it was written by another version.
+    pub fn load(project_key: &str) -> Option<Self> {
+        let content = fs::read_to_string(Self::index_path(project_key)?).ok()?;
+        let index: Self = serde_json::from_str(&content).ok()?;
+        (index.version == Self::VERSION && index.project_key == project_key).then_some(index)
+    }
+
+    pub fn save(&self) -> Result<(), std::io::Error> {
+        let path = Self::index_path(&self.project_key)
+            .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::NotFound, "No cache dir"))?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let content = serde_json::to_string(self)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        fs::write(path, content)
+    }
+
+    /// Bring the index in line with `assets` (a full scan): drop files that
+    /// are gone, parse new ones and re-parse those whose size or mtime
+    /// moved. Returns how many entries were added, re-parsed or dropped.
+    pub fn refresh(&mut self, assets: &[AssetInfo]) -> usize {
+        // Without a cancel flag the refresh always runs to the end.
+        self.refresh_with_progress(assets, None, None)
+            .unwrap_or_default()
+    }
+
+    /// `refresh`, counting parsed files into `progress` and stopping early
+    /// once `cancel` is set — then `None`. Files parsed before the stop are
+    /// still kept: their edges are valid, and the next refresh skips them.
+    pub fn refresh_with_progress(
+        &mut self,
+        assets: &[AssetInfo],
+        progress: Option<&RefreshProgress>,
+        cancel: Option<&AtomicBool>,
+    ) -> Option<usize> {
+        let cancelled = || cancel.is_some_and(|c| c.load(Ordering::Relaxed));
+        let sources: Vec<&AssetInfo> = assets.iter().filter(|a| is_reference_source(a)).collect();
+        let live: HashSet<&str> = sources.iter().map(|a| a.path.as_str()).collect();
+        let before = self.entries.len();
+        self.entries.retain(|path, _| live.contains(path.as_str()));
+        let dropped = before - self.entries.len();
+
+        let stale: Vec<&AssetInfo> = sources
+            .into_iter()
+            .filter(|a| {
+                self.entries
+                    .get(&a.path)
+                    .map_or(true, |e| e.modified != a.modified || e.size != a.size)
+            })
+            .collect();
+        if let Some(progress) = progress {
+            progress.total.store(stale.len(), Ordering::Relaxed);
+        }
+        let parsed: Vec<(String, IndexEntry)> = stale
+            .par_iter()
+            .filter_map(|asset| {
+                if cancelled() {
+                    return None;
+                }
+                let entry = IndexEntry {
+                    modified: asset.modified,
+                    size: asset.size,
+                    references: outbound_references(Path::new(&asset.path)),
+                };
+                if let Some(progress) = progress {
+                    progress.parsed.fetch_add(1, Ordering::Relaxed);
+                }
+                Some((asset.path.clone(), entry))
+            })
+            .collect();
+        let updated = parsed.len();
+        self.entries.extend(parsed);
+        (!cancelled()).then_some(dropped + updated)
+    }
+
+    /// Outbound edges of the referencing file at `path`. Empty for files
+    /// the index doesn't cover (not a reference source, or unreadable).
+    pub fn outbound(&self, path: &str) -> &[IndexedReference] {
+        self.entries
+            .get(path)
+            .map_or(&[], |e| e.references.as_slice())
+    }
+
+    /// Every GUID referenced by any indexed file.
+    pub fn referenced_guids(&self) -> HashSet<&str> {
+        self.entries
+            .values()
+            .flat_map(|e| e.references.iter().map(|r| r.guid.as_str()))
+            .collect()
+    }
+
+    /// Target GUID → paths of the files referencing it, sorted.
+    pub fn inbound_paths(&self) -> HashMap<&str, BTreeSet<&str>> {
+        let mut inbound: HashMap<&str, BTreeSet<&str>> = HashMap::new();
+        for (path, entry) in &self.entries {
+            for reference in &entry.references {
+                inbound
+                    .entry(reference.guid.as_str())
+                    .or_default()
+                    .insert(path.as_str());
+            }
+        }
+        inbound
+    }
+
+    pub fn file_count(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.entries.values().map(|e| e.references.len()).sum()
+    }
+}
+
+/// Parse one file's references into edges. The all-zero sentinel and the
+/// editor built-in bundles never count (see `unity::is_builtin_guid`); a
+/// target referenced several ways keeps its structural kind.
+fn outbound_references(path: &Path) -> Vec<IndexedReference> {
+    let Some(info) = unity::parse_unity_file(path) else {
+        return Vec::new();
+    };
+    let mut targets: BTreeMap<String, ReferenceKind> = BTreeMap::new();
+    for reference in info.references {
+        if unity::is_null_guid(&reference.guid) || unity::is_builtin_guid(&reference.guid) {
+            continue;
+        }
+        let kind = targets
+            .entry(reference.guid)
+            .or_insert(reference.reference_kind);
+        if *kind == ReferenceKind::Direct {
+            *kind = reference.reference_kind;
+        }
+    }
+    targets
+        .into_iter()
+        .map(|(guid, kind)| IndexedReference { guid, kind })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::AssetType;
+    use tempfile::tempdir;
+
+    const TEX_GUID: &str = "abc123def456789012345678901234ab";
+    const MAT_GUID: &str = "11112222333344445555666677778888";
+
+    fn material_referencing(guid: &str) -> String {
+        format!(
+            "%YAML 1.1\n--- !u!21 &2100000\nMaterial:\n  m_SavedProperties:\n    m_TexEnvs:\n    - _MainTex:\n        m_Texture: {{fileID: 2800000, guid: {}, type: 3}}\n",
+            guid
+        )
+    }
+
+    fn asset(path: &Path, guid: &str, modified: u64) -> AssetInfo {
+        let name = path.file_name().unwrap().to_string_lossy().to_string();
+        AssetInfo {
+            path: crate::scanner::path_to_string(path),
+            extension: name.rsplit('.').next().unwrap().to_string(),
+            name,
+            asset_type: AssetType::Material,
+            size: fs::metadata(path).map(|m| m.len()).unwrap_or(0),
+            modified,
+            metadata: None,
+            unity_guid: Some(guid.to_string()),
+        }
+    }
+
+    #[test]
+    fn refresh_reparses_only_changed_files() {
+        let dir = tempdir().unwrap();
+        let mat = dir.path().join("Rock.mat");
+        let tex = dir.path().join("Rock.png");
+        fs::write(&mat, material_referencing(TEX_GUID)).unwrap();
+        fs::write(&tex, "png").unwrap();
+        let mut assets = vec![asset(&mat, MAT_GUID, 1), asset(&tex, TEX_GUID, 1)];
+        let mat_path = assets[0].path.clone();
+
+        let mut index = ReferenceIndex::new("test");
+        // Only the material is a reference source.
+        assert_eq!(index.refresh(&assets), 1);
+        assert_eq!(index.outbound(&mat_path)[0].guid, TEX_GUID);
+        assert_eq!(
+            index.inbound_paths().get(TEX_GUID),
+            Some(&BTreeSet::from([mat_path.as_str()]))
+        );
+        assert_eq!(index.refresh(&assets), 0);
+
+        // Content changes without the scan noticing: the index keeps the
+        // edges it has — the scan's size/mtime is the staleness signal.
+        let other = "ffffeeeeddddccccbbbbaaaa99998888";
+        fs::write(&mat, material_referencing(other)).unwrap();
+        assert_eq!(index.refresh(&assets), 0);
+        assets[0] = asset(&mat, MAT_GUID, 2);
+        assert_eq!(index.refresh(&assets), 1);
+        assert_eq!(index.outbound(&mat_path)[0].guid, other);
+        assert!(!index.referenced_guids().contains(TEX_GUID));
+
+        // Files gone from the scan are dropped.
+        assets.remove(0);
+        assert_eq!(index.refresh(&assets), 1);
+        assert_eq!(index.file_count(), 0);
+    }
+
+    #[test]
+    fn progress_counts_parsed_files_and_cancel_stops() {
+        let dir = tempdir().unwrap();
+        let assets: Vec<AssetInfo> = ["A.mat", "B.mat"]
+            .iter()
+            .map(|name| {
+                let path = dir.path().join(name);
+                fs::write(&path, material_referencing(TEX_GUID)).unwrap();
+                asset(&path, MAT_GUID, 1)
+            })
+            .collect();
+        let mut index = ReferenceIndex::new("test");
+
+        let cancel = AtomicBool::new(true);
+        assert_eq!(
+            index.refresh_with_progress(&assets, None, Some(&cancel)),
+            None
+        );
+        assert_eq!(index.file_count(), 0);
+
+        let progress = RefreshProgress::default();
+        cancel.store(false, Ordering::Relaxed);
+        assert_eq!(
+            index.refresh_with_progress(&assets, Some(&progress), Some(&cancel)),
+            Some(2)
+        );
+        assert_eq!(
+            progress.snapshot(),
+            RefreshSnapshot {
+                current: 2,
+                total: 2
+            }
+        );
+    }
+
+    #[test]
+    fn null_and_builtin_guids_are_not_edges() {
+        let dir = tempdir().unwrap();
+        let mat = dir.path().join("UI.mat");
+        fs::write(
+            &mat,
+            format!(
+                "{}        m_Shader: {{fileID: 10721, guid: 0000000000000000f000000000000000, type: 0}}\n        m_Other: {{fileID: 0, guid: 00000000000000000000000000000000, type: 0}}\n",
+                material_referencing(TEX_GUID)
+            ),
+        )
+        .unwrap();
+        let mut index = ReferenceIndex::new("test");
+        index.refresh(&[asset(&mat, MAT_GUID, 1)]);
+        assert_eq!(index.edge_count(), 1);
+    }
+}
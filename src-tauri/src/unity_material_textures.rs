@@ -0,0 +1,154 @@
+//! Unity materials whose texture slots point at textures that are gone.
+//!
+//! Deleting a texture outside the editor (or losing its `.meta`) leaves
+//! every material that used it holding a dangling GUID; Unity draws the
+//! slot empty, which for an albedo or normal map is hard to miss in game
+//! and easy to miss in review. Each `.mat`'s `m_TexEnvs` GUIDs are
+//! resolved against the scan and the `Library/PackageCache` index;
+//! built-in textures live in the two built-in bundles and are skipped.
+
+use rayon::prelude::*;
+use std::collections::HashSet;
+use std::fs;
+
+use crate::analyzer::rules::RuleDescription;
+use crate::analyzer::{AnalysisResult, Issue, Severity};
+use crate::scanner::AssetInfo;
+use crate::unity::{self, PackageGuidIndex};
+
+/// Rule catalog entry for the material texture check.
+pub fn describe() -> Vec<RuleDescription> {
+    vec![RuleDescription::new(
+        "unity.material_missing_texture",
+        "Material Missing Texture",
+        Severity::Warning,
+        false,
+        "A Unity material's texture slot references a GUID no texture in the project or package cache defines.",
+    )]
+}
+
+/// One warning per material listing its broken slots, sorted by path.
+/// Materials `is_excluded` accepts aren't checked.
+pub fn find_materials_with_missing_textures(
+    assets: &[AssetInfo],
+    packages: &PackageGuidIndex,
+    is_excluded: impl Fn(&str) -> bool + Sync,
+) -> AnalysisResult {
+    let known: HashSet<&str> = assets
+        .iter()
+        .filter_map(|a| a.unity_guid.as_deref())
+        .collect();
+    let mut broken: Vec<(&AssetInfo, Vec<String>)> = assets
+        .par_iter()
+        .filter(|a| a.extension.eq_ignore_ascii_case("mat") && !is_excluded(&a.path))
+        .filter_map(|material| {
+            let content = fs::read_to_string(&material.path).ok()?;
+            let slots: Vec<String> = unity::material_texture_slots(&content)
+                .into_iter()
+                .filter(|slot| {
+                    slot.guid.as_deref().is_some_and(|guid| {
+                        !unity::is_builtin_guid(guid)
+                            && !known.contains(guid)
+                            && packages.get(guid).is_none()
+                    })
+                })
+                .map(|slot| slot.name)
+                .collect();
+            (!slots.is_empty()).then_some((material, slots))
+        })
+        .collect();
+    broken.sort_by(|a, b| a.0.path.cmp(&b.0.path));
+
+    let mut result = AnalysisResult::new();
+    for (material, slots) in broken {
+        result.add_issue(Issue {
+            rule_id: "unity.material_missing_texture".to_string(),
+            rule_name: "Material Missing Texture".to_string(),
+            severity: Severity::Warning,
+            message: format!(
+                "Material references {} that no longer exist{}: {}",
+                if slots.len() == 1 { "a texture" } else { "textures" },
+                if slots.len() == 1 { "s" } else { "" },
+                slots.join(", ")
+            ),
+            asset_path: material.path.clone(),
+            suggestion: Some(
+                "Reassign the slot in the material inspector, or restore the texture with its .meta"
+                    .to_string(),
+            ),
+            auto_fixable: false,
+            related_paths: None,
+        });
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::{self, AssetType};
+    use std::path::Path;
+    use tempfile::tempdir;
+
+    const ALBEDO: &str = "aaaa0000aaaa0000aaaa0000aaaa0000";
+    const DELETED: &str = "dddd0000dddd0000dddd0000dddd0000";
+    const BUILTIN: &str = "0000000000000000f000000000000000";
+
+    fn asset(dir: &Path, name: &str, content: &str, guid: &str) -> AssetInfo {
+        let path = dir.join(name);
+        fs::write(&path, content).unwrap();
+        AssetInfo {
+            path: scanner::path_to_string(&path),
+            name: name.to_string(),
+            extension: name.rsplit('.').next().unwrap().to_string(),
+            asset_type: AssetType::Other,
+            size: 0,
+            modified: 0,
+            metadata: None,
+            unity_guid: Some(guid.to_string()),
+        }
+    }
+
+    /// A material whose `m_TexEnvs` maps each slot to a texture guid.
+    fn material(dir: &Path, name: &str, slots: &[(&str, &str)]) -> AssetInfo {
+        let tex_envs: String = slots
+            .iter()
+            .map(|(slot, guid)| {
+                format!(
+                    "    - {}:\n        m_Texture: {{fileID: 2800000, guid: {}, type: 3}}\n",
+                    slot, guid
+                )
+            })
+            .collect();
+        let content = format!(
+            "Material:\n  m_SavedProperties:\n    m_TexEnvs:\n{}    m_Floats: []\n",
+            tex_envs
+        );
+        asset(dir, name, &content, "cccc0000cccc0000cccc0000cccc0000")
+    }
+
+    #[test]
+    fn reports_slots_whose_texture_is_gone() {
+        let dir = tempdir().unwrap();
+        let dir = dir.path();
+        let assets = vec![
+            asset(dir, "Rock.png", "", ALBEDO),
+            material(
+                dir,
+                "Rock.mat",
+                &[("_MainTex", ALBEDO), ("_BumpMap", DELETED)],
+            ),
+            material(dir, "Ui.mat", &[("_MainTex", BUILTIN)]),
+        ];
+
+        let result =
+            find_materials_with_missing_textures(&assets, &PackageGuidIndex::default(), |_| false);
+        assert_eq!(result.issues.len(), 1);
+        let issue = &result.issues[0];
+        assert!(issue.asset_path.ends_with("Rock.mat"));
+        assert_eq!(issue.severity, Severity::Warning);
+        assert!(issue
+            .message
+            .ends_with("a texture that no longer exists: _BumpMap"));
+    }
+}
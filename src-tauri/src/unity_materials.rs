@@ -0,0 +1,119 @@
+//! Unity materials that render identically but are saved separately.
+//!
+//! Copying a material to tweak it and then reverting the tweak, or two
+//! people making "Rock" in different folders, leaves materials with the
+//! same shader, keywords and property values. Their files never hash alike
+//! — each has its own name, GUID and often a different property order — so
+//! the duplicate check misses them. Grouping by `unity::material_signature`
+//! finds them; each group is a candidate to consolidate into one material.
+
+use rayon::prelude::*;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs;
+
+use crate::scanner::AssetInfo;
+use crate::unity::{self, MaterialSignature};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DuplicateMaterialGroup {
+    /// Two or more materials with the same signature, sorted.
+    pub paths: Vec<String>,
+}
+
+/// Groups of equivalent `.mat` files in `assets`, ordered by first path.
+pub fn find_duplicate_materials(assets: &[AssetInfo]) -> Vec<DuplicateMaterialGroup> {
+    let signatures: Vec<(MaterialSignature, &AssetInfo)> = assets
+        .par_iter()
+        .filter(|asset| asset.extension.eq_ignore_ascii_case("mat"))
+        .filter_map(|asset| {
+            let content = fs::read_to_string(&asset.path).ok()?;
+            Some((unity::material_signature(&content)?, asset))
+        })
+        .collect();
+
+    let mut by_signature: HashMap<MaterialSignature, Vec<String>> = HashMap::new();
+    for (signature, asset) in signatures {
+        by_signature
+            .entry(signature)
+            .or_default()
+            .push(asset.path.clone());
+    }
+    let mut groups: Vec<DuplicateMaterialGroup> = by_signature
+        .into_values()
+        .filter(|paths| paths.len() > 1)
+        .map(|mut paths| {
+            paths.sort();
+            DuplicateMaterialGroup { paths }
+        })
+        .collect();
+    groups.sort_by(|a, b| a.paths[0].cmp(&b.paths[0]));
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::{self, AssetType};
+    use std::path::Path;
+    use tempfile::tempdir;
+
+    const SHADER: &str = "{fileID: 46, guid: 0000000000000000f000000000000000, type: 0}";
+
+    fn material(name: &str, keywords: &str, props: &[&str]) -> String {
+        format!(
+            "%YAML 1.1\n%TAG !u! tag:unity3d.com,2011:\n--- !u!21 &2100000\nMaterial:\n  serializedVersion: 8\n  m_Name: {}\n  m_Shader: {}\n  m_ShaderKeywords: {}\n  m_CustomRenderQueue: -1\n  m_SavedProperties:\n    serializedVersion: 3\n    m_TexEnvs:\n    - _MainTex:\n        m_Texture: {{fileID: 2800000, guid: abc123def456789012345678901234ab, type: 3}}\n        m_Scale: {{x: 1, y: 1}}\n    m_Floats:\n{}",
+            name,
+            SHADER,
+            keywords,
+            props.iter().map(|p| format!("    - {}\n", p)).collect::<String>()
+        )
+    }
+
+    fn file(dir: &Path, name: &str, content: String) -> AssetInfo {
+        let path = dir.join(name);
+        fs::write(&path, content).unwrap();
+        AssetInfo {
+            path: scanner::path_to_string(&path),
+            name: name.to_string(),
+            extension: "mat".to_string(),
+            asset_type: AssetType::Material,
+            size: 0,
+            modified: 0,
+            metadata: None,
+            unity_guid: None,
+        }
+    }
+
+    #[test]
+    fn groups_materials_equal_up_to_name_and_order() {
+        let dir = tempdir().unwrap();
+        let dir = dir.path();
+        let assets = vec![
+            file(
+                dir,
+                "Rock.mat",
+                material("Rock", "_A _B", &["_Glossiness: 0.5", "_Metallic: 0"]),
+            ),
+            // Other name, keyword and property order: same material.
+            file(
+                dir,
+                "Rock 1.mat",
+                material("Rock 1", "_B _A", &["_Metallic: 0", "_Glossiness: 0.5"]),
+            ),
+            file(
+                dir,
+                "Shiny.mat",
+                material("Shiny", "_A _B", &["_Glossiness: 0.9", "_Metallic: 0"]),
+            ),
+        ];
+
+        let groups = find_duplicate_materials(&assets);
+        assert_eq!(
+            groups,
+            vec![DuplicateMaterialGroup {
+                paths: vec![assets[1].path.clone(), assets[0].path.clone()],
+            }]
+        );
+    }
+}
@@ -0,0 +1,156 @@
+//! Unity prefabs and scenes whose components point at deleted scripts.
+//!
+//! Deleting a `.cs` file (or losing its `.meta`, which mints a new GUID)
+//! leaves every MonoBehaviour using it with an `m_Script:` GUID nothing
+//! answers to. Unity shows "The referenced script is missing" only when
+//! the object is selected, and at runtime the component is simply gone —
+//! its behavior silently stripped. Each prefab's and scene's script GUIDs
+//! are resolved against every scanned asset (a script compiled into a
+//! `.dll` carries the DLL's GUID) and the `Library/PackageCache` index.
+
+use rayon::prelude::*;
+use std::collections::HashSet;
+use std::fs;
+
+use crate::analyzer::rules::RuleDescription;
+use crate::analyzer::{AnalysisResult, Issue, Severity};
+use crate::scanner::AssetInfo;
+use crate::unity::{self, PackageGuidIndex};
+
+/// Rule catalog entry for the missing-script check.
+pub fn describe() -> Vec<RuleDescription> {
+    vec![RuleDescription::new(
+        "unity.missing_script",
+        "Missing Script",
+        Severity::Error,
+        false,
+        "A Unity prefab or scene component references a script GUID nothing in the project or package cache defines.",
+    )]
+}
+
+/// One error per prefab / scene listing its unresolved script GUIDs,
+/// sorted by path. Files `is_excluded` accepts aren't checked.
+pub fn find_missing_scripts(
+    assets: &[AssetInfo],
+    packages: &PackageGuidIndex,
+    is_excluded: impl Fn(&str) -> bool + Sync,
+) -> AnalysisResult {
+    let known: HashSet<&str> = assets
+        .iter()
+        .filter_map(|a| a.unity_guid.as_deref())
+        .collect();
+    let mut broken: Vec<(&AssetInfo, Vec<String>)> = assets
+        .par_iter()
+        .filter(|a| {
+            (a.extension.eq_ignore_ascii_case("prefab")
+                || a.extension.eq_ignore_ascii_case("unity"))
+                && !is_excluded(&a.path)
+        })
+        .filter_map(|file| {
+            let content = fs::read_to_string(&file.path).ok()?;
+            let missing: Vec<String> = unity::script_guids(&content)
+                .into_iter()
+                .filter(|guid| {
+                    !unity::is_builtin_guid(guid)
+                        && !known.contains(guid.as_str())
+                        && packages.get(guid).is_none()
+                })
+                .collect();
+            (!missing.is_empty()).then_some((file, missing))
+        })
+        .collect();
+    broken.sort_by(|a, b| a.0.path.cmp(&b.0.path));
+
+    let mut result = AnalysisResult::new();
+    for (file, guids) in broken {
+        result.add_issue(Issue {
+            rule_id: "unity.missing_script".to_string(),
+            rule_name: "Missing Script".to_string(),
+            severity: Severity::Error,
+            message: format!(
+                "{} that no longer exist{} (GUID{} {})",
+                if guids.len() == 1 {
+                    "A component references a script"
+                } else {
+                    "Components reference scripts"
+                },
+                if guids.len() == 1 { "s" } else { "" },
+                if guids.len() == 1 { "" } else { "s" },
+                guids.join(", ")
+            ),
+            asset_path: file.path.clone(),
+            suggestion: Some(
+                "Restore the script with its original .meta, or remove the missing components in the editor"
+                    .to_string(),
+            ),
+            auto_fixable: false,
+            related_paths: None,
+        });
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::{self, AssetType};
+    use std::path::Path;
+    use tempfile::tempdir;
+
+    const PLAYER: &str = "aaaa0000aaaa0000aaaa0000aaaa0000";
+    const DELETED: &str = "dddd0000dddd0000dddd0000dddd0000";
+
+    fn asset(dir: &Path, name: &str, content: &str, guid: &str) -> AssetInfo {
+        let path = dir.join(name);
+        fs::write(&path, content).unwrap();
+        AssetInfo {
+            path: scanner::path_to_string(&path),
+            name: name.to_string(),
+            extension: name.rsplit('.').next().unwrap().to_string(),
+            asset_type: AssetType::Other,
+            size: 0,
+            modified: 0,
+            metadata: None,
+            unity_guid: Some(guid.to_string()),
+        }
+    }
+
+    #[test]
+    fn reports_components_whose_script_is_gone() {
+        let dir = tempdir().unwrap();
+        let dir = dir.path();
+        let components = |guids: &[&str]| -> String {
+            guids
+                .iter()
+                .map(|guid| {
+                    format!(
+                        "--- !u!114 &1\nMonoBehaviour:\n  m_Script: {{fileID: 11500000, guid: {}, type: 3}}\n",
+                        guid
+                    )
+                })
+                .collect()
+        };
+        let assets = vec![
+            asset(dir, "Player.cs", "class Player {}", PLAYER),
+            asset(
+                dir,
+                "Player.prefab",
+                &components(&[PLAYER, DELETED]),
+                "bbbb0000bbbb0000bbbb0000bbbb0000",
+            ),
+            asset(
+                dir,
+                "Main.unity",
+                &format!("{}  m_Script: {{fileID: 0}}\n", components(&[PLAYER])),
+                "cccc0000cccc0000cccc0000cccc0000",
+            ),
+        ];
+
+        let result = find_missing_scripts(&assets, &PackageGuidIndex::default(), |_| false);
+        assert_eq!(result.issues.len(), 1);
+        let issue = &result.issues[0];
+        assert!(issue.asset_path.ends_with("Player.prefab"));
+        assert_eq!(issue.severity, Severity::Error);
+        assert!(issue.message.ends_with(&format!("(GUID {})", DELETED)));
+    }
+}
@@ -0,0 +1,643 @@
+//! String-keyed asset loads in Unity C# scripts.
+//!
+//! `Resources.Load("Enemies/Slime")`, `AssetDatabase.LoadAssetAtPath(...)`,
+//! Addressables keys and AssetBundle `LoadAsset` names reference assets by
+//! path or key, not GUID, so the reference index never sees them and the
+//! unused-asset scan flagged everything loaded this way. We pull the first
+//! argument of each such call out of every `.cs` file and resolve the
+//! literal against the scan. Keys built at runtime (`"Enemies/" + id`,
+//! `$"Enemies/{id}"`, a variable) can't be resolved exactly: they're
+//! reported as dynamic, and their literal prefix, when there is one, still
+//! marks everything under it as used.
+
+use rayon::prelude::*;
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+use std::path::Path;
+
+use crate::scanner::{path_to_string, AssetInfo};
+
+/// How a call's first argument names an asset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum KeyKind {
+    /// Path below a `Resources/` folder, without extension.
+    Resource,
+    /// `Resources.LoadAll` folder below `Resources/` (`""` = all of them).
+    ResourceFolder,
+    /// Project-relative path with extension (`Assets/...`).
+    ProjectPath,
+    /// Addressables address (the asset path unless renamed in a group).
+    Address,
+    /// AssetBundle asset name: file name, stem or asset path.
+    BundleName,
+}
+
+/// Recognized calls. A match must be followed by `(` or generic arguments,
+/// so `Resources.Load` doesn't also fire on `Resources.LoadAll(`.
+const LOAD_CALLS: &[(&str, KeyKind)] = &[
+    ("Resources.Load", KeyKind::Resource),
+    ("Resources.LoadAsync", KeyKind::Resource),
+    ("Resources.LoadAll", KeyKind::ResourceFolder),
+    ("AssetDatabase.LoadAssetAtPath", KeyKind::ProjectPath),
+    ("AssetDatabase.LoadMainAssetAtPath", KeyKind::ProjectPath),
+    ("AssetDatabase.LoadAllAssetsAtPath", KeyKind::ProjectPath),
+    ("Addressables.LoadAssetAsync", KeyKind::Address),
+    ("Addressables.LoadAssetsAsync", KeyKind::Address),
+    ("Addressables.InstantiateAsync", KeyKind::Address),
+    ("Addressables.LoadSceneAsync", KeyKind::Address),
+    // Any receiver — an `AssetBundle` is usually a local or field.
+    (".LoadAsset", KeyKind::BundleName),
+    (".LoadAssetAsync", KeyKind::BundleName),
+];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ScriptLoad {
+    pub script_path: String,
+    /// 1-based line of the call.
+    pub line: usize,
+    /// The matched call, e.g. `Resources.Load` or `LoadAsset`.
+    pub call: String,
+    /// The literal key, or for a dynamic load its literal prefix if any.
+    pub key: Option<String>,
+    /// The key is computed at runtime, so `matches` is a best guess from
+    /// the prefix (empty without one).
+    pub dynamic: bool,
+    /// Scanned assets the key resolved to. Empty for a literal key means
+    /// nothing in the scan matches it.
+    pub matches: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ScriptReferences {
+    /// Every asset some load resolved to, sorted.
+    pub referenced_paths: Vec<String>,
+    /// Every recognized load, by script path then line.
+    pub loads: Vec<ScriptLoad>,
+}
+
+/// Scan every `.cs` file in `assets` for string-keyed loads and resolve
+/// them against `assets`.
+pub fn find_script_references(root_path: &str, assets: &[AssetInfo]) -> ScriptReferences {
+    let keys = AssetKeys::build(Path::new(root_path), assets);
+    let mut loads: Vec<ScriptLoad> = assets
+        .par_iter()
+        .filter(|asset| asset.extension.eq_ignore_ascii_case("cs"))
+        .flat_map_iter(|script| {
+            let source = fs::read_to_string(&script.path).unwrap_or_default();
+            let line_of = |offset: usize| source[..offset].matches('\n').count() + 1;
+            extract_loads(&source)
+                .into_iter()
+                .map(|raw| {
+                    let (key, dynamic) = match raw.argument {
+                        Argument::Literal(key) => (Some(key), false),
+                        Argument::Dynamic(prefix) => (prefix, true),
+                    };
+                    let matches = key
+                        .as_deref()
+                        .map(|key| keys.resolve(raw.kind, key, dynamic))
+                        .unwrap_or_default();
+                    ScriptLoad {
+                        script_path: script.path.clone(),
+                        line: line_of(raw.offset),
+                        call: raw.call.trim_start_matches('.').to_string(),
+                        key,
+                        dynamic,
+                        matches,
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    loads.sort_by(|a, b| (&a.script_path, a.line).cmp(&(&b.script_path, b.line)));
+
+    let referenced: BTreeSet<&str> = loads
+        .iter()
+        .flat_map(|load| load.matches.iter().map(String::as_str))
+        .collect();
+    ScriptReferences {
+        referenced_paths: referenced.into_iter().map(str::to_string).collect(),
+        loads,
+    }
+}
+
+/// Lowercased lookup keys for every scanned asset. Unity resolves
+/// `Resources` and AssetBundle names case-insensitively, and matching the
+/// rest the same way only errs on the side of "used".
+struct AssetKeys<'a> {
+    /// Path below the innermost `Resources/` folder, extension stripped.
+    resources: Vec<(String, &'a str)>,
+    /// Project-relative path (`assets/...`).
+    project: Vec<(String, &'a str)>,
+    /// Addresses renamed in an Addressables group.
+    addresses: Vec<(String, &'a str)>,
+    /// File name and stem, for AssetBundle names.
+    names: Vec<(String, &'a str)>,
+}
+
+impl<'a> AssetKeys<'a> {
+    fn build(root: &Path, assets: &'a [AssetInfo]) -> Self {
+        let mut keys = AssetKeys {
+            resources: Vec::new(),
+            project: Vec::new(),
+            addresses: Vec::new(),
+            names: Vec::new(),
+        };
+        let mut group_files = Vec::new();
+        for asset in assets {
+            let Ok(rel) = Path::new(&asset.path).strip_prefix(root) else {
+                continue;
+            };
+            let rel = path_to_string(rel);
+            if let Some(at) = rel.rfind("/Resources/") {
+                let key = strip_extension(&rel[at + "/Resources/".len()..]);
+                keys.resources
+                    .push((key.to_lowercase(), asset.path.as_str()));
+            }
+            if rel.contains("AddressableAssetsData/") && asset.extension == "asset" {
+                group_files.push(asset.path.as_str());
+            }
+            let name = asset.name.to_lowercase();
+            keys.names
+                .push((strip_extension(&name).to_string(), asset.path.as_str()));
+            keys.names.push((name, asset.path.as_str()));
+            keys.project.push((rel.to_lowercase(), asset.path.as_str()));
+        }
+
+        // Group entries name their asset by GUID; map through the scan.
+        if group_files.is_empty() {
+            return keys;
+        }
+        let by_guid: HashMap<&str, &str> = assets
+            .iter()
+            .filter_map(|a| Some((a.unity_guid.as_deref()?, a.path.as_str())))
+            .collect();
+        for group in group_files {
+            let Ok(content) = fs::read_to_string(group) else {
+                continue;
+            };
+            for (guid, address) in addressable_entries(&content) {
+                if let Some(&path) = by_guid.get(guid.as_str()) {
+                    keys.addresses.push((address.to_lowercase(), path));
+                }
+            }
+        }
+        keys
+    }
+
+    /// Assets `key` names for `kind`. A dynamic key is a prefix.
+    fn resolve(&self, kind: KeyKind, key: &str, mut prefix: bool) -> Vec<String> {
+        let mut key = key.replace('\\', "/").to_lowercase();
+        let tables = match kind {
+            KeyKind::Resource | KeyKind::ResourceFolder => vec![&self.resources],
+            KeyKind::ProjectPath => vec![&self.project],
+            KeyKind::Address => vec![&self.addresses, &self.project],
+            KeyKind::BundleName => vec![&self.names, &self.project],
+        };
+        if kind == KeyKind::ResourceFolder {
+            // Everything below the folder; `""` is every Resources folder.
+            let folder = key.trim_end_matches('/');
+            key = if folder.is_empty() {
+                String::new()
+            } else {
+                format!("{}/", folder)
+            };
+            prefix = true;
+        }
+        let matched: BTreeSet<&str> = tables
+            .iter()
+            .flat_map(|table| table.iter())
+            .filter(|(candidate, _)| {
+                if prefix {
+                    candidate.starts_with(&key)
+                } else {
+                    *candidate == key
+                }
+            })
+            .map(|(_, path)| *path)
+            .collect();
+        matched.into_iter().map(str::to_string).collect()
+    }
+}
+
+/// `(guid, address)` for each entry of an Addressables group asset.
+fn addressable_entries(content: &str) -> Vec<(String, String)> {
+    let mut entries = Vec::new();
+    let mut guid: Option<String> = None;
+    for line in content.lines() {
+        let line = line.trim();
+        if let Some(value) = line.strip_prefix("- m_GUID:") {
+            guid = Some(value.trim().to_string());
+        } else if let Some(value) = line.strip_prefix("m_Address:") {
+            if let Some(guid) = guid.take() {
+                entries.push((guid, crate::unity::unquote_yaml(value)));
+            }
+        }
+    }
+    entries
+}
+
+fn strip_extension(path: &str) -> &str {
+    let name_start = path.rfind('/').map_or(0, |slash| slash + 1);
+    match path[name_start..].rfind('.') {
+        Some(dot) if dot > 0 => &path[..name_start + dot],
+        _ => path,
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Argument {
+    Literal(String),
+    /// Built at runtime; the literal text it starts with, if any.
+    Dynamic(Option<String>),
+}
+
+struct RawLoad {
+    /// Byte offset of the call name.
+    offset: usize,
+    call: &'static str,
+    kind: KeyKind,
+    argument: Argument,
+}
+
+fn extract_loads(source: &str) -> Vec<RawLoad> {
+    let code = blank_comments(source);
+    let mut loads = Vec::new();
+    for &(call, kind) in LOAD_CALLS {
+        for (at, _) in code.match_indices(call) {
+            let before = &code[..at];
+            if call.starts_with('.') {
+                // `Addressables.LoadAssetAsync` has its own entry.
+                if before.trim_end().ends_with("Addressables") {
+                    continue;
+                }
+            } else if before
+                .chars()
+                .next_back()
+                .is_some_and(|c| c.is_alphanumeric() || c == '_')
+            {
+                // `MyResources.Load` is someone else's API.
+                continue;
+            }
+            let Some(open) = call_paren(&code, at + call.len()) else {
+                continue;
+            };
+            loads.push(RawLoad {
+                offset: at,
+                call,
+                kind,
+                argument: first_argument(&code[open + 1..]),
+            });
+        }
+    }
+    loads.sort_by_key(|load| load.offset);
+    loads
+}
+
+/// Offset of the `(` that opens the argument list when the text from
+/// `from` is optional whitespace, optional generic arguments, then `(`.
+fn call_paren(code: &str, from: usize) -> Option<usize> {
+    let bytes = code.as_bytes();
+    let mut i = skip_whitespace(bytes, from);
+    if bytes.get(i) == Some(&b'<') {
+        let mut depth = 0;
+        while i < bytes.len() {
+            match bytes[i] {
+                b'<' => depth += 1,
+                b'>' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        break;
+                    }
+                }
+                b'(' | b';' | b'{' => return None,
+                _ => {}
+            }
+            i += 1;
+        }
+        i = skip_whitespace(bytes, i + 1);
+    }
+    (bytes.get(i) == Some(&b'(')).then_some(i)
+}
+
+fn skip_whitespace(bytes: &[u8], mut i: usize) -> usize {
+    while bytes.get(i).is_some_and(|b| b.is_ascii_whitespace()) {
+        i += 1;
+    }
+    i
+}
+
+/// The first argument of the call whose argument list starts `rest`.
+fn first_argument(rest: &str) -> Argument {
+    let bytes = rest.as_bytes();
+    let mut depth = 0usize;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'"' => {
+                i = skip_string(bytes, i);
+                continue;
+            }
+            b'\'' => {
+                i = skip_char(bytes, i);
+                continue;
+            }
+            b'(' | b'[' | b'{' => depth += 1,
+            b')' | b']' | b'}' if depth == 0 => break,
+            b')' | b']' | b'}' => depth -= 1,
+            b',' | b';' if depth == 0 => break,
+            _ => {}
+        }
+        i += 1;
+    }
+    classify_argument(rest[..i].trim())
+}
+
+/// A chain of string literals joined by `+` is a literal; anything else is
+/// dynamic, keeping the literal text up to the first computed part.
+fn classify_argument(expr: &str) -> Argument {
+    let mut value = String::new();
+    let mut rest = expr;
+    loop {
+        let Some((part, complete, after)) = leading_literal(rest) else {
+            break;
+        };
+        value.push_str(&part);
+        let after = after.trim_start();
+        if !complete {
+            break;
+        }
+        if after.is_empty() {
+            return Argument::Literal(value);
+        }
+        match after.strip_prefix('+') {
+            Some(next) => rest = next.trim_start(),
+            None => break,
+        }
+    }
+    Argument::Dynamic(Some(value).filter(|v| !v.is_empty()))
+}
+
+/// The string literal at the start of `expr`: its value, whether that is
+/// the whole literal (false for an interpolated string with holes — the
+/// value is then the text before the first hole), and the text after it.
+fn leading_literal(expr: &str) -> Option<(String, bool, &str)> {
+    let (quote, verbatim, interpolated) = if expr.starts_with('"') {
+        (0, false, false)
+    } else if expr.starts_with("@\"") {
+        (1, true, false)
+    } else if expr.starts_with("$\"") {
+        (1, false, true)
+    } else if expr.starts_with("$@\"") || expr.starts_with("@$\"") {
+        (2, true, true)
+    } else {
+        return None;
+    };
+    let end = skip_string(expr.as_bytes(), quote);
+    if end < quote + 2 || expr.as_bytes()[end - 1] != b'"' {
+        return None; // unterminated
+    }
+
+    let mut value = String::new();
+    let mut complete = true;
+    let mut chars = expr[quote + 1..end - 1].chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if !verbatim => match chars.next() {
+                Some('n') => value.push('\n'),
+                Some('t') => value.push('\t'),
+                Some(escaped) => value.push(escaped),
+                None => {}
+            },
+            // `""` inside a verbatim string.
+            '"' if verbatim => {
+                chars.next();
+                value.push('"');
+            }
+            '{' | '}' if interpolated => {
+                if chars.peek() == Some(&c) {
+                    chars.next();
+                    value.push(c);
+                } else {
+                    complete = false;
+                    break;
+                }
+            }
+            _ => value.push(c),
+        }
+    }
+    Some((value, complete, &expr[end..]))
+}
+
+/// `source` with comments blanked to spaces. Newlines are kept so offsets
+/// still land on the same line, and string / char literals are skipped so
+/// a `//` inside a path isn't taken for a comment.
+fn blank_comments(source: &str) -> String {
+    let bytes = source.as_bytes();
+    let mut out = bytes.to_vec();
+    let mut i = 0;
+    while i < bytes.len() {
+        let comment_end = match (bytes[i], bytes.get(i + 1)) {
+            (b'/', Some(b'/')) => source[i..].find('\n').map_or(bytes.len(), |n| i + n),
+            (b'/', Some(b'*')) => source[i + 2..]
+                .find("*/")
+                .map_or(bytes.len(), |n| i + 2 + n + 2),
+            (b'"', _) => {
+                i = skip_string(bytes, i);
+                continue;
+            }
+            (b'\'', _) => {
+                i = skip_char(bytes, i);
+                continue;
+            }
+            _ => {
+                i += 1;
+                continue;
+            }
+        };
+        for b in &mut out[i..comment_end] {
+            if *b != b'\n' {
+                *b = b' ';
+            }
+        }
+        i = comment_end;
+    }
+    // Only whole comments were blanked, so multi-byte characters are either
+    // intact or fully replaced.
+    String::from_utf8(out).unwrap_or_else(|_| source.to_string())
+}
+
+/// Index just past the string literal whose opening quote is at `start`.
+/// An unterminated regular string ends at the line break.
+fn skip_string(bytes: &[u8], start: usize) -> usize {
+    let before = &bytes[..start];
+    let verbatim = before.ends_with(b"@") || before.ends_with(b"@$");
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' if !verbatim => i += 2,
+            b'"' if verbatim && bytes.get(i + 1) == Some(&b'"') => i += 2,
+            b'"' => return i + 1,
+            b'\n' if !verbatim => return i,
+            _ => i += 1,
+        }
+    }
+    bytes.len()
+}
+
+/// Index just past the char literal whose opening quote is at `start`.
+fn skip_char(bytes: &[u8], start: usize) -> usize {
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            b'\'' => return i + 1,
+            b'\n' => return i,
+            _ => i += 1,
+        }
+    }
+    bytes.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::AssetType;
+    use tempfile::tempdir;
+
+    fn arguments(source: &str) -> Vec<(&'static str, Argument)> {
+        extract_loads(source)
+            .into_iter()
+            .map(|load| (load.call, load.argument))
+            .collect()
+    }
+
+    fn literal(value: &str) -> Argument {
+        Argument::Literal(value.to_string())
+    }
+
+    #[test]
+    fn extracts_literal_and_dynamic_keys() {
+        let source = r#"
+            var slime = Resources.Load<GameObject>("Enemies/Slime");
+            var all = UnityEngine.Resources.LoadAll<Sprite>(@"UI\Icons");
+            // Resources.Load("Commented/Out");
+            /* Resources.Load("Also/Out"); */
+            var joined = Resources.Load("Enemies/" + "Bat");
+            var byId = Resources.Load("Enemies/" + id, typeof(GameObject));
+            var interp = Resources.Load($"Levels/{index}/Boss");
+            var opaque = Resources.Load(path);
+            var mat = AssetDatabase.LoadAssetAtPath<Material>("Assets/Mats/Rock.mat");
+            var handle = Addressables.LoadAssetAsync<GameObject>("hero");
+            var icon = bundle.LoadAsset<Texture2D>("icon");
+            var notOurs = MyResources.Load("Nope");
+            Debug.Log("// not a comment"); Resources.Load("After/String");
+        "#;
+        assert_eq!(
+            arguments(source),
+            vec![
+                ("Resources.Load", literal("Enemies/Slime")),
+                ("Resources.LoadAll", literal("UI\\Icons")),
+                ("Resources.Load", literal("Enemies/Bat")),
+                (
+                    "Resources.Load",
+                    Argument::Dynamic(Some("Enemies/".to_string()))
+                ),
+                (
+                    "Resources.Load",
+                    Argument::Dynamic(Some("Levels/".to_string()))
+                ),
+                ("Resources.Load", Argument::Dynamic(None)),
+                (
+                    "AssetDatabase.LoadAssetAtPath",
+                    literal("Assets/Mats/Rock.mat")
+                ),
+                ("Addressables.LoadAssetAsync", literal("hero")),
+                (".LoadAsset", literal("icon")),
+                ("Resources.Load", literal("After/String")),
+            ]
+        );
+    }
+
+    fn asset_at(root: &Path, rel: &str, guid: Option<&str>) -> AssetInfo {
+        let path = root.join(rel);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        if !path.exists() {
+            fs::write(&path, "").unwrap();
+        }
+        let name = path.file_name().unwrap().to_string_lossy().to_string();
+        AssetInfo {
+            path: path_to_string(&path),
+            extension: name.rsplit('.').next().unwrap().to_string(),
+            name,
+            asset_type: AssetType::Other,
+            size: 0,
+            modified: 0,
+            metadata: None,
+            unity_guid: guid.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn resolves_keys_against_the_scan() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("Assets/Scripts")).unwrap();
+        fs::write(
+            root.join("Assets/Scripts/Spawner.cs"),
+            r#"
+            Resources.Load("enemies/slime");
+            Resources.LoadAll("Music");
+            Resources.Load("Bosses/" + id);
+            AssetDatabase.LoadAssetAtPath<Material>("Assets/Mats/Rock.mat");
+            Addressables.LoadAssetAsync<GameObject>("hero");
+            Resources.Load("Missing/Thing");
+            "#,
+        )
+        .unwrap();
+        fs::create_dir_all(root.join("Assets/AddressableAssetsData/AssetGroups")).unwrap();
+        fs::write(
+            root.join("Assets/AddressableAssetsData/AssetGroups/Default.asset"),
+            "  m_SerializeEntries:\n  - m_GUID: 1234\n    m_Address: hero\n    m_ReadOnly: 0\n",
+        )
+        .unwrap();
+        let assets = vec![
+            asset_at(root, "Assets/Scripts/Spawner.cs", None),
+            asset_at(
+                root,
+                "Assets/AddressableAssetsData/AssetGroups/Default.asset",
+                None,
+            ),
+            asset_at(root, "Assets/Resources/Enemies/Slime.prefab", None),
+            asset_at(root, "Assets/Game/Resources/Music/Theme.ogg", None),
+            asset_at(root, "Assets/Resources/Bosses/Dragon.prefab", None),
+            asset_at(root, "Assets/Mats/Rock.mat", None),
+            asset_at(root, "Assets/Prefabs/Hero.prefab", Some("1234")),
+            asset_at(root, "Assets/Unused.png", None),
+        ];
+
+        let refs = find_script_references(&path_to_string(root), &assets);
+        let rel: Vec<String> = refs
+            .referenced_paths
+            .iter()
+            .map(|p| p.strip_prefix(&path_to_string(root)).unwrap().to_string())
+            .collect();
+        assert_eq!(
+            rel,
+            vec![
+                "/Assets/Game/Resources/Music/Theme.ogg",
+                "/Assets/Mats/Rock.mat",
+                "/Assets/Prefabs/Hero.prefab",
+                "/Assets/Resources/Bosses/Dragon.prefab",
+                "/Assets/Resources/Enemies/Slime.prefab",
+            ]
+        );
+        assert_eq!(refs.loads.len(), 6);
+        let boss = &refs.loads[2];
+        assert!(boss.dynamic);
+        assert_eq!(boss.key.as_deref(), Some("Bosses/"));
+        assert_eq!(boss.line, 4);
+        let missing = refs.loads.last().unwrap();
+        assert!(!missing.dynamic && missing.matches.is_empty());
+    }
+}
@@ -0,0 +1,235 @@
+//! Which shaders a Unity project's materials actually use.
+//!
+//! Every `.mat` names its shader in one `m_Shader` PPtr. Tallying those
+//! shows shader proliferation — each distinct shader multiplies the variants
+//! a build compiles — and which built-in or package shaders dominate. The
+//! GUID resolves against the scan first, then the `Library/PackageCache`
+//! index; the two built-in bundle GUIDs are told apart by `fileID`. A
+//! material whose shader resolves nowhere (or has none) renders magenta in
+//! the editor and is listed separately.
+
+use rayon::prelude::*;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs;
+
+use crate::scanner::AssetInfo;
+use crate::unity::{self, PackageGuidIndex};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ShaderSource {
+    /// A scanned shader or shader graph in the project.
+    Project,
+    /// Shipped by a UPM package (resolved via `Library/PackageCache`).
+    Package,
+    /// One of the editor's built-in shaders.
+    Builtin,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ShaderUsageEntry {
+    /// Shader file name, or the built-in shader's name when known.
+    pub name: String,
+    pub source: ShaderSource,
+    /// Set for project shaders.
+    pub path: Option<String>,
+    /// Package id, for package shaders.
+    pub package: Option<String>,
+    pub guid: String,
+    /// Only meaningful for built-ins, which all share two GUIDs.
+    pub file_id: i64,
+    pub material_count: usize,
+    /// Paths of the materials using it, sorted.
+    pub materials: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MissingShader {
+    pub material_path: String,
+    /// The dangling GUID; `None` when no shader is assigned at all.
+    pub guid: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ShaderUsage {
+    /// Most-used first.
+    pub shaders: Vec<ShaderUsageEntry>,
+    /// Materials whose shader resolves nowhere, sorted by path.
+    pub missing: Vec<MissingShader>,
+}
+
+/// Names of common built-in shaders by `fileID`. Anything else is labelled
+/// with its id.
+fn builtin_shader_name(file_id: i64) -> Option<&'static str> {
+    match file_id {
+        45 => Some("Standard (Specular setup)"),
+        46 => Some("Standard"),
+        10753 => Some("Sprites/Default"),
+        10770 => Some("UI/Default"),
+        _ => None,
+    }
+}
+
+pub fn shader_usage(assets: &[AssetInfo], packages: &PackageGuidIndex) -> ShaderUsage {
+    let by_guid: HashMap<&str, &AssetInfo> = assets
+        .iter()
+        .filter_map(|a| Some((a.unity_guid.as_deref()?, a)))
+        .collect();
+    let shaders_of: Vec<(&str, unity::ShaderPtr)> = assets
+        .par_iter()
+        .filter(|a| a.extension.eq_ignore_ascii_case("mat"))
+        .filter_map(|a| {
+            let content = fs::read_to_string(&a.path).ok()?;
+            Some((a.path.as_str(), unity::material_shader(&content)?))
+        })
+        .collect();
+
+    let mut entries: HashMap<(String, i64), ShaderUsageEntry> = HashMap::new();
+    let mut missing = Vec::new();
+    for (material, shader) in shaders_of {
+        let Some(guid) = shader.guid else {
+            missing.push(MissingShader {
+                material_path: material.to_string(),
+                guid: None,
+            });
+            continue;
+        };
+        let (name, source, path, package) = if unity::is_builtin_guid(&guid) {
+            let name = builtin_shader_name(shader.file_id)
+                .map(str::to_string)
+                .unwrap_or_else(|| format!("Built-in shader (fileID {})", shader.file_id));
+            (name, ShaderSource::Builtin, None, None)
+        } else if let Some(asset) = by_guid.get(guid.as_str()) {
+            let path = Some(asset.path.clone());
+            (asset.name.clone(), ShaderSource::Project, path, None)
+        } else if let Some(pkg) = packages.get(&guid) {
+            let package = Some(pkg.package.clone());
+            (pkg.file_name.clone(), ShaderSource::Package, None, package)
+        } else {
+            missing.push(MissingShader {
+                material_path: material.to_string(),
+                guid: Some(guid),
+            });
+            continue;
+        };
+        entries
+            .entry((guid.clone(), shader.file_id))
+            .or_insert_with(|| ShaderUsageEntry {
+                name,
+                source,
+                path,
+                package,
+                guid,
+                file_id: shader.file_id,
+                material_count: 0,
+                materials: Vec::new(),
+            })
+            .materials
+            .push(material.to_string());
+    }
+
+    let mut shaders: Vec<ShaderUsageEntry> = entries
+        .into_values()
+        .map(|mut entry| {
+            entry.materials.sort();
+            entry.material_count = entry.materials.len();
+            entry
+        })
+        .collect();
+    shaders.sort_by(|a, b| {
+        b.material_count
+            .cmp(&a.material_count)
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    missing.sort_by(|a, b| a.material_path.cmp(&b.material_path));
+    ShaderUsage { shaders, missing }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::AssetType;
+    use std::path::Path;
+    use tempfile::tempdir;
+
+    const SHADER_GUID: &str = "aaaabbbbccccddddeeeeffff00001111";
+
+    fn material(dir: &Path, name: &str, shader: &str) -> AssetInfo {
+        let path = dir.join(name);
+        fs::write(
+            &path,
+            format!(
+                "%YAML 1.1\n--- !u!21 &2100000\nMaterial:\n  m_Name: {}\n  m_Shader: {}\n",
+                name, shader
+            ),
+        )
+        .unwrap();
+        asset(&path, AssetType::Material, None)
+    }
+
+    fn asset(path: &Path, asset_type: AssetType, guid: Option<&str>) -> AssetInfo {
+        let name = path.file_name().unwrap().to_string_lossy().to_string();
+        AssetInfo {
+            path: crate::scanner::path_to_string(path),
+            extension: name.rsplit('.').next().unwrap().to_string(),
+            name,
+            asset_type,
+            size: 0,
+            modified: 0,
+            metadata: None,
+            unity_guid: guid.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn tallies_shaders_and_lists_missing_ones() {
+        let dir = tempdir().unwrap();
+        let standard = "{fileID: 46, guid: 0000000000000000f000000000000000, type: 0}";
+        let custom = format!("{{fileID: 4800000, guid: {}, type: 3}}", SHADER_GUID);
+        let assets = vec![
+            material(dir.path(), "Rock.mat", standard),
+            material(dir.path(), "Wood.mat", standard),
+            material(dir.path(), "Water.mat", &custom),
+            material(
+                dir.path(),
+                "Gone.mat",
+                "{fileID: 4800000, guid: 99998888777766665555444433332222, type: 3}",
+            ),
+            material(dir.path(), "Empty.mat", "{fileID: 0}"),
+            asset(
+                &dir.path().join("Water.shader"),
+                AssetType::Other,
+                Some(SHADER_GUID),
+            ),
+        ];
+
+        let usage = shader_usage(&assets, &PackageGuidIndex::default());
+        assert_eq!(usage.shaders.len(), 2);
+        assert_eq!(usage.shaders[0].name, "Standard");
+        assert_eq!(usage.shaders[0].source, ShaderSource::Builtin);
+        assert_eq!(usage.shaders[0].material_count, 2);
+        assert_eq!(usage.shaders[1].name, "Water.shader");
+        assert_eq!(usage.shaders[1].source, ShaderSource::Project);
+        assert_eq!(
+            usage.shaders[1].path.as_deref(),
+            Some(assets[5].path.as_str())
+        );
+
+        let missing: Vec<(&str, Option<&str>)> = usage
+            .missing
+            .iter()
+            .map(|m| {
+                let name = m.material_path.rsplit('/').next().unwrap();
+                (name, m.guid.as_deref())
+            })
+            .collect();
+        assert_eq!(
+            missing,
+            vec![
+                ("Empty.mat", None),
+                ("Gone.mat", Some("99998888777766665555444433332222"))
+            ]
+        );
+    }
+}
@@ -0,0 +1,238 @@
+//! C# scripts nothing in a Unity project appears to use.
+//!
+//! A MonoBehaviour or ScriptableObject is used when some scene, prefab or
+//! `.asset` carries its GUID in an `m_Script:` field; the GUID turning up
+//! in any other field doesn't attach it. Plain classes, interfaces and
+//! base types never appear there; they're used from other scripts, so a
+//! script whose class name (the file stem, which Unity requires to match)
+//! appears as an identifier in any other `.cs` file counts as used too.
+//! That check is textual — a mention in a comment or an unrelated member
+//! of the same name keeps a script — so it only errs toward "used", and
+//! what's left is reported as likely dead at info severity, not as
+//! certain.
+//!
+//! Editor code is skipped: anything under an `Editor` folder, and scripts
+//! Unity calls by attribute (`[CustomEditor]`, `[MenuItem]`,
+//! `[InitializeOnLoad]`, ...) rather than by reference.
+
+use rayon::prelude::*;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+use crate::analyzer::rules::RuleDescription;
+use crate::analyzer::{AnalysisResult, Issue, Severity};
+use crate::scanner::AssetInfo;
+use crate::unity;
+use crate::unity_index::is_reference_source;
+
+/// Identifiers marking a script Unity invokes itself.
+const UNITY_INVOKED: &[&str] = &[
+    "CustomEditor",
+    "CustomPropertyDrawer",
+    "EditorWindow",
+    "InitializeOnLoad",
+    "InitializeOnLoadMethod",
+    "MenuItem",
+    "RuntimeInitializeOnLoadMethod",
+    "ScriptedImporter",
+];
+
+fn is_editor_path(path: &str) -> bool {
+    Path::new(path)
+        .parent()
+        .into_iter()
+        .flat_map(|p| p.components())
+        .any(|c| c.as_os_str() == "Editor")
+}
+
+/// Every distinct C# identifier in `source`.
+fn identifiers(source: &str) -> HashSet<&str> {
+    source
+        .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .filter(|word| word.starts_with(|c: char| c.is_alphabetic() || c == '_'))
+        .collect()
+}
+
+/// Rule catalog entry for the unused-script check.
+pub fn describe() -> Vec<RuleDescription> {
+    vec![RuleDescription::new(
+        "unity.unused_script",
+        "Unused Script",
+        Severity::Info,
+        false,
+        "A C# script no scene, prefab or asset references and no other script mentions by name.",
+    )]
+}
+
+/// Every script GUID some Unity text file names in `m_Script:`. Vendored
+/// files count: a package prefab can attach a project script.
+fn attached_scripts(assets: &[AssetInfo]) -> HashSet<String> {
+    assets
+        .par_iter()
+        .filter(|a| is_reference_source(a))
+        .filter_map(|a| fs::read_to_string(&a.path).ok())
+        .flat_map_iter(|content| unity::script_guids(&content))
+        .collect()
+}
+
+pub fn find_unused_scripts(
+    assets: &[AssetInfo],
+    is_excluded: impl Fn(&str) -> bool,
+) -> AnalysisResult {
+    let scripts: Vec<&AssetInfo> = assets
+        .iter()
+        .filter(|a| a.extension.eq_ignore_ascii_case("cs"))
+        .collect();
+    let sources: Vec<String> = scripts
+        .par_iter()
+        .map(|script| fs::read_to_string(&script.path).unwrap_or_default())
+        .collect();
+    let idents: Vec<HashSet<&str>> = sources.iter().map(|s| identifiers(s)).collect();
+
+    // Identifier → number of scripts mentioning it.
+    let mut mentions: HashMap<&str, usize> = HashMap::new();
+    for words in &idents {
+        for word in words {
+            *mentions.entry(*word).or_default() += 1;
+        }
+    }
+    let attached = attached_scripts(assets);
+
+    let mut result = AnalysisResult::new();
+    for (script, words) in scripts.iter().zip(&idents) {
+        let Some(guid) = script.unity_guid.as_deref() else {
+            continue;
+        };
+        if attached.contains(guid)
+            || is_editor_path(&script.path)
+            || is_excluded(&script.path)
+            || UNITY_INVOKED.iter().any(|w| words.contains(w))
+        {
+            continue;
+        }
+        let class = Path::new(&script.name)
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or_default();
+        // Its own file names the class; anything beyond that is another script.
+        let own = usize::from(words.contains(class));
+        if mentions.get(class).copied().unwrap_or(0) > own {
+            continue;
+        }
+
+        result.add_issue(Issue {
+            rule_id: "unity.unused_script".to_string(),
+            rule_name: "Unused Script".to_string(),
+            severity: Severity::Info,
+            message: format!(
+                "Script '{}' ({:.1} KB) is not attached in any scene, prefab or asset, and no other script mentions it",
+                class,
+                script.size as f64 / 1024.0
+            ),
+            asset_path: script.path.clone(),
+            suggestion: Some(
+                "Check it isn't added at runtime or by reflection, then remove it".to_string(),
+            ),
+            auto_fixable: false,
+            related_paths: None,
+        });
+    }
+    result
+        .issues
+        .sort_by(|a, b| a.asset_path.cmp(&b.asset_path));
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::{self, AssetType};
+    use tempfile::tempdir;
+
+    fn guid(n: u8) -> String {
+        format!("{:032x}", n)
+    }
+
+    fn file(dir: &Path, rel: &str, n: u8, content: &str) -> AssetInfo {
+        let path = dir.join(rel);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, content).unwrap();
+        let name = rel.rsplit('/').next().unwrap();
+        AssetInfo {
+            path: scanner::path_to_string(&path),
+            name: name.to_string(),
+            extension: name.rsplit('.').next().unwrap().to_string(),
+            asset_type: if name.ends_with(".cs") {
+                AssetType::Script
+            } else {
+                AssetType::Other
+            },
+            size: content.len() as u64,
+            modified: 1,
+            metadata: None,
+            unity_guid: Some(guid(n)),
+        }
+    }
+
+    #[test]
+    fn flags_scripts_neither_attached_nor_mentioned() {
+        let dir = tempdir().unwrap();
+        let dir = dir.path();
+        let assets = vec![
+            file(
+                dir,
+                "Assets/Main.unity",
+                10,
+                &format!(
+                    "  m_Script: {{fileID: 11500000, guid: {}, type: 3}}\n",
+                    guid(1)
+                ),
+            ),
+            // Attached in the scene.
+            file(
+                dir,
+                "Assets/Player.cs",
+                1,
+                "class Player : MonoBehaviour { Health hp; }",
+            ),
+            // Only used from Player.
+            file(dir, "Assets/Health.cs", 2, "class Health {}"),
+            file(dir, "Assets/Old.cs", 3, "class Old : MonoBehaviour {}"),
+            file(
+                dir,
+                "Assets/Tools/Editor/Inspector.cs",
+                4,
+                "class Inspector {}",
+            ),
+            file(
+                dir,
+                "Assets/Tools/Menu.cs",
+                5,
+                "class Menu { [MenuItem(\"Tools/Go\")] static void Go() {} }",
+            ),
+            file(dir, "Assets/Vendor/Lib.cs", 6, "class Lib {}"),
+            // Its GUID is in a field, but no component runs it.
+            file(
+                dir,
+                "Assets/Config.asset",
+                11,
+                &format!(
+                    "  m_Source: {{fileID: 11500000, guid: {}, type: 3}}\n",
+                    guid(7)
+                ),
+            ),
+            file(dir, "Assets/Stale.cs", 7, "class Stale : MonoBehaviour {}"),
+        ];
+
+        let result = find_unused_scripts(&assets, |path| path.contains("/Vendor/"));
+        let flagged: Vec<&str> = result
+            .issues
+            .iter()
+            .map(|i| i.asset_path.rsplit('/').next().unwrap())
+            .collect();
+        assert_eq!(flagged, ["Old.cs", "Stale.cs"]);
+        assert_eq!(result.issues[0].severity, Severity::Info);
+        assert_eq!(result.issues[0].rule_id, "unity.unused_script");
+    }
+}
@@ -0,0 +1,112 @@
+//! Unused assets summarised for cleanup.
+//!
+//! `find_unused_assets` answers "which files", but deciding whether a
+//! cleanup is worth doing needs "how much, and of what": a few hundred
+//! stray `.mat` files matter far less than a dozen forgotten 4K textures.
+//! The report groups the unused set by asset type with a count and total
+//! size per group, and the grand total a cleanup would reclaim.
+
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+
+use crate::scanner::{AssetInfo, AssetType};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UnusedAssetReport {
+    /// Largest group first.
+    pub groups: Vec<UnusedTypeGroup>,
+    pub total_count: usize,
+    /// Bytes a cleanup would reclaim.
+    pub total_size: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UnusedTypeGroup {
+    pub asset_type: AssetType,
+    pub count: usize,
+    pub total_size: u64,
+    /// Largest first, then by path.
+    pub paths: Vec<String>,
+}
+
+/// Group `unused` (paths as returned by `find_unused_assets`) by type.
+/// Paths no longer in the scan are skipped — there's nothing to reclaim.
+pub fn build(assets: &[AssetInfo], unused: &[String]) -> UnusedAssetReport {
+    let unused: HashSet<&str> = unused.iter().map(String::as_str).collect();
+    let mut by_type: HashMap<&AssetType, Vec<&AssetInfo>> = HashMap::new();
+    for asset in assets.iter().filter(|a| unused.contains(a.path.as_str())) {
+        by_type.entry(&asset.asset_type).or_default().push(asset);
+    }
+
+    let mut groups: Vec<UnusedTypeGroup> = by_type
+        .into_iter()
+        .map(|(asset_type, mut members)| {
+            members.sort_by(|a, b| b.size.cmp(&a.size).then_with(|| a.path.cmp(&b.path)));
+            UnusedTypeGroup {
+                asset_type: asset_type.clone(),
+                count: members.len(),
+                total_size: members.iter().map(|a| a.size).sum(),
+                paths: members.into_iter().map(|a| a.path.clone()).collect(),
+            }
+        })
+        .collect();
+    groups.sort_by(|a, b| {
+        b.total_size
+            .cmp(&a.total_size)
+            .then_with(|| b.count.cmp(&a.count))
+    });
+
+    UnusedAssetReport {
+        total_count: groups.iter().map(|g| g.count).sum(),
+        total_size: groups.iter().map(|g| g.total_size).sum(),
+        groups,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn asset(path: &str, asset_type: AssetType, size: u64) -> AssetInfo {
+        AssetInfo {
+            path: path.to_string(),
+            name: path.rsplit('/').next().unwrap().to_string(),
+            extension: path.rsplit('.').next().unwrap().to_string(),
+            asset_type,
+            size,
+            modified: 0,
+            metadata: None,
+            unity_guid: None,
+        }
+    }
+
+    #[test]
+    fn groups_by_type_largest_first() {
+        let assets = vec![
+            asset("/p/a.png", AssetType::Texture, 4000),
+            asset("/p/b.png", AssetType::Texture, 6000),
+            asset("/p/c.mat", AssetType::Material, 100),
+            asset("/p/d.mat", AssetType::Material, 200),
+            asset("/p/used.png", AssetType::Texture, 9000),
+        ];
+        let unused: Vec<String> = [
+            "/p/a.png",
+            "/p/b.png",
+            "/p/c.mat",
+            "/p/d.mat",
+            "/p/gone.wav",
+        ]
+        .iter()
+        .map(|p| p.to_string())
+        .collect();
+
+        let report = build(&assets, &unused);
+        assert_eq!((report.total_count, report.total_size), (4, 10_300));
+        assert_eq!(report.groups.len(), 2);
+        let textures = &report.groups[0];
+        assert_eq!(textures.asset_type, AssetType::Texture);
+        assert_eq!((textures.count, textures.total_size), (2, 10_000));
+        assert_eq!(textures.paths, vec!["/p/b.png", "/p/a.png"]);
+        assert_eq!(report.groups[1].asset_type, AssetType::Material);
+    }
+}
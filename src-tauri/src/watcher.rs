@@ -8,16 +8,24 @@
 //! the project is unregistered, the state (and thus the debouncer) drops, which
 //! tears down the OS watch and closes the event channel; the processing thread
 //! exits naturally on channel close.
+//!
+//! Optionally the watcher also follows the project's `tidycraft.toml`
+//! (`ConfigWatchMode`): a change is debounced a second time — editors save
+//! in bursts while rules are being tuned — then validated and announced as
+//! `config-changed-{project_id}`, or `config-error-{project_id}` with the
+//! message when it no longer parses. In `Reanalyze` mode a valid config is
+//! also run against the cached scan and the result emitted as
+//! `analysis-updated-{project_id}`.
 
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
-use std::sync::mpsc;
+use std::sync::mpsc::{self, RecvTimeoutError};
 use std::thread;
 use std::time::Duration;
 
 use notify::{RecursiveMode, Watcher as _};
 use notify_debouncer_full::{new_debouncer, DebounceEventResult, Debouncer, FileIdMap};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use tauri::{AppHandle, Emitter};
 
 use crate::project;
@@ -25,6 +33,28 @@ use crate::scanner::{self, AssetInfo, AssetType, DirectoryNode};
 
 const DEBOUNCE_WINDOW: Duration = Duration::from_millis(500);
 
+/// The analysis config, at the project root (see `lib.rs::load_rule_config`).
+const CONFIG_FILE: &str = "tidycraft.toml";
+
+/// How long `tidycraft.toml` must stay untouched, on top of
+/// `DEBOUNCE_WINDOW`, before a change is acted on. Saves further apart than
+/// the filesystem debounce still collapse into one reload.
+const CONFIG_QUIET: Duration = Duration::from_millis(750);
+
+/// What the watcher does when `tidycraft.toml` changes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConfigWatchMode {
+    /// Don't follow the config file.
+    #[default]
+    Off,
+    /// Validate and emit `config-changed` / `config-error`.
+    Notify,
+    /// As `Notify`, then re-run analysis on the cached scan and emit
+    /// `analysis-updated`.
+    Reanalyze,
+}
+
 /// Payload for the per-project `fs-change-{project_id}` event.
 #[derive(Debug, Clone, Serialize)]
 pub struct FsChangeEvent {
@@ -51,6 +81,8 @@ pub fn start(
     project_id: String,
     root_path: String,
     respect_gitignore: bool,
+    include_hidden: bool,
+    config_watch: ConfigWatchMode,
 ) -> Result<ProjectWatcher, String> {
     let root_buf = PathBuf::from(&root_path);
     if !root_buf.exists() {
@@ -63,6 +95,10 @@ pub fn start(
     // get re-added to the cached scan on every modification. `None` when the
     // project scanned with gitignore off.
     let ignore_matcher = scanner::build_gitignore_matcher(&root_buf, respect_gitignore);
+    // Likewise the scan's `[scan].extension_overrides`, so a modified file
+    // keeps the type the scan gave it, and its `include_hidden`, so dot files
+    // come and go exactly when the scan would have walked them.
+    let overrides = scanner::load_extension_overrides(&root_buf);
 
     let (tx, rx) = mpsc::channel::<DebounceEventResult>();
 
@@ -80,8 +116,16 @@ pub fn start(
         .watch(&root_buf, RecursiveMode::Recursive)
         .map_err(|e| format!("Failed to watch path: {}", e))?;
 
+    let config_tx = (config_watch != ConfigWatchMode::Off).then(|| {
+        let (config_tx, config_rx) = mpsc::channel::<()>();
+        let (app, project_id, root) = (app.clone(), project_id.clone(), root_path.clone());
+        thread::spawn(move || run_config_loop(config_rx, app, project_id, root, config_watch));
+        config_tx
+    });
+
     let thread_project_id = project_id.clone();
     let thread_root = root_buf.clone();
+    let config_path = root_buf.join(CONFIG_FILE);
     let event_name = format!("fs-change-{}", project_id);
 
     thread::spawn(move || {
@@ -101,6 +145,11 @@ pub fn start(
             let mut candidates: HashSet<PathBuf> = HashSet::new();
             for event in events {
                 for path in &event.event.paths {
+                    if *path == config_path {
+                        if let Some(config_tx) = &config_tx {
+                            let _ = config_tx.send(());
+                        }
+                    }
                     // A `.meta` change is really a change to its host asset's
                     // Unity metadata (the GUID) — remap to the host so it gets
                     // re-parsed. `is_trackable_path` below drops sidecars, so
@@ -122,14 +171,14 @@ pub fn start(
                     }
                     if p.exists() {
                         // Existing path: track only real asset files (extensioned).
-                        is_trackable_path(p, &thread_root)
+                        is_trackable_path(p, &thread_root, include_hidden)
                     } else {
                         // Deletion: the path is gone. It may be a tracked file, or a
                         // directory whose removal macOS coalesces into one event on
                         // the extensionless directory path. Let it through (dropping
                         // the extension requirement) so apply_changes can remove the
                         // tracked files at or under it; keep the hidden/.meta guards.
-                        path_shape_trackable(p, &thread_root)
+                        path_shape_trackable(p, &thread_root, include_hidden)
                     }
                 })
                 .collect();
@@ -138,8 +187,11 @@ pub fn start(
                 continue;
             }
 
-            let payload =
-                apply_changes(&thread_project_id, &filtered, ignore_matcher.as_ref());
+            let payload = apply_changes(
+                &thread_project_id,
+                &filtered,
+                &overrides,
+            );
 
             if let Ok(ev) = payload {
                 let _ = app.emit(&event_name, &ev);
@@ -152,6 +204,54 @@ pub fn start(
     })
 }
 
+/// Body of the config thread. Each change notice starts a quiet period that
+/// every further notice restarts; once it elapses the config is reloaded
+/// once. Exits when the watcher (and with it the sender) is dropped.
+fn run_config_loop(
+    rx: mpsc::Receiver<()>,
+    app: AppHandle,
+    project_id: String,
+    root_path: String,
+    mode: ConfigWatchMode,
+) {
+    while rx.recv().is_ok() {
+        if !settle(&rx, CONFIG_QUIET) {
+            return;
+        }
+
+        let config = match crate::load_validated_rule_config(&root_path) {
+            Ok(config) => config,
+            Err(e) => {
+                let _ = app.emit(&format!("config-error-{}", project_id), &e);
+                continue;
+            }
+        };
+        let _ = app.emit(&format!("config-changed-{}", project_id), ());
+        if mode != ConfigWatchMode::Reanalyze {
+            continue;
+        }
+        match crate::analyze_with_config(&app, &project_id, &config) {
+            Ok(result) => {
+                let _ = app.emit(&format!("analysis-updated-{}", project_id), &result);
+            }
+            // No scan yet, or a cancelled run: the next save retries.
+            Err(e) => eprintln!("[watcher {}] re-analysis failed: {}", project_id, e),
+        }
+    }
+}
+
+/// Swallow further notices until none has arrived for `quiet`. `false` when
+/// the sender went away meanwhile.
+fn settle(rx: &mpsc::Receiver<()>, quiet: Duration) -> bool {
+    loop {
+        match rx.recv_timeout(quiet) {
+            Ok(()) => continue,
+            Err(RecvTimeoutError::Timeout) => return true,
+            Err(RecvTimeoutError::Disconnected) => return false,
+        }
+    }
+}
+
 /// Apply a batch of candidate paths to the project's cached scan result.
 ///
 /// For each path:
@@ -169,7 +269,7 @@ pub fn start(
 fn apply_changes(
     project_id: &str,
     candidates: &[PathBuf],
-    ignore_matcher: Option<&scanner::IgnoreMatcher>,
+    overrides: &scanner::ExtensionOverrides,
 ) -> Result<FsChangeEvent, String> {
     let event = project::with_mut(project_id, |state| {
         let scan_result = state
@@ -187,6 +287,9 @@ fn apply_changes(
             .collect();
 
         let mut updated: Vec<AssetInfo> = Vec::new();
+        // The versions `updated` replaced and the removed assets, as the
+        // directory tree counted them.
+        let mut superseded: Vec<AssetInfo> = Vec::new();
         // A set so a directory candidate and one of its own file candidates in
         // the same batch can't schedule the same asset for removal twice.
         let mut removed_set: HashSet<String> = HashSet::new();
@@ -197,9 +300,14 @@ fn apply_changes(
             let path_str = scanner::path_to_string(path);
 
             if path.is_file() {
-                if let Some(asset) = scanner::parse_asset_file(path, &project_type) {
+                if let Some(asset) =
+                    scanner::parse_asset_file(path, &project_type, overrides, None, true)
+                {
                     if let Some(&idx) = path_to_idx.get(&path_str) {
-                        scan_result.assets[idx] = asset.clone();
+                        superseded.push(std::mem::replace(
+                            &mut scan_result.assets[idx],
+                            asset.clone(),
+                        ));
                     } else {
                         scan_result.assets.push(asset.clone());
                         path_to_idx.insert(path_str.clone(), scan_result.assets.len() - 1);
@@ -236,7 +344,7 @@ fn apply_changes(
             .collect();
         removed_indices.sort_unstable_by(|a, b| b.cmp(a));
         for idx in removed_indices {
-            scan_result.assets.swap_remove(idx);
+            superseded.push(scan_result.assets.swap_remove(idx));
         }
 
         let removed: Vec<String> = removed_set.into_iter().collect();
@@ -255,12 +363,12 @@ fn apply_changes(
         }
         scan_result.type_counts = type_counts.clone();
 
-        let new_tree = scanner::build_directory_tree(
-            Path::new(&scan_result.root_path),
-            &scan_result.assets,
-            ignore_matcher,
-        );
-        scan_result.directory_tree = new_tree.clone();
+        // Patch the tree instead of rebuilding it: a single save touches
+        // one ancestor chain, and the rebuild re-walks every directory.
+        scan_result
+            .directory_tree
+            .apply_delta(&updated, &superseded);
+        let new_tree = scan_result.directory_tree.clone();
 
         Ok(FsChangeEvent {
             updated,
@@ -308,12 +416,13 @@ fn meta_host_path(path: &Path) -> Option<PathBuf> {
 
 /// Path-shape checks shared by tracked asset files and tracked-path
 /// *deletions*: the path is inside `root`, has no hidden path components, and
-/// its file name is neither a dotfile nor a `.meta` sidecar. Unlike
+/// its file name is neither a dotfile nor a `.meta` sidecar — "hidden" as the
+/// scan judged it (`scanner::skips_entry_name` under `include_hidden`). Unlike
 /// `is_trackable_path` this does NOT require an extension — a deleted directory
 /// (which macOS surfaces as a single event on the extensionless directory
 /// path, never per-child removals) must still be processed so its tracked
 /// children can be removed.
-fn path_shape_trackable(path: &Path, root: &Path) -> bool {
+fn path_shape_trackable(path: &Path, root: &Path, include_hidden: bool) -> bool {
     let rel = match path.strip_prefix(root) {
         Ok(r) => r,
         Err(_) => return false,
@@ -321,19 +430,19 @@ fn path_shape_trackable(path: &Path, root: &Path) -> bool {
 
     for component in rel.components() {
         let name = component.as_os_str().to_string_lossy();
-        if name.starts_with('.') {
+        if scanner::skips_entry_name(&name, include_hidden) {
             return false;
         }
     }
 
     let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
-    !(file_name.is_empty() || file_name.starts_with('.') || file_name.ends_with(".meta"))
+    !(file_name.is_empty() || file_name.ends_with(".meta"))
 }
 
 /// Mirrors the scanner's discovery filters: skip hidden path components (e.g.
 /// `.git/`, `.vscode/`), `.meta` sidecars, and files without an extension.
-fn is_trackable_path(path: &Path, root: &Path) -> bool {
-    path_shape_trackable(path, root) && path.extension().is_some()
+fn is_trackable_path(path: &Path, root: &Path, include_hidden: bool) -> bool {
+    path_shape_trackable(path, root, include_hidden) && path.extension().is_some()
 }
 
 /// Whether `asset_path` names a file at or under `deleted`. Component-wise (via
@@ -384,11 +493,13 @@ mod tests {
         let root = Path::new("/proj");
         assert!(!is_trackable_path(
             Path::new("/proj/.git/HEAD"),
-            root
+            root,
+            false
         ));
         assert!(!is_trackable_path(
             Path::new("/proj/sub/.hidden/file.png"),
-            root
+            root,
+            false
         ));
     }
 
@@ -405,7 +516,7 @@ mod tests {
         // Extensionless host: remapped, but is_trackable_path drops it later
         // (mirrors the scanner, which only tracks extensioned files).
         let host = meta_host_path(Path::new("/proj/Makefile.meta")).unwrap();
-        assert!(!is_trackable_path(&host, Path::new("/proj")));
+        assert!(!is_trackable_path(&host, Path::new("/proj"), false));
     }
 
     #[test]
@@ -413,22 +524,46 @@ mod tests {
         let root = Path::new("/proj");
         assert!(!is_trackable_path(
             Path::new("/proj/foo.png.meta"),
-            root
+            root,
+            false
         ));
-        assert!(!is_trackable_path(Path::new("/proj/.env"), root));
+        assert!(!is_trackable_path(Path::new("/proj/.env"), root, false));
     }
 
     #[test]
     fn trackable_requires_extension() {
         let root = Path::new("/proj");
-        assert!(!is_trackable_path(Path::new("/proj/Makefile"), root));
-        assert!(is_trackable_path(Path::new("/proj/sub/foo.png"), root));
+        assert!(!is_trackable_path(Path::new("/proj/Makefile"), root, false));
+        assert!(is_trackable_path(
+            Path::new("/proj/sub/foo.png"),
+            root,
+            false
+        ));
     }
 
     #[test]
     fn trackable_rejects_outside_root() {
         let root = Path::new("/proj");
-        assert!(!is_trackable_path(Path::new("/other/foo.png"), root));
+        assert!(!is_trackable_path(Path::new("/other/foo.png"), root, false));
+    }
+
+    #[test]
+    fn a_burst_of_config_saves_settles_into_one_reload() {
+        let (tx, rx) = mpsc::channel::<()>();
+        let saver = thread::spawn(move || {
+            for _ in 0..3 {
+                tx.send(()).unwrap();
+                thread::sleep(Duration::from_millis(20));
+            }
+            tx
+        });
+        rx.recv().unwrap();
+        assert!(settle(&rx, Duration::from_millis(200)));
+        // Every save in the burst was absorbed by the same settle.
+        assert!(rx.try_recv().is_err());
+
+        drop(saver.join().unwrap());
+        assert!(!settle(&rx, Duration::from_millis(200)));
     }
 
     #[test]
@@ -446,19 +581,64 @@ mod tests {
     #[test]
     fn deleted_directory_path_is_shape_trackable_despite_no_extension() {
         let root = Path::new("/proj");
-        assert!(path_shape_trackable(Path::new("/proj/Models"), root));
-        assert!(path_shape_trackable(Path::new("/proj/sub/Textures"), root));
+        assert!(path_shape_trackable(Path::new("/proj/Models"), root, false));
+        assert!(path_shape_trackable(
+            Path::new("/proj/sub/Textures"),
+            root,
+            false
+        ));
         // But an *existing* extensionless path is still not a trackable asset.
-        assert!(!is_trackable_path(Path::new("/proj/Models"), root));
+        assert!(!is_trackable_path(Path::new("/proj/Models"), root, false));
     }
 
     #[test]
     fn path_shape_trackable_still_rejects_hidden_meta_and_outside_root() {
         let root = Path::new("/proj");
-        assert!(!path_shape_trackable(Path::new("/proj/.git/HEAD"), root));
-        assert!(!path_shape_trackable(Path::new("/proj/sub/.hidden/file.png"), root));
-        assert!(!path_shape_trackable(Path::new("/proj/tex.png.meta"), root));
-        assert!(!path_shape_trackable(Path::new("/other/foo.png"), root));
+        assert!(!path_shape_trackable(
+            Path::new("/proj/.git/HEAD"),
+            root,
+            false
+        ));
+        assert!(!path_shape_trackable(
+            Path::new("/proj/sub/.hidden/file.png"),
+            root,
+            false
+        ));
+        assert!(!path_shape_trackable(
+            Path::new("/proj/tex.png.meta"),
+            root,
+            false
+        ));
+        assert!(!path_shape_trackable(
+            Path::new("/other/foo.png"),
+            root,
+            false
+        ));
+    }
+
+    #[test]
+    fn include_hidden_tracks_dot_paths_except_git() {
+        let root = Path::new("/proj");
+        assert!(is_trackable_path(
+            Path::new("/proj/.config/app.json"),
+            root,
+            true
+        ));
+        assert!(is_trackable_path(
+            Path::new("/proj/.splash.png"),
+            root,
+            true
+        ));
+        assert!(!path_shape_trackable(
+            Path::new("/proj/.git/HEAD"),
+            root,
+            true
+        ));
+        assert!(!is_trackable_path(
+            Path::new("/proj/.config/app.json"),
+            root,
+            false
+        ));
     }
 
     #[test]
@@ -23,7 +23,7 @@ import { useUiStore } from "./stores/uiStore";
 import { useSettingsStore } from "./stores/settingsStore";
 import { restoreSession } from "./stores/sessionStore";
 import { Toasts } from "./components/Toasts";
-import { exportTextFile } from "./lib/exportFile";
+import { exportFile, exportTextFile } from "./lib/exportFile";
 import { useKeyboardShortcuts } from "./hooks/useKeyboardShortcuts";
 import { useShallow } from "zustand/react/shallow";
 import { isMacOS } from "./lib/platform";
@@ -146,11 +146,13 @@ function App() {
 
   const handleExportCsv = () => {
     if (!activeProjectId) return;
-    exportTextFile({
+    // Streamed to disk by the backend — a 200k-asset CSV is tens of MB.
+    exportFile({
       defaultName: "assets.csv",
       filterName: "CSV",
       extensions: ["csv"],
-      fetchContents: () => invoke<string>("export_to_csv", { projectId: activeProjectId }),
+      write: (path) =>
+        invoke<number>("export_to_csv_file", { projectId: activeProjectId, path }),
     });
   };
 
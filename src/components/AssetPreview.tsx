@@ -450,7 +450,10 @@ export function AssetPreview() {
             {metadata?.dcc_source_kind && (
               <>
                 <dt>{t("assetPreview.dccSource")}</dt>
-                <dd>{dccSourceLabel(metadata.dcc_source_kind)}</dd>
+                <dd>
+                  {dccSourceLabel(metadata.dcc_source_kind)}
+                  {metadata.dcc_version && ` ${metadata.dcc_version}`}
+                </dd>
               </>
             )}
           </dl>
@@ -518,6 +521,12 @@ export function AssetPreview() {
                   <dd>{metadata.material_count}</dd>
                 </>
               )}
+              {metadata.object_count !== undefined && (
+                <>
+                  <dt>{t("assetPreview.objects")}</dt>
+                  <dd>{metadata.object_count.toLocaleString()}</dd>
+                </>
+              )}
             </dl>
           </div>
         )}
@@ -139,7 +139,9 @@ type VirtualRow =
 /// `related_paths` (root-relative, original first) is the group identity;
 /// every member issue carries the same list, so the first occurrence emits
 /// the group row and the rest are dropped. Issues without the field (other
-/// rules, or results from an older backend) pass through untouched.
+/// rules, or results from an older backend) pass through untouched, as do
+/// sampled `duplicate.likely` groups: their members were never compared in
+/// full, so they get no cleanup card.
 function collapseDuplicates(issues: Issue[]): VirtualRow[] {
   const emitted = new Set<string>();
   const rows: VirtualRow[] = [];
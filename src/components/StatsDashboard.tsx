@@ -28,6 +28,7 @@ interface ProjectStats {
   total_assets: number;
   total_size: number;
   type_distribution: Record<string, number>;
+  type_sizes: Record<string, number>;
   size_distribution: Record<string, number>;
   extension_distribution: Record<string, number>;
   largest_files: Array<{
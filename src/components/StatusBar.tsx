@@ -19,6 +19,9 @@ export function StatusBar() {
     error,
     scanProgress,
     cancelScan,
+    isAnalyzing,
+    duplicateProgress,
+    cancelAnalysis,
     projectPath,
     openProject,
     clearError,
@@ -93,10 +96,11 @@ export function StatusBar() {
   }
 
   if (isScanning && scanProgress) {
-    const progressPercent =
-      scanProgress.total && scanProgress.total > 0
-        ? Math.round((scanProgress.current / scanProgress.total) * 100)
-        : 0;
+    const progressPercent = Math.round(scanProgress.percent);
+    // Discovery's total is only an estimate from the previous scan, so the
+    // raw counter is shown for the parse phase alone.
+    const showCounter =
+      scanProgress.phase === "parsing" && !!scanProgress.total && scanProgress.total > 0;
 
     const currentFile = scanProgress.current_file
       ? basename(scanProgress.current_file)
@@ -105,20 +109,20 @@ export function StatusBar() {
     return (
       <footer className="tc-status" data-state="scanning">
         <span className="tc-scan-phase">{t(`scanPhase.${scanProgress.phase}`)}…</span>
-        {scanProgress.total && scanProgress.total > 0 && (
-          <>
-            <span className="mono" style={{ color: "var(--text-2)" }}>
+        <span className="mono" style={{ color: "var(--text-2)" }}>
+          {showCounter && (
+            <>
               {scanProgress.current} / {scanProgress.total}{" "}
-              <span style={{ color: "var(--text-3)" }}>({progressPercent}%)</span>
-            </span>
-            <span
-              className="tc-scan-progress"
-              style={{ ["--p" as string]: `${progressPercent}%` } as React.CSSProperties}
-            >
-              <i />
-            </span>
-          </>
-        )}
+            </>
+          )}
+          <span style={{ color: "var(--text-3)" }}>({progressPercent}%)</span>
+        </span>
+        <span
+          className="tc-scan-progress"
+          style={{ ["--p" as string]: `${progressPercent}%` } as React.CSSProperties}
+        >
+          <i />
+        </span>
         {currentFile && <span className="tc-scan-file">…/{currentFile}</span>}
         <span className="tc-status-spacer" />
         <button onClick={cancelScan} className="tc-scan-cancel">
@@ -129,6 +133,31 @@ export function StatusBar() {
     );
   }
 
+  if (isAnalyzing && duplicateProgress) {
+    const { bytes_hashed, bytes_total } = duplicateProgress;
+    const progressPercent = Math.round((bytes_hashed / bytes_total) * 100);
+    return (
+      <footer className="tc-status" data-state="scanning">
+        <span className="tc-scan-phase">{t("statusBar.hashingDuplicates")}…</span>
+        <span className="mono" style={{ color: "var(--text-2)" }}>
+          {formatFileSize(bytes_hashed)} / {formatFileSize(bytes_total)}{" "}
+          <span style={{ color: "var(--text-3)" }}>({progressPercent}%)</span>
+        </span>
+        <span
+          className="tc-scan-progress"
+          style={{ ["--p" as string]: `${progressPercent}%` } as React.CSSProperties}
+        >
+          <i />
+        </span>
+        <span className="tc-status-spacer" />
+        <button onClick={cancelAnalysis} className="tc-scan-cancel">
+          <X size={12} />
+          {t("statusBar.cancel")}
+        </button>
+      </footer>
+    );
+  }
+
   if (isScanning) {
     return (
       <footer className="tc-status" data-state="scanning">
@@ -19,7 +19,26 @@ export async function exportTextFile(opts: {
   /** Producer for the file contents — runs only after a destination is chosen. */
   fetchContents: () => Promise<string>;
 }): Promise<void> {
-  const { defaultName, filterName, extensions, fetchContents } = opts;
+  const { fetchContents, ...dialog } = opts;
+  await exportFile({
+    ...dialog,
+    write: async (path) => {
+      const contents = await fetchContents();
+      await invoke("save_text_file", { path, contents });
+    },
+  });
+}
+
+/// `exportTextFile` for exports the backend writes to `path` itself, so
+/// large payloads never cross IPC.
+export async function exportFile(opts: {
+  defaultName: string;
+  filterName: string;
+  extensions: string[];
+  /** Writes the file — runs only after a destination is chosen. */
+  write: (path: string) => Promise<unknown>;
+}): Promise<void> {
+  const { defaultName, filterName, extensions, write } = opts;
   const { push } = useToastStore.getState();
   try {
     const path = await save({
@@ -28,8 +47,7 @@ export async function exportTextFile(opts: {
     });
     if (!path) return; // user cancelled the dialog
 
-    const contents = await fetchContents();
-    await invoke("save_text_file", { path, contents });
+    await write(path);
 
     push({
       kind: "success",
@@ -2,7 +2,7 @@ import { create } from "zustand";
 import { invoke } from "@tauri-apps/api/core";
 import { listen, UnlistenFn } from "@tauri-apps/api/event";
 import { basename, dirname } from "../lib/pathUtils";
-import type { ScanResult, AssetInfo, ScanProgress, AssetType, ProjectType, AnalysisResult, UndoResult, HistoryEntry, GitInfo, GitStatusMap, GitFileStatus, FsChangeEvent, DirectoryNode } from "../types/asset";
+import type { ScanResult, AssetInfo, ScanProgress, AssetType, ProjectType, AnalysisResult, DuplicateProgress, UndoResult, HistoryEntry, GitInfo, GitStatusMap, GitFileStatus, FsChangeEvent, DirectoryNode, ReferenceIndexSummary, AutoTagOutcome } from "../types/asset";
 import { useSettingsStore } from "./settingsStore";
 import { evictThumbs } from "../lib/thumbnailCache";
 
@@ -102,6 +102,10 @@ export interface ProjectData {
   /// deleted files. Cleared by the next successful runAnalysis.
   analysisStale: boolean;
   isAnalyzing: boolean;
+  /// Bytes read by the running analysis' duplicate-hashing pass, from
+  /// `duplicate-progress-{id}` events. `null` outside an analysis and
+  /// while nothing needs hashing (every candidate cached).
+  duplicateProgress: DuplicateProgress | null;
   viewMode: ViewMode;
   selectedDirectory: string | null;
   selectedAsset: AssetInfo | null;
@@ -131,6 +135,7 @@ const createDefaultProjectData = (id: string, path: string): ProjectData => ({
   analysisResult: null,
   analysisStale: false,
   isAnalyzing: false,
+  duplicateProgress: null,
   viewMode: "assets",
   selectedDirectory: null,
   selectedAsset: null,
@@ -221,6 +226,7 @@ interface ProjectState {
   analysisResult: AnalysisResult | null;
   analysisStale: boolean;
   isAnalyzing: boolean;
+  duplicateProgress: DuplicateProgress | null;
   viewMode: ViewMode;
   selectedDirectory: string | null;
   selectedAsset: AssetInfo | null;
@@ -258,6 +264,9 @@ interface ProjectState {
 
   // Active project actions
   cancelScan: () => Promise<void>;
+  /// Stop the active project's in-flight analysis; `runAnalysis` then
+  /// settles with an "Analysis cancelled" error.
+  cancelAnalysis: () => Promise<void>;
   /// Cache-clearing force rescan, shared by the Header rescan button and the
   /// Ctrl+R shortcut (the button's tooltip advertises Ctrl+R, so the two must
   /// behave identically). No-op without an active project or while scanning.
@@ -326,6 +335,7 @@ const updateActiveProject = (
   if ('analysisResult' in updates) result.analysisResult = updates.analysisResult ?? null;
   if ('analysisStale' in updates) result.analysisStale = updates.analysisStale ?? false;
   if ('isAnalyzing' in updates) result.isAnalyzing = updates.isAnalyzing ?? false;
+  if ('duplicateProgress' in updates) result.duplicateProgress = updates.duplicateProgress ?? null;
   if ('viewMode' in updates) result.viewMode = updates.viewMode ?? "assets";
   if ('selectedDirectory' in updates) result.selectedDirectory = updates.selectedDirectory ?? null;
   if ('selectedAsset' in updates) result.selectedAsset = updates.selectedAsset ?? null;
@@ -353,6 +363,7 @@ const syncFromActiveProject = (project: ProjectData | undefined): Partial<Projec
       analysisResult: null,
       analysisStale: false,
       isAnalyzing: false,
+      duplicateProgress: null,
       viewMode: "assets",
       selectedDirectory: null,
       selectedAsset: null,
@@ -393,6 +404,7 @@ const syncFromActiveProject = (project: ProjectData | undefined): Partial<Projec
     analysisResult: project.analysisResult,
     analysisStale: project.analysisStale,
     isAnalyzing: project.isAnalyzing,
+    duplicateProgress: project.duplicateProgress,
     viewMode: project.viewMode,
     selectedDirectory: project.selectedDirectory,
     selectedAsset: project.selectedAsset,
@@ -515,6 +527,7 @@ export const useProjectStore = create<ProjectState>((set, get) => ({
   analysisResult: null,
   analysisStale: false,
   isAnalyzing: false,
+  duplicateProgress: null,
   viewMode: "assets",
   selectedDirectory: null,
   selectedAsset: null,
@@ -668,6 +681,14 @@ export const useProjectStore = create<ProjectState>((set, get) => ({
         console.warn("Failed to probe tidycraft.toml:", err);
       }
 
+      // Auto-tag rules run before the result lands, so the tag reload the
+      // new scanResult triggers already sees their tags. No-op without rules.
+      try {
+        await invoke<AutoTagOutcome[]>("apply_auto_tags", { projectId });
+      } catch (err) {
+        console.warn("Failed to apply auto-tag rules:", err);
+      }
+
       // Apply scan result to the project that owns it (not necessarily active).
       const state = get();
       const target = state.projects.get(projectId);
@@ -713,6 +734,17 @@ export const useProjectStore = create<ProjectState>((set, get) => ({
       // guard here.
       get().refreshGitInfo(projectId);
 
+      // Warm the Unity reference index in the background so the first
+      // dependency / unused-asset query doesn't pay for the full parse.
+      // Best-effort: every reference command refreshes it anyway.
+      if (result.project_type === "unity") {
+        invoke<ReferenceIndexSummary>("build_unity_reference_index", {
+          projectId,
+        }).catch((err) =>
+          console.warn("Failed to build Unity reference index:", err)
+        );
+      }
+
       // Start the filesystem watcher now that the cache is populated.
       // Events that arrive before the scan completes would be no-ops on the
       // backend (no cached_scan to patch), so ordering matters.
@@ -897,6 +929,16 @@ export const useProjectStore = create<ProjectState>((set, get) => ({
     }
   },
 
+  cancelAnalysis: async () => {
+    const { activeProjectId } = get();
+    if (!activeProjectId) return;
+    try {
+      await invoke("cancel_analysis", { projectId: activeProjectId });
+    } catch (err) {
+      console.error("Failed to cancel analysis:", err);
+    }
+  },
+
   rescan: async () => {
     const { projectPath, isScanning } = get();
     if (!projectPath || isScanning) return;
@@ -957,7 +999,20 @@ export const useProjectStore = create<ProjectState>((set, get) => ({
       set(patch);
     };
 
-    patchProject({ isAnalyzing: true });
+    patchProject({ isAnalyzing: true, duplicateProgress: null });
+
+    // Bytes-hashed progress of the duplicate pass, the long phase on
+    // projects with large video / model files. Only emitted while there
+    // is uncached content to read.
+    let unlistenProgress: UnlistenFn | null = null;
+    try {
+      unlistenProgress = await listen<DuplicateProgress>(
+        `duplicate-progress-${startedProjectId}`,
+        (event) => patchProject({ duplicateProgress: event.payload })
+      );
+    } catch (err) {
+      console.warn("Failed to listen for duplicate progress:", err);
+    }
 
     // Re-read config at click time (not just at scan-complete) so users
     // can edit `tidycraft.toml` and re-run without rescanning. IO failure
@@ -1002,6 +1057,9 @@ export const useProjectStore = create<ProjectState>((set, get) => ({
         isAnalyzing: false,
         hasCustomConfig,
       });
+    } finally {
+      unlistenProgress?.();
+      patchProject({ duplicateProgress: null });
     }
   },
 
@@ -1,6 +1,6 @@
 import { create } from "zustand";
 import { invoke } from "@tauri-apps/api/core";
-import type { Tag, AssetTagsMap } from "../types/asset";
+import type { Tag, AssetTagsMap, AssetFilter } from "../types/asset";
 import { useProjectStore, registerTagFilterBridge } from "./projectStore";
 
 interface TagsState {
@@ -27,6 +27,10 @@ interface TagsState {
   addTagToAsset: (assetPath: string, tagId: string) => Promise<void>;
   removeTagFromAsset: (assetPath: string, tagId: string) => Promise<void>;
   addTagToAssets: (assetPaths: string[], tagId: string) => Promise<void>;
+  /** Tag / untag every scanned asset matching `filter` in one backend call.
+   *  Resolves to how many assets changed. */
+  tagAssetsByFilter: (filter: AssetFilter, tagId: string) => Promise<number>;
+  untagAssetsByFilter: (filter: AssetFilter, tagId: string) => Promise<number>;
   setTagFilter: (tagId: string | null) => void;
   toggleTagFilter: (tagId: string, multiSelect?: boolean) => void;
   clearTagFilters: () => void;
@@ -38,6 +42,18 @@ interface TagsState {
 const activeProjectId = (): string | null =>
   useProjectStore.getState().activeProjectId;
 
+// Bulk (filter-based) edits touch paths the store never saw, so re-read the
+// whole map instead of patching it. Dropped on a mid-flight project switch.
+const refreshAssetTags = async (
+  projectId: string,
+  set: (patch: Partial<TagsState>) => void
+) => {
+  const assetTags = await invoke<AssetTagsMap>("get_all_asset_tags", {
+    projectId,
+  });
+  if (activeProjectId() === projectId) set({ assetTags });
+};
+
 export const useTagsStore = create<TagsState>((set, get) => ({
   tags: [],
   assetTags: {},
@@ -218,6 +234,30 @@ export const useTagsStore = create<TagsState>((set, get) => ({
     }
   },
 
+  tagAssetsByFilter: async (filter: AssetFilter, tagId: string) => {
+    const projectId = activeProjectId();
+    if (!projectId) return 0;
+    const count = await invoke<number>("tag_assets_by_filter", {
+      projectId,
+      filter,
+      tagId,
+    });
+    if (count > 0) await refreshAssetTags(projectId, set);
+    return count;
+  },
+
+  untagAssetsByFilter: async (filter: AssetFilter, tagId: string) => {
+    const projectId = activeProjectId();
+    if (!projectId) return 0;
+    const count = await invoke<number>("untag_assets_by_filter", {
+      projectId,
+      filter,
+      tagId,
+    });
+    if (count > 0) await refreshAssetTags(projectId, set);
+    return count;
+  },
+
   setTagFilter: (tagId: string | null) => {
     set({ tagFilter: tagId, tagFilters: tagId ? [tagId] : [] });
   },
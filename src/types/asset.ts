@@ -18,10 +18,16 @@ export interface AssetMetadata {
   width?: number;
   height?: number;
   has_alpha?: boolean;
+  /** 8, 16, or 32 (float); only for formats the `image` crate decodes. */
+  bits_per_channel?: number;
   // Model metadata
   vertex_count?: number;
   face_count?: number;
   material_count?: number;
+  /** `.blend`: Object datablocks; FBX: Model nodes; glTF: nodes. */
+  object_count?: number;
+  /** glTF animations / FBX AnimationStacks in the file. */
+  animation_count?: number;
   // Audio / video metadata (duration is shared)
   duration_secs?: number;
   sample_rate?: number;
@@ -33,6 +39,8 @@ export interface AssetMetadata {
   // Texture extras
   color_space?: string;
   mipmap_count?: number;
+  /** DXGI format name from DDS headers, e.g. "BC7_UNORM". */
+  texture_format?: string;
   /** When set, identifies this file as an authoring/source file from
    *  a DCC tool ("blender" / "maya_ascii" / "maya_binary" / "max" /
    *  "zbrush" / "substance_painter" / "substance_designer" / "marvelous"
@@ -41,6 +49,16 @@ export interface AssetMetadata {
    *  and exempts the file from naming-prefix checks backend-side.
    *  Mirror of Rust `AssetMetadata.dcc_source_kind`. */
   dcc_source_kind?: string;
+  /** Version of the DCC tool that last saved the file (`.blend` header).
+   *  Mirror of Rust `AssetMetadata.dcc_version`. */
+  dcc_version?: string;
+  /** Unity: the `.meta` TextureImporter `maxTextureSize`. */
+  import_max_size?: number;
+  /** Unity: the `.meta` TextureImporter `enableMipMap`. */
+  import_mipmaps?: boolean;
+  /** PNG / JPEG embedded resolution as [x, y] DPI; absent when the file
+   *  stores none. Mirror of Rust `AssetMetadata.dpi`. */
+  dpi?: [number, number];
 }
 
 export interface AssetInfo {
@@ -73,6 +91,28 @@ export interface ScanResult {
   total_size: number;
   type_counts: Record<string, number>;
   project_type?: ProjectType;
+  /** Present only when the scan was run with `collectTimings: true`. */
+  timings?: ScanTimings;
+  /** Inventory-only scan (`metadata: false`): no file was decoded, so
+   *  every asset's `metadata` is absent. */
+  metadata_skipped: boolean;
+  project_info: ProjectMeta | null;
+}
+
+/** Mirrors Rust `ParseTiming`. */
+export interface ParseTiming {
+  files: number;
+  total_ns: number;
+}
+
+/** Mirrors Rust `ScanTimings` — phase wall times plus CPU time per
+ *  extension / parser, summed across threads. */
+export interface ScanTimings {
+  discover_ms: number;
+  parse_ms: number;
+  build_ms: number;
+  by_extension: Record<string, ParseTiming>;
+  by_parser: Record<string, ParseTiming>;
 }
 
 export type ScanPhase =
@@ -87,6 +127,8 @@ export interface ScanProgress {
   current: number;
   total?: number;
   current_file: string;
+  /** Overall 0–100 completion across all phases; never goes backwards. */
+  percent: number;
 }
 
 // Analysis types
@@ -115,6 +157,255 @@ export interface AnalysisResult {
   by_rule: Record<string, number>;
 }
 
+/**
+ * Mirrors Rust `DuplicateProgress` — payload of `duplicate-progress-{id}`
+ * while an analysis hashes duplicate candidates. Bytes, not files: cached
+ * hashes count toward neither side.
+ */
+export interface DuplicateProgress {
+  bytes_hashed: number;
+  bytes_total: number;
+}
+
+/**
+ * Mirrors Rust `AnalysisProgress` — payload of `analysis-progress-{id}`
+ * while `analyze_assets_async` runs. `current` / `total` are assets checked
+ * in `rules` and bytes hashed in `duplicates`.
+ */
+export interface AnalysisProgress {
+  phase: "rules" | "duplicates" | "cross_asset" | "completed";
+  current: number;
+  total: number;
+}
+
+/**
+ * Mirrors Rust `unity_index::RefreshSnapshot` — payload of
+ * `unused-scan-progress-{id}` while `find_unused_assets_async` runs:
+ * reference files parsed out of those needing a parse.
+ */
+export interface UnusedScanProgress {
+  current: number;
+  total: number;
+}
+
+/**
+ * Mirrors Rust `issue_rollup::DirectoryIssues` — `get_issues_by_directory`.
+ * The scan's directory tree with the latest analysis' issues counted per
+ * folder, each count covering the folder's whole subtree. Folders with no
+ * issues beneath them are omitted.
+ */
+export interface DirectoryIssues {
+  name: string;
+  path: string;
+  children: DirectoryIssues[];
+  error_count: number;
+  warning_count: number;
+  info_count: number;
+}
+
+/**
+ * Mirrors Rust `unused_report::UnusedAssetReport` — `get_unused_asset_report`.
+ * The `find_unused_assets` set grouped by type, largest group first.
+ */
+export interface UnusedAssetReport {
+  groups: UnusedTypeGroup[];
+  total_count: number;
+  /** Bytes a cleanup would reclaim. */
+  total_size: number;
+}
+
+export interface UnusedTypeGroup {
+  asset_type: AssetType;
+  count: number;
+  total_size: number;
+  /** Largest first. */
+  paths: string[];
+}
+
+/**
+ * Mirrors Rust `fingerprint::ProjectFingerprint` — `compute_project_fingerprint`.
+ * Only fingerprints taken with the same `algorithm` compare.
+ */
+export interface ProjectFingerprint {
+  fingerprint: string;
+  file_count: number;
+  algorithm: "sha256" | "sha1" | "blake3";
+}
+
+/** Mirrors Rust `DirectoryChildSize` — one row of `get_directory_breakdown`. */
+export interface DirectoryChildSize {
+  name: string;
+  path: string;
+  is_directory: boolean;
+  size: number;
+  file_count: number;
+  /** Share of the parent directory's total, 0–100. */
+  percent: number;
+}
+
+/** Mirrors Rust `PathDepth` — one asset in `get_deepest_paths`. */
+export interface PathDepth {
+  path: string;
+  /** Folders between the project root and the file. */
+  depth: number;
+  /** Characters in the absolute path. */
+  length: number;
+}
+
+/** Mirrors Rust `DeepestPaths` — from `get_deepest_paths`. */
+export interface DeepestPaths {
+  deepest: PathDepth[];
+  longest: PathDepth[];
+}
+
+/** Mirrors Rust `BrokenGodotReference` — from `find_broken_godot_references`. */
+export interface BrokenGodotReference {
+  source_path: string;
+  reference: string;
+  resource_type: string | null;
+  reason: "missing" | "unresolvable_uid";
+}
+
+/** Mirrors Rust `AssetCriticality` — from `get_asset_criticality`. */
+export interface AssetCriticality {
+  path: string;
+  direct_dependents: number;
+  transitive_dependents: number;
+}
+
+/** Mirrors Rust `scanner::ProjectMeta` — engine settings read by the scan
+ * (Unity only). */
+export interface ProjectMeta {
+  engine_version: string | null;
+  product_name: string | null;
+  company_name: string | null;
+}
+
+/** Mirrors Rust `scanner::CacheChanges` — `get_changes_since_cache`: what
+ *  an incremental scan would pick up, from file stats alone. Lists sorted. */
+export interface CacheChanges {
+  /** False when there's no cache yet; every file is then `added`. */
+  has_cache: boolean;
+  added: string[];
+  modified: string[];
+  deleted: string[];
+  added_count: number;
+  modified_count: number;
+  deleted_count: number;
+}
+
+/** Mirrors Rust `symlinks::SymlinkInfo` — `find_symlinks`: one link or
+ *  junction in the project, sorted by path. */
+export interface SymlinkInfo {
+  path: string;
+  /** As stored in the link; relative to the link's folder when relative. */
+  target: string;
+  /** The target resolves outside the project root. */
+  outside_root: boolean;
+  /** Nothing exists at the target. */
+  broken: boolean;
+}
+
+/** Mirrors Rust `scanner::EngineVersion` — `get_engine_version`. */
+export interface EngineVersion {
+  engine: ProjectType;
+  /** As the engine writes it, e.g. "2022.3.10f1", "5.3", "4.2". */
+  version: string;
+}
+
+/** Mirrors Rust `UnanalyzedGroup` — `get_unanalyzed_assets`. Assets of one
+ *  extension no enabled type-specific rule applies to, largest group first. */
+export interface UnanalyzedGroup {
+  /** Lowercase, without the dot; empty for extensionless files. */
+  extension: string;
+  count: number;
+  total_size: number;
+  paths: string[];
+}
+
+/** Mirrors Rust `line_endings::LineEnding` — the `ending` argument of
+ *  `fix_mixed_line_endings` and `[line_endings] target`. */
+export type LineEnding = "lf" | "crlf" | "cr";
+
+/** Mirrors Rust `RuleCoverage` — one value of the rule id → coverage map
+ *  from `get_rule_coverage`. `pass_rate` is null when no asset applied. */
+export interface RuleCoverage {
+  applicable: number;
+  failed: number;
+  pass_rate: number | null;
+}
+
+/** Mirrors Rust `thumbnail::CacheRepair` — `verify_thumbnail_cache`. */
+export interface CacheRepair {
+  removed: number;
+  bytes_reclaimed: number;
+}
+
+/** Mirrors Rust `duplicate::DuplicateGroup` — `get_duplicate_groups`. One
+ *  set of identical files, most wasted bytes first. */
+export interface DuplicateGroup {
+  hash: string;
+  /** Size of each member, in bytes. */
+  size: number;
+  /** `size × (paths.length − 1)`. */
+  wasted_bytes: number;
+  /** Matched on sampled chunks only (`[duplicate].sample_min_size`). */
+  sampled: boolean;
+  /** Every member, absolute and path-sorted. */
+  paths: string[];
+}
+
+/** Mirrors Rust `unity_closure::AssetClosure` — `get_scene_asset_closure`.
+ *  Every asset the scene reaches, the scene included, sorted. */
+export interface AssetClosure {
+  paths: string[];
+  total_size: number;
+}
+
+/** Mirrors Rust `DuplicateMaterialGroup` — one group from
+ *  `find_duplicate_materials`: materials with the same shader, keywords and
+ *  property values, sorted. */
+export interface DuplicateMaterialGroup {
+  paths: string[];
+}
+
+/** Mirrors Rust `unity_importers::TypeMismatch` — one asset from
+ *  `reconcile_asset_types` whose `.meta` importer implies another type than
+ *  its extension. */
+export interface TypeMismatch {
+  path: string;
+  guessed: AssetType;
+  importer: string;
+  importer_type: AssetType;
+}
+
+/** Mirrors Rust `name_collisions::NameCollision` — one name from
+ *  `find_name_collisions` with the assets in different folders owning it. */
+export interface NameCollision {
+  name: string;
+  paths: string[];
+}
+
+/** Mirrors Rust `RuleDescription` — one entry from `get_rule_catalog`. */
+export interface RuleDescription {
+  rule_id: string;
+  name: string;
+  /** Part of `rule_id` before the first dot, e.g. `texture`. */
+  category: string;
+  description: string;
+  default_severity: Severity;
+  auto_fixable: boolean;
+}
+
+/** Mirrors Rust `ConfigDifference` — one entry from `diff_config`. */
+export interface ConfigDifference {
+  /** Dotted key path, e.g. `texture.max_size`. */
+  path: string;
+  /** `null` when the setting is unset on that side. */
+  default: unknown;
+  current: unknown;
+}
+
 // ============ Fix-it (auto-fixable naming) Types ============
 
 /** Mirrors Rust `NamingFixPreview` — one proposed auto-fix from
@@ -148,14 +439,26 @@ export interface BatchRenameResult {
 
 // ============ Unity Types ============
 
+/** Mirrors Rust `UnityRenameResult` — the outcome of `rename_unity_asset`.
+ *  `meta_moved` is false when the asset had no `.meta` yet. */
+export interface UnityRenameResult {
+  new_path: string;
+  meta_moved: boolean;
+}
+
 /** Mirrors Rust `unity::UnityReference` (one GUID reference inside a
  *  Unity YAML file). Only the count is consumed by the UI today. */
 export interface UnityReference {
   guid: string;
   file_id?: number | null;
   ref_type?: number | null;
+  reference_kind: ReferenceKind;
 }
 
+/** Mirrors Rust `unity::ReferenceKind`. `prefab_base` links a prefab variant
+ *  to its base; `nested` is a prefab instanced inside a prefab or scene. */
+export type ReferenceKind = "direct" | "prefab_base" | "nested";
+
 /** Mirrors Rust `unity::UnityFileInfo` — on-demand parse of one Unity
  *  YAML asset via `get_unity_file_info`. `components` is deduped and
  *  sorted backend-side; it's only populated for prefab/scene files. */
@@ -252,6 +555,7 @@ export interface DependencyNode {
 export interface DependencyEdge {
   from: string;
   to: string;
+  kind: ReferenceKind;
 }
 
 export interface DependencyGraph {
@@ -259,9 +563,91 @@ export interface DependencyGraph {
   edges: DependencyEdge[];
 }
 
+/** Mirrors Rust `ScriptLoad` — one string-keyed load (`Resources.Load`,
+ *  Addressables key, …) found in a C# script. `key` is the literal, or the
+ *  literal prefix of a `dynamic` key built at runtime. */
+export interface ScriptLoad {
+  script_path: string;
+  line: number;
+  call: string;
+  key: string | null;
+  dynamic: boolean;
+  matches: string[];
+}
+
+/** Mirrors Rust `ScriptReferences` — result of
+ *  `scan_script_string_references`. */
+export interface ScriptReferences {
+  referenced_paths: string[];
+  loads: ScriptLoad[];
+}
+
+export type ShaderSource = "project" | "package" | "builtin";
+
+/** Mirrors Rust `ShaderUsageEntry` — one shader and the materials using
+ *  it. `file_id` only distinguishes built-ins (they share two GUIDs). */
+export interface ShaderUsageEntry {
+  name: string;
+  source: ShaderSource;
+  path: string | null;
+  package: string | null;
+  guid: string;
+  file_id: number;
+  material_count: number;
+  materials: string[];
+}
+
+/** Mirrors Rust `ShaderUsage` — result of `get_shader_usage`. `missing`
+ *  lists materials whose shader resolves nowhere (`guid: null` = none
+ *  assigned). */
+export interface ShaderUsage {
+  shaders: ShaderUsageEntry[];
+  missing: { material_path: string; guid: string | null }[];
+}
+
+/** Mirrors Rust `AtlasCandidate` — one group from `suggest_atlas_candidates`. */
+export interface AtlasCandidate {
+  /** Prefabs and scenes whose materials use these textures. */
+  sources: string[];
+  textures: string[];
+  total_pixels: number;
+  /** 2048 or 4096; `null` when the set doesn't fit either. */
+  atlas_size: number | null;
+}
+
+/** Mirrors Rust `ChannelPackingGroup` — one group from
+ *  `suggest_channel_packing`. `channels` is in R, G, B, A order. */
+export interface ChannelPackingGroup {
+  base: string;
+  channels: {
+    channel: "R" | "G" | "B" | "A";
+    role: "ao" | "roughness" | "metallic" | "height" | "smoothness";
+    path: string;
+  }[];
+  separate_bytes: number;
+  packed_bytes: number;
+  saved_bytes: number;
+}
+
+/** Mirrors Rust `UnresolvedBindings` — one entry of
+ *  `analyze_animation_bindings`. `target_path` is the prefab or model the
+ *  clip was checked against; `null` for malformed paths. */
+export interface UnresolvedBindings {
+  clip_path: string;
+  target_path: string | null;
+  unresolved: string[];
+}
+
+/** Mirrors Rust `ReferenceIndexSummary` — size of the Unity reference
+ *  index after `build_unity_reference_index`. */
+export interface ReferenceIndexSummary {
+  files: number;
+  edges: number;
+}
+
 // ============ Undo Types ============
 
-export type OperationType = "rename" | "move" | "delete";
+export type OperationType = "rename" | "move" | "delete" | "edit";
 
 export interface UndoResult {
   success: boolean;
@@ -302,6 +688,38 @@ export interface GitInfo {
 
 export type GitStatusMap = Record<string, GitFileStatus>;
 
+/** Mirrors Rust `AssetsByGitStatus` — scanned assets bucketed by status
+ *  via `get_assets_by_git_status`. Clean assets are omitted. */
+export interface AssetsByGitStatus {
+  groups: Partial<Record<GitFileStatus, string[]>>;
+  counts: Partial<Record<GitFileStatus, number>>;
+}
+
+/** Mirrors Rust `ConflictRegion`; line numbers are 1-based. */
+export interface ConflictRegion {
+  start_line: number;
+  separator_line: number | null;
+  end_line: number;
+}
+
+/** Mirrors Rust `ConflictedAsset` — one entry from `find_conflicted_assets`. */
+export interface ConflictedAsset {
+  path: string;
+  region_count: number;
+  regions: ConflictRegion[];
+  /** Unity scene/prefab/material: better resolved by taking one side. */
+  unity_yaml: boolean;
+}
+
+/** Mirrors Rust `LargeUntrackedAsset` — one entry from `find_untracked_large_assets`. */
+export interface LargeUntrackedAsset {
+  path: string;
+  size: number;
+  extension: string;
+  /** `.gitattributes` line to add, e.g. `*.psd filter=lfs diff=lfs merge=lfs -text`. */
+  suggested_pattern: string;
+}
+
 // ============ Tag Types ============
 
 export interface Tag {
@@ -316,6 +734,52 @@ export interface Tag {
 
 export type AssetTagsMap = Record<string, Tag[]>;
 
+/** Mirrors Rust `AssetFilter` — criteria for the bulk tag commands. Every
+ *  field is optional and they AND together; `{}` matches every asset. */
+export interface AssetFilter {
+  asset_types?: AssetType[];
+  /** Without the dot, case-insensitive. */
+  extensions?: string[];
+  /** Absolute directory; matches assets below it. */
+  directory?: string;
+  name_contains?: string;
+  min_size?: number;
+  max_size?: number;
+  min_width?: number;
+  max_width?: number;
+  min_height?: number;
+  max_height?: number;
+  has_alpha?: boolean;
+}
+
+/** Mirrors Rust `AutoTagRule` — assets matching `when` get the tag `then`
+ *  (a tag id, or a name; unknown names create the tag). `when.directory`
+ *  is project-relative here. */
+export interface AutoTagRule {
+  when: AssetFilter;
+  then: string;
+}
+
+/** Mirrors Rust `AutoTagOutcome` — one rule's result from `apply_auto_tags`. */
+export interface AutoTagOutcome {
+  tag_id: string;
+  tag_name: string;
+  matched: number;
+  tagged: number;
+  created_tag: boolean;
+}
+
+/** Mirrors Rust `AssetDetail` — returned by `get_asset_detail`. */
+export interface AssetDetail {
+  asset: AssetInfo;
+  git_status: GitFileStatus | null;
+  tags: Tag[];
+  /** Per-asset rule findings only; cross-asset checks need a full run. */
+  issues: Issue[];
+  unity_guid: string | null;
+  reference_count: number | null;
+}
+
 // ============ Delete Types ============
 
 export interface DeleteError {
@@ -328,6 +792,46 @@ export interface DeleteResult {
   errors: DeleteError[];
 }
 
+/** Mirrors Rust `LodMember` */
+export interface LodMember {
+  path: string;
+  /** null for the unsuffixed base model, which counts as LOD0. */
+  level: number | null;
+  vertex_count: number | null;
+}
+
+/** Mirrors Rust `LodChain` — one entry of `analyze_lod_chains`. */
+export interface LodChain {
+  base_name: string;
+  directory: string;
+  members: LodMember[];
+  issues: Issue[];
+}
+
+/** Mirrors Rust `BlockedDelete` */
+export interface BlockedDelete {
+  path: string;
+  /** Referrers that stay on disk; referrers deleted in the same request don't block. */
+  referenced_by: string[];
+}
+
+/** Mirrors Rust `SafeDeleteResult` — result of `safe_delete_assets`. */
+export interface SafeDeleteResult {
+  success_paths: string[];
+  blocked: BlockedDelete[];
+  errors: DeleteError[];
+}
+
+/** Mirrors Rust `DeletionImpact` — result of `analyze_deletion_impact`. */
+export interface DeletionImpact {
+  /** Nothing outside the set references these. */
+  safe: string[];
+  /** Still referenced from outside the set: deleting them breaks those referrers. */
+  breaking: BlockedDelete[];
+  broken_referrers: string[];
+  broken_reference_count: number;
+}
+
 // ============ Move / Copy / Duplicate ============
 
 export interface FileOpError {
@@ -347,6 +851,13 @@ export interface FileOpResult {
 
 // ============ Filesystem Watcher Types ============
 
+/** Mirrors Rust `watcher::ConfigWatchMode` — `start_watching`'s optional
+ *  `configWatch`. `notify` emits `config-changed-{projectId}` /
+ *  `config-error-{projectId}` (message string) after edits to
+ *  `tidycraft.toml` settle; `reanalyze` also emits
+ *  `analysis-updated-{projectId}` with a fresh `AnalysisResult`. */
+export type ConfigWatchMode = "off" | "notify" | "reanalyze";
+
 /// Payload of the `fs-change-{projectId}` Tauri event.
 export interface FsChangeEvent {
   /** Assets that were added or modified. Merge into scanResult.assets by `path`. */
@@ -359,3 +870,78 @@ export interface FsChangeEvent {
   total_size: number;
   type_counts: Record<string, number>;
 }
+
+// ============ Build Estimate Types ============
+
+/** Mirrors Rust `build_estimate::TypeBuildEstimate`. */
+export interface TypeBuildEstimate {
+  asset_type: AssetType;
+  asset_count: number;
+  source_size: number;
+  estimated_size: number;
+}
+
+/** Mirrors Rust `build_estimate::BuildSizeEstimate` — the outcome of
+ *  `estimate_build_size`. A ballpark from the `[build_estimate]` factors;
+ *  `fallback_count` assets lacked dimensions / duration and count at
+ *  `fallback_ratio` of their source size. */
+export interface BuildSizeEstimate {
+  estimated_size: number;
+  source_size: number;
+  /** Largest estimate first. */
+  by_type: TypeBuildEstimate[];
+  fallback_count: number;
+}
+
+/** Mirrors Rust `stats_delta::Delta` — one number then and now. */
+export interface StatsValueDelta {
+  baseline: number;
+  current: number;
+  change: number;
+  /** `change` as a percentage of `baseline`; null when it was zero. */
+  percent: number | null;
+}
+
+/** Mirrors Rust `stats_delta::KeyedDelta`. */
+export interface KeyedStatsDelta {
+  /** Asset type or size bucket. */
+  key: string;
+  count: StatsValueDelta;
+  /** Null for size buckets and for baselines without per-type sizes. */
+  size: StatsValueDelta | null;
+}
+
+/** Mirrors Rust `stats_delta::StatsDelta` — the outcome of
+ *  `compare_stats_to_baseline`. */
+export interface StatsDelta {
+  total_assets: StatsValueDelta;
+  total_size: StatsValueDelta;
+  /** Largest size growth first. */
+  by_type: KeyedStatsDelta[];
+  /** Buckets that gained files, most growth first. */
+  grown_size_buckets: KeyedStatsDelta[];
+}
+
+/** Mirrors Rust `audio_consistency::SampleRateCount`. */
+export interface SampleRateCount {
+  sample_rate: number;
+  count: number;
+}
+
+/** Mirrors Rust `audio_consistency::SampleRateOutlier`. */
+export interface SampleRateOutlier {
+  path: string;
+  sample_rate: number;
+}
+
+/** Mirrors Rust `audio_consistency::SampleRateConsistency` — the outcome of
+ *  `check_sample_rate_consistency`. */
+export interface SampleRateConsistency {
+  /** Most used rate first. */
+  rates: SampleRateCount[];
+  /** The rate over half of the audio uses, if any. */
+  dominant_rate: number | null;
+  /** Audio not at `dominant_rate`, by path; empty without one. */
+  inconsistent: SampleRateOutlier[];
+  unknown_count: number;
+}